@@ -0,0 +1,147 @@
+use ahash::{AHashMap, AHashSet};
+
+use super::{Hypergraph, Node};
+
+impl Hypergraph {
+    /// Checks whether the hypergraph is alpha-acyclic, through the GYO (Graham–Yu–Özsoyoğlu) reduction: nodes
+    /// belonging to a single hyperedge and hyperedges contained in another one (ears) are repeatedly removed, and the
+    /// hypergraph is acyclic if and only if nothing remains. See `join_tree` for more details.
+    ///
+    /// # Returns
+    /// - `bool` - `true` if the hypergraph is alpha-acyclic, `false` otherwise.
+    ///
+    /// # Performance
+    /// - `O(s*d)`, where `s` is the sum of the sizes of the hyperedges and `d` the maximum degree of a node.
+    pub fn is_alpha_acyclic(&self) -> bool {
+        self.join_tree().is_some()
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Returns the join tree of the hypergraph if it is alpha-acyclic, built by the GYO reduction: whenever an ear is
+    /// removed because it is contained in another hyperedge, the two (original) hyperedges are joined.
+    ///
+    /// Hyperedges are compared as sets of nodes. Only the hyperedges which lost some nodes are checked again, since
+    /// removing nodes from a hyperedge can only make it contained in another one. If the hypergraph is not connected,
+    /// the result is a join forest, with a tree for every connected component.
+    ///
+    /// # Returns
+    /// - `Option<Vec<(Vec<Node>, Vec<Node>)>>` - `Some` list of the edges of the join tree, each as the removed ear and
+    ///   the hyperedge containing it, `None` if the hypergraph is not alpha-acyclic.
+    ///
+    /// # Performance
+    /// - `O(s*d)`, where `s` is the sum of the sizes of the hyperedges and `d` the maximum degree of a node.
+    pub fn join_tree(&self) -> Option<Vec<(Vec<Node>, Vec<Node>)>> {
+        // Sorted, so that the join tree does not depend on the order of edge_list
+        let mut originals: Vec<&Vec<Node>> = self.edge_list.values().map(|hyperedge| &hyperedge.nodes).collect();
+        originals.sort();
+
+        let mut remaining: Vec<AHashSet<Node>> =
+            originals.iter().map(|nodes| nodes.iter().copied().collect()).collect();
+        let mut incidences: AHashMap<Node, AHashSet<usize>> = AHashMap::new();
+        for (e, nodes) in remaining.iter().enumerate() {
+            for node in nodes.iter() {
+                incidences.entry(*node).or_default().insert(e);
+            }
+        }
+
+        let mut alive = vec![true; originals.len()];
+        let mut node_queue: Vec<Node> =
+            incidences.iter().filter(|(_, edges)| edges.len() == 1).map(|(node, _)| *node).collect();
+        let mut edge_queue: Vec<usize> = (0..originals.len()).rev().collect();
+        let mut res = Vec::new();
+
+        while !node_queue.is_empty() || !edge_queue.is_empty() {
+            // Nodes in a single hyperedge
+            while let Some(node) = node_queue.pop() {
+                if let Some(edges) = incidences.remove(&node) {
+                    for e in edges {
+                        remaining[e].remove(&node);
+                        edge_queue.push(e);
+                    }
+                }
+            }
+
+            // Ears
+            while let Some(e) = edge_queue.pop() {
+                if !alive[e] {
+                    continue;
+                }
+
+                // The ear is contained in a hyperedge through its member with the lowest degree
+                let witness = remaining[e]
+                    .iter()
+                    .min_by_key(|node| incidences[*node].len())
+                    .and_then(|pivot| {
+                        incidences[pivot]
+                            .iter()
+                            .copied()
+                            .filter(|f| *f != e && remaining[e].is_subset(&remaining[*f]))
+                            .min()
+                    });
+
+                if witness.is_none() && !remaining[e].is_empty() {
+                    continue;
+                }
+
+                alive[e] = false;
+                if let Some(f) = witness {
+                    res.push((originals[e].clone(), originals[f].clone()));
+                }
+                for node in remaining[e].drain() {
+                    let edges = incidences.get_mut(&node).unwrap();
+                    edges.remove(&e);
+                    if edges.len() == 1 {
+                        node_queue.push(node);
+                    }
+                }
+            }
+        }
+
+        if alive.iter().any(|alive| *alive) {
+            None
+        } else {
+            Some(res)
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    #[test]
+    fn test_alpha_acyclic() {
+        // A path of overlapping hyperedges
+        let hg = Hypergraph::from(&[vec![0, 1, 2], vec![2, 3], vec![3, 4, 5]]);
+        assert!(hg.is_alpha_acyclic());
+        assert_eq!(hg.join_tree().unwrap().len(), 2);
+
+        // A triangle of pairwise overlapping hyperedges, with and without a covering hyperedge
+        let mut hg = Hypergraph::from(&[vec![0, 1], vec![1, 2], vec![2, 0]]);
+        assert!(!hg.is_alpha_acyclic());
+        assert_eq!(hg.join_tree(), None);
+
+        hg.add_edge(&[0, 1, 2]);
+        let mut tree = hg.join_tree().unwrap();
+        tree.sort();
+        assert_eq!(
+            tree,
+            vec![(vec![0, 1], vec![0, 1, 2]), (vec![1, 2], vec![0, 1, 2]), (vec![2, 0], vec![0, 1, 2])]
+        );
+
+        assert_eq!(Hypergraph::new(false).join_tree(), Some(Vec::new()));
+    }
+
+    #[test]
+    fn test_join_forest() {
+        // Two components, the second one covered by its largest hyperedge
+        let hg = Hypergraph::from(&[vec![0, 1], vec![5, 6, 7], vec![6, 7, 8], vec![7, 8, 5], vec![5, 6, 7, 8]]);
+        let tree = hg.join_tree().unwrap();
+        assert_eq!(tree.len(), hg.num_edges() - 2);
+
+        // A cycle of length 4, only partially covered
+        let hg = Hypergraph::from(&[vec![0, 1], vec![1, 2], vec![2, 3], vec![3, 0], vec![0, 1, 2]]);
+        assert!(!hg.is_alpha_acyclic());
+    }
+}
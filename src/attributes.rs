@@ -0,0 +1,416 @@
+use ahash::AHashMap;
+
+use super::{EdgeID, Hypergraph, HypergraphError, Node};
+
+type Attrs = AHashMap<String, AttrValue>;
+
+/// Value of an attribute attached to a node or a hyperedge, eg the name of an author or the year of a paper.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AttrValue {
+    /// An integer value.
+    Int(i64),
+
+    /// A floating point value.
+    Float(f64),
+
+    /// A textual value.
+    Text(String),
+
+    /// A boolean value.
+    Bool(bool),
+}
+
+impl Hypergraph {
+    /// `type Node = i64`
+    ///
+    /// Sets the attribute `key` of a node to `value`, replacing the previous value if one.
+    ///
+    /// Attributes follow the node: they are dropped when the node is removed (weakly or strongly), and kept by
+    /// `clone`, by the subhypergraphs which keep the node and by `HypergraphData`. They are not compared by `==`.
+    ///
+    /// # Parameters
+    /// - `node` : `Node` - The node to annotate.
+    /// - `key` : `&str` - The name of the attribute.
+    /// - `value` : `AttrValue` - The value of the attribute.
+    ///
+    /// # Returns
+    /// - `Result<Option<AttrValue>, HypergraphError>` - `Ok` containing the previous value of the attribute, if one.
+    ///   Returns `Err(HypergraphError::NodeNotFound)` if the node is not in the hypergraph.
+    ///
+    /// # Performance
+    /// - `O(1)` expected.
+    pub fn set_node_attr(&mut self, node: Node, key: &str, value: AttrValue) -> Result<Option<AttrValue>, HypergraphError> {
+        if !self.incidence_list.contains_key(&node) {
+            return Err(HypergraphError::NodeNotFound(node));
+        }
+        Ok(self.node_attrs.entry(node).or_default().insert(key.to_string(), value))
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Returns the attribute `key` of a node.
+    ///
+    /// # Parameters
+    /// - `node` : `Node` - The node.
+    /// - `key` : `&str` - The name of the attribute.
+    ///
+    /// # Returns
+    /// - `Option<&AttrValue>` - The value of the attribute, `None` if the node is not in the hypergraph or has no such
+    ///   attribute.
+    ///
+    /// # Performance
+    /// - `O(1)` expected.
+    pub fn get_node_attr(&self, node: Node, key: &str) -> Option<&AttrValue> {
+        self.node_attrs.get(&node)?.get(key)
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Removes the attribute `key` of a node.
+    ///
+    /// # Parameters
+    /// - `node` : `Node` - The node.
+    /// - `key` : `&str` - The name of the attribute.
+    ///
+    /// # Returns
+    /// - `Option<AttrValue>` - The removed value, `None` if the node is not in the hypergraph or has no such
+    ///   attribute.
+    ///
+    /// # Performance
+    /// - `O(1)` expected.
+    pub fn remove_node_attr(&mut self, node: Node, key: &str) -> Option<AttrValue> {
+        let attrs = self.node_attrs.get_mut(&node)?;
+        let res = attrs.remove(key);
+        if attrs.is_empty() {
+            self.node_attrs.remove(&node);
+        }
+        res
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Returns all the attributes of a node, sorted by name.
+    ///
+    /// # Parameters
+    /// - `node` : `Node` - The node.
+    ///
+    /// # Returns
+    /// - `Option<Vec<(&str, &AttrValue)>>` - The `(key, value)` pairs of the node, empty if it has no attributes.
+    ///   `None` if the node is not in the hypergraph.
+    ///
+    /// # Performance
+    /// - `O(a*log(a))`, where `a` is the number of attributes of the node.
+    pub fn node_attrs(&self, node: Node) -> Option<Vec<(&str, &AttrValue)>> {
+        if !self.incidence_list.contains_key(&node) {
+            return None;
+        }
+
+        Some(compute_sorted_attrs(self.node_attrs.get(&node)))
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Sets the attribute `key` of a hyperedge to `value`, replacing the previous value if one. Hyperedges are looked
+    /// up by their nodes, in their order, as in `check_edge`.
+    ///
+    /// Attributes follow the hyperedge: when `remove_node` shrinks it, they move to its new `EdgeID`, and if it is
+    /// merged into an existing hyperedge, the latter keeps its own values and gets only the attributes it lacks. They
+    /// are dropped when the hyperedge is removed, and kept by `clone`, by the subhypergraphs which keep the hyperedge
+    /// and by `HypergraphData`. They are not compared by `==`.
+    ///
+    /// # Parameters
+    /// - `edge` : `&[Node]` - The hyperedge to annotate.
+    /// - `key` : `&str` - The name of the attribute.
+    /// - `value` : `AttrValue` - The value of the attribute.
+    ///
+    /// # Returns
+    /// - `Result<Option<AttrValue>, HypergraphError>` - `Ok` containing the previous value of the attribute, if one.
+    ///   Returns `Err(HypergraphError::EdgeNotFound)` if the hyperedge is not in the hypergraph.
+    ///
+    /// # Performance
+    /// - `O(k)`, where `k` is the size of the hyperedge.
+    pub fn set_edge_attr(&mut self, edge: &[Node], key: &str, value: AttrValue) -> Result<Option<AttrValue>, HypergraphError> {
+        let edge_id = Self::compute_edge_id(edge);
+        if !self.edge_list.contains_key(&edge_id) {
+            return Err(HypergraphError::EdgeNotFound);
+        }
+        Ok(self.edge_attrs.entry(edge_id).or_default().insert(key.to_string(), value))
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Returns the attribute `key` of a hyperedge.
+    ///
+    /// # Parameters
+    /// - `edge` : `&[Node]` - The hyperedge.
+    /// - `key` : `&str` - The name of the attribute.
+    ///
+    /// # Returns
+    /// - `Option<&AttrValue>` - The value of the attribute, `None` if the hyperedge is not in the hypergraph or has no
+    ///   such attribute.
+    ///
+    /// # Performance
+    /// - `O(k)`, where `k` is the size of the hyperedge.
+    pub fn get_edge_attr(&self, edge: &[Node], key: &str) -> Option<&AttrValue> {
+        self.edge_attrs.get(&Self::compute_edge_id(edge))?.get(key)
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Removes the attribute `key` of a hyperedge.
+    ///
+    /// # Parameters
+    /// - `edge` : `&[Node]` - The hyperedge.
+    /// - `key` : `&str` - The name of the attribute.
+    ///
+    /// # Returns
+    /// - `Option<AttrValue>` - The removed value, `None` if the hyperedge is not in the hypergraph or has no such
+    ///   attribute.
+    ///
+    /// # Performance
+    /// - `O(k)`, where `k` is the size of the hyperedge.
+    pub fn remove_edge_attr(&mut self, edge: &[Node], key: &str) -> Option<AttrValue> {
+        let edge_id = Self::compute_edge_id(edge);
+        let attrs = self.edge_attrs.get_mut(&edge_id)?;
+        let res = attrs.remove(key);
+        if attrs.is_empty() {
+            self.edge_attrs.remove(&edge_id);
+        }
+        res
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Returns all the attributes of a hyperedge, sorted by name.
+    ///
+    /// # Parameters
+    /// - `edge` : `&[Node]` - The hyperedge.
+    ///
+    /// # Returns
+    /// - `Option<Vec<(&str, &AttrValue)>>` - The `(key, value)` pairs of the hyperedge, empty if it has no attributes.
+    ///   `None` if the hyperedge is not in the hypergraph.
+    ///
+    /// # Performance
+    /// - `O(k + a*log(a))`, where `k` is the size of the hyperedge and `a` the number of its attributes.
+    pub fn edge_attrs(&self, edge: &[Node]) -> Option<Vec<(&str, &AttrValue)>> {
+        self.edge_attrs_by_id(Self::compute_edge_id(edge))
+    }
+
+    /// Returns all the attributes of the hyperedge identified by `id`, as `edge_attrs`, eg for the handles returned
+    /// by `edge_id` or `visits::edge_bfs`.
+    ///
+    /// # Parameters
+    /// - `id` : `EdgeID` - The handle of the hyperedge.
+    ///
+    /// # Returns
+    /// - `Option<Vec<(&str, &AttrValue)>>` - The `(key, value)` pairs of the hyperedge, empty if it has no attributes.
+    ///   `None` if the hyperedge is not in the hypergraph.
+    ///
+    /// # Performance
+    /// - `O(a*log(a))`, where `a` is the number of attributes of the hyperedge.
+    pub fn edge_attrs_by_id(&self, id: EdgeID) -> Option<Vec<(&str, &AttrValue)>> {
+        if !self.edge_list.contains_key(&id) {
+            return None;
+        }
+        Some(compute_sorted_attrs(self.edge_attrs.get(&id)))
+    }
+
+    /// Effectively copies into `res` the attributes of the nodes and of the hyperedges which are in both hypergraphs,
+    /// and the timestamps and the multiplicities of the hyperedges.
+    pub(crate) fn compute_copy_metadata(&self, res: &mut Hypergraph) {
+        let node_attrs: Vec<(Node, Attrs)> = self
+            .node_attrs
+            .iter()
+            .filter(|(node, _)| res.incidence_list.contains_key(node))
+            .map(|(node, attrs)| (*node, attrs.clone()))
+            .collect();
+        res.node_attrs.extend(node_attrs);
+
+        let edge_attrs: Vec<(EdgeID, Attrs)> = self
+            .edge_attrs
+            .iter()
+            .filter(|(edge_id, _)| res.edge_list.contains_key(edge_id))
+            .map(|(edge_id, attrs)| (*edge_id, attrs.clone()))
+            .collect();
+        res.edge_attrs.extend(edge_attrs);
+
+        let edge_times: Vec<(EdgeID, Vec<i64>)> = self
+            .edge_times
+            .iter()
+            .filter(|(edge_id, _)| res.edge_list.contains_key(edge_id))
+            .map(|(edge_id, times)| (*edge_id, times.clone()))
+            .collect();
+        res.edge_times.extend(edge_times);
+
+        for (edge_id, hyperedge) in res.edge_list.iter_mut() {
+            if let Some(original) = self.edge_list.get(edge_id) {
+                hyperedge.multiplicity = original.multiplicity;
+            }
+        }
+    }
+
+    /// Effectively moves the attributes of the hyperedge `from` to the hyperedge `to`, which keeps its own values on
+    /// conflicts, eg after `from` is shrunk into `to`.
+    pub(crate) fn compute_move_edge_attrs(&mut self, from: EdgeID, to: EdgeID) {
+        if let Some(attrs) = self.edge_attrs.remove(&from) {
+            let target = self.edge_attrs.entry(to).or_default();
+            for (key, value) in attrs {
+                target.entry(key).or_insert(value);
+            }
+        }
+    }
+}
+
+/// Effectively lists the attributes of a node or a hyperedge, sorted by name.
+fn compute_sorted_attrs(attrs: Option<&Attrs>) -> Vec<(&str, &AttrValue)> {
+    let mut res: Vec<(&str, &AttrValue)> =
+        attrs.map(|attrs| attrs.iter().map(|(key, value)| (key.as_str(), value)).collect()).unwrap_or_default();
+    res.sort_unstable_by(|a, b| a.0.cmp(b.0));
+    res
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    fn build() -> Hypergraph {
+        let mut hg = Hypergraph::from(&[vec![1, 2, 3], vec![3, 4], vec![4, 5]]);
+        hg.set_node_attr(1, "name", AttrValue::Text("Ada".to_string())).unwrap();
+        hg.set_node_attr(1, "year", AttrValue::Int(1815)).unwrap();
+        hg.set_node_attr(3, "score", AttrValue::Float(0.5)).unwrap();
+        hg.set_node_attr(4, "active", AttrValue::Bool(true)).unwrap();
+        hg
+    }
+
+    #[test]
+    fn test_node_attrs() {
+        let mut hg = build();
+
+        assert_eq!(hg.get_node_attr(1, "year"), Some(&AttrValue::Int(1815)));
+        assert_eq!(hg.get_node_attr(1, "score"), None);
+        assert_eq!(hg.get_node_attr(9, "year"), None);
+        assert_eq!(
+            hg.node_attrs(1),
+            Some(vec![("name", &AttrValue::Text("Ada".to_string())), ("year", &AttrValue::Int(1815))])
+        );
+        assert_eq!(hg.node_attrs(2), Some(vec![]));
+        assert_eq!(hg.node_attrs(9), None);
+
+        assert_eq!(hg.set_node_attr(1, "year", AttrValue::Int(1816)), Ok(Some(AttrValue::Int(1815))));
+        assert_eq!(hg.set_node_attr(9, "year", AttrValue::Int(0)), Err(HypergraphError::NodeNotFound(9)));
+        assert_eq!(hg.remove_node_attr(1, "year"), Some(AttrValue::Int(1816)));
+        assert_eq!(hg.remove_node_attr(1, "year"), None);
+        assert_eq!(hg.remove_node_attr(1, "name"), Some(AttrValue::Text("Ada".to_string())));
+        assert_eq!(hg.node_attrs(1), Some(vec![]));
+    }
+
+    #[test]
+    fn test_node_attrs_removal() {
+        let mut hg = build();
+
+        assert!(hg.strong_remove_node(3));
+        assert_eq!(hg.get_node_attr(3, "score"), None);
+        // The node comes back without its old attributes
+        hg.add_node(3);
+        assert_eq!(hg.node_attrs(3), Some(vec![]));
+
+        assert!(hg.remove_node(4));
+        assert_eq!(hg.node_attrs(4), None);
+        assert_eq!(hg.get_node_attr(1, "year"), Some(&AttrValue::Int(1815)));
+
+        hg.contract_nodes(&[1, 2], 2, crate::MergePolicy::Replace, true).unwrap();
+        assert_eq!(hg.node_attrs(1), None);
+
+        hg.clear();
+        hg.add_node(1);
+        assert_eq!(hg.node_attrs(1), Some(vec![]));
+    }
+
+    #[test]
+    fn test_node_attrs_copies() {
+        let hg = build();
+
+        let sub = hg.subhypergraph(&[1, 2, 4]);
+        assert_eq!(sub.get_node_attr(1, "name"), Some(&AttrValue::Text("Ada".to_string())));
+        assert_eq!(sub.get_node_attr(4, "active"), Some(&AttrValue::Bool(true)));
+        assert_eq!(sub.node_attrs(3), None);
+
+        let sub = hg.subhypergraph_by_edges(&[vec![3, 4]], false, false).unwrap();
+        assert_eq!(sub.get_node_attr(3, "score"), Some(&AttrValue::Float(0.5)));
+        assert_eq!(sub.node_attrs(1), None);
+
+        let sub = hg.subhypergraph_filter(|edge, _| edge.len() == 3, true);
+        assert_eq!(sub.get_node_attr(4, "active"), Some(&AttrValue::Bool(true)));
+
+        let copy = hg.clone();
+        assert_eq!(copy.node_attrs(1), hg.node_attrs(1));
+    }
+
+    #[test]
+    fn test_edge_attrs() {
+        let mut hg = build();
+
+        assert_eq!(hg.set_edge_attr(&[3, 4], "year", AttrValue::Int(2020)), Ok(None));
+        assert_eq!(hg.set_edge_attr(&[4, 3], "year", AttrValue::Int(2020)), Err(HypergraphError::EdgeNotFound));
+        assert_eq!(hg.get_edge_attr(&[3, 4], "year"), Some(&AttrValue::Int(2020)));
+        assert_eq!(hg.edge_attrs(&[4, 5]), Some(vec![]));
+        assert_eq!(hg.edge_attrs(&[4, 6]), None);
+        assert_eq!(hg.edge_attrs_by_id(hg.edge_id(&[3, 4]).unwrap()), Some(vec![("year", &AttrValue::Int(2020))]));
+
+        assert_eq!(hg.remove_edge_attr(&[3, 4], "year"), Some(AttrValue::Int(2020)));
+        assert_eq!(hg.edge_attrs(&[3, 4]), Some(vec![]));
+
+        hg.set_edge_attr(&[3, 4], "year", AttrValue::Int(2020)).unwrap();
+        assert!(hg.remove_edge(&[3, 4]));
+        // The hyperedge comes back without its old attributes
+        hg.add_edge(&[3, 4]);
+        assert_eq!(hg.edge_attrs(&[3, 4]), Some(vec![]));
+
+        hg.set_edge_attr(&[4, 5], "year", AttrValue::Int(2019)).unwrap();
+        hg.clear_edges();
+        assert_eq!(hg.edge_attrs(&[4, 5]), None);
+    }
+
+    #[test]
+    fn test_edge_attrs_remove_node() {
+        let mut hg = Hypergraph::from(&[vec![1, 2, 3], vec![2, 3], vec![1, 4], vec![4, 5, 6]]);
+        hg.set_edge_attr(&[1, 2, 3], "venue", AttrValue::Text("A".to_string())).unwrap();
+        hg.set_edge_attr(&[1, 2, 3], "year", AttrValue::Int(2020)).unwrap();
+        hg.set_edge_attr(&[2, 3], "year", AttrValue::Int(2021)).unwrap();
+        hg.set_edge_attr(&[1, 4], "year", AttrValue::Int(2022)).unwrap();
+        hg.set_edge_attr(&[4, 5, 6], "year", AttrValue::Int(2023)).unwrap();
+
+        assert!(hg.remove_node(1));
+        // [1, 2, 3] is merged into [2, 3], which keeps its own year
+        assert_eq!(
+            hg.edge_attrs(&[2, 3]),
+            Some(vec![("venue", &AttrValue::Text("A".to_string())), ("year", &AttrValue::Int(2021))])
+        );
+        // [1, 4] is moved under the EdgeID of [4]
+        assert_eq!(hg.get_edge_attr(&[4], "year"), Some(&AttrValue::Int(2022)));
+
+        assert!(hg.remove_node(5));
+        assert_eq!(hg.get_edge_attr(&[4, 6], "year"), Some(&AttrValue::Int(2023)));
+        assert_eq!(hg.get_edge_attr(&[4, 5, 6], "year"), None);
+
+        hg.strong_remove_node(4);
+        assert_eq!(hg.edge_attrs(&[4, 6]), None);
+        assert_eq!(hg.edge_attrs.len(), 1);
+    }
+
+    #[test]
+    fn test_edge_attrs_copies() {
+        let mut hg = build();
+        hg.set_edge_attr(&[1, 2, 3], "year", AttrValue::Int(2020)).unwrap();
+        hg.set_edge_attr(&[4, 5], "year", AttrValue::Int(2021)).unwrap();
+
+        let sub = hg.subhypergraph(&[1, 2, 3, 4]);
+        assert_eq!(sub.get_edge_attr(&[1, 2, 3], "year"), Some(&AttrValue::Int(2020)));
+        assert_eq!(sub.edge_attrs(&[4, 5]), None);
+        assert_eq!(hg.clone().get_edge_attr(&[4, 5], "year"), Some(&AttrValue::Int(2021)));
+
+        hg.contract_nodes(&[5, 3], 3, crate::MergePolicy::Replace, true).unwrap();
+        assert_eq!(hg.get_edge_attr(&[4, 3], "year"), Some(&AttrValue::Int(2021)));
+        assert_eq!(hg.get_edge_attr(&[1, 2, 3], "year"), Some(&AttrValue::Int(2020)));
+    }
+}
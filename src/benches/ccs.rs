@@ -0,0 +1,24 @@
+// Compares the sequential `ccs` with the parallel `par_ccs` on a sparse random hypergraph.
+//
+// Run with `cargo bench --bench ccs --features rayon`.
+
+use std::time::Instant;
+
+use hgraph::generators::{random_uniform, SplitMix64};
+
+const NODES: usize = 500_000;
+const EDGES: usize = 300_000;
+
+fn main() {
+    let mut rng = SplitMix64::new(42);
+    let hg = random_uniform(NODES, 3, EDGES, &mut rng).unwrap();
+    println!("{} nodes, {} edges, {} threads", NODES, EDGES, rayon::current_num_threads());
+
+    let start = Instant::now();
+    let sequential = hg.ccs(None, None).unwrap();
+    println!("ccs: {} components in {:?}", sequential.len(), start.elapsed());
+
+    let start = Instant::now();
+    let parallel = hg.par_ccs();
+    println!("par_ccs: {} components in {:?}", parallel.len(), start.elapsed());
+}
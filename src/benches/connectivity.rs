@@ -0,0 +1,29 @@
+// Compares `is_connected`, which stops as soon as every node is reached, with counting all the connected components,
+// on a large connected random hypergraph.
+//
+// Run with `cargo bench --bench connectivity`.
+
+use std::time::Instant;
+
+use hgraph::generators::{Rng, SplitMix64};
+use hgraph::Hypergraph;
+
+const NODES: usize = 1_000_000;
+
+fn main() {
+    let mut rng = SplitMix64::new(42);
+    // A path through all the nodes, with a random third node in every hyperedge
+    let edges: Vec<Vec<i64>> = (0..NODES as i64 - 1)
+        .map(|i| vec![i, i + 1, rng.gen_index(NODES) as i64])
+        .collect();
+    let hg = Hypergraph::from(&edges);
+    println!("{} nodes, {} edges", hg.num_nodes(), hg.num_edges());
+
+    let start = Instant::now();
+    let connected = hg.num_ccs(None, None).unwrap() <= 1;
+    println!("num_ccs <= 1: {} in {:?}", connected, start.elapsed());
+
+    let start = Instant::now();
+    let connected = hg.is_connected(None, None).unwrap();
+    println!("is_connected: {} in {:?}", connected, start.elapsed());
+}
@@ -0,0 +1,35 @@
+// Compares loading a large list of hyperedges into an empty hypergraph with loading it into a pre-sized one.
+//
+// Run with `cargo bench --bench construction`.
+
+use std::time::Instant;
+
+use hgraph::generators::{random_uniform, SplitMix64};
+use hgraph::Hypergraph;
+
+const NODES: usize = 1_000_000;
+const EDGES: usize = 5_000_000;
+
+fn main() {
+    let mut rng = SplitMix64::new(42);
+    let hg = random_uniform(NODES, 3, EDGES, &mut rng).unwrap();
+    let edges: Vec<Vec<i64>> = hg.into_iter().map(|(edge, _)| edge).collect();
+    println!("{} nodes, {} edges", NODES, edges.len());
+
+    let start = Instant::now();
+    let mut hg = Hypergraph::new(false);
+    hg.add_edges(&edges);
+    println!("new + add_edges: {:?}", start.elapsed());
+    drop(hg);
+
+    let start = Instant::now();
+    let mut hg = Hypergraph::with_capacity(false, NODES, EDGES);
+    hg.add_edges(&edges);
+    println!("with_capacity + add_edges: {:?}", start.elapsed());
+    drop(hg);
+
+    let start = Instant::now();
+    let hg = Hypergraph::from(&edges);
+    println!("from: {:?}", start.elapsed());
+    drop(hg);
+}
@@ -0,0 +1,28 @@
+// Compares the sequential `from` with `from_par`, which hashes the hyperedges in parallel.
+//
+// Run with `cargo bench --bench from_par --features rayon`.
+
+use std::time::Instant;
+
+use hgraph::generators::{Rng, SplitMix64};
+use hgraph::Hypergraph;
+
+const NODES: usize = 2_000_000;
+const EDGES: usize = 10_000_000;
+
+fn main() {
+    let mut rng = SplitMix64::new(42);
+    let edges: Vec<Vec<i64>> =
+        (0..EDGES).map(|_| (0..3).map(|_| rng.gen_index(NODES) as i64).collect()).collect();
+    println!("{} edges, {} threads", EDGES, rayon::current_num_threads());
+
+    let start = Instant::now();
+    let sequential = Hypergraph::from(&edges);
+    println!("from: {:?}", start.elapsed());
+    drop(sequential);
+
+    let start = Instant::now();
+    let parallel = Hypergraph::from_par(&edges);
+    println!("from_par: {:?}", start.elapsed());
+    drop(parallel);
+}
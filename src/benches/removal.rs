@@ -0,0 +1,38 @@
+// Times the removal of hyperedges and nodes from a large random hypergraph.
+//
+// Run with `cargo bench --bench removal`.
+
+use std::time::Instant;
+
+use hgraph::generators::{random_uniform, Rng, SplitMix64};
+use hgraph::Hypergraph;
+
+const NODES: usize = 1_000_000;
+const EDGES: usize = 1_000_000;
+const REMOVED_EDGES: usize = 100_000;
+const REMOVED_NODES: usize = 10_000;
+
+fn main() {
+    let mut rng = SplitMix64::new(42);
+    let hg = random_uniform(NODES, 3, EDGES, &mut rng).unwrap();
+    println!("{} nodes, {} edges", hg.num_nodes(), hg.num_edges());
+
+    let edges: Vec<Vec<i64>> = hg.get_edges_sorted().unwrap().into_iter().take(REMOVED_EDGES).collect();
+    let mut copy = hg.clone();
+    let start = Instant::now();
+    copy.remove_edges(&edges);
+    println!("remove_edges ({} edges): {:?}", edges.len(), start.elapsed());
+
+    let nodes: Vec<i64> = (0..REMOVED_NODES).map(|_| rng.gen_index(NODES) as i64).collect();
+    let mut copy = hg.clone();
+    let start = Instant::now();
+    let removed = copy.strong_remove_nodes(&nodes);
+    println!("strong_remove_nodes ({} nodes, {} edges): {:?}", nodes.len(), removed, start.elapsed());
+
+    // A hub in every hyperedge of a star
+    let star: Vec<Vec<i64>> = (1..EDGES as i64).map(|i| vec![0, i, i + 1]).collect();
+    let mut star = Hypergraph::from(&star);
+    let start = Instant::now();
+    star.remove_node(0);
+    println!("remove_node (degree {}): {:?}", EDGES - 1, start.elapsed());
+}
@@ -0,0 +1,43 @@
+// Compares `subhypergraph`, which only checks the hyperedges incident to the given nodes, with a full scan of the
+// hyperedges, on a small query over hypergraphs of growing size.
+//
+// Run with `cargo bench --bench subhypergraph`.
+
+use std::collections::HashSet;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use hgraph::generators::{random_uniform, Rng, SplitMix64};
+use hgraph::Hypergraph;
+
+const REPETITIONS: u32 = 20;
+
+fn time<T>(f: impl Fn() -> T) -> Duration {
+    let start = Instant::now();
+    for _ in 0..REPETITIONS {
+        black_box(f());
+    }
+    start.elapsed() / REPETITIONS
+}
+
+fn main() {
+    let mut rng = SplitMix64::new(42);
+
+    println!("{:>10} {:>10} {:>15} {:>15}", "nodes", "edges", "incidence", "full scan");
+    for m in [1_000, 10_000, 100_000, 1_000_000] {
+        let n = m / 2;
+        let hg = random_uniform(n, 3, m, &mut rng).unwrap();
+        let query: Vec<i64> = (0..5).map(|_| rng.gen_index(n) as i64).collect();
+        let query_set: HashSet<i64> = query.iter().copied().collect();
+
+        let incidence = time(|| hg.subhypergraph(&query));
+        let full_scan = time(|| {
+            let mut res: Hypergraph =
+                hg.subhypergraph_filter(|nodes, _| nodes.iter().all(|node| query_set.contains(node)), false);
+            res.add_nodes(&query);
+            res
+        });
+
+        println!("{:>10} {:>10} {:>15?} {:>15?}", n, m, incidence, full_scan);
+    }
+}
@@ -1,10 +1,67 @@
-use ahash::AHashSet;
+use std::cmp::Ordering;
+use std::collections::VecDeque;
 
-use super::{Hypergraph, Node};
-use super::visits::_bfs;
+use ahash::{AHashMap, AHashSet};
+
+use super::{EdgeID, Hypergraph, HypergraphError, HypergraphOps, Node};
+use super::visits::{_bfs_filtered, compute_order_filter};
 
 type Component = AHashSet<Node>;
 
+/// `type Node = i64`  
+/// `type Component = AHashSet<Node>`
+///
+/// Computes the connected components of a hypergraph.  
+///
+/// If `order` or `size` is specified, only the hyperedges with that order or size connect their nodes, so a node in
+/// none of them is a component on its own.
+///
+/// # Parameters
+/// - `hg` : `&impl HypergraphOps` - The hypergraph to analyse.
+/// - `order` : `Option<usize>` - The order of the hyperedges to consider. If None, all hyperedges are considered.
+/// - `size` : `Option<usize>` - The size of the hyperedges to consider. If None, all hyperedges are considered.
+///
+/// # Returns
+/// - `Result<Vec<Component>, HypergraphError>` - `Ok` containing the list of connected components. Returns  
+///   `Err(HypergraphError::BothOrderAndSize)` if both `order` and `size` are specified.
+///
+/// # Performance
+/// - `O(n + I*log(I))`, where `n` is the number of nodes and `I` the sum of the sizes of the hyperedges.
+pub fn _ccs(hg: &impl HypergraphOps, order: Option<usize>, size: Option<usize>) -> Result<Vec<Component>, HypergraphError> {
+    Ok(_ccs_filtered(hg, compute_order_filter(order, size, false)?))
+}
+
+/// `type Node = i64`  
+/// `type Component = AHashSet<Node>`
+///
+/// Computes the connected components of a hypergraph, as `_ccs`, where only the hyperedges accepted by `filter`
+/// connect their nodes. A filter rejecting every hyperedge makes every node a component on its own.
+///
+/// # Parameters
+/// - `hg` : `&impl HypergraphOps` - The hypergraph to analyse.
+/// - `filter` : `impl Fn(&[Node], f64) -> bool` - Given the nodes and the weight of a hyperedge, whether to consider it.
+///
+/// # Returns
+/// - `Vec<Component>` - The list of connected components.
+///
+/// # Performance
+/// - `O(n + I*log(I))`, where `n` is the number of nodes and `I` the sum of the sizes of the hyperedges, plus a call of
+///   `filter` for every incident hyperedge of every node.
+pub fn _ccs_filtered(hg: &impl HypergraphOps, filter: impl Fn(&[Node], f64) -> bool) -> Vec<Component> {
+    let mut visited: AHashSet<Node> = AHashSet::new();
+    let mut cc = Vec::new();
+
+    hg.nodes().for_each(|node| {
+        if !visited.contains(&node) {
+            let res = _bfs_filtered(hg, node, None, &filter);
+            visited.extend(res.iter());
+            cc.push(res);
+        }
+    });
+
+    cc
+}
+
 impl Hypergraph {
     /// `type Node = i64`
     /// `type Component = AHashSet<Node>`.   
@@ -12,35 +69,201 @@ impl Hypergraph {
     /// Returns the connected components of the hypergraph.     
     /// 
     /// If the returned list is empty, then the hypergraph is empty, ie without nodes.
+    ///
+    /// If `order` or `size` is specified, only the hyperedges with that order or size connect their nodes, so a node in
+    /// none of them is a component on its own. Every node is always in exactly one component.
     /// 
     /// # Parameters 
     /// - `order` : `Option<usize>` - The order of the hyperedges to consider. If None, all hyperedges are considered.
     /// - `size` : `Option<usize>` - The size of the hyperedges to consider. If None, all hyperedges are considered.
     /// 
     /// # Returns 
-    /// - `Result<Vec<Component>, &str>` - `Ok` containing the list of connected components (each one is a set of nodes  
-    /// representing a connected subgraph of the hypergraph). Returns `Err` with a message if both `order` and `size`  
-    /// are specified.
+    /// - `Result<Vec<Component>, HypergraphError>` - `Ok` containing the list of connected components (each one is a set of nodes  
+    ///   representing a connected subgraph of the hypergraph). Returns `Err(HypergraphError::BothOrderAndSize)` if both `order` and `size`  
+    ///   are specified.
     /// 
     /// # Performance 
-    /// - `O(n*n*m)`, where `n` and `m` are the number of nodes and the number of hyperedges of the hypergraph, respectively.
-    pub fn ccs(&self, order: Option<usize>, size: Option<usize>) -> Result<Vec<Component>, &str> {
-        if order != None && size != None {
-            Err("Order and size cannot be both specified.")
-        } else {
-            let mut visited: AHashSet<Node> = AHashSet::new();
-            let mut cc = Vec::new();
-
-            self.get_nodes().iter().for_each(|node| {
-                if !visited.contains(&node) {
-                    let res = _bfs(self, *node, None, None, None);
-                    visited.extend(res.iter());
-                    cc.push(res);
+    /// - `O(n + I*log(I))`, where `n` is the number of nodes and `I` the sum of the sizes of the hyperedges.
+    pub fn ccs(&self, order: Option<usize>, size: Option<usize>) -> Result<Vec<Component>, HypergraphError> {
+        Ok(self.ccs_filtered(compute_order_filter(order, size, false)?))
+    }
+
+    /// `type Node = i64`
+    /// `type Component = AHashSet<Node>`.
+    ///
+    /// Returns the connected components of the hypergraph, as `ccs`, where only the hyperedges accepted by `filter`
+    /// connect their nodes, eg `|nodes, weight| nodes.len() == 3 && weight > 1.0`. A filter rejecting every hyperedge
+    /// makes every node a component on its own, as in a hypergraph without hyperedges.
+    ///
+    /// # Parameters
+    /// - `filter` : `impl Fn(&[Node], f64) -> bool` - Given the nodes and the weight of a hyperedge, whether to
+    ///   consider it.
+    ///
+    /// # Returns
+    /// - `Vec<Component>` - The list of connected components, empty if the hypergraph has no nodes.
+    ///
+    /// # Performance
+    /// - `O(n + I*log(I))`, where `n` is the number of nodes and `I` the sum of the sizes of the hyperedges, plus a
+    ///   call of `filter` for every incident hyperedge of every node.
+    pub fn ccs_filtered(&self, filter: impl Fn(&[Node], f64) -> bool) -> Vec<Component> {
+        // Isolated nodes are components on their own, so they are not visited. A node isolated in the whole hypergraph
+        // is also isolated when only some hyperedges are considered, the other ones are left to the visit
+        let mut cc: Vec<Component> = self.iter_isolated_nodes().map(|node| AHashSet::from([node])).collect();
+        let mut visited: AHashSet<Node> = cc.iter().flatten().copied().collect();
+
+        for node in self.iter_nodes() {
+            if !visited.contains(&node) {
+                let res = _bfs_filtered(self, node, None, &filter);
+                visited.extend(res.iter());
+                cc.push(res);
+            }
+        }
+
+        cc
+    }
+
+    /// `type Node = i64`
+    /// `type Component = AHashSet<Node>`.
+    ///
+    /// Returns the connected components of the hypergraph, as `ccs`, with a disjoint-set union instead of a visit: the
+    /// hyperedges are scanned once, merging the sets of their nodes, so no list of neighbors is ever built.
+    ///
+    /// If `order` or `size` is specified, the other hyperedges are skipped, and the nodes in none of the remaining
+    /// hyperedges are components on their own.
+    ///
+    /// # Parameters
+    /// - `order` : `Option<usize>` - The order of the hyperedges to consider. If None, all hyperedges are considered.
+    /// - `size` : `Option<usize>` - The size of the hyperedges to consider. If None, all hyperedges are considered.
+    ///
+    /// # Returns
+    /// - `Result<Vec<Component>, HypergraphError>` - `Ok` containing the list of connected components, in no
+    ///   particular order. Returns `Err(HypergraphError::BothOrderAndSize)` if both `order` and `size` are specified.
+    ///
+    /// # Performance
+    /// - `O((n + I)*α(n))`, where `n` is the number of nodes, `I` the sum of the sizes of the hyperedges and `α` the
+    ///   inverse Ackermann function.
+    pub fn ccs_fast(&self, order: Option<usize>, size: Option<usize>) -> Result<Vec<Component>, HypergraphError> {
+        Ok(self.ccs_fast_filtered(compute_order_filter(order, size, false)?))
+    }
+
+    /// `type Node = i64`
+    /// `type Component = AHashSet<Node>`.
+    ///
+    /// Returns the connected components of the hypergraph, as `ccs_filtered`, with the disjoint-set union of
+    /// `ccs_fast`: only the hyperedges accepted by `filter` are merged.
+    ///
+    /// # Parameters
+    /// - `filter` : `impl Fn(&[Node], f64) -> bool` - Given the nodes and the weight of a hyperedge, whether to
+    ///   consider it.
+    ///
+    /// # Returns
+    /// - `Vec<Component>` - The list of connected components, in no particular order.
+    ///
+    /// # Performance
+    /// - `O((n + I)*α(n))`, where `n` is the number of nodes, `I` the sum of the sizes of the hyperedges and `α` the
+    ///   inverse Ackermann function, plus a call of `filter` for every hyperedge.
+    pub fn ccs_fast_filtered(&self, filter: impl Fn(&[Node], f64) -> bool) -> Vec<Component> {
+        let nodes: Vec<Node> = self.incidence_list.keys().copied().collect();
+        let index: AHashMap<Node, usize> = nodes.iter().enumerate().map(|(i, node)| (*node, i)).collect();
+        let mut parent: Vec<usize> = (0..nodes.len()).collect();
+        let mut rank: Vec<u8> = vec![0; nodes.len()];
+
+        // O(I*α(n))
+        for hyperedge in self.edge_list.values() {
+            if !filter(&hyperedge.nodes, hyperedge.weight) {
+                continue;
+            }
+            if let Some((first, others)) = hyperedge.nodes.split_first() {
+                for node in others.iter() {
+                    compute_union(&mut parent, &mut rank, index[first], index[node]);
                 }
-            });
+            }
+        }
 
-            Ok(cc) 
+        // O(n*α(n))
+        let mut components: AHashMap<usize, Component> = AHashMap::new();
+        for (i, node) in nodes.iter().enumerate() {
+            components.entry(compute_find(&mut parent, i)).or_default().insert(*node);
         }
+
+        components.into_values().collect()
+    }
+
+    /// `type Node = i64`
+    /// `type Component = AHashSet<Node>`.
+    ///
+    /// Returns the connected components of the hypergraph when only the hyperedges with weight at least `min_weight`
+    /// connect their nodes, as `ccs_fast`, without building a pruned copy of the hypergraph. The nodes in none of the
+    /// remaining hyperedges are components on their own.
+    ///
+    /// # Parameters
+    /// - `min_weight` : `f64` - The minimum weight of the hyperedges to consider.
+    ///
+    /// # Returns
+    /// - `Vec<Component>` - The list of connected components, in no particular order. All the hyperedges of an
+    ///   unweighted hypergraph have weight 0.
+    ///
+    /// # Performance
+    /// - `O((n + I)*α(n))`, where `n` is the number of nodes, `I` the sum of the sizes of the hyperedges and `α` the
+    ///   inverse Ackermann function.
+    pub fn ccs_min_weight(&self, min_weight: f64) -> Vec<Component> {
+        self.ccs_fast_filtered(|_, weight| weight >= min_weight)
+    }
+
+    /// Returns the size of the largest connected component for every weight threshold, where only the hyperedges
+    /// with weight at least the threshold connect their nodes (see `ccs_min_weight`), eg to see at which threshold a
+    /// weighted co-occurrence hypergraph falls apart.
+    ///
+    /// The hyperedges are sorted by weight once and added from the heaviest, with the thresholds in decreasing order,
+    /// so the whole sweep costs about as much as a single `ccs_fast`.
+    ///
+    /// # Parameters
+    /// - `thresholds` : `&[f64]` - The minimum weights of the hyperedges to consider, in any order.
+    ///
+    /// # Returns
+    /// - `Vec<usize>` - The size of the largest connected component for each threshold, in the same order. Every
+    ///   size is 0 if the hypergraph has no nodes, and at least 1 otherwise. A NaN threshold keeps no hyperedge.
+    ///
+    /// # Performance
+    /// - `O((n + I)*α(n) + m*log(m) + t*log(t))`, where `n` and `m` are the number of nodes and hyperedges, `I` the sum
+    ///   of the sizes of the hyperedges, `t` the number of thresholds and `α` the inverse Ackermann function.
+    pub fn percolation_profile(&self, thresholds: &[f64]) -> Vec<usize> {
+        let index: AHashMap<Node, usize> = self.incidence_list.keys().enumerate().map(|(i, node)| (*node, i)).collect();
+        let mut parent: Vec<usize> = (0..index.len()).collect();
+        let mut rank: Vec<u8> = vec![0; index.len()];
+        let mut sizes: Vec<usize> = vec![1; index.len()];
+        let no_edges = usize::from(!index.is_empty());
+
+        // Weights are finite, so they are totally ordered
+        let mut edges: Vec<(f64, &Vec<Node>)> =
+            self.edge_list.values().map(|hyperedge| (hyperedge.weight, &hyperedge.nodes)).collect();
+        edges.sort_unstable_by(|a, b| b.0.total_cmp(&a.0));
+        let mut order: Vec<usize> = (0..thresholds.len()).filter(|i| !thresholds[*i].is_nan()).collect();
+        order.sort_unstable_by(|a, b| thresholds[*b].total_cmp(&thresholds[*a]));
+
+        let mut res = vec![no_edges; thresholds.len()];
+        let (mut largest, mut next) = (no_edges, 0);
+        // O(I*α(n)), every hyperedge is added once across all the thresholds
+        for i in order {
+            while next < edges.len() && edges[next].0 >= thresholds[i] {
+                if let Some((first, others)) = edges[next].1.split_first() {
+                    for node in others.iter() {
+                        let (root_a, root_b) =
+                            (compute_find(&mut parent, index[first]), compute_find(&mut parent, index[node]));
+                        if root_a != root_b {
+                            compute_union(&mut parent, &mut rank, root_a, root_b);
+                            let root = compute_find(&mut parent, root_a);
+                            sizes[root] = sizes[root_a] + sizes[root_b];
+                            largest = largest.max(sizes[root]);
+                        }
+                    }
+                }
+                next += 1;
+            }
+            res[i] = largest;
+        }
+
+        res
     }
 
     /// `type Node = i64`.   
@@ -56,34 +279,31 @@ impl Hypergraph {
     /// - `size` : `Option<usize>` - The size of the hyperedges to consider. If None, all hyperedges are considered.
     /// 
     /// # Returns 
-    /// - `Result<Component, &str>` - `Ok` containing the connected component that includes the specified node (or an  
-    ///  empty set if the node is not in the hypergraph). Returns `Err` with a message if both `order` and `size` are specified.
+    /// - `Result<Component, HypergraphError>` - `Ok` containing the connected component that includes the specified node (or an  
+    ///   empty set if the node is not in the hypergraph). Returns `Err(HypergraphError::BothOrderAndSize)` if both `order` and `size` are specified.
     /// 
     /// # Performance 
-    /// - `O(n*m)`, where `n` and `m` are the number of nodes and the number of hyperedges of the hypergraph, respectively.
-    pub fn node_cc(&self, node: Node, order: Option<usize>, size: Option<usize>) -> Result<Component, &str>{
-        if order != None && size != None {
-            Err("Order and size cannot be both specified.")
-        } else {
-            Ok(_bfs(self, node, None, order, size))
-        }
+    /// - `O(n + I*log(I))`, where `n` is the number of nodes and `I` the sum of the sizes of the hyperedges.
+    pub fn node_cc(&self, node: Node, order: Option<usize>, size: Option<usize>) -> Result<Component, HypergraphError>{
+        Ok(_bfs_filtered(self, node, None, compute_order_filter(order, size, false)?))
     }
 
     /// Return the number of connected components of the hypergraph.     
     /// 
-    /// If the returned number is 0, then the hypergraph is empty, ie without nodes.
+    /// If the returned number is 0, then the hypergraph is empty, ie without nodes. To only check if there is at most
+    /// one component, `is_connected` is faster, as it stops at the first one.
     /// 
     /// # Parameters 
     /// - `order` : `Option<usize>` - The order of the hyperedges to consider. If None, all hyperedges are considered.
     /// - `size` : `Option<usize>` - The size of the hyperedges to consider. If None, all hyperedges are considered.  
     /// 
     /// # Returns 
-    /// - `Result<usize, &str>` - `Ok` containing the number of connected components in the hypergraph. Returns `Err`    
-    /// with a message if both `order` and `size` are specified.
+    /// - `Result<usize, HypergraphError>` - `Ok` containing the number of connected components in the hypergraph. Returns `Err`    
+    ///   containing `HypergraphError::BothOrderAndSize` if both `order` and `size` are specified.
     /// 
     /// # Performance 
-    /// - `O(n*n*m)`, where `n` and `m` are the number of nodes and the number of hyperedges of the hypergraph, respectively.
-    pub fn num_ccs(&self, order: Option<usize>, size: Option<usize>) -> Result<usize, &str> {
+    /// - `O(n + I*log(I))`, where `n` is the number of nodes and `I` the sum of the sizes of the hyperedges.
+    pub fn num_ccs(&self, order: Option<usize>, size: Option<usize>) -> Result<usize, HypergraphError> {
         match self.ccs(order, size) {
             Ok(val) => Ok(val.len()),
             Err(err) => Err(err)
@@ -102,12 +322,12 @@ impl Hypergraph {
     /// - `size` : `Option<usize>` - The size of the hyperedges to consider. If None, all hyperedges are considered.  
     /// 
     /// # Returns 
-    /// - `Result<Component, &str>` - `Ok` containing the largest connected component in the hypergraph (or an empty set if   
-    /// the hypergraph has no nodes). Returns `Err` with a message if both `order` and `size` are specified.
+    /// - `Result<Component, HypergraphError>` - `Ok` containing the largest connected component in the hypergraph (or an empty set if   
+    ///   the hypergraph has no nodes). Returns `Err(HypergraphError::BothOrderAndSize)` if both `order` and `size` are specified.
     /// 
     /// # Performance 
-    /// - `O(n*n*m)`, where `n` and `m` are the number of nodes and the number of hyperedges of the hypergraph, respectively.
-    pub fn largest_cc(&self, order: Option<usize>, size: Option<usize>) -> Result<Component, &str> {
+    /// - `O(n + I*log(I))`, where `n` is the number of nodes and `I` the sum of the sizes of the hyperedges.
+    pub fn largest_cc(&self, order: Option<usize>, size: Option<usize>) -> Result<Component, HypergraphError> {
         match self.ccs(order,size) {
             Ok(ccs) => {
                 let mut res = &AHashSet::new();
@@ -126,6 +346,79 @@ impl Hypergraph {
         }
     }
 
+    /// `type Node = i64`
+    ///
+    /// Returns the connected components of the hypergraph as subhypergraphs, ie each component with the hyperedges
+    /// among its nodes, built in a single pass over the hyperedges instead of calling `subhypergraph` for each of them.
+    ///
+    /// Every hyperedge belongs to exactly one component. If `order` or `size` is specified, only the hyperedges with
+    /// that order or size are kept. The weighted flag, the weights and the merge policy are carried over.
+    ///
+    /// # Parameters
+    /// - `order` : `Option<usize>` - The order of the hyperedges to consider. If None, all hyperedges are considered.
+    /// - `size` : `Option<usize>` - The size of the hyperedges to consider. If None, all hyperedges are considered.
+    ///
+    /// # Returns
+    /// - `Result<Vec<Hypergraph>, HypergraphError>` - `Ok` containing a subhypergraph for each connected component, in
+    ///   the same order as `ccs`. Returns `Err(HypergraphError::BothOrderAndSize)` if both `order` and `size` are
+    ///   specified.
+    ///
+    /// # Performance
+    /// - `O(n + I*log(I))` to find the components, as `ccs`, then `O(n + I)` to build the subhypergraphs, where `n` is
+    ///   the number of nodes and `I` the sum of the sizes of the hyperedges.
+    pub fn ccs_subhypergraphs(&self, order: Option<usize>, size: Option<usize>) -> Result<Vec<Hypergraph>, HypergraphError> {
+        let filter = compute_order_filter(order, size, false)?;
+        let ccs = self.ccs_filtered(filter);
+
+        let mut index: AHashMap<Node, usize> = AHashMap::with_capacity(self.incidence_list.len());
+        let mut res: Vec<Hypergraph> = Vec::with_capacity(ccs.len());
+        for (i, cc) in ccs.iter().enumerate() {
+            let mut hg = Hypergraph::with_merge_policy(self.weighted, self.merge_policy);
+            for node in cc.iter() {
+                hg.add_node(*node);
+                index.insert(*node, i);
+            }
+            res.push(hg);
+        }
+
+        // O(m*k), all the nodes of a hyperedge are in the same component, so the first one is enough
+        for hyperedge in self.edge_list.values() {
+            if !filter(&hyperedge.nodes, hyperedge.weight) {
+                continue;
+            }
+            if let Some(node) = hyperedge.nodes.first() {
+                res[index[node]].add_edge_weighted(&hyperedge.nodes, hyperedge.weight);
+            }
+        }
+
+        Ok(res)
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Returns the largest connected component of the hypergraph as a subhypergraph, see `ccs_subhypergraphs`.
+    ///
+    /// If the returned hypergraph has no nodes, then the hypergraph is empty.
+    ///
+    /// # Parameters
+    /// - `order` : `Option<usize>` - The order of the hyperedges to consider. If None, all hyperedges are considered.
+    /// - `size` : `Option<usize>` - The size of the hyperedges to consider. If None, all hyperedges are considered.
+    ///
+    /// # Returns
+    /// - `Result<Hypergraph, HypergraphError>` - `Ok` containing the subhypergraph of the component with the most
+    ///   nodes. Returns `Err(HypergraphError::BothOrderAndSize)` if both `order` and `size` are specified.
+    ///
+    /// # Performance
+    /// - `O(n + I*log(I))`, where `n` is the number of nodes and `I` the sum of the sizes of the hyperedges.
+    pub fn largest_cc_subhypergraph(&self, order: Option<usize>, size: Option<usize>) -> Result<Hypergraph, HypergraphError> {
+        let largest = self
+            .ccs_subhypergraphs(order, size)?
+            .into_iter()
+            .reduce(|largest, cc| if cc.num_nodes() > largest.num_nodes() { cc } else { largest });
+
+        Ok(largest.unwrap_or_else(|| Hypergraph::with_merge_policy(self.weighted, self.merge_policy)))
+    }
+
     /// Return the size of the largest connected component of the hypergraph.   
     /// 
     /// If the returned size is 0, then the hypergraph is empty, ie without nodes.  
@@ -135,12 +428,12 @@ impl Hypergraph {
     /// - `size` : `Option<usize>` - The size of the hyperedges to consider. If None, all hyperedges are considered.  
     /// 
     /// # Returns 
-    /// - `Result<usize, &str>` - `Ok` containing the size of the largest connected component in the hypergraph (0    
-    /// if the hypergraph has no nodes). Returns `Err` with a message if both `order` and `size` are specified.
+    /// - `Result<usize, HypergraphError>` - `Ok` containing the size of the largest connected component in the hypergraph (0    
+    ///   if the hypergraph has no nodes). Returns `Err(HypergraphError::BothOrderAndSize)` if both `order` and `size` are specified.
     /// 
     /// # Performance 
-    /// - `O(n*n*m)`, where `n` and `m` are the number of nodes and the number of hyperedges of the hypergraph, respectively.
-    pub fn largest_cc_size(&self, order: Option<usize>, size: Option<usize>) -> Result<usize, &str> {
+    /// - `O(n + I*log(I))`, where `n` is the number of nodes and `I` the sum of the sizes of the hyperedges.
+    pub fn largest_cc_size(&self, order: Option<usize>, size: Option<usize>) -> Result<usize, HypergraphError> {
         match self.largest_cc(order, size) {
             Ok(val) => {
                 Ok(val.len())
@@ -152,110 +445,517 @@ impl Hypergraph {
     // WORKS IN O(n*m), INSTEAD OF O(n*n*m)
     /// `type Node = i64`.  
     /// 
-    /// Returns the isolated nodes of the hypergraph.
+    /// Returns the isolated nodes of the hypergraph, as `is_isolated`.
     /// 
     /// # Parameters 
     /// - `order` : `Option<usize>` - The order of the hyperedges to consider. If None, all hyperedges are considered.
     /// - `size` : `Option<usize>` - The size of the hyperedges to consider. If None, all hyperedges are considered.  
+    /// - `up_to` : `bool` - If `true`, it specifies to consider hyperedges with order/size less than or equal to the provided   
+    ///   value, instead of exactly equal. Ignored if neither `order` nor `size` is specified.
     /// 
     /// # Returns 
-    /// - `Result<Vec<Node>, &str>` - `Ok` containing a list of isolated nodes in the hypergraph. Returns `Err` with  
-    /// a message if both `order` and `size` are specified.
+    /// - `Result<Vec<Node>, HypergraphError>` - `Ok` containing a list of isolated nodes in the hypergraph. Returns `Err`  
+    ///   containing `HypergraphError::BothOrderAndSize` if both `order` and `size` are specified.
     /// 
     /// # Performance 
     /// - `O(n*m)`, where `n` and `m` are the number of nodes and the number of hyperedges of the hypergraph, respectively.
-    pub fn isolated_nodes(&self, order: Option<usize>, size: Option<usize>) -> Result<Vec<Node>, &str> {
-        if order != None && size != None {
-            Err("Order and size cannot be both specified.")
-        } else {
-            let mut res = Vec::new();
-
-            for node in self.incidence_list.keys() { // O(n)
-                if let Ok(Some(isolated)) = self.is_isolated(*node, order, size){ //O(m)
-                    if isolated {
-                        res.push(*node);
-                    } 
-                }
-            }
-
-            Ok(res)
+    pub fn isolated_nodes(&self, order: Option<usize>, size: Option<usize>, up_to: bool) -> Result<Vec<Node>, HypergraphError> {
+        let filter = compute_order_filter(order, size, up_to)?;
+        if order.is_none() && size.is_none() {
+            return Ok(self.iter_isolated_nodes().collect());
         }
+
+        Ok(self.isolated_nodes_filtered(filter))
+    }
+
+    /// `type Node = i64`.  
+    ///
+    /// Returns the isolated nodes of the hypergraph, as `is_isolated_filtered`.
+    ///
+    /// # Parameters
+    /// - `filter` : `impl Fn(&[Node], f64) -> bool` - Given the nodes and the weight of a hyperedge, whether to
+    ///   consider it.
+    ///
+    /// # Returns
+    /// - `Vec<Node>` - The isolated nodes, in no particular order.
+    ///
+    /// # Performance
+    /// - `O(n + I)`, where `n` is the number of nodes and `I` the sum of the sizes of the hyperedges, plus a call of
+    ///   `filter` for every incident hyperedge of every node.
+    pub fn isolated_nodes_filtered(&self, filter: impl Fn(&[Node], f64) -> bool) -> Vec<Node> {
+        self.incidence_list
+            .iter()
+            .filter(|(_, edge_ids)| self.compute_only_singletons_filtered(edge_ids, &filter))
+            .map(|(node, _)| *node)
+            .collect()
+    }
+
+    /// `type Node = i64`.  
+    /// 
+    /// Returns an iterator over the isolated nodes of the hypergraph, ie the nodes which are in no hyperedge, or only in
+    /// their own singleton hyperedge (as in `is_isolated`), without allocating a list as `isolated_nodes` does.
+    /// 
+    /// # Returns 
+    /// - `impl Iterator<Item = Node> + '_` - The iterator over the isolated nodes, in no particular order.
+    /// 
+    /// # Performance 
+    /// - `O(n+m)` to consume it, where `n` and `m` are the number of nodes and the number of hyperedges of the hypergraph,
+    ///   respectively.
+    pub fn iter_isolated_nodes(&self) -> impl Iterator<Item = Node> + '_ {
+        self.incidence_list
+            .iter()
+            .filter(|(_, edge_ids)| self.compute_only_singletons(edge_ids))
+            .map(|(node, _)| *node)
     }
 
     // WORKS IN O(m), INSTEAD OF O(n*m)
     /// `type Node = i64`.  
     /// 
-    /// Returns if the given node is isolated. 
+    /// Returns if the given node is isolated, ie if it shares none of the considered hyperedges with another node. A
+    /// singleton hyperedge made only of the node itself does not connect it to anything, so it never breaks isolation.
+    ///
+    /// With `up_to`, the node is isolated when considering only the hyperedges with order/size at most the given one.
     /// 
     /// # Parameters 
     /// - `order` : `Option<usize>` - The order of the hyperedges to consider. If None, all hyperedges are considered.
     /// - `size` : `Option<usize>` - The size of the hyperedges to consider. If None, all hyperedges are considered.  
+    /// - `up_to` : `bool` - If `true`, it specifies to consider hyperedges with order/size less than or equal to the provided   
+    ///   value, instead of exactly equal. Ignored if neither `order` nor `size` is specified.
     /// 
     /// # Returns 
-    /// - `Result<Option<bool>, &str>` - `Ok(Some(true))` if the node is isolated, `Ok(Some(false))` if not. Returns   
-    /// `Ok(None)` if the node is not found. Returns `Err` if both `order` and `size` are specified.
-
+    /// - `Result<Option<bool>, HypergraphError>` - `Ok(Some(true))` if the node is isolated, `Ok(Some(false))` if not. Returns   
+    ///   `Ok(None)` if the node is not found. Returns `Err(HypergraphError::BothOrderAndSize)` if both `order` and `size` are specified.
     /// 
     /// # Performance 
     /// - `O(m)`, where `m`is the number of hyperedges of the hypergraph. 
-    pub fn is_isolated(&self, node: Node, order: Option<usize>, size: Option<usize>) -> Result<Option<bool>, &str> {
-        // Both are specified
-        if order != None && size != None {
-            Err("Order and size cannot be both specified.")
-        } else {
-            match self.incidence_list.get(&node) {
-                Some(edge_ids) => {
-                    // None is specified 
-                    if order == None && size == None {
-                        for edge_id in edge_ids.iter() {
-                            let hyperedge = self.edge_list.get(edge_id).unwrap(); // It will not panic
-                            
-                            // This could be more appropriate with hashset as edges 
-                            if hyperedge.nodes.len() > 1 || (hyperedge.nodes.len() == 1 && hyperedge.nodes[0] != node) {
-                                return Ok(Some(false));
-                            }
-                        }
-                        return Ok(Some(true));
-                    // Only one is specified
-                    } else {
-                        let filter = if let Some(val) = order {
-                            val + 1
-                        } else {
-                            size.unwrap()
-                        };
-
-                        for edge_id in edge_ids.iter() {
-                            let hyperedge = self.edge_list.get(edge_id).unwrap(); // It will not panic
-                            
-                            // This could be more appropriate with hashset as edges 
-                            if hyperedge.nodes.len() == filter && (filter > 1 || (filter == 1 && hyperedge.nodes[0] != node)) {
-                                return Ok(Some(false));
-                            }
-                        }
-                        return Ok(Some(true));
-                    }
-                }, 
-                _ => Ok(None)
-            }
-        }
+    pub fn is_isolated(&self, node: Node, order: Option<usize>, size: Option<usize>, up_to: bool) -> Result<Option<bool>, HypergraphError> {
+        Ok(self.is_isolated_filtered(node, compute_order_filter(order, size, up_to)?))
     }
 
-    // STILL O(n*n*m) IN WORST CASE, BUT IT SHOULD HALT BEFORE
-    /// Returns if the given hypergraph is connected. 
+    /// `type Node = i64`.  
+    ///
+    /// Returns if the given node is isolated, as `is_isolated`, when only the hyperedges accepted by `filter` are
+    /// considered. A filter rejecting every hyperedge makes every node isolated.
+    ///
+    /// # Parameters
+    /// - `node` : `Node` - The node to check.
+    /// - `filter` : `impl Fn(&[Node], f64) -> bool` - Given the nodes and the weight of a hyperedge, whether to
+    ///   consider it.
+    ///
+    /// # Returns
+    /// - `Option<bool>` - `Some(true)` if the node is isolated, `Some(false)` if not, `None` if the node is not found.
+    ///
+    /// # Performance
+    /// - `O(d)`, where `d` is the degree of the node, plus a call of `filter` for every incident hyperedge.
+    pub fn is_isolated_filtered(&self, node: Node, filter: impl Fn(&[Node], f64) -> bool) -> Option<bool> {
+        let edge_ids = self.incidence_list.get(&node)?;
+        Some(self.compute_only_singletons_filtered(edge_ids, &filter))
+    }
+
+    /// Returns if the given hypergraph is connected, with a single visit from an arbitrary node which stops as soon as
+    /// every node has been reached, instead of computing all the connected components as `num_ccs` does.
+    ///
+    /// The hypergraph with no nodes is connected, as it has no pair of disconnected nodes (and `num_ccs` is 0). If
+    /// `order` or `size` is specified, only the hyperedges with that order or size connect their nodes, as in `ccs`.
     /// 
     /// # Parameters 
     /// - `order` : `Option<usize>` - The order of the hyperedges to consider. If None, all hyperedges are considered.
     /// - `size` : `Option<usize>` - The size of the hyperedges to consider. If None, all hyperedges are considered.  
     /// 
     /// # Returns 
-    /// - `Result<bool, &str>` - `Ok` containing `true` if the hypergraph is connected, `false` otherwise. Returns `Err`  
-    /// if both `order` and `size` are specified.
+    /// - `Result<bool, HypergraphError>` - `Ok` containing `true` if the hypergraph is connected, `false` otherwise. Returns `Err`  
+    ///   containing `HypergraphError::BothOrderAndSize` if both `order` and `size` are specified.
     /// 
     /// # Performance 
-    /// - `O(n*n*m)`, where `n` and `m` are the number of nodes and the number of hyperedges of the hypergraph, respectively. 
-    pub fn is_connected(&self, order: Option<usize>, size: Option<usize>) -> Result<bool, &str> {
-        self.ccs(order, size).map_or(
-            Err("Order and size cannot be both specified."),
-            |components| {Ok(components.len() <= 1)}) // If the hypergraph has 0 nodes is connected by def. (?)
+    /// - `O(n + I)`, where `n` is the number of nodes and `I` the sum of the sizes of the hyperedges, in the worst case,
+    ///   ie when the hypergraph is not connected or the last node is reached at the end.
+    pub fn is_connected(&self, order: Option<usize>, size: Option<usize>) -> Result<bool, HypergraphError> {
+        Ok(self.is_connected_filtered(compute_order_filter(order, size, false)?))
+    }
+
+    /// Returns if the given hypergraph is connected, as `is_connected`, when only the hyperedges accepted by `filter`
+    /// connect their nodes.
+    ///
+    /// # Parameters
+    /// - `filter` : `impl Fn(&[Node], f64) -> bool` - Given the nodes and the weight of a hyperedge, whether to
+    ///   consider it.
+    ///
+    /// # Returns
+    /// - `bool` - `true` if the hypergraph is connected, `false` otherwise.
+    ///
+    /// # Performance
+    /// - `O(n + I)`, where `n` is the number of nodes and `I` the sum of the sizes of the hyperedges, in the worst case,
+    ///   plus a call of `filter` for every reached hyperedge.
+    pub fn is_connected_filtered(&self, filter: impl Fn(&[Node], f64) -> bool) -> bool {
+        let num_nodes = self.incidence_list.len();
+        let Some(start) = self.incidence_list.keys().next().copied() else {
+            return true;
+        };
+
+        // Every hyperedge is expanded once, from the first of its nodes to be reached
+        let mut visited: AHashSet<Node> = AHashSet::from([start]);
+        let mut expanded: AHashSet<EdgeID> = AHashSet::new();
+        let mut queue: VecDeque<Node> = VecDeque::from([start]);
+        while let Some(now) = queue.pop_front() {
+            if visited.len() == num_nodes {
+                return true;
+            }
+            for edge_id in self.incidence_list[&now].iter() {
+                let hyperedge = &self.edge_list[edge_id];
+                if !expanded.insert(*edge_id) || !filter(&hyperedge.nodes, hyperedge.weight) {
+                    continue;
+                }
+                for node in hyperedge.nodes.iter() {
+                    if visited.insert(*node) {
+                        queue.push_back(*node);
+                    }
+                }
+            }
+        }
+
+        visited.len() == num_nodes
+    }
+
+    /// Effectively checks if the given hyperedges, all incident to the same node, are singletons (ie the node is
+    /// isolated).
+    fn compute_only_singletons(&self, edge_ids: &AHashSet<EdgeID>) -> bool {
+        edge_ids.iter().all(|edge_id| self.edge_list[edge_id].nodes.len() <= 1)
+    }
+
+    /// Effectively checks if the given hyperedges accepted by `filter`, all incident to the same node, are singletons
+    /// (ie the node is isolated when only they are considered).
+    fn compute_only_singletons_filtered(&self, edge_ids: &AHashSet<EdgeID>, filter: &impl Fn(&[Node], f64) -> bool) -> bool {
+        edge_ids.iter().all(|edge_id| {
+            let hyperedge = &self.edge_list[edge_id];
+            // A hyperedge of size 1 can only be the node itself
+            hyperedge.nodes.len() <= 1 || !filter(&hyperedge.nodes, hyperedge.weight)
+        })
+    }
+}
+/// Effectively finds the root of the set of `x`, halving the path on the way.
+fn compute_find(parent: &mut [usize], mut x: usize) -> usize {
+    while parent[x] != x {
+        parent[x] = parent[parent[x]];
+        x = parent[x];
+    }
+    x
+}
+
+/// Effectively merges the sets of `a` and `b`, by rank.
+fn compute_union(parent: &mut [usize], rank: &mut [u8], a: usize, b: usize) {
+    let (root_a, root_b) = (compute_find(parent, a), compute_find(parent, b));
+    if root_a == root_b {
+        return;
+    }
+
+    match rank[root_a].cmp(&rank[root_b]) {
+        Ordering::Less => parent[root_a] = root_b,
+        Ordering::Greater => parent[root_b] = root_a,
+        Ordering::Equal => {
+            parent[root_b] = root_a;
+            rank[root_a] += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    fn sorted(mut nodes: Vec<Node>) -> Vec<Node> {
+        nodes.sort();
+        nodes
+    }
+
+    #[test]
+    fn test_iter_nodes() {
+        let mut hg = Hypergraph::from(&[vec![1, 2, 3], vec![3, 4], vec![5], vec![6, 6]]);
+        hg.add_nodes(&[7, 8]);
+
+        assert_eq!(sorted(hg.iter_nodes().collect()), sorted(hg.get_nodes()));
+        assert_eq!(sorted(hg.iter_isolated_nodes().collect()), vec![5, 7, 8]);
+        assert_eq!(
+            sorted(hg.iter_isolated_nodes().collect()),
+            sorted(hg.isolated_nodes(None, None, false).unwrap())
+        );
+        for node in hg.iter_nodes() {
+            let isolated = hg.iter_isolated_nodes().any(|other| other == node);
+            assert_eq!(hg.is_isolated(node, None, None, false), Ok(Some(isolated)));
+        }
+
+        assert_eq!(Hypergraph::new(false).iter_nodes().count(), 0);
+    }
+
+    #[test]
+    fn test_isolated_up_to() {
+        let mut hg = Hypergraph::from(&[vec![1, 2], vec![2, 3, 4], vec![4, 5, 6, 7], vec![8]]);
+        hg.add_node(9);
+
+        // (size, up_to, isolated nodes)
+        let cases: Vec<(usize, bool, Vec<Node>)> = vec![
+            (1, false, vec![1, 2, 3, 4, 5, 6, 7, 8, 9]),
+            (1, true, vec![1, 2, 3, 4, 5, 6, 7, 8, 9]),
+            (2, false, vec![3, 4, 5, 6, 7, 8, 9]),
+            (2, true, vec![3, 4, 5, 6, 7, 8, 9]),
+            (3, false, vec![1, 5, 6, 7, 8, 9]),
+            (3, true, vec![5, 6, 7, 8, 9]),
+            (4, false, vec![1, 2, 3, 8, 9]),
+            (4, true, vec![8, 9]),
+            (5, false, vec![1, 2, 3, 4, 5, 6, 7, 8, 9]),
+            (5, true, vec![8, 9]),
+        ];
+        for (size, up_to, expected) in cases {
+            assert_eq!(sorted(hg.isolated_nodes(None, Some(size), up_to).unwrap()), expected);
+            assert_eq!(sorted(hg.isolated_nodes(Some(size - 1), None, up_to).unwrap()), expected);
+            for node in hg.iter_nodes() {
+                let isolated = expected.contains(&node);
+                assert_eq!(hg.is_isolated(node, None, Some(size), up_to), Ok(Some(isolated)));
+                assert_eq!(hg.is_isolated(node, Some(size - 1), None, up_to), Ok(Some(isolated)));
+            }
+        }
+
+        for up_to in [false, true] {
+            assert_eq!(sorted(hg.isolated_nodes(None, None, up_to).unwrap()), vec![8, 9]);
+            assert_eq!(hg.is_isolated(8, None, None, up_to), Ok(Some(true)));
+            assert_eq!(hg.is_isolated(10, None, Some(2), up_to), Ok(None));
+            assert_eq!(hg.is_isolated(1, Some(1), Some(2), up_to), Err(HypergraphError::BothOrderAndSize));
+            assert_eq!(hg.isolated_nodes(Some(1), Some(2), up_to), Err(HypergraphError::BothOrderAndSize));
+        }
+    }
+
+    #[test]
+    fn test_ccs_isolated() {
+        let mut hg = Hypergraph::from(&[vec![1, 2, 3], vec![3, 4], vec![5], vec![9, 10]]);
+        hg.add_node(7);
+
+        let mut ccs: Vec<Vec<Node>> = hg.ccs(None, None).unwrap().into_iter().map(|cc| sorted(cc.into_iter().collect())).collect();
+        ccs.sort();
+        assert_eq!(ccs, vec![vec![1, 2, 3, 4], vec![5], vec![7], vec![9, 10]]);
+        assert_eq!(hg.ccs(Some(1), Some(2)), Err(HypergraphError::BothOrderAndSize));
+    }
+
+    #[test]
+    fn test_ccs_subhypergraphs() {
+        let mut hg = Hypergraph::from_weighted(
+            &[vec![1, 2, 3], vec![3, 4], vec![5], vec![9, 10], vec![10, 11, 9]],
+            &[1.0, 2.0, 3.0, 4.0, 5.0],
+        );
+        hg.add_node(7);
+
+        let ccs = hg.ccs_subhypergraphs(None, None).unwrap();
+        assert_eq!(ccs.len(), hg.num_ccs(None, None).unwrap());
+        let mut counts: Vec<(usize, usize)> = ccs.iter().map(|cc| (cc.num_nodes(), cc.num_edges())).collect();
+        counts.sort();
+        assert_eq!(counts, vec![(1, 0), (1, 1), (3, 2), (4, 2)]);
+
+        let mut union = Hypergraph::new(true);
+        for cc in ccs.iter() {
+            assert!(cc.is_weighted());
+            assert!(cc.is_subhypergraph_of(&hg, true));
+            union.add_nodes(&cc.get_nodes());
+            union.extend(cc.clone());
+        }
+        assert_eq!(union, hg);
+
+        let largest = hg.largest_cc_subhypergraph(None, None).unwrap();
+        assert_eq!(largest.get_nodes_sorted(), vec![1, 2, 3, 4]);
+        assert_eq!(largest.get_weight(&[3, 4]), Some(2.0));
+
+        let ccs = hg.ccs_subhypergraphs(None, Some(2)).unwrap();
+        assert_eq!(ccs.iter().map(|cc| cc.num_edges()).sum::<usize>(), 2);
+        assert_eq!(hg.ccs_subhypergraphs(Some(1), Some(2)), Err(HypergraphError::BothOrderAndSize));
+        assert_eq!(Hypergraph::new(false).largest_cc_subhypergraph(None, None).unwrap().num_nodes(), 0);
+    }
+
+    fn sorted_ccs(ccs: Vec<Component>) -> Vec<Vec<Node>> {
+        let mut res: Vec<Vec<Node>> = ccs.into_iter().map(|cc| sorted(cc.into_iter().collect())).collect();
+        res.sort();
+        res
+    }
+
+    #[test]
+    fn test_ccs_fast() {
+        let mut hg = Hypergraph::from(&[vec![1, 2, 3], vec![3, 4], vec![5], vec![9, 10], vec![10, 11, 9], vec![6, 6]]);
+        hg.add_node(7);
+
+        let expected = vec![vec![1, 2, 3, 4], vec![5], vec![6], vec![7], vec![9, 10, 11]];
+        assert_eq!(sorted_ccs(hg.ccs_fast(None, None).unwrap()), expected);
+        assert_eq!(sorted_ccs(hg.ccs_fast(None, None).unwrap()), sorted_ccs(hg.ccs(None, None).unwrap()));
+
+        // Only the hyperedges of size 3
+        let expected = vec![vec![1, 2, 3], vec![4], vec![5], vec![6], vec![7], vec![9, 10, 11]];
+        assert_eq!(sorted_ccs(hg.ccs_fast(Some(2), None).unwrap()), expected);
+        assert_eq!(sorted_ccs(hg.ccs_fast(None, Some(3)).unwrap()), expected);
+
+        assert_eq!(hg.ccs_fast(Some(1), Some(2)), Err(HypergraphError::BothOrderAndSize));
+        assert!(Hypergraph::new(false).ccs_fast(None, None).unwrap().is_empty());
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_ccs_fast_random() {
+        use crate::generators::{random_uniform, SplitMix64};
+
+        let mut rng = SplitMix64::new(13);
+        for (n, k, m) in [(100, 2, 40), (200, 3, 60), (500, 2, 400), (50, 5, 30), (30, 1, 10)] {
+            let hg = random_uniform(n, k, m, &mut rng).unwrap();
+            assert_eq!(sorted_ccs(hg.ccs_fast(None, None).unwrap()), sorted_ccs(hg.ccs(None, None).unwrap()));
+        }
+
+        // Mixed sizes, so that filtering changes the components
+        let mut hg = random_uniform(200, 2, 80, &mut rng).unwrap();
+        hg.extend(random_uniform(200, 3, 40, &mut rng).unwrap());
+        for size in 1..=4 {
+            assert_eq!(
+                sorted_ccs(hg.ccs_fast(None, Some(size)).unwrap()),
+                sorted_ccs(hg.ccs(None, Some(size)).unwrap())
+            );
+        }
+    }
+
+    #[test]
+    fn test_ccs_filtered() {
+        // 1 - 2 and 3 - 4 are only connected through the hyperedge of size 3
+        let mut hg = Hypergraph::from(&[vec![1, 2], vec![2, 3, 4], vec![3, 4], vec![5]]);
+        hg.add_node(6);
+
+        assert_eq!(sorted_ccs(hg.ccs(None, None).unwrap()), vec![vec![1, 2, 3, 4], vec![5], vec![6]]);
+        assert!(!hg.is_connected(None, None).unwrap());
+
+        let expected = vec![vec![1, 2], vec![3, 4], vec![5], vec![6]];
+        assert_eq!(sorted_ccs(hg.ccs(None, Some(2)).unwrap()), expected);
+        assert_eq!(sorted_ccs(hg.ccs(Some(1), None).unwrap()), expected);
+        assert_eq!(sorted_ccs(_ccs(&hg, None, Some(2)).unwrap()), expected);
+        assert_eq!(hg.num_ccs(None, Some(2)), Ok(4));
+        assert_eq!(hg.largest_cc_size(None, Some(2)), Ok(2));
+
+        // Nodes in no hyperedge of the requested size are singleton components
+        let expected = vec![vec![1], vec![2, 3, 4], vec![5], vec![6]];
+        assert_eq!(sorted_ccs(hg.ccs(None, Some(3)).unwrap()), expected);
+        assert_eq!(sorted(hg.largest_cc(None, Some(3)).unwrap().into_iter().collect()), vec![2, 3, 4]);
+        assert_eq!(hg.num_ccs(None, Some(5)), Ok(6));
+        assert_eq!(hg.largest_cc_size(None, Some(5)), Ok(1));
+
+        let hg = Hypergraph::from(&[vec![1, 2], vec![2, 3, 4], vec![3, 4]]);
+        assert!(hg.is_connected(None, None).unwrap());
+        assert!(!hg.is_connected(None, Some(3)).unwrap());
+        assert!(!hg.is_connected(Some(1), None).unwrap());
+    }
+
+    #[test]
+    fn test_is_connected() {
+        // The hypergraph with no nodes is connected, as is a single node
+        let mut hg = Hypergraph::new(false);
+        assert_eq!(hg.is_connected(None, None), Ok(true));
+        assert_eq!(hg.is_connected(None, Some(2)), Ok(true));
+        hg.add_node(1);
+        assert_eq!(hg.is_connected(None, None), Ok(true));
+        assert_eq!(hg.is_connected(Some(3), None), Ok(true));
+        hg.add_node(2);
+        assert_eq!(hg.is_connected(None, None), Ok(false));
+
+        hg.add_edges(&[vec![1, 2], vec![3], vec![3, 3, 1]]);
+        assert_eq!(hg.is_connected(None, None), Ok(true));
+        assert_eq!(hg.is_connected(None, Some(2)), Ok(false));
+        assert_eq!(hg.is_connected(None, Some(1)), Ok(false));
+        assert_eq!(hg.is_connected(Some(1), Some(2)), Err(HypergraphError::BothOrderAndSize));
+
+        // The same answer as counting the components
+        let hg = Hypergraph::from(&[vec![1, 2, 3], vec![3, 4], vec![5, 4], vec![6, 5, 1], vec![7, 8]]);
+        for size in [None, Some(1), Some(2), Some(3), Some(4)] {
+            assert_eq!(hg.is_connected(None, size), Ok(hg.num_ccs(None, size).unwrap() <= 1));
+        }
+    }
+
+    // Two clusters of three nodes, joined by a single light hyperedge
+    fn two_clusters() -> Hypergraph {
+        let mut hg = Hypergraph::new(true);
+        hg.add_edge_weighted(&[1, 2, 3], 5.0);
+        hg.add_edge_weighted(&[1, 2], 3.0);
+        hg.add_edge_weighted(&[4, 5, 6], 4.0);
+        hg.add_edge_weighted(&[3, 4], 0.5);
+        hg.add_node(7);
+        hg
+    }
+
+    #[test]
+    fn test_ccs_min_weight() {
+        let hg = two_clusters();
+
+        assert_eq!(sorted_ccs(hg.ccs_min_weight(0.0)), vec![vec![1, 2, 3, 4, 5, 6], vec![7]]);
+        assert_eq!(sorted_ccs(hg.ccs_min_weight(0.5)), sorted_ccs(hg.ccs_fast(None, None).unwrap()));
+        assert_eq!(sorted_ccs(hg.ccs_min_weight(1.0)), vec![vec![1, 2, 3], vec![4, 5, 6], vec![7]]);
+        assert_eq!(sorted_ccs(hg.ccs_min_weight(4.5)), vec![vec![1, 2, 3], vec![4], vec![5], vec![6], vec![7]]);
+        assert_eq!(hg.ccs_min_weight(10.0).len(), 7);
+
+        // The same components as on the pruned copy
+        let mut pruned = hg.clone();
+        pruned.remove_edge(&[3, 4]);
+        assert_eq!(sorted_ccs(hg.ccs_min_weight(1.0)), sorted_ccs(pruned.ccs(None, None).unwrap()));
+    }
+
+    #[test]
+    fn test_percolation_profile() {
+        let hg = two_clusters();
+
+        let thresholds = [0.0, 10.0, 1.0, 0.5, 4.0, 5.0, 0.6, f64::NAN];
+        assert_eq!(hg.percolation_profile(&thresholds), vec![6, 1, 3, 6, 3, 3, 3, 1]);
+        for threshold in &thresholds[..7] {
+            let largest = hg.ccs_min_weight(*threshold).iter().map(|cc| cc.len()).max();
+            assert_eq!(hg.percolation_profile(&[*threshold]), vec![largest.unwrap()]);
+        }
+
+        assert!(hg.percolation_profile(&[]).is_empty());
+        assert_eq!(Hypergraph::new(true).percolation_profile(&[0.0, 1.0]), vec![0, 0]);
+        // All the hyperedges of an unweighted hypergraph have weight 0
+        let hg = Hypergraph::from(&[vec![1, 2], vec![2, 3]]);
+        assert_eq!(hg.percolation_profile(&[0.0, 0.1]), vec![3, 1]);
+    }
+
+    #[test]
+    fn test_filtered_closures() {
+        let mut hg = Hypergraph::new(true);
+        hg.add_edge_weighted(&[1, 2, 3], 2.0);
+        hg.add_edge_weighted(&[3, 4, 5], 0.5);
+        hg.add_edge_weighted(&[5, 6, 7], 1.5);
+        hg.add_edge_weighted(&[7, 8], 3.0);
+        hg.add_edge_weighted(&[8, 9, 10, 11], 4.0);
+        hg.add_edge_weighted(&[12], 2.0);
+        hg.add_node(13);
+
+        // The same hypergraph, keeping by hand only the heavy hyperedges of size 3
+        let heavy = |nodes: &[Node], weight: f64| nodes.len() == 3 && weight > 1.0;
+        let mut pruned = Hypergraph::new(true);
+        for node in hg.iter_nodes() {
+            pruned.add_node(node);
+        }
+        pruned.add_edge_weighted(&[1, 2, 3], 2.0);
+        pruned.add_edge_weighted(&[5, 6, 7], 1.5);
+
+        let expected = sorted_ccs(pruned.ccs(None, None).unwrap());
+        assert_eq!(sorted_ccs(hg.ccs_filtered(heavy)), expected);
+        assert_eq!(sorted_ccs(hg.ccs_fast_filtered(heavy)), expected);
+        assert_eq!(sorted_ccs(_ccs_filtered(&hg, heavy)), expected);
+        assert_eq!(hg.is_connected_filtered(heavy), pruned.is_connected(None, None).unwrap());
+        assert_eq!(sorted(hg.isolated_nodes_filtered(heavy)), sorted(pruned.isolated_nodes(None, None, false).unwrap()));
+        for node in hg.iter_nodes().chain([42]) {
+            assert_eq!(hg.is_isolated_filtered(node, heavy), pruned.is_isolated(node, None, None, false).unwrap());
+            let neighbors = hg.get_neighbors_filtered(node, heavy).map(sorted);
+            assert_eq!(neighbors, pruned.get_neighbors(node, None, None).unwrap().map(sorted));
+        }
+
+        // A filter rejecting everything is the hypergraph without hyperedges
+        let nothing = |_: &[Node], _: f64| false;
+        let singletons: Vec<Vec<Node>> = sorted(hg.iter_nodes().collect()).into_iter().map(|node| vec![node]).collect();
+        assert_eq!(sorted_ccs(hg.ccs_filtered(nothing)), singletons);
+        assert_eq!(sorted_ccs(hg.ccs_fast_filtered(nothing)), singletons);
+        assert_eq!(hg.isolated_nodes_filtered(nothing).len(), 13);
+        assert!(!hg.is_connected_filtered(nothing));
+        assert_eq!(hg.get_neighbors_filtered(1, nothing), Some(vec![]));
+
+        // The order/size versions are the same as their filters
+        let size_3 = |nodes: &[Node], _: f64| nodes.len() == 3;
+        assert_eq!(sorted_ccs(hg.ccs(None, Some(3)).unwrap()), sorted_ccs(hg.ccs_filtered(size_3)));
+        assert_eq!(hg.is_isolated(8, Some(1), None, true), Ok(hg.is_isolated_filtered(8, |nodes, _| nodes.len() <= 2)));
     }
-}
\ No newline at end of file
+}
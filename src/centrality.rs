@@ -0,0 +1,443 @@
+use std::collections::VecDeque;
+
+use ahash::AHashMap;
+
+use super::visits::_distances_from;
+use super::{Hypergraph, HypergraphError, Node};
+
+impl Hypergraph {
+    /// `type Node = i64`
+    ///
+    /// Computes the PageRank of every node, ie the stationary distribution of the hypergraph random walk with
+    /// teleportation.
+    ///
+    /// At each step the walker, with probability `damping`, moves from its node to one of the incident hyperedges and
+    /// then to one of the members of that hyperedge, chosen uniformly (itself included); otherwise it teleports to a node
+    /// chosen uniformly. Hyperedges are chosen uniformly if the hypergraph is not weighted, proportionally to their weight
+    /// otherwise. The mass of isolated nodes (and of nodes whose incident hyperedges have total weight `0`) is
+    /// redistributed uniformly to every node.
+    ///
+    /// The walk is computed on the incidence structure, without building the clique expansion.
+    ///
+    /// # Parameters
+    /// - `damping` : `f64` - The probability of following a hyperedge instead of teleporting, usually `0.85`.
+    /// - `tol` : `f64` - The iterations stop when the L1 distance between two consecutive distributions is below `tol`.
+    /// - `max_iter` : `usize` - The maximum number of iterations.
+    ///
+    /// # Returns
+    /// - `AHashMap<Node, f64>` - The score of every node, summing to `1` (or an empty map if the hypergraph has no nodes).
+    ///
+    /// # Performance
+    /// - `O(n*log(n) + i*(n + s))`, where `n` is the number of nodes, `s` the sum of the sizes of the hyperedges and `i`
+    ///   the number of iterations.
+    pub fn pagerank(&self, damping: f64, tol: f64, max_iter: usize) -> AHashMap<Node, f64> {
+        let mut nodes = self.get_nodes();
+        nodes.sort_unstable();
+        let n = nodes.len();
+        if n == 0 {
+            return AHashMap::new();
+        }
+
+        let index: AHashMap<Node, usize> = nodes.iter().enumerate().map(|(i, node)| (*node, i)).collect();
+
+        // Total weight of the hyperedges incident to every node
+        let mut strengths = vec![0_f64; n];
+        for hyperedge in self.edge_list.values() {
+            let weight = self.compute_expansion_weight(hyperedge.weight);
+            for node in hyperedge.nodes.iter() {
+                strengths[index[node]] += weight;
+            }
+        }
+
+        let mut scores = vec![1_f64 / n as f64; n];
+        for _ in 0..max_iter {
+            let dangling: f64 = (0..n).filter(|i| strengths[*i] <= 0_f64).map(|i| scores[i]).sum();
+            let mut next = vec![(1_f64 - damping + damping * dangling) / n as f64; n];
+
+            // O(s)
+            for hyperedge in self.edge_list.values() {
+                let weight = self.compute_expansion_weight(hyperedge.weight);
+                let flow: f64 = hyperedge
+                    .nodes
+                    .iter()
+                    .map(|node| index[node])
+                    .filter(|i| strengths[*i] > 0_f64)
+                    .map(|i| scores[i] * weight / strengths[i])
+                    .sum();
+
+                let share = damping * flow / hyperedge.nodes.len() as f64;
+                for node in hyperedge.nodes.iter() {
+                    next[index[node]] += share;
+                }
+            }
+
+            let delta: f64 = scores.iter().zip(next.iter()).map(|(a, b)| (a - b).abs()).sum();
+            scores = next;
+            if delta < tol {
+                break;
+            }
+        }
+
+        nodes.into_iter().zip(scores).collect()
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Computes the closeness centrality of every node, ie the inverse of its average distance from the other nodes of
+    /// its connected component: `(c - 1) / sum(d(v, u))`, where `c` is the number of nodes of the component.
+    ///
+    /// Since the average is taken within each connected component, the scores of nodes in different components are
+    /// comparable. Isolated nodes have score `0`.
+    ///
+    /// # Parameters
+    /// - `order` : `Option<usize>` - `Some` order of the hyperedges to consider. If `None` all hyperedges are considered.
+    /// - `size` : `Option<usize>` - `Some` size of the hyperedges to consider. If `None` all hyperedges are considered.
+    ///
+    /// # Returns
+    /// - `Result<AHashMap<Node, f64>, HypergraphError>` - `Ok` containing the score of every node. Returns
+    ///   `Err(HypergraphError::BothOrderAndSize)` if both `order` and `size` are specified.
+    ///
+    /// # Performance
+    /// - `O(n*(n + I*log(I)))`, where `n` is the number of nodes and `I` the sum of the sizes of the hyperedges.
+    pub fn closeness_centrality(&self, order: Option<usize>, size: Option<usize>) -> Result<AHashMap<Node, f64>, HypergraphError> {
+        if order.is_some() && size.is_some() {
+            return Err(HypergraphError::BothOrderAndSize);
+        }
+
+        Ok(self
+            .incidence_list
+            .keys()
+            .map(|node| {
+                let distances = _distances_from(self, *node, None, order, size);
+                let total: usize = distances.values().sum();
+                let score = if total > 0 { (distances.len() - 1) as f64 / total as f64 } else { 0_f64 };
+                (*node, score)
+            })
+            .collect())
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Computes the harmonic centrality of every node, ie the sum of the inverse distances from every other node:
+    /// `sum(1 / d(v, u))`, where unreachable nodes contribute `0`. Unlike closeness, it is well defined on disconnected
+    /// hypergraphs without any normalization.
+    ///
+    /// # Parameters
+    /// - `order` : `Option<usize>` - `Some` order of the hyperedges to consider. If `None` all hyperedges are considered.
+    /// - `size` : `Option<usize>` - `Some` size of the hyperedges to consider. If `None` all hyperedges are considered.
+    ///
+    /// # Returns
+    /// - `Result<AHashMap<Node, f64>, HypergraphError>` - `Ok` containing the score of every node. Returns
+    ///   `Err(HypergraphError::BothOrderAndSize)` if both `order` and `size` are specified.
+    ///
+    /// # Performance
+    /// - `O(n*(n + I*log(I)))`, where `n` is the number of nodes and `I` the sum of the sizes of the hyperedges.
+    pub fn harmonic_centrality(&self, order: Option<usize>, size: Option<usize>) -> Result<AHashMap<Node, f64>, HypergraphError> {
+        if order.is_some() && size.is_some() {
+            return Err(HypergraphError::BothOrderAndSize);
+        }
+
+        Ok(self
+            .incidence_list
+            .keys()
+            .map(|node| {
+                let distances = _distances_from(self, *node, None, order, size);
+                let score = distances.values().filter(|d| **d > 0).map(|d| 1_f64 / *d as f64).sum();
+                (*node, score)
+            })
+            .collect())
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Computes the betweenness centrality of every node with Brandes' algorithm: the sum, over every pair of other
+    /// nodes `s`, `t`, of the fraction of the shortest paths between `s` and `t` passing through the node.
+    ///
+    /// Two nodes are adjacent if they belong to the same hyperedge, so a large hyperedge often gives many shortest paths
+    /// of the same length, which are all counted. Each unordered pair is counted once; if `normalized` is `true` the
+    /// scores are divided by the number `(n-1)*(n-2)/2` of pairs not containing the node.
+    ///
+    /// # Parameters
+    /// - `normalized` : `bool` - If `true` the scores are normalized in `[0, 1]`.
+    /// - `order` : `Option<usize>` - `Some` order of the hyperedges to consider. If `None` all hyperedges are considered.
+    /// - `size` : `Option<usize>` - `Some` size of the hyperedges to consider. If `None` all hyperedges are considered.
+    ///
+    /// # Returns
+    /// - `Result<AHashMap<Node, f64>, HypergraphError>` - `Ok` containing the score of every node. Returns
+    ///   `Err(HypergraphError::BothOrderAndSize)` if both `order` and `size` are specified.
+    ///
+    /// # Performance
+    /// - `O(n*m*k + n*a)`, where `n` and `m` are the number of nodes and hyperedges, `k` the maximum size of a
+    ///   hyperedge and `a` the number of pairs of adjacent nodes.
+    pub fn betweenness_centrality(&self, normalized: bool, order: Option<usize>, size: Option<usize>) -> Result<AHashMap<Node, f64>, HypergraphError> {
+        if order.is_some() && size.is_some() {
+            return Err(HypergraphError::BothOrderAndSize);
+        }
+
+        let mut nodes = self.get_nodes();
+        nodes.sort_unstable();
+        let n = nodes.len();
+        let index: AHashMap<Node, usize> = nodes.iter().enumerate().map(|(i, node)| (*node, i)).collect();
+
+        // O(n*m*k)
+        let adjacency: Vec<Vec<usize>> = nodes
+            .iter()
+            .map(|node| match self.get_neighbors(*node, order, size) {
+                Ok(Some(neighbors)) => neighbors.iter().map(|neighbor| index[neighbor]).collect(),
+                _ => Vec::new(),
+            })
+            .collect();
+
+        let mut scores = vec![0_f64; n];
+        for source in 0..n {
+            // Number of shortest paths from the source, distances and predecessors on the shortest paths
+            let mut sigma = vec![0_f64; n];
+            let mut distances: Vec<Option<usize>> = vec![None; n];
+            let mut predecessors: Vec<Vec<usize>> = vec![Vec::new(); n];
+            let mut stack = Vec::with_capacity(n);
+            let mut queue = VecDeque::new();
+
+            sigma[source] = 1_f64;
+            distances[source] = Some(0);
+            queue.push_back(source);
+
+            while let Some(v) = queue.pop_front() {
+                stack.push(v);
+                let next = distances[v].unwrap() + 1;
+
+                for w in adjacency[v].iter() {
+                    if distances[*w].is_none() {
+                        distances[*w] = Some(next);
+                        queue.push_back(*w);
+                    }
+                    if distances[*w] == Some(next) {
+                        sigma[*w] += sigma[v];
+                        predecessors[*w].push(v);
+                    }
+                }
+            }
+
+            // Dependencies, accumulated from the farthest nodes
+            let mut delta = vec![0_f64; n];
+            while let Some(w) = stack.pop() {
+                for v in predecessors[w].iter() {
+                    delta[*v] += sigma[*v] / sigma[w] * (1_f64 + delta[w]);
+                }
+                if w != source {
+                    scores[w] += delta[w];
+                }
+            }
+        }
+
+        // Every unordered pair has been counted from both its nodes
+        let mut scale = 0.5_f64;
+        if normalized && n > 2 {
+            scale /= ((n - 1) * (n - 2)) as f64 / 2_f64;
+        }
+
+        Ok(nodes.into_iter().zip(scores.into_iter().map(|score| score * scale)).collect())
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Computes the eigenvector centrality of every node by power iteration on the co-membership matrix, where the
+    /// entry of two distinct nodes is the total weight of the hyperedges containing both (their number, if the
+    /// hypergraph is not weighted).
+    ///
+    /// At each step the score of a node becomes the sum of the scores of its co-members in every incident hyperedge,
+    /// times the weight of the hyperedge, plus its own score: the shift has the same eigenvectors and prevents the
+    /// oscillations of bipartite-like structures. The scores are computed on the incidence structure, without building
+    /// the matrix. Isolated nodes have score `0`.
+    ///
+    /// # Parameters
+    /// - `tol` : `f64` - The iterations stop when the L1 distance between two consecutive score vectors is at most `tol`.
+    /// - `max_iter` : `usize` - The maximum number of iterations.
+    ///
+    /// # Returns
+    /// - `Result<AHashMap<Node, f64>, HypergraphError>` - `Ok` containing the score of every node, normalized to unit euclidean
+    ///   norm. Returns `Err(HypergraphError::NotConverged)` if the scores did not converge within `max_iter` iterations.
+    ///
+    /// # Performance
+    /// - `O(n*log(n) + i*(n + s))`, where `n` is the number of nodes, `s` the sum of the sizes of the hyperedges and `i`
+    ///   the number of iterations.
+    pub fn eigenvector_centrality(&self, tol: f64, max_iter: usize) -> Result<AHashMap<Node, f64>, HypergraphError> {
+        let mut nodes = self.get_nodes();
+        nodes.sort_unstable();
+        let index: AHashMap<Node, usize> = nodes.iter().enumerate().map(|(i, node)| (*node, i)).collect();
+
+        let mut scores: Vec<f64> = nodes
+            .iter()
+            .map(|node| if self.incidence_list[node].is_empty() { 0_f64 } else { 1_f64 })
+            .collect();
+        compute_normalize(&mut scores);
+
+        for _ in 0..max_iter {
+            let mut next = scores.clone();
+
+            // O(s)
+            for hyperedge in self.edge_list.values() {
+                let weight = self.compute_expansion_weight(hyperedge.weight);
+                let total: f64 = hyperedge.nodes.iter().map(|node| scores[index[node]]).sum();
+                for node in hyperedge.nodes.iter() {
+                    let i = index[node];
+                    next[i] += weight * (total - scores[i]);
+                }
+            }
+            compute_normalize(&mut next);
+
+            let delta: f64 = scores.iter().zip(next.iter()).map(|(a, b)| (a - b).abs()).sum();
+            scores = next;
+            if delta <= tol {
+                return Ok(nodes.into_iter().zip(scores).collect());
+            }
+        }
+
+        Err(HypergraphError::NotConverged(max_iter))
+    }
+}
+
+/// Effectively scales `vector` to unit euclidean norm, unless it is the zero vector.
+fn compute_normalize(vector: &mut [f64]) {
+    let norm = vector.iter().map(|value| value * value).sum::<f64>().sqrt();
+    if norm > 0_f64 {
+        vector.iter_mut().for_each(|value| *value /= norm);
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pagerank_symmetric() {
+        let hg = Hypergraph::from(&[vec![0, 1, 2], vec![1, 2, 3], vec![2, 3, 0], vec![3, 0, 1]]);
+        let scores = hg.pagerank(0.85, 1e-12, 100);
+
+        assert_eq!(scores.len(), 4);
+        assert!((scores.values().sum::<f64>() - 1.0).abs() < 1e-9);
+        assert!(scores.values().all(|score| (score - 0.25).abs() < 1e-9));
+    }
+
+    #[test]
+    fn test_pagerank_star() {
+        let mut hg = Hypergraph::from(&[vec![0, 1], vec![0, 2], vec![0, 3], vec![0, 4, 5]]);
+        hg.add_node(6);
+        let scores = hg.pagerank(0.85, 1e-12, 100);
+
+        assert!((scores.values().sum::<f64>() - 1.0).abs() < 1e-9);
+        assert!(scores.iter().all(|(node, score)| *node == 0 || *score < scores[&0]));
+
+        // The isolated node only receives teleportation and redistributed mass
+        assert!(scores[&6] > 0.0);
+        assert!(scores[&6] < scores[&1]);
+    }
+
+    #[test]
+    fn test_pagerank_weighted() {
+        let mut hg = Hypergraph::new(true);
+        hg.add_edge_weighted(&[0, 1], 1.0);
+        hg.add_edge_weighted(&[0, 2], 9.0);
+
+        let scores = hg.pagerank(0.85, 1e-12, 100);
+        assert!(scores[&2] > scores[&1]);
+        assert!(Hypergraph::new(false).pagerank(0.85, 1e-12, 100).is_empty());
+    }
+
+    #[test]
+    fn test_closeness_harmonic_star() {
+        let hg = Hypergraph::from(&[vec![0, 1, 2, 3, 4]]);
+
+        let closeness = hg.closeness_centrality(None, None).unwrap();
+        let harmonic = hg.harmonic_centrality(None, None).unwrap();
+        assert!(closeness.values().all(|score| *score == 1.0));
+        assert!(harmonic.values().all(|score| *score == 4.0));
+    }
+
+    #[test]
+    fn test_closeness_harmonic_chain() {
+        let mut hg = Hypergraph::from(&[vec![0, 1], vec![1, 2], vec![2, 3], vec![3, 4], vec![0, 2, 4], vec![7, 8]]);
+        hg.add_node(9);
+
+        // Without the triangle, the middle node is the most central
+        let closeness = hg.closeness_centrality(None, Some(2)).unwrap();
+        assert_eq!(closeness[&2], 4.0 / 6.0);
+        assert_eq!(closeness[&0], 4.0 / 10.0);
+        assert!([0, 1, 3, 4].iter().all(|node| closeness[node] < closeness[&2]));
+
+        // Within their own component, the nodes of a pair are as central as possible
+        assert_eq!(closeness[&7], 1.0);
+        assert_eq!(closeness[&9], 0.0);
+
+        let harmonic = hg.harmonic_centrality(None, Some(2)).unwrap();
+        assert_eq!(harmonic[&2], 1.0 + 1.0 + 0.5 + 0.5);
+        assert!((harmonic[&0] - (1.0 + 0.5 + 1.0 / 3.0 + 0.25)).abs() < 1e-12);
+        assert_eq!(harmonic[&7], 1.0);
+        assert_eq!(harmonic[&9], 0.0);
+
+        // The triangle brings the endpoints closer
+        let harmonic = hg.harmonic_centrality(None, None).unwrap();
+        assert!((harmonic[&0] - 3.5).abs() < 1e-12);
+
+        assert_eq!(hg.closeness_centrality(Some(1), Some(2)).unwrap_err(), HypergraphError::BothOrderAndSize);
+        assert_eq!(hg.harmonic_centrality(Some(1), Some(2)).unwrap_err(), HypergraphError::BothOrderAndSize);
+    }
+
+    #[test]
+    fn test_betweenness_hand_computed() {
+        let hg = Hypergraph::from(&[vec![0, 1], vec![0, 2], vec![1, 3], vec![2, 3], vec![3, 4, 5]]);
+
+        let scores = hg.betweenness_centrality(false, None, None).unwrap();
+        let expected = [(0, 0.5), (1, 1.5), (2, 1.5), (3, 6.5), (4, 0.0), (5, 0.0)];
+        for (node, score) in expected {
+            assert!((scores[&node] - score).abs() < 1e-12);
+        }
+
+        let scores = hg.betweenness_centrality(true, None, None).unwrap();
+        assert!((scores[&3] - 0.65).abs() < 1e-12);
+
+        // Without the triangle 4 and 5 are only reachable through the pairwise hyperedges
+        let scores = hg.betweenness_centrality(false, None, Some(2)).unwrap();
+        assert!((scores[&3] - 0.5).abs() < 1e-12);
+        assert_eq!(hg.betweenness_centrality(false, Some(1), Some(2)).unwrap_err(), HypergraphError::BothOrderAndSize);
+    }
+
+    #[test]
+    fn test_betweenness_uniform() {
+        let edges: Vec<Vec<Node>> = (0..6).map(|i| vec![i, (i + 1) % 6, (i + 2) % 6]).collect();
+        let mut hg = Hypergraph::from(&edges);
+        hg.add_node(6);
+
+        let scores = hg.betweenness_centrality(false, None, None).unwrap();
+        assert!(scores[&0] > 0.0);
+        assert!((0..6).all(|node| (scores[&node] - scores[&0]).abs() < 1e-12));
+        assert_eq!(scores[&6], 0.0);
+    }
+
+    #[test]
+    fn test_eigenvector_symmetric() {
+        let hg = Hypergraph::from(&[vec![0, 1, 2], vec![1, 2, 3], vec![2, 3, 0], vec![3, 0, 1]]);
+        let scores = hg.eigenvector_centrality(1e-10, 100).unwrap();
+
+        assert!(scores.values().all(|score| (score - 0.5).abs() < 1e-9));
+    }
+
+    #[test]
+    fn test_eigenvector_star() {
+        let mut hg = Hypergraph::new(true);
+        hg.add_edge_weighted(&[0, 1], 1.0);
+        hg.add_edge_weighted(&[0, 2], 1.0);
+        hg.add_edge_weighted(&[0, 3, 4], 1.0);
+        hg.add_edge_weighted(&[4, 5], 0.5);
+        hg.add_node(6);
+
+        let scores = hg.eigenvector_centrality(1e-10, 1000).unwrap();
+        assert!((scores.values().map(|score| score * score).sum::<f64>() - 1.0).abs() < 1e-9);
+        assert!(scores.iter().all(|(node, score)| *node == 0 || *score < scores[&0]));
+        assert!((scores[&1] - scores[&2]).abs() < 1e-9);
+        assert!(scores[&4] > scores[&3]);
+        assert_eq!(scores[&6], 0.0);
+
+        assert_eq!(hg.eigenvector_centrality(1e-10, 1), Err(HypergraphError::NotConverged(1)));
+        assert!(Hypergraph::new(false).eigenvector_centrality(1e-10, 1).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,528 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use ahash::{AHashMap, AHashSet};
+
+#[cfg(feature = "rand")]
+use super::generators::{compute_shuffle, Rng};
+use super::{Hypergraph, Node};
+
+/// State of the search for a 2-coloring: the hyperedges with at least 2 distinct nodes mapped to indices, and the
+/// partial coloring with the number of members of every color in each hyperedge.
+struct TwoColoring {
+    /// Distinct members of every hyperedge.
+    members: Vec<Vec<usize>>,
+
+    /// Hyperedges incident to every node.
+    incidences: Vec<Vec<usize>>,
+
+    /// Color of every node, if assigned.
+    colors: Vec<Option<bool>>,
+
+    /// Number of members colored `false` and `true` in every hyperedge.
+    counts: Vec<[usize; 2]>,
+
+    /// Assigned nodes, in order of assignment.
+    trail: Vec<usize>,
+}
+
+impl TwoColoring {
+    /// Colors a node and propagates the consequences: a hyperedge with a single uncolored member, and all the others of
+    /// the same color, forces the opposite color on it. Returns `false` if a hyperedge becomes monochromatic.
+    fn propagate(&mut self, node: usize, color: bool) -> bool {
+        let mut stack = vec![(node, color)];
+
+        while let Some((v, color)) = stack.pop() {
+            match self.colors[v] {
+                Some(current) if current == color => continue,
+                Some(_) => return false,
+                None => {}
+            }
+            self.colors[v] = Some(color);
+            self.trail.push(v);
+
+            // Counted in every hyperedge before checking them, so that undo stays consistent
+            for e in self.incidences[v].iter() {
+                self.counts[*e][color as usize] += 1;
+            }
+            for e in self.incidences[v].iter() {
+                let [falses, trues] = self.counts[*e];
+                let uncolored = self.members[*e].len() - falses - trues;
+
+                if uncolored == 0 && (falses == 0 || trues == 0) {
+                    return false;
+                }
+                if uncolored == 1 && (falses == 0 || trues == 0) {
+                    let last = self.members[*e].iter().find(|w| self.colors[**w].is_none()).unwrap();
+                    stack.push((*last, trues == 0));
+                }
+            }
+        }
+
+        true
+    }
+
+    /// Uncolors the nodes assigned after the trail had length `mark`.
+    fn undo(&mut self, mark: usize) {
+        while self.trail.len() > mark {
+            let v = self.trail.pop().unwrap();
+            let color = self.colors[v].take().unwrap();
+            for e in self.incidences[v].iter() {
+                self.counts[*e][color as usize] -= 1;
+            }
+        }
+    }
+}
+
+impl Hypergraph {
+    /// `type Node = i64`
+    ///
+    /// Returns a maximal matching of the hypergraph, ie a set of pairwise node-disjoint hyperedges such that no other
+    /// hyperedge can be added, built by a greedy pass over the hyperedges.
+    ///
+    /// The hyperedges are visited by decreasing weight, breaking ties by their nodes in ascending order, so that heavy
+    /// hyperedges are preferred and the result is deterministic. See `maximal_matching_random` for a random order.
+    ///
+    /// # Returns
+    /// - `Vec<&Vec<Node>>` - The hyperedges of the matching, in the order they were selected.
+    ///
+    /// # Performance
+    /// - `O(m*log(m) + s)`, where `m` is the number of hyperedges and `s` the sum of their sizes.
+    pub fn maximal_matching(&self) -> Vec<&Vec<Node>> {
+        let mut hyperedges: Vec<_> = self.edge_list.values().collect();
+        hyperedges.sort_by(|a, b| a.nodes.cmp(&b.nodes));
+        hyperedges.sort_by(|a, b| b.weight.total_cmp(&a.weight));
+
+        Self::compute_greedy_matching(hyperedges.into_iter().map(|hyperedge| &hyperedge.nodes))
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Returns a maximal matching of the hypergraph like `maximal_matching`, visiting the hyperedges in a random order.
+    ///
+    /// # Parameters
+    /// - `rng` : `&mut dyn Rng` - The source of randomness.
+    ///
+    /// # Returns
+    /// - `Vec<&Vec<Node>>` - The hyperedges of the matching, in the order they were selected.
+    ///
+    /// # Performance
+    /// - `O(m*log(m) + s)`, where `m` is the number of hyperedges and `s` the sum of their sizes.
+    #[cfg(feature = "rand")]
+    pub fn maximal_matching_random(&self, rng: &mut dyn Rng) -> Vec<&Vec<Node>> {
+        // Sorted first, so that the result only depends on the state of `rng`
+        let mut hyperedges: Vec<_> = self.edge_list.values().map(|hyperedge| &hyperedge.nodes).collect();
+        hyperedges.sort();
+        compute_shuffle(&mut hyperedges, rng);
+
+        Self::compute_greedy_matching(hyperedges)
+    }
+
+    /// Effectively selects greedily the hyperedges disjoint from the ones already selected, in the given order.
+    fn compute_greedy_matching<'a>(hyperedges: impl IntoIterator<Item = &'a Vec<Node>>) -> Vec<&'a Vec<Node>> {
+        let mut used: AHashSet<Node> = AHashSet::new();
+        let mut res = Vec::new();
+        for nodes in hyperedges {
+            if !nodes.iter().any(|node| used.contains(node)) {
+                used.extend(nodes.iter().copied());
+                res.push(nodes);
+            }
+        }
+
+        res
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Checks whether a list of hyperedges is a matching of the hypergraph, ie they all belong to the hypergraph and
+    /// they are pairwise node-disjoint.
+    ///
+    /// # Parameters
+    /// - `edges` : `&[Vec<Node>]` - The hyperedges to check.
+    ///
+    /// # Returns
+    /// - `bool` - `true` if the hyperedges are a matching, `false` otherwise.
+    ///
+    /// # Performance
+    /// - `O(s)`, where `s` is the sum of the sizes of `edges`.
+    pub fn is_matching(&self, edges: &[Vec<Node>]) -> bool {
+        let mut used: AHashSet<Node> = AHashSet::new();
+
+        for edge in edges {
+            if !self.check_edge(edge) {
+                return false;
+            }
+
+            // Repeated nodes of the same hyperedge do not break the disjointness
+            let distinct: AHashSet<Node> = edge.iter().copied().collect();
+            if distinct.iter().any(|node| used.contains(node)) {
+                return false;
+            }
+            used.extend(distinct);
+        }
+
+        true
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Looks for a 2-coloring of the nodes such that no hyperedge with at least 2 distinct nodes is monochromatic (the
+    /// hypergraph has property B). Hyperedges with a single distinct node are ignored.
+    ///
+    /// The search assigns the nodes by decreasing degree with backtracking, propagating the forced colors: whenever all
+    /// the colored members of a hyperedge share a color and a single member is left, it gets the other one. The color
+    /// of the first node is fixed, since swapping the two colors of a coloring gives another one.
+    ///
+    /// # Parameters
+    /// - `budget` : `usize` - The maximum number of failed assignments before giving up.
+    ///
+    /// # Returns
+    /// - `Option<AHashMap<Node, bool>>` - `Some` color of every node, `None` if the hypergraph is not 2-colorable or
+    ///   if the budget ran out.
+    ///
+    /// # Performance
+    /// - `O(2^n*s)` in the worst case, where `n` is the number of nodes and `s` the sum of the sizes of the hyperedges,
+    ///   but at most `O(budget*s)`.
+    pub fn two_coloring(&self, budget: usize) -> Option<AHashMap<Node, bool>> {
+        let (nodes, members, incidences) = self.compute_coloring_structure();
+
+        let mut order: Vec<usize> = (0..nodes.len()).collect();
+        order.sort_by_key(|v| Reverse(incidences[*v].len()));
+
+        let mut state = TwoColoring {
+            counts: vec![[0, 0]; members.len()],
+            members,
+            incidences,
+            colors: vec![None; nodes.len()],
+            trail: Vec::new(),
+        };
+
+        // Decisions taken so far: position in order, color and length of the trail before it
+        let mut decisions: Vec<(usize, bool, usize)> = Vec::new();
+        let (mut pos, mut color, mut failures) = (0, false, 0);
+        loop {
+            if !color {
+                while pos < order.len() && state.colors[order[pos]].is_some() {
+                    pos += 1;
+                }
+                if pos == order.len() {
+                    break;
+                }
+            }
+
+            let mark = state.trail.len();
+            if state.propagate(order[pos], color) {
+                decisions.push((pos, color, mark));
+                (pos, color) = (pos + 1, false);
+                continue;
+            }
+            state.undo(mark);
+
+            failures += 1;
+            if failures > budget || mark == 0 {
+                // By symmetry, the first node fails with both colors
+                return None;
+            }
+            if !color {
+                color = true;
+                continue;
+            }
+
+            // Both colors failed: back to the last decision with a color left to try
+            loop {
+                let (last, last_color, last_mark) = decisions.pop()?;
+                state.undo(last_mark);
+                if !last_color && last_mark > 0 {
+                    (pos, color) = (last, true);
+                    break;
+                }
+            }
+        }
+
+        Some(nodes.into_iter().zip(state.colors).map(|(node, color)| (node, color.unwrap())).collect())
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Computes a coloring of the nodes such that no hyperedge with at least 2 distinct nodes is monochromatic, with an
+    /// unbounded palette. Hyperedges with a single distinct node are ignored.
+    ///
+    /// The nodes are colored greedily by decreasing degree, breaking ties by ascending node, each one with the smallest
+    /// color which does not complete a monochromatic hyperedge. So at most `d + 1` colors are used, where `d` is the
+    /// maximum degree of a node.
+    ///
+    /// # Returns
+    /// - `AHashMap<Node, usize>` - The color of every node, from `0`.
+    ///
+    /// # Performance
+    /// - `O(n*log(n) + s*d)`, where `n` is the number of nodes, `s` the sum of the sizes of the hyperedges and `d` the
+    ///   maximum degree of a node.
+    pub fn greedy_coloring(&self) -> AHashMap<Node, usize> {
+        let (nodes, members, incidences) = self.compute_coloring_structure();
+
+        let mut order: Vec<usize> = (0..nodes.len()).collect();
+        order.sort_by_key(|v| Reverse(incidences[*v].len()));
+
+        let mut colors: Vec<Option<usize>> = vec![None; nodes.len()];
+        for v in order {
+            // Colors shared by all the other members of a hyperedge, once they are all colored
+            let forbidden: AHashSet<usize> = incidences[v]
+                .iter()
+                .filter_map(|e| {
+                    let mut others = members[*e].iter().filter(|w| **w != v).map(|w| colors[*w]);
+                    let first = others.next()??;
+                    others.all(|color| color == Some(first)).then_some(first)
+                })
+                .collect();
+
+            colors[v] = (0..).find(|color| !forbidden.contains(color));
+        }
+
+        nodes.into_iter().zip(colors).map(|(node, color)| (node, color.unwrap())).collect()
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Computes an independent set of nodes, ie no two selected nodes belong to the same hyperedge, such that no other
+    /// node can be added.
+    ///
+    /// The nodes are visited greedily by ascending degree, breaking ties by ascending node, and selected whenever none
+    /// of their neighbors was selected before.
+    ///
+    /// # Returns
+    /// - `Vec<Node>` - The selected nodes, in the order they were selected.
+    ///
+    /// # Performance
+    /// - `O(n*log(n) + n*d*k)`, where `n` is the number of nodes, `d` the maximum degree of a node and `k` the maximum
+    ///   size of a hyperedge.
+    pub fn independent_set(&self) -> Vec<Node> {
+        let mut nodes: Vec<(usize, Node)> =
+            self.incidence_list.iter().map(|(node, edges)| (edges.len(), *node)).collect();
+        nodes.sort_unstable();
+
+        let mut blocked: AHashSet<Node> = AHashSet::new();
+        let mut res = Vec::new();
+        for (_, node) in nodes {
+            if !blocked.contains(&node) {
+                blocked.extend(self.compute_closed_neighborhood(node));
+                res.push(node);
+            }
+        }
+
+        res
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Computes a dominating set of nodes, ie every node is either selected or belongs to a hyperedge with a selected
+    /// node.
+    ///
+    /// The nodes are selected greedily by descending coverage, ie the number of nodes not yet dominated among the node
+    /// and its neighbors, breaking ties by ascending node. Since the coverage of a node can only decrease, it is only
+    /// recomputed when the node reaches the top of the priority queue.
+    ///
+    /// # Returns
+    /// - `Vec<Node>` - The selected nodes, in the order they were selected.
+    ///
+    /// # Performance
+    /// - `O(n*log(n)*d*k)`, where `n` is the number of nodes, `d` the maximum degree of a node and `k` the maximum size
+    ///   of a hyperedge.
+    pub fn dominating_set(&self) -> Vec<Node> {
+        let mut queue: BinaryHeap<(usize, Reverse<Node>)> = self
+            .incidence_list
+            .keys()
+            .map(|node| (self.compute_closed_neighborhood(*node).len(), Reverse(*node)))
+            .collect();
+
+        let mut dominated: AHashSet<Node> = AHashSet::new();
+        let mut res = Vec::new();
+        while let Some((coverage, Reverse(node))) = queue.pop() {
+            let neighborhood = self.compute_closed_neighborhood(node);
+            let current = neighborhood.iter().filter(|neighbor| !dominated.contains(*neighbor)).count();
+
+            if current == 0 {
+                continue;
+            }
+            if current < coverage {
+                queue.push((current, Reverse(node)));
+                continue;
+            }
+
+            dominated.extend(neighborhood);
+            res.push(node);
+        }
+
+        res
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Effectively computes the sorted nodes, the distinct members of the hyperedges with at least 2 distinct nodes
+    /// and the incidences of every node, with nodes and hyperedges mapped to indices.
+    fn compute_coloring_structure(&self) -> (Vec<Node>, Vec<Vec<usize>>, Vec<Vec<usize>>) {
+        let mut nodes: Vec<Node> = self.incidence_list.keys().copied().collect();
+        nodes.sort_unstable();
+        let index: AHashMap<Node, usize> = nodes.iter().enumerate().map(|(i, node)| (*node, i)).collect();
+
+        let mut members: Vec<Vec<usize>> = Vec::new();
+        let mut incidences: Vec<Vec<usize>> = vec![Vec::new(); nodes.len()];
+        for hyperedge in self.edge_list.values() {
+            let mut distinct: Vec<usize> = hyperedge.nodes.iter().map(|node| index[node]).collect();
+            distinct.sort_unstable();
+            distinct.dedup();
+            if distinct.len() < 2 {
+                continue;
+            }
+
+            for v in distinct.iter() {
+                incidences[*v].push(members.len());
+            }
+            members.push(distinct);
+        }
+
+        (nodes, members, incidences)
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Effectively computes the node together with all the nodes sharing a hyperedge with it.
+    fn compute_closed_neighborhood(&self, node: Node) -> AHashSet<Node> {
+        let mut res: AHashSet<Node> = self.incidence_list[&node]
+            .iter()
+            .flat_map(|id| self.edge_list[id].nodes.iter().copied())
+            .collect();
+        res.insert(node);
+        res
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    fn fano() -> Hypergraph {
+        Hypergraph::from(&[
+            vec![1, 2, 3],
+            vec![1, 4, 5],
+            vec![1, 6, 7],
+            vec![2, 4, 6],
+            vec![2, 5, 7],
+            vec![3, 4, 7],
+            vec![3, 5, 6],
+        ])
+    }
+
+    fn is_proper<T: PartialEq>(hg: &Hypergraph, colors: &AHashMap<Node, T>) -> bool {
+        // Hyperedges with a single distinct node are ignored
+        hg.iter_edges().all(|hyperedge| {
+            let first = &hyperedge.nodes[0];
+            let monochromatic = hyperedge.nodes.iter().all(|node| colors[node] == colors[first]);
+            hyperedge.nodes.iter().all(|node| node == first) || !monochromatic
+        })
+    }
+
+    #[test]
+    fn test_maximal_matching() {
+        // A sunflower with core 0, and a disjoint hyperedge
+        let mut hg = Hypergraph::new(true);
+        hg.add_edges_weighted(&[vec![0, 1, 2], vec![0, 3, 4], vec![0, 5, 6], vec![7, 8]], &[1.0, 5.0, 3.0, 2.0]);
+
+        let matching = hg.maximal_matching();
+        assert_eq!(matching, vec![&vec![0, 3, 4], &vec![7, 8]]);
+
+        assert!(Hypergraph::new(false).maximal_matching().is_empty());
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_maximal_matching_random() {
+        use crate::generators::SplitMix64;
+
+        let hg = Hypergraph::from(&[vec![0, 1, 2], vec![0, 3, 4], vec![0, 5, 6], vec![7, 8]]);
+
+        let mut rng = SplitMix64::new(7);
+        for _ in 0..10 {
+            let matching: Vec<Vec<Node>> = hg.maximal_matching_random(&mut rng).into_iter().cloned().collect();
+            assert_eq!(matching.len(), 2);
+            assert!(matching.contains(&vec![7, 8]));
+            assert!(hg.is_matching(&matching));
+        }
+
+        let (mut rng1, mut rng2) = (SplitMix64::new(3), SplitMix64::new(3));
+        assert_eq!(hg.maximal_matching_random(&mut rng1), hg.maximal_matching_random(&mut rng2));
+        assert!(Hypergraph::new(false).maximal_matching_random(&mut rng).is_empty());
+    }
+
+    #[test]
+    fn test_is_matching() {
+        let hg = Hypergraph::from(&[vec![0, 1, 2], vec![0, 3], vec![3, 4], vec![5, 5, 6]]);
+
+        assert!(hg.is_matching(&[vec![0, 1, 2], vec![3, 4], vec![5, 5, 6]]));
+        assert!(hg.is_matching(&[]));
+        assert!(!hg.is_matching(&[vec![0, 1, 2], vec![0, 3]]));
+        assert!(!hg.is_matching(&[vec![3, 4], vec![3, 4]]));
+        assert!(!hg.is_matching(&[vec![4, 3]]));
+    }
+
+    #[test]
+    fn test_two_coloring() {
+        let hg = Hypergraph::from(&[vec![0, 1, 2], vec![3], vec![4, 4]]);
+        let colors = hg.two_coloring(100).unwrap();
+        assert_eq!(colors.len(), 5);
+        assert!(is_proper(&hg, &colors));
+
+        // An odd cycle of pairs is not 2-colorable, but it is if one pair grows
+        let mut hg = Hypergraph::from(&[vec![0, 1], vec![1, 2], vec![2, 3], vec![3, 4], vec![4, 0]]);
+        assert_eq!(hg.two_coloring(100), None);
+        hg.remove_edge(&[4, 0]);
+        hg.add_edge(&[4, 0, 5]);
+        assert!(is_proper(&hg, &hg.two_coloring(100).unwrap()));
+
+        assert_eq!(fano().two_coloring(1000), None);
+
+        // Removing a line of the Fano plane makes it 2-colorable
+        let mut hg = fano();
+        hg.remove_edge(&[3, 5, 6]);
+        assert!(is_proper(&hg, &hg.two_coloring(1000).unwrap()));
+
+        assert_eq!(Hypergraph::new(false).two_coloring(0), Some(AHashMap::new()));
+    }
+
+    #[test]
+    fn test_greedy_coloring() {
+        let hg = fano();
+        let colors = hg.greedy_coloring();
+        assert_eq!(colors.len(), 7);
+        assert!(is_proper(&hg, &colors));
+        assert_eq!(colors.values().max(), Some(&2));
+
+        // Pairs need as many colors as a graph coloring
+        let hg = Hypergraph::from(&[vec![0, 1], vec![1, 2], vec![2, 0], vec![3]]);
+        let colors = hg.greedy_coloring();
+        assert!(is_proper(&hg, &colors));
+        assert_eq!(colors[&3], 0);
+    }
+
+    #[test]
+    fn test_independent_set() {
+        // A star, with the center in every hyperedge
+        let hg = Hypergraph::from(&[vec![0, 1], vec![0, 2], vec![0, 3, 4]]);
+        assert_eq!(hg.independent_set(), vec![1, 2, 3]);
+
+        let mut hg = Hypergraph::from(&[vec![0, 1], vec![2, 3, 4]]);
+        hg.add_node(5);
+        assert_eq!(hg.independent_set(), vec![5, 0, 2]);
+    }
+
+    #[test]
+    fn test_dominating_set() {
+        let hg = Hypergraph::from(&[vec![0, 1], vec![0, 2], vec![0, 3, 4]]);
+        assert_eq!(hg.dominating_set(), vec![0]);
+
+        let mut hg = Hypergraph::from(&[vec![0, 1], vec![2, 3, 4]]);
+        hg.add_node(5);
+        assert_eq!(hg.dominating_set(), vec![2, 0, 5]);
+
+        assert!(Hypergraph::new(false).dominating_set().is_empty());
+    }
+}
@@ -0,0 +1,411 @@
+use ahash::{AHashMap, AHashSet};
+
+use super::{Hypergraph, HypergraphError, Node};
+
+// Minimum modularity gain for a move to be applied, so that rounding errors cannot make the search loop
+const MIN_GAIN: f64 = 1e-12;
+
+/// State of the community detection: the hypergraph with nodes and hyperedges mapped to indices, and the current
+/// partition with the volume of every community.
+struct Louvain {
+    /// Distinct members and weight of every hyperedge.
+    edges: Vec<(Vec<usize>, f64)>,
+
+    /// Hyperedges incident to every node.
+    incidences: Vec<Vec<usize>>,
+
+    /// Weighted degree of every node.
+    degrees: Vec<f64>,
+
+    /// Total weight of the hyperedges of every size.
+    by_size: Vec<(usize, f64)>,
+
+    /// Sum of the volumes of all the communities.
+    total_volume: f64,
+
+    resolution: f64,
+
+    /// Community of every node, and volume of every community.
+    community: Vec<usize>,
+    volumes: Vec<f64>,
+}
+
+impl Louvain {
+    /// Contribution of a community with the given volume to the null-model term of the modularity.
+    fn null(&self, volume: f64) -> f64 {
+        self.by_size
+            .iter()
+            .map(|(size, weight)| weight * (volume / self.total_volume).powi(*size as i32))
+            .sum()
+    }
+
+    fn is_inside(&self, edge: usize, community: impl Fn(usize) -> usize) -> bool {
+        let members = &self.edges[edge].0;
+        members.iter().all(|v| community(*v) == community(members[0]))
+    }
+
+    /// Unnormalized modularity gain of moving all the `nodes` (distinct) into the community `target`.
+    fn gain(&self, nodes: &[usize], target: usize) -> f64 {
+        let moved: AHashSet<usize> = nodes.iter().copied().collect();
+        let after = |v: usize| if moved.contains(&v) { target } else { self.community[v] };
+
+        // Null-model term, only for the communities whose volume changes
+        let mut volumes: AHashMap<usize, f64> = AHashMap::new();
+        volumes.insert(target, self.volumes[target]);
+        for v in nodes.iter() {
+            volumes.entry(self.community[*v]).or_insert(self.volumes[self.community[*v]]);
+            *volumes.get_mut(&self.community[*v]).unwrap() -= self.degrees[*v];
+            *volumes.get_mut(&target).unwrap() += self.degrees[*v];
+        }
+        let null_delta: f64 = volumes.iter().map(|(c, volume)| self.null(*volume) - self.null(self.volumes[*c])).sum();
+
+        // Inside term, only for the hyperedges incident to the moved nodes
+        let touched: AHashSet<usize> = nodes.iter().flat_map(|v| self.incidences[*v].iter().copied()).collect();
+        let inside_delta: f64 = touched
+            .into_iter()
+            .map(|e| {
+                match (self.is_inside(e, |v| self.community[v]), self.is_inside(e, after)) {
+                    (false, true) => self.edges[e].1,
+                    (true, false) => -self.edges[e].1,
+                    _ => 0_f64,
+                }
+            })
+            .sum();
+
+        inside_delta - self.resolution * null_delta
+    }
+
+    fn apply(&mut self, nodes: &[usize], target: usize) {
+        for v in nodes.iter() {
+            self.volumes[self.community[*v]] -= self.degrees[*v];
+            self.volumes[target] += self.degrees[*v];
+            self.community[*v] = target;
+        }
+    }
+
+    /// Applies the move of `nodes` with the best positive gain among the `candidates` communities, the smallest one
+    /// among ties, and returns if a move was applied.
+    fn best_move(&mut self, nodes: &[usize], candidates: impl Iterator<Item = usize>) -> bool {
+        let mut candidates: Vec<usize> = candidates.collect();
+        candidates.sort_unstable();
+        candidates.dedup();
+
+        let mut best: Option<(usize, f64)> = None;
+        for target in candidates {
+            let gain = self.gain(nodes, target);
+            if gain > MIN_GAIN && best.is_none_or(|(_, value)| gain > value) {
+                best = Some((target, gain));
+            }
+        }
+
+        match best {
+            Some((target, _)) => {
+                self.apply(nodes, target);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+impl Hypergraph {
+    /// `type Node = i64`
+    ///
+    /// Computes the strict (all-or-nothing) modularity of a partition of the nodes, as defined by Kumar et al. and
+    /// Chodrow et al.: a hyperedge counts only if all its members belong to the same community, and the null model
+    /// expects a hyperedge of size `k` to fall inside community `c` with probability `(vol(c) / vol(V))^k`:
+    ///
+    /// `Q = (1/W) * (sum(w(e) : e inside a community) - sum_k(W_k * sum_c((vol(c) / vol(V))^k)))`
+    ///
+    /// where `W` is the total weight of the hyperedges, `W_k` the total weight of the ones of size `k` (counting
+    /// distinct nodes), and `vol(c)` the sum of the weights of the hyperedges incident to the nodes of `c`. If the
+    /// hypergraph is not weighted, every hyperedge has weight `1`.
+    ///
+    /// # Parameters
+    /// - `partition` : `&AHashMap<Node, usize>` - The community of every node.
+    ///
+    /// # Returns
+    /// - `Result<f64, HypergraphError>` - `Ok` containing the modularity (`0` if the hyperedges have no weight). Returns
+    ///   `Err(HypergraphError::IncompletePartition)` with the smallest node of the hypergraph which is not in `partition`,
+    ///   if any.
+    ///
+    /// # Performance
+    /// - `O(n + s + m*c)`, where `n` is the number of nodes, `s` the sum of the sizes of the hyperedges, `m` the number of
+    ///   distinct sizes and `c` the number of communities.
+    pub fn modularity(&self, partition: &AHashMap<Node, usize>) -> Result<f64, HypergraphError> {
+        if let Some(node) = self.incidence_list.keys().filter(|node| !partition.contains_key(node)).min() {
+            return Err(HypergraphError::IncompletePartition(*node));
+        }
+
+        Ok(self.compute_modularity(partition, 1_f64))
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Effectively computes the strict modularity of a complete partition, scaling the null-model term by `resolution`.
+    fn compute_modularity(&self, partition: &AHashMap<Node, usize>, resolution: f64) -> f64 {
+        let mut total = 0_f64;
+        let mut inside = 0_f64;
+        let mut by_size: AHashMap<usize, f64> = AHashMap::new();
+        let mut volumes: AHashMap<usize, f64> = AHashMap::new();
+
+        for hyperedge in self.edge_list.values() {
+            let weight = self.compute_expansion_weight(hyperedge.weight);
+            let members: AHashSet<&Node> = hyperedge.nodes.iter().collect();
+
+            total += weight;
+            *by_size.entry(members.len()).or_insert(0_f64) += weight;
+
+            let mut communities = members.iter().map(|node| partition[*node]);
+            let first = communities.next();
+            if communities.all(|community| Some(community) == first) {
+                inside += weight;
+            }
+
+            for node in members {
+                *volumes.entry(partition[node]).or_insert(0_f64) += weight;
+            }
+        }
+
+        if total == 0_f64 {
+            return 0_f64;
+        }
+
+        let volume: f64 = volumes.values().sum();
+        let expected: f64 = by_size
+            .iter()
+            .map(|(size, weight)| {
+                weight * volumes.values().map(|v| (v / volume).powi(*size as i32)).sum::<f64>()
+            })
+            .sum();
+
+        (inside - resolution * expected) / total
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Detects communities greedily maximizing the strict modularity (see `modularity`), with a Louvain-style search
+    /// adapted to hyperedges. Every pass tries, in order:
+    /// - node moves: every node moves to the community of one of its co-members, if that increases the modularity;
+    /// - hyperedge moves: all the members of every hyperedge which is not inside a community move to the community of
+    ///   one of them, if that increases the modularity;
+    /// - aggregation: all the members of every community move to an adjacent community, if that increases the
+    ///   modularity, as a move of a node of the aggregated hypergraph would.
+    ///
+    /// Each move goes to the community with the largest gain. The passes stop when none of them improves the modularity.
+    /// Nodes and hyperedges are visited in ascending order, so the result is deterministic.
+    ///
+    /// # Parameters
+    /// - `resolution` : `f64` - Multiplies the null-model term: values greater than `1` give smaller communities.
+    ///
+    /// # Returns
+    /// - `AHashMap<Node, usize>` - The community of every node, numbered from `0` in ascending order of their smallest node.
+    ///
+    /// # Performance
+    /// - `O(p*(n*d + m*k + c*c)*(d*k + z))` for `p` passes, where `n`, `m` and `c` are the number of nodes, hyperedges and
+    ///   communities, `d` the maximum degree, `k` the maximum size of a hyperedge and `z` the number of distinct sizes.
+    pub fn detect_communities(&self, resolution: f64) -> AHashMap<Node, usize> {
+        let mut nodes = self.get_nodes();
+        nodes.sort_unstable();
+        let n = nodes.len();
+        let index: AHashMap<Node, usize> = nodes.iter().enumerate().map(|(i, node)| (*node, i)).collect();
+
+        let mut sorted: Vec<_> = self.edge_list.values().collect();
+        sorted.sort_by(|a, b| a.nodes.cmp(&b.nodes));
+
+        let mut edges = Vec::with_capacity(sorted.len());
+        let mut incidences = vec![Vec::new(); n];
+        let mut degrees = vec![0_f64; n];
+        let mut by_size: AHashMap<usize, f64> = AHashMap::new();
+        for (e, hyperedge) in sorted.into_iter().enumerate() {
+            let weight = self.compute_expansion_weight(hyperedge.weight);
+            let mut members: Vec<usize> = hyperedge.nodes.iter().map(|node| index[node]).collect();
+            members.sort_unstable();
+            members.dedup();
+
+            for v in members.iter() {
+                incidences[*v].push(e);
+                degrees[*v] += weight;
+            }
+            *by_size.entry(members.len()).or_insert(0_f64) += weight;
+            edges.push((members, weight));
+        }
+
+        let mut state = Louvain {
+            edges,
+            incidences,
+            total_volume: degrees.iter().sum(),
+            volumes: degrees.clone(),
+            degrees,
+            by_size: by_size.into_iter().collect(),
+            resolution,
+            community: (0..n).collect(),
+        };
+
+        if state.total_volume > 0_f64 {
+            loop {
+                let mut improved = false;
+
+                for v in 0..n {
+                    let candidates: Vec<usize> = state.incidences[v]
+                        .iter()
+                        .flat_map(|e| state.edges[*e].0.iter().map(|w| state.community[*w]))
+                        .filter(|c| *c != state.community[v])
+                        .collect();
+                    improved |= state.best_move(&[v], candidates.into_iter());
+                }
+
+                for e in 0..state.edges.len() {
+                    if state.is_inside(e, |v| state.community[v]) {
+                        continue;
+                    }
+
+                    // Only the members outside the target community move
+                    let members = state.edges[e].0.clone();
+                    let moving = |target: usize| -> Vec<usize> {
+                        members.iter().copied().filter(|v| state.community[*v] != target).collect()
+                    };
+
+                    let mut candidates: Vec<usize> = members.iter().map(|v| state.community[*v]).collect();
+                    candidates.sort_unstable();
+                    candidates.dedup();
+
+                    let mut best: Option<(usize, f64)> = None;
+                    for target in candidates {
+                        let gain = state.gain(&moving(target), target);
+                        if gain > MIN_GAIN && best.is_none_or(|(_, value)| gain > value) {
+                            best = Some((target, gain));
+                        }
+                    }
+
+                    if let Some((target, _)) = best {
+                        let nodes = moving(target);
+                        state.apply(&nodes, target);
+                        improved = true;
+                    }
+                }
+
+                let mut groups: Vec<Vec<usize>> = vec![Vec::new(); n];
+                for v in 0..n {
+                    groups[state.community[v]].push(v);
+                }
+                for c in 0..n {
+                    if groups[c].is_empty() {
+                        continue;
+                    }
+                    let candidates: Vec<usize> = groups[c]
+                        .iter()
+                        .flat_map(|v| state.incidences[*v].iter())
+                        .flat_map(|e| state.edges[*e].0.iter().map(|w| state.community[*w]))
+                        .filter(|other| *other != c)
+                        .collect();
+
+                    let members = groups[c].clone();
+                    if state.best_move(&members, candidates.into_iter()) {
+                        let target = state.community[members[0]];
+                        groups[target].extend(members);
+                        groups[c].clear();
+                        improved = true;
+                    }
+                }
+
+                if !improved {
+                    break;
+                }
+            }
+        }
+
+        // Communities numbered in ascending order of their smallest node
+        let mut labels: AHashMap<usize, usize> = AHashMap::new();
+        nodes
+            .into_iter()
+            .zip(state.community)
+            .map(|(node, c)| {
+                let next = labels.len();
+                (node, *labels.entry(c).or_insert(next))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    fn planted() -> Hypergraph {
+        Hypergraph::from(&[
+            vec![0, 1, 2],
+            vec![1, 2, 3],
+            vec![0, 2, 3],
+            vec![0, 1, 3],
+            vec![4, 5, 6],
+            vec![5, 6, 7],
+            vec![4, 6, 7],
+            vec![4, 5, 7],
+            vec![3, 4],
+        ])
+    }
+
+    #[test]
+    fn test_modularity() {
+        let hg = planted();
+
+        let good: AHashMap<Node, usize> = (0..8).map(|node| (node, node as usize / 4)).collect();
+        let mixed: AHashMap<Node, usize> = (0..8).map(|node| (node, node as usize % 2)).collect();
+        let single: AHashMap<Node, usize> = (0..8).map(|node| (node, 0)).collect();
+
+        let q_good = hg.modularity(&good).unwrap();
+        assert!(q_good > 0.0);
+        assert!(q_good > hg.modularity(&mixed).unwrap());
+
+        // A single community is as good as the null model
+        assert!(hg.modularity(&single).unwrap().abs() < 1e-12);
+
+        let mut missing = good.clone();
+        missing.remove(&7);
+        assert_eq!(hg.modularity(&missing), Err(HypergraphError::IncompletePartition(7)));
+    }
+
+    #[test]
+    fn test_modularity_weighted() {
+        let mut hg = Hypergraph::new(true);
+        hg.add_edge_weighted(&[0, 1], 5.0);
+        hg.add_edge_weighted(&[2, 3], 5.0);
+        hg.add_edge_weighted(&[1, 2], 1.0);
+
+        let good: AHashMap<Node, usize> = [(0, 0), (1, 0), (2, 1), (3, 1)].into_iter().collect();
+        let bad: AHashMap<Node, usize> = [(0, 0), (1, 1), (2, 1), (3, 0)].into_iter().collect();
+
+        // Inside: 10 of 11; volumes: 11 and 11 of 22, so the null model expects 11 * (1/4 + 1/4)
+        assert!((hg.modularity(&good).unwrap() - (10.0 - 5.5) / 11.0).abs() < 1e-12);
+        assert!(hg.modularity(&bad).unwrap() < 0.0);
+        assert_eq!(Hypergraph::new(true).modularity(&AHashMap::new()), Ok(0.0));
+    }
+
+    #[test]
+    fn test_detect_communities() {
+        let hg = planted();
+        let communities = hg.detect_communities(1.0);
+
+        let expected: AHashMap<Node, usize> = (0..8).map(|node| (node, node as usize / 4)).collect();
+        assert_eq!(communities, expected);
+        assert!(hg.modularity(&communities).unwrap() >= hg.modularity(&expected).unwrap());
+
+        // Deterministic
+        assert_eq!(hg.detect_communities(1.0), communities);
+    }
+
+    #[test]
+    fn test_detect_communities_edge_cases() {
+        let mut hg = Hypergraph::from(&[vec![0, 1, 2]]);
+        hg.add_node(5);
+
+        let communities = hg.detect_communities(1.0);
+        assert_eq!(communities.len(), 4);
+        assert_eq!(communities[&0], communities[&1]);
+        assert_eq!(communities[&0], communities[&2]);
+        assert_ne!(communities[&0], communities[&5]);
+
+        assert!(Hypergraph::new(false).detect_communities(1.0).is_empty());
+    }
+}
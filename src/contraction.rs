@@ -0,0 +1,382 @@
+use ahash::{AHashMap, AHashSet};
+
+use super::hyperedge::Hyperedge;
+use super::{EdgeID, Hypergraph, HypergraphError, MergePolicy, Node};
+
+impl Hypergraph {
+    /// `type Node = i64`
+    ///
+    /// Contracts a set of nodes into a single node: every occurrence of a node of `nodes` in a hyperedge is replaced by
+    /// `into`, which is added if it is not in the hypergraph yet. Repeated nodes in the resulting hyperedges are
+    /// collapsed, keeping the first occurrence, and the contracted nodes (except `into`) are removed.
+    ///
+    /// Since the nodes of the hyperedges change, so do their `EdgeID`s: the hyperedges are removed and added again.
+    /// Hyperedges which become equal, to each other or to a hyperedge already in the hypergraph, are merged, with
+    /// their weights merged by `policy`, in lexicographic order of the original hyperedges. Hyperedges with more than
+    /// one node, which collapse to the single node `into`, are kept only if `keep_singletons` is `true`. The attributes,
+    /// the timestamps and the multiplicities of the hyperedges follow them, as in `remove_node`, while the attributes
+    /// of the contracted nodes are dropped.
+    ///
+    /// # Parameters
+    /// - `nodes` : `&[Node]` - The nodes to contract. It may contain `into`.
+    /// - `into` : `Node` - The node replacing them.
+    /// - `policy` : `MergePolicy` - The policy for the weights of the hyperedges which are merged.
+    /// - `keep_singletons` : `bool` - If `true`, the hyperedges which collapse to a single node are kept.
+    ///
+    /// # Returns
+    /// - `Result<(), HypergraphError>` - `Ok(())` if the nodes were contracted. Returns
+    ///   `Err(HypergraphError::NodeNotFound)` with the first node of `nodes` which is not in the hypergraph, and then
+    ///   the hypergraph is left unchanged.
+    ///
+    /// # Performance
+    /// - `O(c*k*log(c))`, where `c` is the number of hyperedges incident to the contracted nodes and `k` the maximum
+    ///   size of a hyperedge.
+    pub fn contract_nodes(
+        &mut self,
+        nodes: &[Node],
+        into: Node,
+        policy: MergePolicy,
+        keep_singletons: bool,
+    ) -> Result<(), HypergraphError> {
+        if let Some(missing) = nodes.iter().find(|node| !self.incidence_list.contains_key(node)) {
+            return Err(HypergraphError::NodeNotFound(*missing));
+        }
+
+        let contracted: AHashSet<Node> = nodes.iter().copied().filter(|node| *node != into).collect();
+
+        // Remove the hyperedges incident to the contracted nodes, with full maintenance of incidence_list
+        let mut removed: Vec<(EdgeID, Hyperedge)> = Vec::new();
+        for node in contracted.iter() {
+            self.node_attrs.remove(node);
+            for edge_id in self.incidence_list.remove(node).unwrap_or_default() {
+                if let Some(hyperedge) = self.edge_list.remove(&edge_id) {
+                    for member in hyperedge.nodes.iter() {
+                        if let Some(edge_ids) = self.incidence_list.get_mut(member) {
+                            edge_ids.remove(&edge_id);
+                        }
+                    }
+                    removed.push((edge_id, hyperedge));
+                }
+            }
+        }
+        self.add_node(into);
+
+        // Sorted, so that the merged weights do not depend on the order of edge_list
+        removed.sort_by(|a, b| a.1.nodes.cmp(&b.1.nodes));
+        for (edge_id, hyperedge) in removed {
+            let mut seen = AHashSet::new();
+            let edge: Vec<Node> = hyperedge
+                .nodes
+                .iter()
+                .map(|node| if contracted.contains(node) { into } else { *node })
+                .filter(|node| seen.insert(*node))
+                .collect();
+
+            if edge.len() > 1 || hyperedge.nodes.len() == 1 || keep_singletons {
+                let added = Self::compute_add_edge_with(self, &edge, hyperedge.weight, policy);
+                // The new hyperedges have no contracted node, so they never take the EdgeID of a removed one
+                let new_id = Self::compute_edge_id(&edge);
+                if let Some(merged) = self.edge_list.get_mut(&new_id) {
+                    merged.multiplicity = hyperedge.multiplicity + if added { 0 } else { merged.multiplicity };
+                }
+                self.compute_move_edge_attrs(edge_id, new_id);
+                self.compute_move_edge_times(edge_id, new_id);
+            } else {
+                self.edge_attrs.remove(&edge_id);
+                self.edge_times.remove(&edge_id);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Returns a copy of the hypergraph with the nodes relabeled according to `mapping`, with the same weights. The
+    /// `EdgeID`s are recomputed, since the nodes of the hyperedges change.
+    ///
+    /// If the mapping is not injective, the nodes with the same label are contracted, as in `contract_nodes`: repeated
+    /// nodes in a hyperedge are collapsed, keeping the first occurrence, and hyperedges which become equal are merged,
+    /// with their weights merged by the merge policy of the hypergraph, in lexicographic order of the original
+    /// hyperedges. Hyperedges which collapse to a single node are kept.
+    ///
+    /// The attributes of the nodes and of the hyperedges, the timestamps and the multiplicities are carried over: the
+    /// multiplicities of merged hyperedges are summed and their timestamps joined, while the attributes set on more
+    /// than one of the merged nodes (hyperedges) keep the value of the smallest one.
+    ///
+    /// # Parameters
+    /// - `mapping` : `&AHashMap<Node, Node>` - The new label of the nodes.
+    /// - `strict` : `bool` - If `true`, every node must be in `mapping`; otherwise, the nodes which are not in it keep
+    ///   their label.
+    ///
+    /// # Returns
+    /// - `Result<Hypergraph, HypergraphError>` - `Ok` containing the relabeled hypergraph. Returns
+    ///   `Err(HypergraphError::UnmappedNode)` with the smallest node which is not in `mapping`, if `strict` is `true`.
+    ///
+    /// # Performance
+    /// - `O(n + m*k*log(m))`, where `n` and `m` are the number of nodes and hyperedges, and `k` the maximum size of a
+    ///   hyperedge.
+    pub fn relabel_nodes(&self, mapping: &AHashMap<Node, Node>, strict: bool) -> Result<Hypergraph, HypergraphError> {
+        if strict {
+            if let Some(missing) = self.incidence_list.keys().filter(|node| !mapping.contains_key(node)).min() {
+                return Err(HypergraphError::UnmappedNode(*missing));
+            }
+        }
+        Ok(self.compute_relabel(|node| mapping.get(&node).copied().unwrap_or(node)))
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Returns a copy of the hypergraph with `f` applied to every node, eg to offset the labels or to hash external
+    /// identifiers. The `EdgeID`s are recomputed, and the weights preserved.
+    ///
+    /// Nodes which `f` maps to the same label are contracted, exactly as in `relabel_nodes` with a mapping which is not
+    /// injective.
+    ///
+    /// # Parameters
+    /// - `f` : `F` - The new label of every node.
+    ///
+    /// # Returns
+    /// - `Hypergraph` - The relabeled hypergraph.
+    ///
+    /// # Performance
+    /// - `O(n + m*k*log(m))`, where `n` and `m` are the number of nodes and hyperedges, and `k` the maximum size of a
+    ///   hyperedge.
+    pub fn map_nodes<F: Fn(Node) -> Node>(&self, f: F) -> Hypergraph {
+        self.compute_relabel(f)
+    }
+
+    /// Applies `f` to the weight of every hyperedge, in place, eg for a log-transform or a normalization.
+    ///
+    /// Unweighted hypergraphs are left unchanged. NaN and infinite results are clamped as in `add_edge_weighted`, so
+    /// the weights stay finite.
+    ///
+    /// # Parameters
+    /// - `f` : `F` - The transformation of the weights.
+    ///
+    /// # Returns
+    /// - `()`
+    ///
+    /// # Performance
+    /// - `O(m)`, where `m` is the number of hyperedges.
+    pub fn map_weights<F: Fn(f64) -> f64>(&mut self, f: F) {
+        if self.weighted {
+            for hyperedge in self.edge_list.values_mut() {
+                hyperedge.set_weight(Self::compute_finite_weight(f(hyperedge.weight)));
+            }
+        }
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Effectively computes the relabeling of the hypergraph, contracting the nodes with the same label.
+    fn compute_relabel(&self, label: impl Fn(Node) -> Node) -> Hypergraph {
+        let mut res = Hypergraph::with_merge_policy(self.weighted, self.merge_policy);
+
+        // Sorted, so that the smallest of the nodes with the same label wins the conflicting attributes
+        let mut nodes: Vec<Node> = self.incidence_list.keys().copied().collect();
+        nodes.sort_unstable();
+        for node in nodes {
+            let new_node = label(node);
+            res.add_node(new_node);
+            if let Some(attrs) = self.node_attrs.get(&node) {
+                let target = res.node_attrs.entry(new_node).or_default();
+                for (key, value) in attrs.iter() {
+                    target.entry(key.clone()).or_insert_with(|| value.clone());
+                }
+            }
+        }
+
+        // Sorted, so that the merged weights do not depend on the order of edge_list
+        let mut hyperedges: Vec<(&EdgeID, &Hyperedge)> = self.edge_list.iter().collect();
+        hyperedges.sort_by(|a, b| a.1.nodes.cmp(&b.1.nodes));
+        for (edge_id, hyperedge) in hyperedges {
+            let mut seen = AHashSet::new();
+            let edge: Vec<Node> =
+                hyperedge.nodes.iter().map(|node| label(*node)).filter(|node| seen.insert(*node)).collect();
+            let added = Self::compute_add_edge(&mut res, &edge, hyperedge.weight);
+
+            // Multiplicities, attributes and timestamps follow the hyperedge, as in `contract_nodes`
+            let new_id = Self::compute_edge_id(&edge);
+            if let Some(merged) = res.edge_list.get_mut(&new_id) {
+                merged.multiplicity = hyperedge.multiplicity + if added { 0 } else { merged.multiplicity };
+            }
+            if let Some(attrs) = self.edge_attrs.get(edge_id) {
+                let target = res.edge_attrs.entry(new_id).or_default();
+                for (key, value) in attrs.iter() {
+                    target.entry(key.clone()).or_insert_with(|| value.clone());
+                }
+            }
+            if let Some(times) = self.edge_times.get(edge_id) {
+                let target = res.edge_times.entry(new_id).or_default();
+                target.extend(times.iter().copied());
+                target.sort_unstable();
+                target.dedup();
+            }
+        }
+
+        res
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    #[test]
+    fn test_contract_shared_edge() {
+        let mut hg = Hypergraph::from_weighted(&[vec![1, 2, 3], vec![2, 4], vec![1, 2]], &[1.0, 2.0, 3.0]);
+
+        hg.contract_nodes(&[1, 2], 1, MergePolicy::Sum, false).unwrap();
+        assert!(!hg.check_node(2));
+        assert_eq!(hg.get_weight(&[1, 3]), Some(1.0));
+        assert_eq!(hg.get_weight(&[1, 4]), Some(2.0));
+        // [1, 2] collapsed to a single node, and was dropped
+        assert_eq!(hg.num_edges(), 2);
+        assert_eq!(hg.get_incident_edges(1, None, None).unwrap().unwrap().len(), 2);
+        assert_eq!(hg.get_incident_edges(3, None, None).unwrap().unwrap(), vec![&vec![1, 3]]);
+
+        let mut hg = Hypergraph::from_weighted(&[vec![1, 2, 3], vec![1, 2]], &[1.0, 3.0]);
+        hg.contract_nodes(&[1, 2], 9, MergePolicy::Sum, true).unwrap();
+        assert!(!hg.check_node(1) && !hg.check_node(2));
+        assert_eq!(hg.get_weight(&[9, 3]), Some(1.0));
+        assert_eq!(hg.get_weight(&[9]), Some(3.0));
+    }
+
+    #[test]
+    fn test_contract_merges_edges() {
+        // Two hyperedges differing only in the contracted nodes
+        let mut hg = Hypergraph::from_weighted(&[vec![1, 5, 6], vec![2, 5, 6], vec![7]], &[1.0, 2.0, 4.0]);
+        let mut max = hg.clone();
+
+        hg.contract_nodes(&[1, 2], 1, MergePolicy::Sum, false).unwrap();
+        assert_eq!(hg.num_edges(), 2);
+        assert_eq!(hg.get_weight(&[1, 5, 6]), Some(3.0));
+        assert_eq!(hg.get_incident_edges(5, None, None).unwrap().unwrap(), vec![&vec![1, 5, 6]]);
+
+        max.contract_nodes(&[1, 2], 1, MergePolicy::Max, false).unwrap();
+        assert_eq!(max.get_weight(&[1, 5, 6]), Some(2.0));
+
+        assert_eq!(hg.contract_nodes(&[5, 8], 5, MergePolicy::Sum, false), Err(HypergraphError::NodeNotFound(8)));
+        assert_eq!(hg.num_edges(), 2);
+        assert!(hg.check_edge(&[1, 5, 6]));
+    }
+
+    #[test]
+    fn test_relabel_bijective() {
+        let mut hg = Hypergraph::from_weighted(&[vec![10, 20, 30], vec![30, 40], vec![40]], &[1.0, 2.0, 3.0]);
+        hg.add_node(50);
+
+        // Densify the labels into 0..n
+        let nodes = hg.get_nodes_sorted();
+        let mapping: AHashMap<Node, Node> = nodes.iter().enumerate().map(|(i, node)| (*node, i as Node)).collect();
+        let relabeled = hg.relabel_nodes(&mapping, true).unwrap();
+
+        assert_eq!(relabeled.get_nodes_sorted(), vec![0, 1, 2, 3, 4]);
+        assert_eq!(relabeled.get_weight(&[0, 1, 2]), Some(1.0));
+        assert_eq!(relabeled.get_weight(&[2, 3]), Some(2.0));
+        assert_eq!(relabeled.get_weight(&[3]), Some(3.0));
+
+        let degrees = |hg: &Hypergraph, nodes: &[Node]| -> Vec<usize> {
+            nodes.iter().map(|node| hg.get_incident_edges(*node, None, None).unwrap().unwrap().len()).collect()
+        };
+        assert_eq!(degrees(&hg, &nodes), degrees(&relabeled, &[0, 1, 2, 3, 4]));
+        let sizes = |hg: &Hypergraph| {
+            let mut sizes: Vec<usize> = hg.get_edges().unwrap().iter().map(|edge| edge.len()).collect();
+            sizes.sort();
+            sizes
+        };
+        assert_eq!(sizes(&hg), sizes(&relabeled));
+
+        // Relabeling back gives the original hypergraph
+        let inverse: AHashMap<Node, Node> = mapping.iter().map(|(node, label)| (*label, *node)).collect();
+        assert_eq!(relabeled.relabel_nodes(&inverse, true).unwrap(), hg);
+    }
+
+    #[test]
+    fn test_relabel_partial() {
+        let hg = Hypergraph::from_weighted(&[vec![1, 2, 3], vec![2, 3], vec![4]], &[1.0, 2.0, 3.0]);
+
+        let mapping = AHashMap::from([(1, 2), (4, 40)]);
+        assert_eq!(hg.relabel_nodes(&mapping, true), Err(HypergraphError::UnmappedNode(2)));
+
+        // Not injective: 1 and 2 are contracted, and [2, 3] is merged with the default policy
+        let relabeled = hg.relabel_nodes(&mapping, false).unwrap();
+        assert_eq!(relabeled.get_nodes_sorted(), vec![2, 3, 40]);
+        assert_eq!(relabeled.num_edges(), 2);
+        assert_eq!(relabeled.get_weight(&[2, 3]), Some(2.0));
+        assert_eq!(relabeled.get_weight(&[40]), Some(3.0));
+    }
+
+    #[test]
+    fn test_map_nodes() {
+        let mut hg = Hypergraph::from_weighted(&[vec![1, 2, 3], vec![3, 4], vec![4]], &[1.0, 2.0, 3.0]);
+        hg.add_node(5);
+
+        let shifted = hg.map_nodes(|node| node + 1000);
+        assert_eq!(shifted.get_nodes_sorted(), vec![1001, 1002, 1003, 1004, 1005]);
+        assert_eq!(shifted.get_weight(&[1003, 1004]), Some(2.0));
+        assert_eq!(shifted.map_nodes(|node| node - 1000), hg);
+
+        // Collisions are contracted: [1, 2, 3] becomes [0, 1], [3, 4] and [4] merge into [1, 2] and [2]
+        let halved = hg.map_nodes(|node| node / 2);
+        assert_eq!(halved.get_nodes_sorted(), vec![0, 1, 2]);
+        assert_eq!(halved.get_edges_sorted(), Some(vec![vec![0, 1], vec![1, 2], vec![2]]));
+    }
+
+    #[test]
+    fn test_relabel_metadata() {
+        use crate::AttrValue;
+
+        let mut hg = Hypergraph::new(true);
+        hg.add_edge_at(&[2, 4], 1.0, 10);
+        hg.add_edge_at(&[3, 5], 2.0, 11);
+        hg.add_edge_multi(&[3, 5]);
+        hg.add_edge_multi(&[1]);
+        hg.set_node_attr(2, "name", AttrValue::Text("b".to_string())).unwrap();
+        hg.set_node_attr(3, "name", AttrValue::Text("c".to_string())).unwrap();
+        hg.set_edge_attr(&[2, 4], "year", AttrValue::Int(2020)).unwrap();
+        hg.set_edge_attr(&[3, 5], "year", AttrValue::Int(2021)).unwrap();
+
+        // Bijective, everything follows the nodes
+        let mapping: AHashMap<Node, Node> = [(1, 10), (2, 20), (3, 30), (4, 40), (5, 50)].into_iter().collect();
+        let relabeled = hg.relabel_nodes(&mapping, true).unwrap();
+        assert_eq!(relabeled.multiplicity(&[30, 50]), Some(2));
+        assert_eq!(relabeled.multiplicity(&[10]), Some(1));
+        assert_eq!(relabeled.edge_times(&[20, 40]), Some(&[10][..]));
+        assert_eq!(relabeled.get_edge_attr(&[30, 50], "year"), Some(&AttrValue::Int(2021)));
+        assert_eq!(relabeled.get_node_attr(20, "name"), Some(&AttrValue::Text("b".to_string())));
+
+        // [2, 4] and [3, 5] merge into [1, 2]: multiplicities are summed, timestamps joined, and the smallest wins
+        let halved = hg.map_nodes(|node| node / 2);
+        assert_eq!(halved.get_edges_sorted(), Some(vec![vec![0], vec![1, 2]]));
+        assert_eq!(halved.multiplicity(&[1, 2]), Some(3));
+        assert_eq!(halved.edge_times(&[1, 2]), Some(&[10, 11][..]));
+        assert_eq!(halved.get_edge_attr(&[1, 2], "year"), Some(&AttrValue::Int(2020)));
+        assert_eq!(halved.get_node_attr(1, "name"), Some(&AttrValue::Text("b".to_string())));
+        assert_eq!(halved.node_attrs(2), Some(vec![]));
+    }
+
+    #[test]
+    fn test_map_weights() {
+        let mut hg = Hypergraph::from_weighted(&[vec![1, 2, 3], vec![3, 4]], &[0.0, 1.0]);
+        hg.map_weights(f64::ln_1p);
+        assert_eq!(hg.get_weight(&[1, 2, 3]), Some(0.0));
+        assert_eq!(hg.get_weight(&[3, 4]), Some(2_f64.ln()));
+        let mut weights = hg.get_weights().unwrap();
+        weights.sort_by(f64::total_cmp);
+        assert_eq!(weights, vec![0.0, 2_f64.ln()]);
+
+        // Results which are not finite are clamped
+        hg.map_weights(|weight| 1.0 / weight);
+        assert_eq!(hg.get_weight(&[1, 2, 3]), Some(f64::MAX));
+        hg.map_weights(|_| f64::NAN);
+        assert_eq!(hg.validate_weights(), Ok(()));
+        assert_eq!(hg.get_weight(&[3, 4]), Some(0.0));
+
+        let mut hg = Hypergraph::from(&[vec![1, 2]]);
+        hg.map_weights(|weight| weight + 1.0);
+        assert_eq!(hg.get_weights(), None);
+        assert_eq!(hg.get_edges_weighted().unwrap(), vec![(&vec![1, 2], 0.0)]);
+    }
+}
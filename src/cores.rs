@@ -0,0 +1,155 @@
+use ahash::{AHashMap, AHashSet};
+
+use super::{Hypergraph, Node};
+
+impl Hypergraph {
+    /// `type Node = i64`
+    ///
+    /// Computes the core number of every node: the largest `k` such that the node belongs to the k-core, ie to what is
+    /// left after repeatedly removing the nodes with degree less than `k`.
+    ///
+    /// The degree of a node is the number of its alive incident hyperedges, and removing a node shrinks its hyperedges:
+    /// a hyperedge stays alive while it has at least 2 remaining distinct members. Hyperedges with a single distinct
+    /// node are never alive, so they do not contribute to any degree.
+    ///
+    /// The nodes are peeled in increasing order of degree with a bucket queue.
+    ///
+    /// # Returns
+    /// - `AHashMap<Node, usize>` - The core number of every node.
+    ///
+    /// # Performance
+    /// - `O(n + s)`, where `n` is the number of nodes and `s` the sum of the sizes of the hyperedges.
+    pub fn core_decomposition(&self) -> AHashMap<Node, usize> {
+        let nodes: Vec<Node> = self.incidence_list.keys().copied().collect();
+        let index: AHashMap<Node, usize> = nodes.iter().enumerate().map(|(i, node)| (*node, i)).collect();
+
+        // Distinct members of every hyperedge, and the hyperedges of every node
+        let members: Vec<Vec<usize>> = self
+            .edge_list
+            .values()
+            .map(|hyperedge| {
+                let distinct: AHashSet<usize> = hyperedge.nodes.iter().map(|node| index[node]).collect();
+                distinct.into_iter().collect()
+            })
+            .collect();
+        let mut incidences: Vec<Vec<usize>> = vec![Vec::new(); nodes.len()];
+        for (e, edge) in members.iter().enumerate() {
+            for v in edge.iter() {
+                incidences[*v].push(e);
+            }
+        }
+
+        let mut remaining: Vec<usize> = members.iter().map(|edge| edge.len()).collect();
+        let mut degrees: Vec<usize> = incidences
+            .iter()
+            .map(|edges| edges.iter().filter(|e| remaining[**e] >= 2).count())
+            .collect();
+
+        // Bucket queue with lazy deletion: stale entries are skipped when their degree no longer matches
+        let max_degree = degrees.iter().copied().max().unwrap_or(0);
+        let mut buckets: Vec<Vec<usize>> = vec![Vec::new(); max_degree + 1];
+        for (v, degree) in degrees.iter().enumerate() {
+            buckets[*degree].push(v);
+        }
+
+        let mut removed = vec![false; nodes.len()];
+        let mut cores = vec![0; nodes.len()];
+        for k in 0..=max_degree {
+            while let Some(v) = buckets[k].pop() {
+                if removed[v] || degrees[v] != k {
+                    continue;
+                }
+                removed[v] = true;
+                cores[v] = k;
+
+                for e in incidences[v].iter() {
+                    remaining[*e] -= 1;
+                    if remaining[*e] == 1 {
+                        // The hyperedge dies: its last member loses it
+                        for w in members[*e].iter() {
+                            if !removed[*w] && degrees[*w] > k {
+                                degrees[*w] -= 1;
+                                buckets[degrees[*w]].push(*w);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        nodes.into_iter().zip(cores).collect()
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Returns the k-core of the hypergraph, ie the subhypergraph induced by the nodes with core number at least `k`.
+    /// See `core_decomposition` for more details.
+    ///
+    /// # Parameters
+    /// - `k` : `usize` - The minimum core number of the nodes to keep.
+    ///
+    /// # Returns
+    /// - `Self` - The k-core of the hypergraph.
+    ///
+    /// # Performance
+    /// - `O(n*m)`, where `n` and `m` are the number of nodes and the number of hyperedges of the hypergraph.
+    pub fn k_core(&self, k: usize) -> Self {
+        let nodes: Vec<Node> = self
+            .core_decomposition()
+            .into_iter()
+            .filter(|(_, core)| *core >= k)
+            .map(|(node, _)| node)
+            .collect();
+
+        self.subhypergraph(&nodes)
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    fn build() -> Hypergraph {
+        // Every pair of 0, 1, 2, 3 shares two hyperedges, 4 and 5 hang from them, 6 only has a hyperedge of size 1
+        let mut hg = Hypergraph::from(&[
+            vec![0, 1, 2],
+            vec![0, 1, 3],
+            vec![0, 2, 3],
+            vec![1, 2, 3],
+            vec![3, 4],
+            vec![4, 5, 0],
+            vec![6],
+        ]);
+        hg.add_node(7);
+        hg
+    }
+
+    #[test]
+    fn test_core_decomposition() {
+        let cores = build().core_decomposition();
+        let expected: AHashMap<Node, usize> = [(0, 3), (1, 3), (2, 3), (3, 3), (4, 2), (5, 1), (6, 0), (7, 0)]
+            .into_iter()
+            .collect();
+
+        assert_eq!(cores, expected);
+        assert!(Hypergraph::new(false).core_decomposition().is_empty());
+    }
+
+    #[test]
+    fn test_k_core() {
+        let hg = build();
+
+        let core = hg.k_core(3);
+        assert_eq!(core.num_nodes(), 4);
+        assert_eq!(core.num_edges(), 4);
+        assert!(core.check_edge(&[1, 2, 3]));
+
+        let core = hg.k_core(2);
+        assert_eq!(core.num_nodes(), 5);
+        assert!(core.check_edge(&[3, 4]));
+        assert!(!core.check_edge(&[4, 5, 0]));
+
+        assert_eq!(hg.k_core(0).num_edges(), hg.num_edges());
+        assert_eq!(hg.k_core(4).num_nodes(), 0);
+    }
+}
@@ -0,0 +1,482 @@
+use std::collections::VecDeque;
+
+use ahash::{AHashMap, AHashSet};
+
+use super::{EdgeID, Hypergraph, HypergraphError, Node};
+
+// Marks a vertex of the star expansion which has not been visited yet
+const UNVISITED: usize = usize::MAX;
+
+// Capacity of the arcs which cannot be cut, larger than any flow since the flow is at most the number of nodes
+const INFINITE: usize = usize::MAX / 2;
+
+/// Low-link values of the star expansion of a hypergraph, ie the bipartite graph between the nodes and the hyperedges
+/// where each hyperedge is adjacent to its nodes. The vertices `0..nodes.len()` are the nodes, the other ones the
+/// hyperedges.
+struct LowLink {
+    nodes: Vec<Node>,
+    cut_nodes: Vec<bool>,
+    edge_ids: Vec<EdgeID>,
+    cut_edges: Vec<bool>,
+}
+
+/// Flow network with integer capacities, where every arc is stored next to its reverse one, so that the reverse of
+/// the arc `i` is `i ^ 1`.
+struct FlowNetwork {
+    heads: Vec<usize>,
+    capacities: Vec<usize>,
+    arcs: Vec<Vec<usize>>,
+}
+
+impl FlowNetwork {
+    fn new(vertices: usize) -> Self {
+        Self { heads: Vec::new(), capacities: Vec::new(), arcs: vec![Vec::new(); vertices] }
+    }
+
+    fn add_arc(&mut self, from: usize, to: usize, capacity: usize) {
+        self.arcs[from].push(self.heads.len());
+        self.heads.push(to);
+        self.capacities.push(capacity);
+        self.arcs[to].push(self.heads.len());
+        self.heads.push(from);
+        self.capacities.push(0);
+    }
+
+    /// Effectively finds a shortest augmenting path in the residual network with a BFS.
+    ///
+    /// # Returns
+    /// - `Result<Vec<usize>, Vec<bool>>` - `Ok` containing the arcs of the path, from `sink` back to `source`, or
+    ///   `Err` containing the vertices reachable from `source` if `sink` is not.
+    fn compute_augmenting_path(&self, source: usize, sink: usize) -> Result<Vec<usize>, Vec<bool>> {
+        let mut reached = vec![false; self.arcs.len()];
+        let mut through: Vec<usize> = vec![UNVISITED; self.arcs.len()];
+        let mut queue = VecDeque::from([source]);
+        reached[source] = true;
+
+        while let Some(vertex) = queue.pop_front() {
+            for arc in self.arcs[vertex].iter() {
+                let head = self.heads[*arc];
+                if self.capacities[*arc] > 0 && !reached[head] {
+                    reached[head] = true;
+                    through[head] = *arc;
+                    queue.push_back(head);
+                }
+            }
+
+            if reached[sink] {
+                let mut path = Vec::new();
+                let mut vertex = sink;
+                while vertex != source {
+                    path.push(through[vertex]);
+                    vertex = self.heads[through[vertex] ^ 1];
+                }
+                return Ok(path);
+            }
+        }
+
+        Err(reached)
+    }
+}
+
+impl Hypergraph {
+    /// `type Node = i64`
+    ///
+    /// Returns a minimum set of nodes whose removal disconnects `source` from `sink`, ie a minimum vertex cut, whose
+    /// size is the maximum number of paths between them with no inner node in common (Menger's theorem).
+    ///
+    /// The cut is computed with Edmonds-Karp's max-flow algorithm on the star expansion of the hypergraph, where every
+    /// node but `source` and `sink` is split into an entry and an exit joined by an arc of capacity 1, and the arcs
+    /// between nodes and hyperedges cannot be cut. The returned nodes are the split ones on the border of the set
+    /// reachable from `source` in the final residual network.
+    ///
+    /// # Parameters
+    /// - `source` : `Node` - The node to disconnect from `sink`.
+    /// - `sink` : `Node` - The node to disconnect from `source`.
+    ///
+    /// # Returns
+    /// - `Result<Vec<Node>, HypergraphError>` - `Ok` containing the sorted nodes of a minimum cut, empty if `source` and
+    ///   `sink` are already disconnected. Returns `Err(HypergraphError::NodeNotFound)` if one of them is not in the
+    ///   hypergraph, `Err(HypergraphError::SourceIsSink)` if they are the same node and
+    ///   `Err(HypergraphError::AdjacentNodes)` if they share a hyperedge, since then no cut exists.
+    ///
+    /// # Performance
+    /// - `O(c*(n + m + I))`, where `c` is the size of the cut, `n` and `m` the number of nodes and hyperedges and `I`
+    ///   the sum of their sizes.
+    pub fn min_node_cut(&self, source: Node, sink: Node) -> Result<Vec<Node>, HypergraphError> {
+        for node in [source, sink] {
+            if !self.incidence_list.contains_key(&node) {
+                return Err(HypergraphError::NodeNotFound(node));
+            }
+        }
+        if source == sink {
+            return Err(HypergraphError::SourceIsSink(source));
+        }
+        if self.co_occurrence(source, sink) > 0 {
+            return Err(HypergraphError::AdjacentNodes(source, sink));
+        }
+
+        // The node `i` enters at `2*i` and exits at `2*i + 1`, the hyperedge `j` is the vertex `2*n + j`
+        let nodes: Vec<Node> = self.incidence_list.keys().copied().collect();
+        let index: AHashMap<Node, usize> = nodes.iter().enumerate().map(|(i, node)| (*node, i)).collect();
+        let mut network = FlowNetwork::new(2 * nodes.len() + self.edge_list.len());
+        for i in 0..nodes.len() {
+            network.add_arc(2 * i, 2 * i + 1, 1);
+        }
+        for (j, hyperedge) in self.edge_list.values().enumerate() {
+            let vertex = 2 * nodes.len() + j;
+            let members: AHashSet<usize> = hyperedge.nodes.iter().map(|node| index[node]).collect();
+            for member in members {
+                network.add_arc(2 * member + 1, vertex, INFINITE);
+                network.add_arc(vertex, 2 * member, INFINITE);
+            }
+        }
+
+        // Every augmenting path crosses at least one split node, so it carries exactly one unit of flow
+        let (from, to) = (2 * index[&source] + 1, 2 * index[&sink]);
+        let reached = loop {
+            match network.compute_augmenting_path(from, to) {
+                Ok(path) => {
+                    for arc in path {
+                        network.capacities[arc] -= 1;
+                        network.capacities[arc ^ 1] += 1;
+                    }
+                }
+                Err(reached) => break reached,
+            }
+        };
+
+        let mut res: Vec<Node> = (0..nodes.len())
+            .filter(|i| reached[2 * i] && !reached[2 * i + 1])
+            .map(|i| nodes[i])
+            .collect();
+        res.sort_unstable();
+
+        Ok(res)
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Returns the cut nodes (articulation points) of the hypergraph, ie the nodes whose weak removal, which only
+    /// takes them out of their hyperedges, increases the number of connected components.
+    ///
+    /// The nodes are found with Tarjan's low-link algorithm on the star expansion of the hypergraph, with an explicit
+    /// stack. A node is a cut node if the other nodes of its component are split by its removal, so the nodes inside
+    /// a single hyperedge are never cut nodes, and an isolated node is not one either.
+    ///
+    /// # Parameters
+    /// - `order` : `Option<usize>` - The order of the hyperedges to consider. If None, all hyperedges are considered.
+    /// - `size` : `Option<usize>` - The size of the hyperedges to consider. If None, all hyperedges are considered.
+    ///
+    /// # Returns
+    /// - `Result<Vec<Node>, HypergraphError>` - `Ok` containing the sorted cut nodes, with respect to the considered
+    ///   hyperedges. Returns `Err(HypergraphError::BothOrderAndSize)` if both `order` and `size` are specified.
+    ///
+    /// # Performance
+    /// - `O(n + m + I)`, where `n` and `m` are the number of nodes and hyperedges and `I` the sum of their sizes.
+    pub fn cut_nodes(&self, order: Option<usize>, size: Option<usize>) -> Result<Vec<Node>, HypergraphError> {
+        if order.is_some() && size.is_some() {
+            return Err(HypergraphError::BothOrderAndSize);
+        }
+
+        let low_link = self.compute_low_link(order.map(|val| val + 1).or(size));
+        let mut res: Vec<Node> = low_link
+            .nodes
+            .iter()
+            .zip(low_link.cut_nodes.iter())
+            .filter(|(_, cut)| **cut)
+            .map(|(node, _)| *node)
+            .collect();
+        res.sort_unstable();
+
+        Ok(res)
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Returns the cut hyperedges (bridges) of the hypergraph, ie the hyperedges whose removal, which keeps their
+    /// nodes, increases the number of connected components.
+    ///
+    /// As for `cut_nodes`, the hyperedges are found with Tarjan's low-link algorithm on the star expansion. Splitting
+    /// off a single node counts as a cut: a hyperedge which is the only one containing some node is a cut hyperedge,
+    /// unless it has no other node. So every hyperedge of a chain of pairs is a cut hyperedge, the ones at the ends
+    /// included, while no hyperedge of a cycle is.
+    ///
+    /// # Returns
+    /// - `Vec<&Vec<Node>>` - The cut hyperedges, sorted.
+    ///
+    /// # Performance
+    /// - `O(n + m + I + c*log(c))`, where `n` and `m` are the number of nodes and hyperedges, `I` the sum of their sizes
+    ///   and `c` the number of cut hyperedges.
+    pub fn cut_edges(&self) -> Vec<&Vec<Node>> {
+        let low_link = self.compute_low_link(None);
+        let mut res: Vec<&Vec<Node>> = low_link
+            .edge_ids
+            .iter()
+            .zip(low_link.cut_edges.iter())
+            .filter(|(_, cut)| **cut)
+            .map(|(edge_id, _)| &self.edge_list[edge_id].nodes)
+            .collect();
+        res.sort_unstable();
+
+        res
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Effectively computes the low-link values of the star expansion, considering only the hyperedges of size
+    /// `filter` if specified. Every DFS starts from a node, so the parent of a hyperedge is always a node.
+    fn compute_low_link(&self, filter: Option<usize>) -> LowLink {
+        let nodes: Vec<Node> = self.incidence_list.keys().copied().collect();
+        let index: AHashMap<Node, usize> = nodes.iter().enumerate().map(|(i, node)| (*node, i)).collect();
+
+        // O(n + m + I), adjacency lists of the star expansion, without the repeated nodes of a hyperedge
+        let mut adjacency: Vec<Vec<usize>> = vec![Vec::new(); nodes.len()];
+        let mut edge_ids = Vec::new();
+        for (edge_id, hyperedge) in self.edge_list.iter() {
+            if filter.is_some_and(|len| hyperedge.nodes.len() != len) {
+                continue;
+            }
+            edge_ids.push(*edge_id);
+
+            let vertex = adjacency.len();
+            let members: AHashSet<usize> = hyperedge.nodes.iter().map(|node| index[node]).collect();
+            for member in members.iter() {
+                adjacency[*member].push(vertex);
+            }
+            adjacency.push(members.into_iter().collect());
+        }
+
+        let mut disc = vec![UNVISITED; adjacency.len()];
+        let mut low = vec![UNVISITED; adjacency.len()];
+        // Number of nodes in the DFS subtree of every vertex
+        let mut below: Vec<usize> = (0..adjacency.len()).map(|vertex| usize::from(vertex < nodes.len())).collect();
+        // Number of DFS children of every root which bring at least one node
+        let mut root_children = vec![0; nodes.len()];
+        let mut cut_nodes = vec![false; nodes.len()];
+        let mut cut_edges = vec![false; edge_ids.len()];
+        let mut time = 0;
+
+        for root in 0..nodes.len() {
+            if disc[root] != UNVISITED {
+                continue;
+            }
+            disc[root] = time;
+            low[root] = time;
+            time += 1;
+
+            // (vertex, parent, index of the next neighbor to check)
+            let mut stack = vec![(root, UNVISITED, 0)];
+            while let Some((vertex, parent, next)) = stack.last_mut() {
+                if let Some(neighbor) = adjacency[*vertex].get(*next).copied() {
+                    *next += 1;
+                    if neighbor == *parent {
+                        continue;
+                    }
+
+                    if disc[neighbor] == UNVISITED {
+                        disc[neighbor] = time;
+                        low[neighbor] = time;
+                        time += 1;
+                        let vertex = *vertex;
+                        stack.push((neighbor, vertex, 0));
+                    } else {
+                        low[*vertex] = low[*vertex].min(disc[neighbor]);
+                    }
+                    continue;
+                }
+
+                let (child, parent, _) = stack.pop().unwrap(); // It will not panic
+                if parent == UNVISITED {
+                    continue;
+                }
+                low[parent] = low[parent].min(low[child]);
+                below[parent] += below[child];
+
+                // The subtree of a child hyperedge is cut off by removing its parent node, if it brings other nodes
+                if parent < nodes.len() && below[child] > 0 {
+                    if parent == root {
+                        root_children[root] += 1;
+                    } else if low[child] >= disc[parent] {
+                        cut_nodes[parent] = true;
+                    }
+                }
+                // The subtree of a child node is cut off by removing its parent hyperedge, which is never a root
+                if parent >= nodes.len() && low[child] >= disc[parent] {
+                    cut_edges[parent - nodes.len()] = true;
+                }
+            }
+
+            // The root has no ancestors, so it is a cut node if it separates two of its subtrees
+            cut_nodes[root] = root_children[root] > 1;
+        }
+
+        LowLink { nodes, cut_nodes, edge_ids, cut_edges }
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    /// Cut nodes by definition, removing every node from a copy and counting the components. The hyperedges of other
+    /// sizes are dropped first, as the removal shrinks the hyperedges.
+    fn naive_cut_nodes(hg: &Hypergraph, size: Option<usize>) -> Vec<Node> {
+        let hg = hg.subhypergraph_filter(|nodes, _| size.is_none_or(|len| nodes.len() == len), true);
+        let before = hg.num_ccs(None, None).unwrap();
+        let mut res: Vec<Node> = hg
+            .get_nodes()
+            .into_iter()
+            .filter(|node| {
+                let mut copy = hg.clone();
+                copy.remove_node(*node);
+                copy.num_ccs(None, None).unwrap() > before
+            })
+            .collect();
+        res.sort();
+        res
+    }
+
+    #[test]
+    fn test_cut_nodes() {
+        // 3 is the only node shared by two otherwise disjoint hyperedges
+        let hg = Hypergraph::from(&[vec![1, 2, 3], vec![3, 4, 5]]);
+        assert_eq!(hg.cut_nodes(None, None), Ok(vec![3]));
+
+        // No node inside a single hyperedge is a cut node
+        let hg = Hypergraph::from(&[vec![1, 2, 3, 4, 5]]);
+        assert_eq!(hg.cut_nodes(None, None), Ok(vec![]));
+
+        // A chain of pairs, whose interior nodes are all cut nodes, and a triangle
+        let mut hg = Hypergraph::from(&[vec![1, 2], vec![2, 3], vec![3, 4], vec![5, 6], vec![6, 7], vec![7, 5]]);
+        hg.add_edges(&[vec![8], vec![9, 9, 4], vec![2, 3, 10]]);
+        hg.add_node(11);
+        assert_eq!(hg.cut_nodes(None, None), Ok(vec![2, 3, 4]));
+        assert_eq!(hg.cut_nodes(None, None).unwrap(), naive_cut_nodes(&hg, None));
+
+        // Only the pairs: 10 and 9 are isolated, so 4 is the end of the chain
+        assert_eq!(hg.cut_nodes(None, Some(2)), Ok(vec![2, 3]));
+        assert_eq!(hg.cut_nodes(None, Some(2)).unwrap(), naive_cut_nodes(&hg, Some(2)));
+        assert_eq!(hg.cut_nodes(Some(2), None), Ok(vec![]));
+        assert_eq!(hg.cut_nodes(Some(1), Some(2)), Err(HypergraphError::BothOrderAndSize));
+        assert_eq!(Hypergraph::new(false).cut_nodes(None, None), Ok(vec![]));
+    }
+
+    /// Cut hyperedges by definition, removing every hyperedge from a copy and counting the components.
+    fn naive_cut_edges(hg: &Hypergraph) -> Vec<&Vec<Node>> {
+        let before = hg.num_ccs(None, None).unwrap();
+        let mut res: Vec<&Vec<Node>> = hg
+            .edge_list
+            .iter()
+            .filter(|(edge_id, _)| {
+                let mut copy = hg.clone();
+                copy.remove_edge_by_id(**edge_id);
+                copy.num_ccs(None, None).unwrap() > before
+            })
+            .map(|(_, hyperedge)| &hyperedge.nodes)
+            .collect();
+        res.sort();
+        res
+    }
+
+    #[test]
+    fn test_cut_edges() {
+        // Every hyperedge of a chain is a bridge
+        let hg = Hypergraph::from(&[vec![1, 2], vec![2, 3, 4], vec![4, 5], vec![5, 6]]);
+        assert_eq!(hg.cut_edges(), vec![&vec![1, 2], &vec![2, 3, 4], &vec![4, 5], &vec![5, 6]]);
+
+        // No hyperedge of a cycle is a bridge
+        let mut hg = Hypergraph::from(&[vec![1, 2], vec![2, 4], vec![4, 5], vec![5, 1]]);
+        assert!(hg.cut_edges().is_empty());
+        // Unless it is the only one containing a node
+        hg.add_edge(&[2, 3, 4]);
+        assert_eq!(hg.cut_edges(), vec![&vec![2, 3, 4]]);
+
+        // Two hyperedges with the same nodes in a different order, a singleton and a hyperedge with a repeated node
+        let mut hg = Hypergraph::from(&[vec![1, 2], vec![2, 1], vec![2, 3], vec![3], vec![3, 3], vec![4, 4, 3]]);
+        hg.add_node(5);
+        assert_eq!(hg.cut_edges(), vec![&vec![2, 3], &vec![4, 4, 3]]);
+        assert_eq!(hg.cut_edges(), naive_cut_edges(&hg));
+
+        assert!(Hypergraph::new(false).cut_edges().is_empty());
+    }
+
+    /// Checks that removing the nodes disconnects `source` from `sink`.
+    fn disconnects(hg: &Hypergraph, nodes: &[Node], source: Node, sink: Node) -> bool {
+        let mut copy = hg.clone();
+        copy.remove_nodes(nodes);
+        !copy.node_cc(source, None, None).unwrap().contains(&sink)
+    }
+
+    #[test]
+    fn test_min_node_cut() {
+        // The only cut is the articulation node 3
+        let hg = Hypergraph::from(&[vec![1, 2, 3], vec![3, 4, 5], vec![2, 6], vec![6, 3]]);
+        assert_eq!(hg.min_node_cut(1, 5), Ok(vec![3]));
+        assert_eq!(hg.min_node_cut(5, 1), Ok(vec![3]));
+
+        // Two paths between 1 and 4 with no inner node in common
+        let hg = Hypergraph::from(&[vec![1, 2], vec![2, 3, 4], vec![4, 5], vec![5, 6], vec![6, 1]]);
+        let cut = hg.min_node_cut(1, 4).unwrap();
+        assert_eq!(cut.len(), 2);
+        assert!(disconnects(&hg, &cut, 1, 4));
+        for node in cut.iter() {
+            assert!(!disconnects(&hg, &[*node], 1, 4));
+        }
+
+        let mut hg = Hypergraph::from(&[vec![1, 2], vec![3, 4]]);
+        hg.add_node(5);
+        assert_eq!(hg.min_node_cut(1, 4), Ok(vec![]));
+        assert_eq!(hg.min_node_cut(1, 5), Ok(vec![]));
+        assert_eq!(hg.min_node_cut(1, 2), Err(HypergraphError::AdjacentNodes(1, 2)));
+        assert_eq!(hg.min_node_cut(1, 1), Err(HypergraphError::SourceIsSink(1)));
+        assert_eq!(hg.min_node_cut(1, 9), Err(HypergraphError::NodeNotFound(9)));
+        assert_eq!(hg.min_node_cut(9, 1), Err(HypergraphError::NodeNotFound(9)));
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_min_node_cut_random() {
+        use crate::generators::{random_uniform, SplitMix64};
+
+        let mut rng = SplitMix64::new(23);
+        for (n, k, m) in [(10, 2, 18), (10, 3, 8), (9, 2, 14)] {
+            let hg = random_uniform(n, k, m, &mut rng).unwrap();
+            let nodes = hg.get_nodes_sorted();
+
+            for (i, source) in nodes.iter().enumerate() {
+                for sink in nodes[i + 1..].iter() {
+                    let Ok(cut) = hg.min_node_cut(*source, *sink) else {
+                        continue;
+                    };
+                    assert!(disconnects(&hg, &cut, *source, *sink));
+
+                    // No smaller set of other nodes is a cut
+                    let others: Vec<Node> = nodes.iter().copied().filter(|node| node != source && node != sink).collect();
+                    for mask in 0_u32..(1 << others.len()) {
+                        if (mask.count_ones() as usize) < cut.len() {
+                            let subset: Vec<Node> =
+                                (0..others.len()).filter(|bit| mask & (1 << bit) != 0).map(|bit| others[bit]).collect();
+                            assert!(!disconnects(&hg, &subset, *source, *sink));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_cut_nodes_random() {
+        use crate::generators::{random_uniform, SplitMix64};
+
+        let mut rng = SplitMix64::new(17);
+        for (n, k, m) in [(30, 2, 30), (40, 3, 20), (25, 2, 15), (30, 4, 12)] {
+            let mut hg = random_uniform(n, k, m, &mut rng).unwrap();
+            hg.extend(random_uniform(n, k + 1, m / 3, &mut rng).unwrap());
+
+            assert_eq!(hg.cut_nodes(None, None).unwrap(), naive_cut_nodes(&hg, None));
+            assert_eq!(hg.cut_nodes(None, Some(k)).unwrap(), naive_cut_nodes(&hg, Some(k)));
+            assert_eq!(hg.cut_edges(), naive_cut_edges(&hg));
+        }
+    }
+}
@@ -0,0 +1,505 @@
+use std::collections::VecDeque;
+
+use ahash::{AHashMap, AHashSet, RandomState};
+
+use super::{EdgeID, Hypergraph, Node, SEED1, SEED2, SEED3, SEED4};
+
+type Component = AHashSet<Node>;
+
+/// `type Node = i64`
+///
+/// A directed hyperedge, from the `tail` nodes to the `head` nodes, eg the reactants and the products of a reaction.
+#[derive(Debug, Clone, PartialEq)]
+struct DirectedHyperedge {
+    /// The nodes the hyperedge leaves from, sorted and without repetitions.
+    tail: Vec<Node>,
+
+    /// The nodes the hyperedge points to, sorted and without repetitions.
+    head: Vec<Node>,
+
+    /// The weight of the hyperedge, 0 if the hypergraph is not weighted.
+    weight: f64,
+}
+
+/// `type Node = i64`
+///
+/// A directed hypergraph, whose hyperedges go from a set of nodes (the tail) to another one (the head), eg a reaction
+/// network where every reaction turns a set of reactants into a set of products.
+///
+/// It follows the design of `Hypergraph`: each hyperedge is stored once, under an `EdgeID` computed over the ordered
+/// pair `(tail, head)`, and each node keeps two incidence lists, with the hyperedges it is in the tail of and the ones
+/// it is in the head of. Tails and heads are sets: their nodes are sorted and deduplicated on insertion, so
+/// `[2, 1] -> [3]` and `[1, 2, 2] -> [3]` are the same hyperedge, while `[3] -> [1, 2]` is a different one. A node may
+/// be both in the tail and in the head of a hyperedge, eg a catalyst.
+#[derive(Debug, Clone, Default)]
+pub struct DirectedHypergraph {
+    /// States if the hypergraph is weighted.
+    weighted: bool,
+
+    /// Maps each node to the `EdgeID`s of the hyperedges whose tail contains it, ie its outgoing hyperedges.
+    tail_incidence: AHashMap<Node, AHashSet<EdgeID>>,
+
+    /// Maps each node to the `EdgeID`s of the hyperedges whose head contains it, ie its incoming hyperedges.
+    head_incidence: AHashMap<Node, AHashSet<EdgeID>>,
+
+    /// Maps each `EdgeID` to its hyperedge.
+    edge_list: AHashMap<EdgeID, DirectedHyperedge>,
+}
+
+impl DirectedHypergraph {
+    /// Creates a new, empty `DirectedHypergraph`.
+    ///
+    /// # Parameters
+    /// - `weighted`: `bool` - Specifies whether the hypergraph is weighted (`true`), or nor (`false`).
+    ///
+    /// # Returns
+    /// - `Self` - A new instance of `DirectedHypergraph`.
+    pub fn new(weighted: bool) -> Self {
+        Self {
+            weighted,
+            ..Default::default()
+        }
+    }
+
+    /// Returns the number of nodes in the hypergraph, including the isolated ones.
+    ///
+    /// # Returns
+    /// - `usize` - The number of nodes.
+    ///
+    /// # Performance
+    /// - `O(1)`
+    pub fn num_nodes(&self) -> usize {
+        self.tail_incidence.len()
+    }
+
+    /// Returns the number of hyperedges in the hypergraph.
+    ///
+    /// # Returns
+    /// - `usize` - The number of hyperedges.
+    ///
+    /// # Performance
+    /// - `O(1)`
+    pub fn num_edges(&self) -> usize {
+        self.edge_list.len()
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Returns the list of all the nodes in the hypergraph, sorted.
+    ///
+    /// # Returns
+    /// - `Vec<Node>` - The nodes of the hypergraph, isolated ones included.
+    ///
+    /// # Performance
+    /// - `O(n*log(n))`, where `n` is the number of nodes.
+    pub fn get_nodes(&self) -> Vec<Node> {
+        let mut res: Vec<Node> = self.tail_incidence.keys().copied().collect();
+        res.sort_unstable();
+        res
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Adds a node to the hypergraph, with no incident hyperedges.
+    ///
+    /// # Parameters
+    /// - `node` : `Node` - The node to add.
+    ///
+    /// # Returns
+    /// - `bool` - `true` if the node was not already in, `false` otherwise.
+    ///
+    /// # Performance
+    /// - `O(1)` expected.
+    pub fn add_node(&mut self, node: Node) -> bool {
+        if self.tail_incidence.contains_key(&node) {
+            return false;
+        }
+        self.tail_incidence.insert(node, AHashSet::new());
+        self.head_incidence.insert(node, AHashSet::new());
+        true
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Adds a hyperedge from `tail` to `head`, with weight 0. The nodes which are not in the hypergraph are added.
+    ///
+    /// # Parameters
+    /// - `tail` : `&[Node]` - The nodes the hyperedge leaves from.
+    /// - `head` : `&[Node]` - The nodes the hyperedge points to.
+    ///
+    /// # Returns
+    /// - `bool` - `false` if the hyperedge was already in, `true` otherwise.
+    ///
+    /// # Performance
+    /// - `O(k*log(k))`, where `k` is the size of the hyperedge, ie the length of `tail` plus the one of `head`.
+    pub fn add_directed_edge(&mut self, tail: &[Node], head: &[Node]) -> bool {
+        self.compute_add_edge(tail, head, 0_f64)
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Adds a weighted hyperedge from `tail` to `head`, as `add_directed_edge`. If the hyperedge is already in, its
+    /// weight is replaced. If the hypergraph is not weighted, `weight` is ignored (it is stored as 0), and NaN and
+    /// infinite weights are clamped, as in `Hypergraph::add_edge_weighted`.
+    ///
+    /// # Parameters
+    /// - `tail` : `&[Node]` - The nodes the hyperedge leaves from.
+    /// - `head` : `&[Node]` - The nodes the hyperedge points to.
+    /// - `weight` : `f64` - The weight of the hyperedge.
+    ///
+    /// # Returns
+    /// - `bool` - `false` if the hyperedge was already in, `true` otherwise.
+    ///
+    /// # Performance
+    /// - `O(k*log(k))`, where `k` is the size of the hyperedge.
+    pub fn add_directed_edge_weighted(&mut self, tail: &[Node], head: &[Node], weight: f64) -> bool {
+        let weight = if self.weighted { weight } else { 0_f64 };
+        self.compute_add_edge(tail, head, weight)
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Checks if the hyperedge from `tail` to `head` is in the hypergraph.
+    ///
+    /// # Parameters
+    /// - `tail` : `&[Node]` - The nodes the hyperedge leaves from.
+    /// - `head` : `&[Node]` - The nodes the hyperedge points to.
+    ///
+    /// # Returns
+    /// - `bool` - `true` if the hyperedge is in the hypergraph, `false` otherwise.
+    ///
+    /// # Performance
+    /// - `O(k*log(k))`, where `k` is the size of the hyperedge.
+    pub fn check_directed_edge(&self, tail: &[Node], head: &[Node]) -> bool {
+        let (tail, head) = (compute_canonical(tail), compute_canonical(head));
+        self.edge_list.contains_key(&Self::compute_edge_id(&tail, &head))
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Returns the weight of the hyperedge from `tail` to `head`.
+    ///
+    /// # Parameters
+    /// - `tail` : `&[Node]` - The nodes the hyperedge leaves from.
+    /// - `head` : `&[Node]` - The nodes the hyperedge points to.
+    ///
+    /// # Returns
+    /// - `Option<f64>` - The weight of the hyperedge, `None` if it is not in the hypergraph.
+    ///
+    /// # Performance
+    /// - `O(k*log(k))`, where `k` is the size of the hyperedge.
+    pub fn get_weight(&self, tail: &[Node], head: &[Node]) -> Option<f64> {
+        let (tail, head) = (compute_canonical(tail), compute_canonical(head));
+        self.edge_list.get(&Self::compute_edge_id(&tail, &head)).map(|hyperedge| hyperedge.weight)
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Returns all the hyperedges, as `(tail, head)` pairs, sorted lexicographically.
+    ///
+    /// # Returns
+    /// - `Vec<(&Vec<Node>, &Vec<Node>)>` - The hyperedges, each with sorted tail and head.
+    ///
+    /// # Performance
+    /// - `O(m*log(m)*k)`, where `m` is the number of hyperedges and `k` their maximum size.
+    pub fn get_directed_edges(&self) -> Vec<(&Vec<Node>, &Vec<Node>)> {
+        let mut res: Vec<(&Vec<Node>, &Vec<Node>)> =
+            self.edge_list.values().map(|hyperedge| (&hyperedge.tail, &hyperedge.head)).collect();
+        res.sort_unstable();
+        res
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Removes the hyperedge from `tail` to `head`, keeping its nodes.
+    ///
+    /// # Parameters
+    /// - `tail` : `&[Node]` - The nodes the hyperedge leaves from.
+    /// - `head` : `&[Node]` - The nodes the hyperedge points to.
+    ///
+    /// # Returns
+    /// - `bool` - `true` if the hyperedge was in the hypergraph, `false` otherwise.
+    ///
+    /// # Performance
+    /// - `O(k*log(k))`, where `k` is the size of the hyperedge.
+    pub fn remove_directed_edge(&mut self, tail: &[Node], head: &[Node]) -> bool {
+        let (tail, head) = (compute_canonical(tail), compute_canonical(head));
+        let edge_id = Self::compute_edge_id(&tail, &head);
+        if self.edge_list.remove(&edge_id).is_none() {
+            return false;
+        }
+
+        for node in tail.iter() {
+            if let Some(edge_ids) = self.tail_incidence.get_mut(node) {
+                edge_ids.remove(&edge_id);
+            }
+        }
+        for node in head.iter() {
+            if let Some(edge_ids) = self.head_incidence.get_mut(node) {
+                edge_ids.remove(&edge_id);
+            }
+        }
+        true
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Returns the successors of a node, ie the nodes in the head of a hyperedge whose tail contains it. The node
+    /// itself is a successor if it is in both the tail and the head of a hyperedge.
+    ///
+    /// # Parameters
+    /// - `node` : `Node` - The node.
+    ///
+    /// # Returns
+    /// - `Option<Vec<Node>>` - The sorted successors, `None` if the node is not in the hypergraph.
+    ///
+    /// # Performance
+    /// - `O(d*k*log(d*k))`, where `d` is the out-degree of the node and `k` the maximum size of a head.
+    pub fn successors(&self, node: Node) -> Option<Vec<Node>> {
+        let edge_ids = self.tail_incidence.get(&node)?;
+        Some(self.compute_collect(edge_ids, |hyperedge| &hyperedge.head))
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Returns the predecessors of a node, ie the nodes in the tail of a hyperedge whose head contains it. The node
+    /// itself is a predecessor if it is in both the tail and the head of a hyperedge.
+    ///
+    /// # Parameters
+    /// - `node` : `Node` - The node.
+    ///
+    /// # Returns
+    /// - `Option<Vec<Node>>` - The sorted predecessors, `None` if the node is not in the hypergraph.
+    ///
+    /// # Performance
+    /// - `O(d*k*log(d*k))`, where `d` is the in-degree of the node and `k` the maximum size of a tail.
+    pub fn predecessors(&self, node: Node) -> Option<Vec<Node>> {
+        let edge_ids = self.head_incidence.get(&node)?;
+        Some(self.compute_collect(edge_ids, |hyperedge| &hyperedge.tail))
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Returns the out-degree of a node, ie the number of hyperedges whose tail contains it.
+    ///
+    /// # Parameters
+    /// - `node` : `Node` - The node.
+    ///
+    /// # Returns
+    /// - `Option<usize>` - The out-degree, `None` if the node is not in the hypergraph.
+    ///
+    /// # Performance
+    /// - `O(1)` expected.
+    pub fn out_degree(&self, node: Node) -> Option<usize> {
+        self.tail_incidence.get(&node).map(|edge_ids| edge_ids.len())
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Returns the in-degree of a node, ie the number of hyperedges whose head contains it.
+    ///
+    /// # Parameters
+    /// - `node` : `Node` - The node.
+    ///
+    /// # Returns
+    /// - `Option<usize>` - The in-degree, `None` if the node is not in the hypergraph.
+    ///
+    /// # Performance
+    /// - `O(1)` expected.
+    pub fn in_degree(&self, node: Node) -> Option<usize> {
+        self.head_incidence.get(&node).map(|edge_ids| edge_ids.len())
+    }
+
+    /// `type Node = i64`
+    /// `type Component = AHashSet<Node>`
+    ///
+    /// Returns the weakly connected components of the hypergraph, ie the connected components when the direction of
+    /// the hyperedges is ignored, as `Hypergraph::ccs` on the hypergraph whose hyperedges are `tail ∪ head`.
+    ///
+    /// # Returns
+    /// - `Vec<Component>` - The components, in no particular order. Isolated nodes are singleton components.
+    ///
+    /// # Performance
+    /// - `O(n + I)`, where `n` is the number of nodes and `I` the sum of the sizes of the hyperedges.
+    pub fn weak_ccs(&self) -> Vec<Component> {
+        let mut visited: AHashSet<Node> = AHashSet::with_capacity(self.num_nodes());
+        let mut expanded: AHashSet<EdgeID> = AHashSet::with_capacity(self.num_edges());
+        let mut res = Vec::new();
+
+        for start in self.tail_incidence.keys() {
+            if !visited.insert(*start) {
+                continue;
+            }
+
+            let mut component: Component = AHashSet::new();
+            let mut queue = VecDeque::from([*start]);
+            while let Some(node) = queue.pop_front() {
+                component.insert(node);
+                for edge_id in self.tail_incidence[&node].iter().chain(self.head_incidence[&node].iter()) {
+                    if !expanded.insert(*edge_id) {
+                        continue;
+                    }
+                    let hyperedge = &self.edge_list[edge_id];
+                    for other in hyperedge.tail.iter().chain(hyperedge.head.iter()) {
+                        if visited.insert(*other) {
+                            queue.push_back(*other);
+                        }
+                    }
+                }
+            }
+            res.push(component);
+        }
+        res
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Effectively adds a hyperedge, replacing the weight if it is already in.
+    fn compute_add_edge(&mut self, tail: &[Node], head: &[Node], weight: f64) -> bool {
+        let weight = Hypergraph::compute_finite_weight(weight);
+        let (tail, head) = (compute_canonical(tail), compute_canonical(head));
+        let edge_id = Self::compute_edge_id(&tail, &head);
+
+        if let Some(hyperedge) = self.edge_list.get_mut(&edge_id) {
+            hyperedge.weight = weight;
+            return false;
+        }
+
+        for node in tail.iter().chain(head.iter()) {
+            self.add_node(*node);
+        }
+        for node in tail.iter() {
+            self.tail_incidence.entry(*node).or_default().insert(edge_id);
+        }
+        for node in head.iter() {
+            self.head_incidence.entry(*node).or_default().insert(edge_id);
+        }
+        self.edge_list.insert(edge_id, DirectedHyperedge { tail, head, weight });
+        true
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Effectively collects, sorted and without repetitions, the nodes of one side (the tail or the head) of the given
+    /// hyperedges.
+    fn compute_collect<F: Fn(&DirectedHyperedge) -> &Vec<Node>>(&self, edge_ids: &AHashSet<EdgeID>, side: F) -> Vec<Node> {
+        let mut res: Vec<Node> =
+            edge_ids.iter().flat_map(|edge_id| side(&self.edge_list[edge_id]).iter().copied()).collect();
+        res.sort_unstable();
+        res.dedup();
+        res
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Effectively computes the `EdgeID` of the hyperedge from the canonical `tail` to the canonical `head`. The pair
+    /// is hashed with the lengths of the slices, so moving a node from the tail to the head changes the `EdgeID`.
+    fn compute_edge_id(tail: &[Node], head: &[Node]) -> EdgeID {
+        let hasher_factory = RandomState::with_seeds(SEED1, SEED2, SEED3, SEED4);
+        EdgeID::from(hasher_factory.hash_one((tail, head)))
+    }
+}
+
+/// `type Node = i64`
+///
+/// Effectively sorts a set of nodes and removes the repetitions.
+fn compute_canonical(nodes: &[Node]) -> Vec<Node> {
+    let mut res = nodes.to_vec();
+    res.sort_unstable();
+    res.dedup();
+    res
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    // A toy reaction network:
+    // 1 + 2 -> 3, 3 -> 4 + 5, 5 + 6 -> 1 (with 6 as a catalyst), 7 -> 8
+    fn reactions() -> DirectedHypergraph {
+        let mut hg = DirectedHypergraph::new(true);
+        hg.add_directed_edge_weighted(&[1, 2], &[3], 0.5);
+        hg.add_directed_edge_weighted(&[3], &[4, 5], 1.5);
+        hg.add_directed_edge_weighted(&[6, 5], &[1, 6], 2.0);
+        hg.add_directed_edge(&[7], &[8]);
+        hg.add_node(9);
+        hg
+    }
+
+    fn sorted(components: Vec<Component>) -> Vec<Vec<Node>> {
+        let mut res: Vec<Vec<Node>> = components
+            .into_iter()
+            .map(|component| {
+                let mut nodes: Vec<Node> = component.into_iter().collect();
+                nodes.sort();
+                nodes
+            })
+            .collect();
+        res.sort();
+        res
+    }
+
+    #[test]
+    fn test_directed_edges() {
+        let mut hg = reactions();
+
+        assert_eq!(hg.num_nodes(), 9);
+        assert_eq!(hg.num_edges(), 4);
+        assert!(hg.check_directed_edge(&[2, 1, 2], &[3]));
+        assert!(!hg.check_directed_edge(&[3], &[1, 2]));
+        assert!(!hg.check_directed_edge(&[1], &[2, 3]));
+        assert_eq!(hg.get_weight(&[5, 6], &[6, 1]), Some(2.0));
+
+        // The reverse reaction is a different hyperedge
+        assert!(hg.add_directed_edge_weighted(&[3], &[1, 2], 0.1));
+        assert!(!hg.add_directed_edge_weighted(&[2, 1], &[3], 0.7));
+        assert_eq!(hg.get_weight(&[1, 2], &[3]), Some(0.7));
+        assert_eq!(hg.get_weight(&[3], &[1, 2]), Some(0.1));
+
+        assert!(hg.remove_directed_edge(&[3], &[2, 1]));
+        assert!(!hg.remove_directed_edge(&[3], &[2, 1]));
+        assert_eq!(
+            hg.get_directed_edges(),
+            vec![(&vec![1, 2], &vec![3]), (&vec![3], &vec![4, 5]), (&vec![5, 6], &vec![1, 6]), (&vec![7], &vec![8])]
+        );
+
+        let mut unweighted = DirectedHypergraph::new(false);
+        unweighted.add_directed_edge_weighted(&[1], &[2], 3.0);
+        assert_eq!(unweighted.get_weight(&[1], &[2]), Some(0.0));
+    }
+
+    #[test]
+    fn test_directed_neighbors() {
+        let mut hg = reactions();
+
+        assert_eq!(hg.successors(3), Some(vec![4, 5]));
+        assert_eq!(hg.predecessors(3), Some(vec![1, 2]));
+        assert_eq!(hg.successors(6), Some(vec![1, 6]));
+        assert_eq!(hg.predecessors(1), Some(vec![5, 6]));
+        assert_eq!(hg.successors(4), Some(vec![]));
+        assert_eq!(hg.successors(10), None);
+
+        assert_eq!(hg.out_degree(5), Some(1));
+        assert_eq!(hg.in_degree(5), Some(1));
+        assert_eq!(hg.out_degree(6), Some(1));
+        assert_eq!(hg.in_degree(6), Some(1));
+        assert_eq!(hg.in_degree(2), Some(0));
+        assert_eq!(hg.in_degree(10), None);
+
+        hg.remove_directed_edge(&[3], &[4, 5]);
+        assert_eq!(hg.successors(3), Some(vec![]));
+        assert_eq!(hg.in_degree(4), Some(0));
+        assert!(!hg.add_node(4));
+    }
+
+    #[test]
+    fn test_weak_ccs() {
+        let mut hg = reactions();
+
+        assert_eq!(sorted(hg.weak_ccs()), vec![vec![1, 2, 3, 4, 5, 6], vec![7, 8], vec![9]]);
+
+        hg.remove_directed_edge(&[3], &[4, 5]);
+        assert_eq!(sorted(hg.weak_ccs()), vec![vec![1, 2, 3, 5, 6], vec![4], vec![7, 8], vec![9]]);
+        assert!(DirectedHypergraph::new(false).weak_ccs().is_empty());
+    }
+}
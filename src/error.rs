@@ -0,0 +1,109 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use super::Node;
+
+/// `type Node = i64`
+///
+/// Errors returned by the fallible methods of the crate.
+///
+/// The enum is non-exhaustive, so that new kinds of errors can be added without breaking downstream matches.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum HypergraphError {
+    /// Both an order and a size were specified, while at most one of them is allowed.
+    BothOrderAndSize,
+
+    /// Neither an order nor a size was specified, while exactly one of them is required.
+    NeitherOrderNorSize,
+
+    /// The node is not in the hypergraph.
+    NodeNotFound(Node),
+
+    /// The hyperedge is not in the hypergraph.
+    EdgeNotFound,
+
+    /// The operation needs a weighted hypergraph.
+    NotWeighted,
+
+    /// The node of the hypergraph has no entry in the mapping.
+    UnmappedNode(Node),
+
+    /// A hyperedge has a negative or NaN weight, while only non-negative weights are allowed.
+    InvalidWeight,
+
+    /// A weight is NaN or infinite, while only finite weights are allowed.
+    NonFiniteWeight,
+
+    /// An iterative algorithm did not converge within the given number of iterations.
+    NotConverged(usize),
+
+    /// The node of the hypergraph is not in the partition.
+    IncompletePartition(Node),
+
+    /// Hyperedges with no nodes were requested.
+    ZeroEdgeSize,
+
+    /// More distinct hyperedges were requested than the ones which exist.
+    TooManyEdges,
+
+    /// The sum of the degrees does not match the sum of the sizes of the hyperedges.
+    DegreeSizeMismatch,
+
+    /// The number of weights, the second value, differs from the number of hyperedges, the first value.
+    WeightCountMismatch(usize, usize),
+
+    /// The input document is malformed, with a message locating the problem.
+    Parse(String),
+
+    /// The source and the sink are the same node.
+    SourceIsSink(Node),
+
+    /// The two nodes share a hyperedge, so no set of other nodes separates them.
+    AdjacentNodes(Node, Node),
+}
+
+impl Display for HypergraphError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::BothOrderAndSize => write!(f, "Order and size cannot be both specified."),
+            Self::NeitherOrderNorSize => write!(f, "At least one between order and size should be specified."),
+            Self::NodeNotFound(node) => write!(f, "Node {} is not in the hypergraph.", node),
+            Self::UnmappedNode(node) => write!(f, "Node {} is not in the mapping.", node),
+            Self::EdgeNotFound => write!(f, "The hyperedge is not in the hypergraph."),
+            Self::NotWeighted => write!(f, "The hypergraph is not weighted."),
+            Self::InvalidWeight => write!(f, "Hyperedges cannot have negative or NaN weights."),
+            Self::NonFiniteWeight => write!(f, "Weights must be finite, NaN and infinite values are not allowed."),
+            Self::NotConverged(max_iter) => write!(f, "Did not converge in {} iterations.", max_iter),
+            Self::IncompletePartition(node) => write!(f, "Node {} is not in the partition.", node),
+            Self::ZeroEdgeSize => write!(f, "The size of the hyperedges must be positive."),
+            Self::TooManyEdges => {
+                write!(f, "The number of hyperedges exceeds the number of distinct hyperedges of the given size.")
+            }
+            Self::DegreeSizeMismatch => write!(f, "The sum of the degrees does not match the sum of the sizes."),
+            Self::WeightCountMismatch(edges, weights) => {
+                write!(f, "Expected {} weights, one for each hyperedge, but {} were given.", edges, weights)
+            }
+            Self::Parse(message) => write!(f, "{}", message),
+            Self::SourceIsSink(node) => write!(f, "Node {} is both the source and the sink.", node),
+            Self::AdjacentNodes(a, b) => write!(f, "Nodes {} and {} share a hyperedge, no node cut separates them.", a, b),
+        }
+    }
+}
+
+impl Error for HypergraphError {}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(HypergraphError::BothOrderAndSize.to_string(), "Order and size cannot be both specified.");
+        assert_eq!(HypergraphError::NodeNotFound(7).to_string(), "Node 7 is not in the hypergraph.");
+        assert_eq!(HypergraphError::Parse("Line 3: invalid node 'x'".to_string()).to_string(), "Line 3: invalid node 'x'");
+
+        let err: Box<dyn Error> = Box::new(HypergraphError::NotConverged(10));
+        assert_eq!(err.to_string(), "Did not converge in 10 iterations.");
+    }
+}
@@ -0,0 +1,176 @@
+use ahash::AHashMap;
+
+use super::{Hypergraph, Node};
+
+// Index of each node in the vertices of an expansion
+type NodeIndex = AHashMap<Node, usize>;
+
+/// `type Node = i64`
+///
+/// Vertex of the star expansion of a hypergraph: either one of its nodes or one of its hyperedges.
+#[derive(Debug, Clone, PartialEq)]
+pub enum StarVertex {
+    /// An original node of the hypergraph.
+    Node(Node),
+
+    /// A hyperedge of the hypergraph, with its weight.
+    Hyperedge(Vec<Node>, f64),
+}
+
+impl Hypergraph {
+    /// `type Node = i64`
+    ///
+    /// Returns the clique expansion (two-section graph) of the hypergraph as a weighted graph: two nodes are adjacent
+    /// if they share at least one hyperedge, and the weight of their edge is the sum of the weights of the hyperedges
+    /// they share (their number, if the hypergraph is not weighted).
+    ///
+    /// The result is laid out so that it can be loaded as is into any graph library: vertex `i` is `nodes[i]` and
+    /// `index` translates a `Node` back into its vertex.
+    ///
+    /// # Returns
+    /// - `(Vec<Node>, AHashMap<Node, usize>, Vec<(usize, usize, f64)>)` - The nodes sorted in ascending order, the
+    ///   map from each node to its index, and the undirected edges as `(u, v, weight)` triplets with `u < v`, sorted.
+    ///
+    /// # Performance
+    /// - `O(n*log(n) + m*k*k)`, where `n` and `m` are the number of nodes and hyperedges, and `k` the maximum size of a hyperedge.
+    pub fn clique_expansion(&self) -> (Vec<Node>, NodeIndex, Vec<(usize, usize, f64)>) {
+        let mut nodes = self.get_nodes();
+        nodes.sort_unstable();
+
+        let index: NodeIndex = nodes.iter().enumerate().map(|(i, node)| (*node, i)).collect();
+        let mut weights: AHashMap<(usize, usize), f64> = AHashMap::new();
+
+        // O(m*k*k)
+        for hyperedge in self.edge_list.values() {
+            let weight = self.compute_expansion_weight(hyperedge.weight);
+
+            // Repeated members count once
+            let mut members: Vec<usize> = hyperedge.nodes.iter().map(|node| index[node]).collect();
+            members.sort_unstable();
+            members.dedup();
+
+            for (a, i) in members.iter().enumerate() {
+                for j in members[a + 1..].iter() {
+                    *weights.entry((*i, *j)).or_insert(0_f64) += weight;
+                }
+            }
+        }
+
+        let mut edges: Vec<(usize, usize, f64)> = weights.into_iter().map(|((i, j), weight)| (i, j, weight)).collect();
+        edges.sort_unstable_by_key(|(i, j, _)| (*i, *j));
+
+        (nodes, index, edges)
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Returns the star expansion of the hypergraph as a bipartite graph: every node and every hyperedge becomes a
+    /// vertex, and each hyperedge vertex is adjacent to the vertices of its nodes.
+    ///
+    /// The nodes come first, sorted in ascending order, followed by the hyperedges sorted lexicographically.
+    ///
+    /// # Returns
+    /// - `(Vec<StarVertex>, AHashMap<Node, usize>, Vec<(usize, usize)>)` - The vertices, the map from each node to its
+    ///   index, and the undirected edges as `(hyperedge, node)` pairs of indices, sorted.
+    ///
+    /// # Performance
+    /// - `O(n*log(n) + m*log(m)*k)`, where `n` and `m` are the number of nodes and hyperedges, and `k` the maximum size of a hyperedge.
+    pub fn star_expansion(&self) -> (Vec<StarVertex>, NodeIndex, Vec<(usize, usize)>) {
+        let mut nodes = self.get_nodes();
+        nodes.sort_unstable();
+
+        let mut hyperedges: Vec<(&Vec<Node>, f64)> = self
+            .edge_list
+            .values()
+            .map(|hyperedge| (&hyperedge.nodes, hyperedge.weight))
+            .collect();
+        hyperedges.sort_by(|a, b| a.0.cmp(b.0));
+
+        let index: NodeIndex = nodes.iter().enumerate().map(|(i, node)| (*node, i)).collect();
+        let mut vertices: Vec<StarVertex> = nodes.iter().map(|node| StarVertex::Node(*node)).collect();
+        let mut edges = Vec::new();
+
+        for (nodes, weight) in hyperedges {
+            let vertex = vertices.len();
+            for node in nodes.iter() {
+                edges.push((vertex, index[node]));
+            }
+            vertices.push(StarVertex::Hyperedge(nodes.clone(), weight));
+        }
+        edges.sort_unstable();
+
+        (vertices, index, edges)
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use std::collections::VecDeque;
+
+    fn build() -> Hypergraph {
+        let mut hg = Hypergraph::new(true);
+        hg.add_edge_weighted(&[0, 1, 2], 2.0);
+        hg.add_edge_weighted(&[1, 2], 0.5);
+        hg.add_edge_weighted(&[2, 3], 1.0);
+        hg.add_edge_weighted(&[3, 4], 1.0);
+        hg.add_node(5);
+        hg
+    }
+
+    fn hops(num_vertices: usize, edges: &[(usize, usize)], from: usize, to: usize) -> Option<usize> {
+        let mut adjacency = vec![Vec::new(); num_vertices];
+        for (u, v) in edges.iter() {
+            adjacency[*u].push(*v);
+            adjacency[*v].push(*u);
+        }
+
+        let mut distances = vec![None; num_vertices];
+        let mut queue = VecDeque::from([from]);
+        distances[from] = Some(0);
+        while let Some(u) = queue.pop_front() {
+            for v in adjacency[u].iter() {
+                if distances[*v].is_none() {
+                    distances[*v] = Some(distances[u].unwrap() + 1);
+                    queue.push_back(*v);
+                }
+            }
+        }
+        distances[to]
+    }
+
+    #[test]
+    fn test_clique_expansion() {
+        let (nodes, index, edges) = build().clique_expansion();
+
+        assert_eq!(nodes, vec![0, 1, 2, 3, 4, 5]);
+        assert_eq!(index[&3], 3);
+        assert_eq!(edges, vec![(0, 1, 2.0), (0, 2, 2.0), (1, 2, 2.5), (2, 3, 1.0), (3, 4, 1.0)]);
+
+        let pairs: Vec<(usize, usize)> = edges.iter().map(|(u, v, _)| (*u, *v)).collect();
+        assert_eq!(hops(nodes.len(), &pairs, index[&0], index[&4]), Some(3));
+        assert_eq!(hops(nodes.len(), &pairs, index[&0], index[&5]), None);
+
+        let unweighted = Hypergraph::from(&[vec![0, 1, 2], vec![1, 2]]);
+        let (_, _, edges) = unweighted.clique_expansion();
+        assert_eq!(edges, vec![(0, 1, 1.0), (0, 2, 1.0), (1, 2, 2.0)]);
+
+        // A repeated node does not count the pairs twice
+        let repeated = Hypergraph::from(&[vec![0, 1, 1, 2], vec![1, 2]]);
+        assert_eq!(repeated.clique_expansion().2, edges);
+    }
+
+    #[test]
+    fn test_star_expansion() {
+        let (vertices, index, edges) = build().star_expansion();
+
+        assert_eq!(vertices.len(), 6 + 4);
+        assert_eq!(edges.len(), 3 + 2 + 2 + 2);
+        assert_eq!(vertices[index[&5]], StarVertex::Node(5));
+        assert_eq!(vertices[6], StarVertex::Hyperedge(vec![0, 1, 2], 2.0));
+        assert!(edges.iter().all(|(e, v)| matches!(vertices[*e], StarVertex::Hyperedge(..)) && *v < 6));
+
+        // Every step in the hypergraph is two steps in its star expansion
+        assert_eq!(hops(vertices.len(), &edges, index[&0], index[&4]), Some(6));
+    }
+}
@@ -0,0 +1,139 @@
+use std::io::{BufReader, BufWriter, Error, ErrorKind, Read, Write};
+
+use crate::{Hypergraph, HypergraphData, Node};
+
+// Header of a binary snapshot: magic bytes and format version.
+const MAGIC: &[u8; 4] = b"HGRB";
+const VERSION: u8 = 1;
+
+// Upper bound for the capacity reserved from the counts stored in a snapshot, so that a corrupted count cannot
+// trigger a huge allocation before the data runs out.
+const MAX_RESERVE: usize = 1 << 16;
+
+impl Hypergraph {
+    /// Saves the hypergraph as a compact binary snapshot, readable by `Hypergraph::load_binary`.
+    ///
+    /// All the values are little-endian. The layout is:
+    /// - the magic bytes `HGRB` and the format version (`u8`);
+    /// - the weighted flag (`u8`, 0 or 1);
+    /// - the number of nodes and the number of hyperedges (`u64` each);
+    /// - every node (`i64`);
+    /// - for every hyperedge, its length (`u64`), its nodes (`i64` each) and its weight (`f64`).
+    ///
+    /// `EdgeID`s are not stored: they are recomputed when loading, so snapshots stay valid if the hashing seeds change.
+    /// Nodes and hyperedges are written in the order of `HypergraphData`, so equal hypergraphs produce identical
+    /// snapshots.
+    ///
+    /// # Parameters
+    /// - `writer` : `impl Write` - Destination of the snapshot.
+    ///
+    /// # Returns
+    /// - `std::io::Result<()>` - `Err` if writing to `writer` fails.
+    ///
+    /// # Performance
+    /// - `O(n*log(n) + m*log(m)*k)`, where `n` and `m` are the number of nodes and hyperedges, and `k` the maximum size
+    ///   of a hyperedge.
+    pub fn save_binary(&self, writer: impl Write) -> std::io::Result<()> {
+        let mut writer = BufWriter::new(writer);
+
+        // Sorted as in HypergraphData, so that equal hypergraphs produce identical snapshots
+        let data = HypergraphData::from(self);
+
+        writer.write_all(MAGIC)?;
+        writer.write_all(&[VERSION, data.weighted as u8])?;
+        writer.write_all(&(data.nodes.len() as u64).to_le_bytes())?;
+        writer.write_all(&(data.edges.len() as u64).to_le_bytes())?;
+
+        for node in data.nodes.iter() {
+            writer.write_all(&node.to_le_bytes())?;
+        }
+
+        for (nodes, weight) in data.edges.iter() {
+            writer.write_all(&(nodes.len() as u64).to_le_bytes())?;
+            for node in nodes.iter() {
+                writer.write_all(&node.to_le_bytes())?;
+            }
+            writer.write_all(&weight.to_le_bytes())?;
+        }
+
+        writer.flush()
+    }
+
+    /// Loads a hypergraph from a binary snapshot written by `Hypergraph::save_binary`.
+    ///
+    /// The incidence lists and the `EdgeID`s are rebuilt from the stored hyperedges rather than read from the snapshot.
+    ///
+    /// # Parameters
+    /// - `reader` : `impl Read` - Source of the snapshot.
+    ///
+    /// # Returns
+    /// - `std::io::Result<Hypergraph>` - `Ok` containing the loaded hypergraph. Returns `Err` with kind `InvalidData`
+    ///   if the snapshot is malformed (wrong magic bytes, unknown version, invalid flag), `UnexpectedEof` if it is
+    ///   truncated, or the error of `reader` if reading fails.
+    ///
+    /// # Performance
+    /// - `O(n + m*k)`, where `n` and `m` are the number of nodes and hyperedges, and `k` the maximum size of a hyperedge.
+    pub fn load_binary(reader: impl Read) -> std::io::Result<Hypergraph> {
+        let mut reader = BufReader::new(reader);
+
+        let mut magic = [0_u8; 4];
+        reader.read_exact(&mut magic)?;
+        if &magic != MAGIC {
+            return Err(Error::new(ErrorKind::InvalidData, "Not a hypergraph binary snapshot"));
+        }
+
+        let mut header = [0_u8; 2];
+        reader.read_exact(&mut header)?;
+        if header[0] != VERSION {
+            return Err(Error::new(ErrorKind::InvalidData, format!("Unsupported snapshot version {}", header[0])));
+        }
+        let weighted = match header[1] {
+            0 => false,
+            1 => true,
+            flag => return Err(Error::new(ErrorKind::InvalidData, format!("Invalid weighted flag {}", flag))),
+        };
+
+        let num_nodes = Self::compute_read_len(&mut reader)?;
+        let num_edges = Self::compute_read_len(&mut reader)?;
+
+        let mut res = Hypergraph::new(weighted);
+        res.incidence_list.reserve(num_nodes.min(MAX_RESERVE));
+        res.edge_list.reserve(num_edges.min(MAX_RESERVE));
+
+        for _ in 0..num_nodes {
+            res.add_node(Self::compute_read_node(&mut reader)?);
+        }
+
+        let mut edge = Vec::new();
+        for _ in 0..num_edges {
+            let len = Self::compute_read_len(&mut reader)?;
+
+            edge.clear();
+            edge.reserve(len.min(MAX_RESERVE));
+            for _ in 0..len {
+                edge.push(Self::compute_read_node(&mut reader)?);
+            }
+
+            let mut weight = [0_u8; 8];
+            reader.read_exact(&mut weight)?;
+            Self::compute_add_edge(&mut res, &edge, f64::from_le_bytes(weight));
+        }
+
+        Ok(res)
+    }
+
+    /// Effectively reads a little-endian `u64` length.
+    fn compute_read_len(reader: &mut impl Read) -> std::io::Result<usize> {
+        let mut bytes = [0_u8; 8];
+        reader.read_exact(&mut bytes)?;
+        usize::try_from(u64::from_le_bytes(bytes))
+            .map_err(|_| Error::new(ErrorKind::InvalidData, "Length does not fit in memory"))
+    }
+
+    /// Effectively reads a little-endian node.
+    fn compute_read_node(reader: &mut impl Read) -> std::io::Result<Node> {
+        let mut bytes = [0_u8; 8];
+        reader.read_exact(&mut bytes)?;
+        Ok(Node::from_le_bytes(bytes))
+    }
+}
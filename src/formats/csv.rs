@@ -0,0 +1,171 @@
+use std::io::Read;
+
+use crate::{Hypergraph, HypergraphError, Node};
+
+/// Options for `Hypergraph::from_csv`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CsvOptions {
+    /// Separator between the cells of a row.
+    pub delimiter: char,
+
+    /// Index (starting from 0) of the column holding the nodes of the hyperedge.
+    pub nodes_column: usize,
+
+    /// Separator between the nodes inside the nodes cell. If it is a whitespace character, any run of whitespace
+    /// separates the nodes.
+    pub node_delimiter: char,
+
+    /// Index (starting from 0) of the column holding the weight of the hyperedge, if any. When `Some`, the returned
+    /// hypergraph is weighted.
+    pub weight_column: Option<usize>,
+
+    /// Whether the first row is a header, to be skipped.
+    pub has_header: bool,
+
+    /// If `true`, malformed rows are skipped and reported; if `false`, the first malformed row makes the import fail.
+    pub lossy: bool,
+}
+
+impl Default for CsvOptions {
+    /// Comma-separated cells, nodes in the first column separated by whitespace, no weights, a header row and
+    /// fail-fast error handling.
+    fn default() -> Self {
+        Self {
+            delimiter: ',',
+            nodes_column: 0,
+            node_delimiter: ' ',
+            weight_column: None,
+            has_header: true,
+            lossy: false,
+        }
+    }
+}
+
+impl Hypergraph {
+    /// Reads a hypergraph from a CSV document where one column holds the nodes of a hyperedge and, optionally,
+    /// another one holds its weight.
+    ///
+    /// Cells can be quoted with `"`, in which case they may contain the delimiter, newlines and escaped quotes (`""`).
+    /// Empty rows are skipped. An empty or missing weight cell gives weight 0. If a hyperedge appears more than once,
+    /// it is inserted only once, with the weight of its last occurrence.
+    ///
+    /// # Parameters
+    /// - `reader` : `impl Read` - Source of the CSV document.
+    /// - `options` : `&CsvOptions` - Columns, delimiters and error handling, see `CsvOptions`.
+    ///
+    /// # Returns
+    /// - `Result<(Hypergraph, Vec<usize>), HypergraphError>` - `Ok` containing the hypergraph and the numbers (starting from 1,
+    ///   header included) of the rows skipped because malformed, which is always empty if `options.lossy` is `false`.
+    ///   Returns `Err(HypergraphError::Parse)` if reading fails, if a quoted cell is not terminated or, when
+    ///   `options.lossy` is `false`, for the first malformed row.
+    ///
+    /// # Performance
+    /// - `O(l)`, where `l` is the length of the input.
+    pub fn from_csv(reader: impl Read, options: &CsvOptions) -> Result<(Hypergraph, Vec<usize>), HypergraphError> {
+        Self::compute_from_csv(reader, options).map_err(HypergraphError::Parse)
+    }
+
+    /// Effectively reads the CSV document, with the errors as messages.
+    fn compute_from_csv(mut reader: impl Read, options: &CsvOptions) -> Result<(Hypergraph, Vec<usize>), String> {
+        let mut input = String::new();
+        reader.read_to_string(&mut input).map_err(|err| err.to_string())?;
+
+        let mut res = Hypergraph::new(options.weight_column.is_some());
+        let mut skipped = Vec::new();
+
+        for (index, row) in Self::compute_csv_rows(&input, options.delimiter)?.into_iter().enumerate() {
+            let row_number = index + 1;
+            if (options.has_header && index == 0) || row.iter().all(|cell| cell.trim().is_empty()) {
+                continue;
+            }
+
+            match Self::compute_csv_edge(&row, options) {
+                Ok((edge, weight)) => {
+                    res.add_edge_weighted(&edge, weight);
+                }
+                Err(_) if options.lossy => skipped.push(row_number),
+                Err(err) => return Err(format!("Row {}: {}", row_number, err)),
+            }
+        }
+
+        Ok((res, skipped))
+    }
+
+    /// Effectively extracts the hyperedge and its weight from a CSV row.
+    fn compute_csv_edge(row: &[String], options: &CsvOptions) -> Result<(Vec<Node>, f64), String> {
+        let cell = row
+            .get(options.nodes_column)
+            .ok_or_else(|| format!("missing nodes column {}", options.nodes_column))?;
+
+        let fields: Vec<&str> = if options.node_delimiter.is_whitespace() {
+            cell.split_whitespace().collect()
+        } else {
+            cell.split(options.node_delimiter).map(str::trim).filter(|field| !field.is_empty()).collect()
+        };
+        if fields.is_empty() {
+            return Err("a hyperedge needs at least one node".to_string());
+        }
+
+        let edge = fields
+            .iter()
+            .map(|field| field.parse::<Node>().map_err(|_| format!("invalid node '{}'", field)))
+            .collect::<Result<Vec<Node>, String>>()?;
+
+        let weight = match options.weight_column.and_then(|column| row.get(column)) {
+            Some(cell) if !cell.trim().is_empty() => cell
+                .trim()
+                .parse()
+                .map_err(|_| format!("invalid weight '{}'", cell))?,
+            _ => 0_f64,
+        };
+
+        Ok((edge, weight))
+    }
+
+    /// Effectively splits a CSV document into rows of unquoted cells.
+    fn compute_csv_rows(input: &str, delimiter: char) -> Result<Vec<Vec<String>>, String> {
+        let mut rows = Vec::new();
+        let mut row = Vec::new();
+        let mut cell = String::new();
+        let mut quoted = false;
+        let mut chars = input.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            if quoted {
+                if c == '"' {
+                    if chars.peek() == Some(&'"') {
+                        chars.next();
+                        cell.push('"');
+                    } else {
+                        quoted = false;
+                    }
+                } else {
+                    cell.push(c);
+                }
+            } else if c == '"' && cell.trim().is_empty() {
+                cell.clear();
+                quoted = true;
+            } else if c == delimiter {
+                row.push(std::mem::take(&mut cell));
+            } else if c == '\n' || c == '\r' {
+                if c == '\r' && chars.peek() == Some(&'\n') {
+                    chars.next();
+                }
+                row.push(std::mem::take(&mut cell));
+                rows.push(std::mem::take(&mut row));
+            } else {
+                cell.push(c);
+            }
+        }
+
+        if quoted {
+            return Err(format!("Row {}: unterminated quoted cell", rows.len() + 1));
+        }
+        if !cell.is_empty() || !row.is_empty() {
+            row.push(cell);
+            rows.push(row);
+        }
+
+        Ok(rows)
+    }
+}
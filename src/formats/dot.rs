@@ -0,0 +1,86 @@
+use std::fmt::Write;
+
+use ahash::AHashMap;
+
+use crate::{Hypergraph, HypergraphData, Node};
+
+/// Layout used by `Hypergraph::to_dot`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DotStyle {
+    /// Hyperedges are drawn as square vertices connected to their members (star expansion).
+    Bipartite,
+
+    /// Every pair of nodes sharing a hyperedge is connected by an edge (clique expansion).
+    Clique,
+}
+
+impl Hypergraph {
+    /// Exports the hypergraph in the DOT language of Graphviz, as an undirected graph.
+    ///
+    /// Nodes are labelled with their id. With `DotStyle::Bipartite` the hyperedges, sorted, become square vertices
+    /// `e0`, `e1`, ... labelled with their weight when the hypergraph is weighted. With `DotStyle::Clique` every pair of
+    /// nodes sharing at least one hyperedge is joined by a single edge, labelled, when the hypergraph is weighted, with
+    /// the sum of the weights of the hyperedges containing both nodes. Everything is sorted, so the output is
+    /// deterministic.
+    ///
+    /// # Parameters
+    /// - `style` : `DotStyle` - The layout to use.
+    ///
+    /// # Returns
+    /// - `String` - The DOT document.
+    ///
+    /// # Performance
+    /// - `O(n*log(n) + m*k*k)`, where `n` and `m` are the number of nodes and hyperedges, and `k` the maximum size of a
+    ///   hyperedge (`O(m*k)` for the bipartite style).
+    pub fn to_dot(&self, style: DotStyle) -> String {
+        let data = HypergraphData::from(self);
+        let mut res = String::from("graph hypergraph {\n    node [shape=circle];\n");
+
+        for node in data.nodes.iter() {
+            let _ = writeln!(res, "    \"{}\";", node);
+        }
+
+        match style {
+            DotStyle::Bipartite => {
+                for (index, (_, weight)) in data.edges.iter().enumerate() {
+                    if data.weighted {
+                        let _ = writeln!(res, "    \"e{}\" [shape=square, label=\"{}\"];", index, weight);
+                    } else {
+                        let _ = writeln!(res, "    \"e{}\" [shape=square, label=\"\"];", index);
+                    }
+                }
+                for (index, (edge, _)) in data.edges.iter().enumerate() {
+                    for node in edge.iter() {
+                        let _ = writeln!(res, "    \"e{}\" -- \"{}\";", index, node);
+                    }
+                }
+            }
+            DotStyle::Clique => {
+                let mut pairs: AHashMap<(Node, Node), f64> = AHashMap::new();
+                for (edge, weight) in data.edges.iter() {
+                    for (i, u) in edge.iter().enumerate() {
+                        for v in edge[i + 1..].iter() {
+                            if u != v {
+                                *pairs.entry((*u.min(v), *u.max(v))).or_insert(0_f64) += weight;
+                            }
+                        }
+                    }
+                }
+
+                let mut pairs: Vec<((Node, Node), f64)> = pairs.into_iter().collect();
+                pairs.sort_unstable_by_key(|(pair, _)| *pair);
+
+                for ((u, v), weight) in pairs.iter() {
+                    if data.weighted {
+                        let _ = writeln!(res, "    \"{}\" -- \"{}\" [label=\"{}\"];", u, v, weight);
+                    } else {
+                        let _ = writeln!(res, "    \"{}\" -- \"{}\";", u, v);
+                    }
+                }
+            }
+        }
+
+        res.push_str("}\n");
+        res
+    }
+}
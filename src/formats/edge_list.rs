@@ -0,0 +1,105 @@
+use std::io::{BufRead, BufReader, Read, Write};
+
+use crate::{Hypergraph, HypergraphData, HypergraphError, Node};
+
+impl Hypergraph {
+    /// Reads a hypergraph from a plain-text edge list.
+    ///
+    /// Every line is a hyperedge, given as its nodes separated by `separator`. If `separator` is a whitespace
+    /// character, any run of whitespace separates the nodes; otherwise the fields are split on `separator` and trimmed.
+    /// If `weighted` is `true`, the last field of every line is the weight of the hyperedge. Blank lines and lines
+    /// starting with `#` are skipped.
+    ///
+    /// If a hyperedge appears more than once, it is inserted only once, with the weight of its last occurrence.
+    ///
+    /// # Parameters
+    /// - `reader` : `impl Read` - Source of the edge list, eg a `std::fs::File`.
+    /// - `separator` : `char` - Separator between the fields of a line.
+    /// - `weighted` : `bool` - Whether the lines end with a weight, and whether the returned hypergraph is weighted.
+    ///
+    /// # Returns
+    /// - `Result<Hypergraph, HypergraphError>` - `Ok` containing the hypergraph, `Err(HypergraphError::Parse)` with a
+    ///   message, with the line number, for the first malformed line (or if reading fails).
+    ///
+    /// # Performance
+    /// - `O(l)`, where `l` is the length of the input.
+    pub fn read_edge_list(reader: impl Read, separator: char, weighted: bool) -> Result<Hypergraph, HypergraphError> {
+        Self::compute_read_edge_list(reader, separator, weighted).map_err(HypergraphError::Parse)
+    }
+
+    /// Effectively reads the edge list, with the errors as messages.
+    fn compute_read_edge_list(reader: impl Read, separator: char, weighted: bool) -> Result<Hypergraph, String> {
+        let mut res = Hypergraph::new(weighted);
+
+        for (index, line) in BufReader::new(reader).lines().enumerate() {
+            let line_number = index + 1;
+            let line = line.map_err(|err| format!("Line {}: {}", line_number, err))?;
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let mut fields: Vec<&str> = if separator.is_whitespace() {
+                line.split_whitespace().collect()
+            } else {
+                line.split(separator).map(str::trim).collect()
+            };
+
+            let mut weight = 0_f64;
+            if weighted {
+                let field = fields.pop().unwrap(); // The line is not empty
+                weight = field
+                    .parse()
+                    .map_err(|_| format!("Line {}: invalid weight '{}'", line_number, field))?;
+            }
+
+            if fields.is_empty() {
+                return Err(format!("Line {}: a hyperedge needs at least one node", line_number));
+            }
+
+            let edge = fields
+                .iter()
+                .map(|field| {
+                    field
+                        .parse::<Node>()
+                        .map_err(|_| format!("Line {}: invalid node '{}'", line_number, field))
+                })
+                .collect::<Result<Vec<Node>, String>>()?;
+
+            res.add_edge_weighted(&edge, weight);
+        }
+
+        Ok(res)
+    }
+
+    /// Writes the hypergraph as a plain-text edge list, readable by `Hypergraph::read_edge_list`.
+    ///
+    /// Every hyperedge is written on its own line, with its nodes separated by `separator` and, if the hypergraph is
+    /// weighted, followed by its weight. Hyperedges are sorted, so the output is deterministic. Isolated nodes are not
+    /// part of any hyperedge, so they are not written.
+    ///
+    /// # Parameters
+    /// - `writer` : `impl Write` - Destination of the edge list.
+    /// - `separator` : `char` - Separator between the fields of a line.
+    ///
+    /// # Returns
+    /// - `std::io::Result<()>` - `Err` if writing to `writer` fails.
+    ///
+    /// # Performance
+    /// - `O(m*log(m)*k)`, where `m` is the number of hyperedges and `k` the maximum size of a hyperedge.
+    pub fn write_edge_list(&self, writer: impl Write, separator: char) -> std::io::Result<()> {
+        let mut writer = std::io::BufWriter::new(writer);
+        let data = HypergraphData::from(self);
+
+        for (edge, weight) in data.edges.iter() {
+            let mut fields: Vec<String> = edge.iter().map(|node| node.to_string()).collect();
+            if data.weighted {
+                fields.push(weight.to_string());
+            }
+            writeln!(writer, "{}", fields.join(&separator.to_string()))?;
+        }
+
+        writer.flush()
+    }
+}
@@ -0,0 +1,76 @@
+use std::io::{BufWriter, Write};
+
+use crate::{Hypergraph, HypergraphData};
+
+impl Hypergraph {
+    /// Writes the star expansion of the hypergraph as a GraphML document, eg for Gephi or yEd.
+    ///
+    /// The star expansion is the bipartite graph with a vertex for every node and a vertex for every hyperedge, where
+    /// each hyperedge vertex is connected to its members. In the document:
+    /// - every node becomes a `<node>` with id `n<node>` and `kind` attribute `node`;
+    /// - every hyperedge, sorted, becomes a `<node>` with id `e<index>`, `kind` attribute `edge` and a `weight` attribute;
+    /// - every incidence becomes an `<edge>` with id `i<index>` from the hyperedge vertex to the node vertex.
+    ///
+    /// Ids are unique and deterministic, since nodes and hyperedges are sorted.
+    ///
+    /// # Parameters
+    /// - `writer` : `impl Write` - Destination of the document.
+    ///
+    /// # Returns
+    /// - `std::io::Result<()>` - `Err` if writing to `writer` fails.
+    ///
+    /// # Performance
+    /// - `O(n*log(n) + m*log(m)*k)`, where `n` and `m` are the number of nodes and hyperedges, and `k` the maximum size of a hyperedge.
+    pub fn to_graphml(&self, writer: impl Write) -> std::io::Result<()> {
+        let mut writer = BufWriter::new(writer);
+        let data = HypergraphData::from(self);
+
+        writeln!(writer, "<?xml version=\"1.0\" encoding=\"UTF-8\"?>")?;
+        writeln!(
+            writer,
+            "<graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\" \
+             xmlns:xsi=\"http://www.w3.org/2001/XMLSchema-instance\" \
+             xsi:schemaLocation=\"http://graphml.graphdrawing.org/xmlns \
+             http://graphml.graphdrawing.org/xmlns/1.0/graphml.xsd\">"
+        )?;
+        writeln!(writer, "  <key id=\"kind\" for=\"node\" attr.name=\"kind\" attr.type=\"string\"/>")?;
+        writeln!(writer, "  <key id=\"weight\" for=\"node\" attr.name=\"weight\" attr.type=\"double\"/>")?;
+        writeln!(writer, "  <graph id=\"hypergraph\" edgedefault=\"undirected\">")?;
+
+        for node in data.nodes.iter() {
+            writeln!(writer, "    <node id=\"n{}\"><data key=\"kind\">node</data></node>", node)?;
+        }
+
+        for (index, (_, weight)) in data.edges.iter().enumerate() {
+            writeln!(
+                writer,
+                "    <node id=\"e{}\"><data key=\"kind\">edge</data><data key=\"weight\">{}</data></node>",
+                index,
+                Self::compute_xml_double(*weight)
+            )?;
+        }
+
+        let mut incidence = 0;
+        for (index, (edge, _)) in data.edges.iter().enumerate() {
+            for node in edge.iter() {
+                writeln!(writer, "    <edge id=\"i{}\" source=\"e{}\" target=\"n{}\"/>", incidence, index, node)?;
+                incidence += 1;
+            }
+        }
+
+        writeln!(writer, "  </graph>")?;
+        writeln!(writer, "</graphml>")?;
+        writer.flush()
+    }
+
+    /// Effectively formats a weight as an XML Schema `double`.
+    fn compute_xml_double(value: f64) -> String {
+        if value.is_nan() {
+            "NaN".to_string()
+        } else if value.is_infinite() {
+            if value > 0_f64 { "INF" } else { "-INF" }.to_string()
+        } else {
+            value.to_string()
+        }
+    }
+}
@@ -0,0 +1,184 @@
+use std::fmt::Write as _;
+use std::io::{Read, Write};
+
+use ahash::AHashMap;
+
+use super::json::{self, JsonValue};
+use crate::{Hypergraph, HypergraphData, HypergraphError, Node};
+
+impl Hypergraph {
+    /// Imports a hypergraph from a HIF (Hypergraph Interchange Format) JSON document.
+    ///
+    /// The `incidences`, `nodes` and `edges` arrays are supported; any other field (`metadata`, `attrs`, ...) is ignored.
+    /// - Every HIF edge becomes the hyperedge made of the nodes incident to it, in the order in which the incidences
+    ///   appear. HIF edges without incidences are skipped, since they would be empty; HIF edges with the same set of
+    ///   nodes are collapsed into one hyperedge, keeping the weight of the last one.
+    /// - The hypergraph is weighted if at least one entry of `edges` has a `weight`; edges without weight receive 0.
+    /// - The entries of `nodes` are added as nodes, so isolated nodes are preserved.
+    ///
+    /// HIF allows string identifiers, while nodes of this crate are integers: node identifiers must be integers or
+    /// integer-like strings (eg `"42"`), otherwise an error is returned. Edge identifiers can be arbitrary.
+    ///
+    /// # Parameters
+    /// - `reader` : `impl Read` - Source of the HIF document.
+    ///
+    /// # Returns
+    /// - `Result<Hypergraph, HypergraphError>` - `Ok` containing the imported hypergraph, `Err(HypergraphError::Parse)` if
+    ///   the document cannot be read, is not valid JSON, nests more than 256 arrays or objects, or does not follow the
+    ///   HIF layout.
+    ///
+    /// # Performance
+    /// - `O(l + i)`, where `l` is the length of the document and `i` the number of incidences.
+    pub fn from_hif(reader: impl Read) -> Result<Hypergraph, HypergraphError> {
+        Self::compute_from_hif(reader).map_err(HypergraphError::Parse)
+    }
+
+    /// Effectively imports the HIF document, with the errors as messages.
+    fn compute_from_hif(mut reader: impl Read) -> Result<Hypergraph, String> {
+        let mut input = String::new();
+        reader.read_to_string(&mut input).map_err(|err| err.to_string())?;
+
+        let document = json::parse(&input)?;
+        if document.as_object().is_none() {
+            return Err("The HIF document must be an object".to_string());
+        }
+
+        let incidences = Self::compute_hif_array(&document, "incidences")?;
+        let nodes = Self::compute_hif_array(&document, "nodes")?;
+        let edges = Self::compute_hif_array(&document, "edges")?;
+
+        // Edge identifier -> nodes, kept in order of first appearance
+        let mut edge_ids: Vec<String> = Vec::new();
+        let mut members: AHashMap<String, Vec<Node>> = AHashMap::new();
+
+        for (index, incidence) in incidences.iter().enumerate() {
+            let edge = incidence
+                .get("edge")
+                .and_then(Self::compute_hif_id)
+                .ok_or_else(|| format!("The incidence at index {} has no valid \"edge\"", index))?;
+            let node = incidence
+                .get("node")
+                .and_then(Self::compute_hif_node)
+                .ok_or_else(|| format!("The incidence at index {} has no integer-like \"node\"", index))?;
+
+            members
+                .entry(edge.clone())
+                .or_insert_with(|| {
+                    edge_ids.push(edge);
+                    Vec::new()
+                })
+                .push(node);
+        }
+
+        let mut weights: AHashMap<String, f64> = AHashMap::new();
+        for (index, edge) in edges.iter().enumerate() {
+            let id = edge
+                .get("edge")
+                .and_then(Self::compute_hif_id)
+                .ok_or_else(|| format!("The edge at index {} has no valid \"edge\"", index))?;
+
+            if let Some(weight) = edge.get("weight") {
+                let weight = weight
+                    .as_f64()
+                    .ok_or_else(|| format!("The weight of the edge at index {} must be a number", index))?;
+                weights.insert(id, weight);
+            }
+        }
+
+        let mut res = Hypergraph::new(!weights.is_empty());
+
+        for (index, node) in nodes.iter().enumerate() {
+            let node = node
+                .get("node")
+                .and_then(Self::compute_hif_node)
+                .ok_or_else(|| format!("The node at index {} has no integer-like \"node\"", index))?;
+            res.add_node(node);
+        }
+
+        for id in edge_ids.iter() {
+            let weight = weights.get(id).copied().unwrap_or(0_f64);
+            res.add_edge_weighted(&members[id], weight);
+        }
+
+        Ok(res)
+    }
+
+    /// Exports the hypergraph as a HIF (Hypergraph Interchange Format) JSON document.
+    ///
+    /// Hyperedges are sorted and identified by their position, starting from 0. The document contains the
+    /// `network-type` (`"undirected"`), the `incidences`, all the `nodes` (so isolated nodes are preserved) and the
+    /// `edges`, which carry a `weight` only if the hypergraph is weighted.
+    ///
+    /// # Parameters
+    /// - `writer` : `impl Write` - Destination of the HIF document.
+    ///
+    /// # Returns
+    /// - `std::io::Result<()>` - `Err` if writing to `writer` fails.
+    ///
+    /// # Performance
+    /// - `O(n*log(n) + m*log(m)*k)`, where `n` and `m` are the number of nodes and hyperedges, and `k` the maximum size of a hyperedge.
+    pub fn to_hif(&self, mut writer: impl Write) -> std::io::Result<()> {
+        let data = HypergraphData::from(self);
+        let mut res = String::from("{\"network-type\": \"undirected\", \"incidences\": [");
+
+        let mut first = true;
+        for (index, (edge, _)) in data.edges.iter().enumerate() {
+            for node in edge.iter() {
+                if !first {
+                    res.push_str(", ");
+                }
+                first = false;
+                let _ = write!(res, "{{\"edge\": {}, \"node\": {}}}", index, node);
+            }
+        }
+
+        res.push_str("], \"nodes\": [");
+        for (index, node) in data.nodes.iter().enumerate() {
+            if index > 0 {
+                res.push_str(", ");
+            }
+            let _ = write!(res, "{{\"node\": {}}}", node);
+        }
+
+        res.push_str("], \"edges\": [");
+        for (index, (_, weight)) in data.edges.iter().enumerate() {
+            if index > 0 {
+                res.push_str(", ");
+            }
+            let _ = write!(res, "{{\"edge\": {}", index);
+            if data.weighted {
+                res.push_str(", \"weight\": ");
+                json::write_f64(&mut res, *weight);
+            }
+            res.push('}');
+        }
+        res.push_str("]}");
+
+        writer.write_all(res.as_bytes())
+    }
+
+    /// Effectively reads an optional array field of a HIF document.
+    fn compute_hif_array<'a>(document: &'a JsonValue, key: &str) -> Result<&'a [JsonValue], String> {
+        match document.get(key) {
+            Some(value) => value
+                .as_array()
+                .map(|values| values.as_slice())
+                .ok_or_else(|| format!("The \"{}\" field must be an array", key)),
+            None => Ok(&[]),
+        }
+    }
+
+    /// Effectively converts a HIF identifier, either a number or a string, to a string.
+    fn compute_hif_id(value: &JsonValue) -> Option<String> {
+        match value {
+            JsonValue::Number(id) => Some(id.clone()),
+            JsonValue::Str(id) => Some(id.clone()),
+            _ => None,
+        }
+    }
+
+    /// Effectively converts a HIF node identifier, either an integer or an integer-like string, to a node.
+    fn compute_hif_node(value: &JsonValue) -> Option<Node> {
+        value.as_i64().or_else(|| value.as_str().and_then(|id| id.trim().parse().ok()))
+    }
+}
@@ -0,0 +1,138 @@
+use std::fmt::Write;
+
+use super::json::{self, JsonValue};
+use crate::{Hypergraph, HypergraphData, HypergraphError, Node};
+
+impl Hypergraph {
+    /// Exports the hypergraph as a JSON document compatible with the dumps of the Python library `hypergraphx`.
+    ///
+    /// The document is an object with the following fields:
+    /// - `"type"`: always `"Hypergraph"`;
+    /// - `"weighted"`: the weighted flag;
+    /// - `"nodes"`: the list of all nodes, including the isolated ones;
+    /// - `"edges"`: the list of hyperedges, each one an array of nodes;
+    /// - `"weights"`: only if the hypergraph is weighted, an object mapping the index of each hyperedge in `"edges"`
+    ///   (as a string) to its weight.
+    ///
+    /// Nodes and hyperedges are sorted, so equal hypergraphs always produce the same document.
+    ///
+    /// # Returns
+    /// - `String` - The JSON document.
+    ///
+    /// # Performance
+    /// - `O(n*log(n) + m*log(m)*k)`, where `n` and `m` are the number of nodes and hyperedges, and `k` the maximum size of a hyperedge.
+    pub fn to_json(&self) -> String {
+        let data = HypergraphData::from(self);
+        let mut res = String::new();
+
+        let _ = write!(res, "{{\"type\": \"Hypergraph\", \"weighted\": {}, \"nodes\": ", data.weighted);
+        Self::compute_json_nodes(&mut res, &data.nodes);
+
+        res.push_str(", \"edges\": [");
+        for (index, (edge, _)) in data.edges.iter().enumerate() {
+            if index > 0 {
+                res.push_str(", ");
+            }
+            Self::compute_json_nodes(&mut res, edge);
+        }
+        res.push(']');
+
+        if data.weighted {
+            res.push_str(", \"weights\": {");
+            for (index, (_, weight)) in data.edges.iter().enumerate() {
+                if index > 0 {
+                    res.push_str(", ");
+                }
+                let _ = write!(res, "\"{}\": ", index);
+                json::write_f64(&mut res, *weight);
+            }
+            res.push('}');
+        }
+
+        res.push('}');
+        res
+    }
+
+    /// Imports a hypergraph from a JSON document in the `hypergraphx` layout (see `Hypergraph::to_json`).
+    ///
+    /// Only `"edges"` is required. Unknown fields are ignored. If `"weighted"` is missing, the hypergraph is weighted
+    /// when a `"weights"` field is present. Hyperedges without a weight receive 0, as in the rest of the crate; the
+    /// weights are ignored for unweighted hypergraphs. `"weights"` may also be given as an array parallel to `"edges"`.
+    ///
+    /// # Parameters
+    /// - `json` : `&str` - The JSON document.
+    ///
+    /// # Returns
+    /// - `Result<Hypergraph, HypergraphError>` - `Ok` containing the imported hypergraph, `Err(HypergraphError::Parse)` if
+    ///   the document is not valid JSON, nests more than 256 arrays or objects, or does not follow the layout.
+    ///
+    /// # Performance
+    /// - `O(l + n + m*k)`, where `l` is the length of the document.
+    pub fn from_json(json: &str) -> Result<Hypergraph, HypergraphError> {
+        Self::compute_from_json(json).map_err(HypergraphError::Parse)
+    }
+
+    /// Effectively imports the `hypergraphx` JSON document, with the errors as messages.
+    fn compute_from_json(json: &str) -> Result<Hypergraph, String> {
+        let document = json::parse(json)?;
+        if document.as_object().is_none() {
+            return Err("The JSON document must be an object".to_string());
+        }
+
+        let edges = document
+            .get("edges")
+            .and_then(JsonValue::as_array)
+            .ok_or("The JSON document must contain an \"edges\" array")?;
+        let weights = document.get("weights");
+        let weighted = match document.get("weighted") {
+            Some(value) => value.as_bool().ok_or("The \"weighted\" field must be a boolean")?,
+            None => weights.is_some(),
+        };
+
+        let mut res = Hypergraph::new(weighted);
+
+        if let Some(nodes) = document.get("nodes") {
+            let nodes = Self::compute_json_to_nodes(nodes).ok_or("The \"nodes\" field must be an array of integers")?;
+            res.add_nodes(&nodes);
+        }
+
+        for (index, edge) in edges.iter().enumerate() {
+            let edge = Self::compute_json_to_nodes(edge)
+                .ok_or_else(|| format!("The hyperedge at index {} must be an array of integers", index))?;
+
+            let weight = match weights {
+                Some(object @ JsonValue::Object(_)) => object.get(&index.to_string()),
+                Some(JsonValue::Array(values)) => values.get(index),
+                Some(_) => return Err("The \"weights\" field must be an object or an array".to_string()),
+                None => None,
+            };
+            let weight = match weight {
+                Some(JsonValue::Null) | None => 0_f64,
+                Some(value) => value
+                    .as_f64()
+                    .ok_or_else(|| format!("The weight of the hyperedge at index {} must be a number", index))?,
+            };
+
+            res.add_edge_weighted(&edge, weight);
+        }
+
+        Ok(res)
+    }
+
+    /// Effectively writes a list of nodes as a JSON array.
+    fn compute_json_nodes(out: &mut String, nodes: &[Node]) {
+        out.push('[');
+        for (index, node) in nodes.iter().enumerate() {
+            if index > 0 {
+                out.push_str(", ");
+            }
+            let _ = write!(out, "{}", node);
+        }
+        out.push(']');
+    }
+
+    /// Effectively converts a JSON array of integers to a list of nodes.
+    fn compute_json_to_nodes(value: &JsonValue) -> Option<Vec<Node>> {
+        value.as_array()?.iter().map(JsonValue::as_i64).collect()
+    }
+}
@@ -0,0 +1,15 @@
+// Import and export of hypergraphs from and to external formats.
+
+mod json;
+mod hypergraphx;
+mod hif;
+mod edge_list;
+mod binary;
+mod dot;
+mod graphml;
+#[cfg(feature = "csv")]
+mod csv;
+
+pub use dot::DotStyle;
+#[cfg(feature = "csv")]
+pub use csv::CsvOptions;
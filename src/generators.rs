@@ -0,0 +1,739 @@
+// Random hypergraph generators, mainly meant for benchmarks and null-model comparisons.
+//
+// Every generator takes its source of randomness as a `&mut impl Rng`, so that a seeded generator (such as
+// `SplitMix64`) always produces the same hypergraph.
+
+use ahash::{AHashMap, AHashSet};
+
+use super::{EdgeID, Hypergraph, HypergraphError, Node};
+
+// Maximum number of random swaps tried to move a repeated member out of a hyperedge
+const MAX_REWIRINGS: usize = 100;
+
+// Maximum number of draws with replacement for every member of a hyperedge of `chung_lu`, before drawing it exactly
+const MAX_DRAWS: usize = 32;
+
+/// Source of uniformly distributed random numbers used by the generators.
+///
+/// Only `next_u64` has to be implemented; the other methods are derived from it.
+pub trait Rng {
+    /// Returns the next uniformly distributed `u64`.
+    fn next_u64(&mut self) -> u64;
+
+    /// Returns a uniformly distributed index in `0..bound`.
+    ///
+    /// # Panics
+    /// - If `bound` is 0.
+    fn gen_index(&mut self, bound: usize) -> usize {
+        assert!(bound > 0, "The bound must be positive.");
+        let bound = bound as u64;
+
+        // Rejection sampling, to avoid the bias of a plain modulo
+        let zone = u64::MAX - u64::MAX % bound;
+        loop {
+            let value = self.next_u64();
+            if value < zone {
+                return (value % bound) as usize;
+            }
+        }
+    }
+
+    /// Returns a uniformly distributed `f64` in `[0, 1)`.
+    fn gen_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1_u64 << 53) as f64
+    }
+}
+
+/// Small and fast seeded pseudo-random generator (SplitMix64), not suitable for cryptographic purposes.
+#[derive(Debug, Clone)]
+pub struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    /// Creates a new generator from `seed`: equal seeds produce equal sequences.
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+}
+
+impl Rng for SplitMix64 {
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E3779B97F4A7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^ (z >> 31)
+    }
+}
+
+/// Samples an unweighted hypergraph with `m` distinct hyperedges of exactly `k` nodes, chosen uniformly at random
+/// among the nodes `0..n`.
+///
+/// All the `n` nodes belong to the result, even the ones not covered by any hyperedge. The nodes of every hyperedge
+/// are sorted in ascending order; sampled hyperedges which are already in the hypergraph are rejected and re-sampled.
+///
+/// # Parameters
+/// - `n` : `usize` - The number of nodes.
+/// - `k` : `usize` - The size of every hyperedge.
+/// - `m` : `usize` - The number of hyperedges.
+/// - `rng` : `&mut impl Rng` - The source of randomness.
+///
+/// # Returns
+/// - `Result<Hypergraph, HypergraphError>` - `Ok` containing the sampled hypergraph. Returns
+///   `Err(HypergraphError::ZeroEdgeSize)` if `k` is 0 while `m` is positive, or `Err(HypergraphError::TooManyEdges)` if
+///   `m` exceeds the number `C(n, k)` of distinct hyperedges of size `k`.
+///
+/// # Performance
+/// - `O(n + m*k*log(k))` expected, as long as `m` is not close to `C(n, k)`.
+pub fn random_uniform(n: usize, k: usize, m: usize, rng: &mut impl Rng) -> Result<Hypergraph, HypergraphError> {
+    if k == 0 && m > 0 {
+        return Err(HypergraphError::ZeroEdgeSize);
+    }
+    if compute_binomial(n, k) < m as u128 {
+        return Err(HypergraphError::TooManyEdges);
+    }
+
+    let mut res = Hypergraph::new(false);
+    let nodes: Vec<Node> = (0..n as Node).collect();
+    res.add_nodes(&nodes);
+
+    while res.num_edges() < m {
+        let edge = compute_sample(n, k, rng);
+        if !res.check_edge(&edge) {
+            res.add_edge(&edge);
+        }
+    }
+
+    Ok(res)
+}
+
+/// `type Node = i64`
+///
+/// Samples an unweighted hypergraph on the nodes `0..n`, where every subset of `k` nodes is a hyperedge with
+/// probability `p_k`, independently of the others.
+///
+/// All the `n` nodes belong to the result, even the ones not covered by any hyperedge. The nodes of every hyperedge
+/// are sorted in ascending order. Rather than flipping a coin for each of the `C(n, k)` subsets, the subsets are
+/// visited in lexicographic order skipping a geometrically distributed number of them after every hit, so the cost
+/// is proportional to the number of generated hyperedges. Sizes equal to 0 or larger than `n`, and probabilities not
+/// in `(0, 1]`, produce no hyperedges.
+///
+/// # Parameters
+/// - `n` : `usize` - The number of nodes.
+/// - `probs` : `&[(usize, f64)]` - The pairs `(k, p_k)`, giving the probability of each subset of size `k`.
+/// - `rng` : `&mut impl Rng` - The source of randomness.
+///
+/// # Returns
+/// - `Hypergraph` - The sampled hypergraph.
+///
+/// # Performance
+/// - `O(n + m*n*k)` expected, where `m` is the number of generated hyperedges and `k` their maximum size.
+pub fn random_gnp(n: usize, probs: &[(usize, f64)], rng: &mut impl Rng) -> Hypergraph {
+    let mut res = Hypergraph::new(false);
+    let nodes: Vec<Node> = (0..n as Node).collect();
+    res.add_nodes(&nodes);
+
+    for (k, p) in probs.iter() {
+        if *k == 0 || p.is_nan() || *p <= 0_f64 {
+            continue;
+        }
+
+        let total = compute_binomial(n, *k);
+        let mut rank = 0_u128;
+        loop {
+            // Number of subsets to skip before the next hit, with P(skip = s) = (1-p)^s * p
+            // ln(1-p) is computed as ln_1p(-p), since 1-p rounds to 1 for tiny p
+            if *p < 1_f64 {
+                let u = 1_f64 - rng.gen_f64();
+                let skip = (u.ln() / (-*p).ln_1p()).floor();
+                if !skip.is_finite() || skip >= (total - rank) as f64 {
+                    break;
+                }
+                rank += skip as u128;
+            }
+            if rank >= total {
+                break;
+            }
+
+            let edge = compute_unrank(n, *k, rank);
+            if !res.check_edge(&edge) {
+                res.add_edge(&edge);
+            }
+            rank += 1;
+        }
+    }
+
+    res
+}
+
+/// `type Node = i64`
+///
+/// Samples an unweighted hypergraph with the given degree and size sequences (configuration model).
+///
+/// Every node receives as many stubs as its degree and every hyperedge as many slots as its size; the stubs are then
+/// shuffled and matched to the slots. When a node falls twice into the same hyperedge, one of its stubs is swapped
+/// with a random stub of another hyperedge, trying at most a fixed number of swaps; if none of them is valid the
+/// repeated member is dropped. In the common case the degrees and the sizes of the result match the input exactly;
+/// they can be smaller when a repetition could not be resolved, or when two hyperedges end up with the same nodes,
+/// as they are collapsed into one. Nodes with degree 0 are isolated nodes of the result, while sizes equal to 0
+/// are ignored.
+///
+/// # Parameters
+/// - `degrees` : `&[(Node, usize)]` - The pairs `(node, degree)`.
+/// - `sizes` : `&[usize]` - The size of every hyperedge.
+/// - `rng` : `&mut impl Rng` - The source of randomness.
+///
+/// # Returns
+/// - `Result<Hypergraph, HypergraphError>` - `Ok` containing the sampled hypergraph. Returns
+///   `Err(HypergraphError::DegreeSizeMismatch)` if the sum of the degrees differs from the sum of the sizes.
+///
+/// # Performance
+/// - `O(s*k)` expected, where `s` is the sum of the sizes and `k` their maximum.
+pub fn configuration_model(degrees: &[(Node, usize)], sizes: &[usize], rng: &mut impl Rng) -> Result<Hypergraph, HypergraphError> {
+    if degrees.iter().map(|(_, degree)| degree).sum::<usize>() != sizes.iter().sum::<usize>() {
+        return Err(HypergraphError::DegreeSizeMismatch);
+    }
+
+    let mut stubs: Vec<Node> = degrees
+        .iter()
+        .flat_map(|(node, degree)| std::iter::repeat_n(*node, *degree))
+        .collect();
+    compute_shuffle(&mut stubs, rng);
+
+    // Slots of every hyperedge in `stubs`
+    let mut bounds = Vec::with_capacity(sizes.len());
+    let mut start = 0;
+    for size in sizes.iter().filter(|size| **size > 0) {
+        bounds.push((start, start + size));
+        start += size;
+    }
+
+    for (e, (start, end)) in bounds.iter().enumerate() {
+        for i in *start..*end {
+            if !stubs[*start..i].contains(&stubs[i]) {
+                continue;
+            }
+
+            for _ in 0..MAX_REWIRINGS {
+                let f = rng.gen_index(bounds.len());
+                if f == e {
+                    continue;
+                }
+
+                let (other_start, other_end) = bounds[f];
+                let j = other_start + rng.gen_index(other_end - other_start);
+                if !stubs[*start..*end].contains(&stubs[j]) && !stubs[other_start..other_end].contains(&stubs[i]) {
+                    stubs.swap(i, j);
+                    break;
+                }
+            }
+        }
+    }
+
+    let mut res = Hypergraph::new(false);
+    let nodes: Vec<Node> = degrees.iter().map(|(node, _)| *node).collect();
+    res.add_nodes(&nodes);
+
+    for (start, end) in bounds {
+        let mut edge = stubs[start..end].to_vec();
+        edge.sort_unstable();
+        edge.dedup();
+        res.add_edge(&edge);
+    }
+
+    Ok(res)
+}
+
+/// `type Node = i64`
+///
+/// Samples an unweighted hypergraph whose expected degrees match `expected_degrees` (Chung-Lu model).
+///
+/// For every size in `expected_sizes` a hyperedge is built drawing its members at random, each node with probability
+/// proportional to its expected degree, and re-drawing the members already in the hyperedge (after too many re-draws,
+/// eg next to a negligible expected degree, the member is drawn among the other nodes only). The expected degree of
+/// every node matches the input when the sum of the sizes equals the sum of the expected degrees, up to the small bias
+/// of drawing distinct members. Nodes whose expected degree is not positive never appear in a hyperedge, but are
+/// still nodes of the result; sizes larger than the number of nodes with positive expected degree are reduced to it.
+///
+/// A hyperedge can be drawn twice: if `resample_duplicates` is `true` it is re-drawn (up to a fixed number of times),
+/// otherwise it is collapsed into the existing one, so the result can have fewer hyperedges than `expected_sizes`.
+///
+/// # Parameters
+/// - `expected_degrees` : `&AHashMap<Node, f64>` - The expected degree of every node.
+/// - `expected_sizes` : `&[usize]` - The size of every hyperedge.
+/// - `resample_duplicates` : `bool` - If `true` duplicate hyperedges are re-drawn, otherwise they are collapsed.
+/// - `rng` : `&mut impl Rng` - The source of randomness.
+///
+/// # Returns
+/// - `Hypergraph` - The sampled hypergraph.
+///
+/// # Performance
+/// - `O(n*log(n) + s*log(n))` expected, where `n` is the number of nodes and `s` the sum of the sizes.
+pub fn chung_lu(
+    expected_degrees: &AHashMap<Node, f64>,
+    expected_sizes: &[usize],
+    resample_duplicates: bool,
+    rng: &mut impl Rng,
+) -> Hypergraph {
+    let mut res = Hypergraph::new(false);
+
+    // Sorted, so that the result does not depend on the iteration order of the map
+    let mut nodes: Vec<(Node, f64)> = expected_degrees.iter().map(|(node, degree)| (*node, *degree)).collect();
+    nodes.sort_unstable_by_key(|(node, _)| *node);
+    res.add_nodes(&nodes.iter().map(|(node, _)| *node).collect::<Vec<Node>>());
+
+    nodes.retain(|(_, degree)| *degree > 0_f64);
+    let mut cumulative = Vec::with_capacity(nodes.len());
+    let mut total = 0_f64;
+    for (_, degree) in nodes.iter() {
+        total += degree;
+        cumulative.push(total);
+    }
+
+    for size in expected_sizes.iter() {
+        let size = (*size).min(nodes.len());
+        if size == 0 {
+            continue;
+        }
+
+        for _ in 0..MAX_REWIRINGS {
+            let mut edge: Vec<Node> = Vec::with_capacity(size);
+            let mut draws = 0;
+            while edge.len() < size {
+                // The members already drawn may take almost all the weight, eg next to a negligible degree
+                if draws >= MAX_DRAWS * size {
+                    edge.push(compute_draw_remaining(&nodes, &edge, rng));
+                    continue;
+                }
+                draws += 1;
+
+                let target = rng.gen_f64() * total;
+                let i = cumulative.partition_point(|value| *value <= target).min(nodes.len() - 1);
+                if !edge.contains(&nodes[i].0) {
+                    edge.push(nodes[i].0);
+                }
+            }
+            edge.sort_unstable();
+
+            if !resample_duplicates || !res.check_edge(&edge) {
+                res.add_edge(&edge);
+                break;
+            }
+        }
+    }
+
+    res
+}
+
+impl Hypergraph {
+    /// Rewires the hyperedges of the hypergraph, preserving the degree of every node and the size of every hyperedge.
+    ///
+    /// Each of the `n_swaps` attempts picks two random hyperedges and a random member of each, and swaps the two
+    /// members. Empty hyperedges are never picked. The swap is rejected if one of the members is already in the other
+    /// hyperedge or is repeated in its own, or if one of the rewired hyperedges is already in the hypergraph; otherwise
+    /// both hyperedges are replaced by their rewired versions, each one keeping its weight, its multiplicity, its
+    /// attributes and its timestamps. Repeating many swaps destroys the correlations of the hypergraph, which is the
+    /// standard way to build null models from empirical data.
+    ///
+    /// # Parameters
+    /// - `n_swaps` : `usize` - The number of attempted swaps.
+    /// - `rng` : `&mut impl Rng` - The source of randomness.
+    ///
+    /// # Returns
+    /// - `usize` - The number of successful swaps.
+    ///
+    /// # Performance
+    /// - `O(m*log(m)*k + s*k)`, where `m` is the number of hyperedges, `k` their maximum size and `s` is `n_swaps`.
+    pub fn shuffle_edges(&mut self, n_swaps: usize, rng: &mut impl Rng) -> usize {
+        // Sorted, so that the result does not depend on the iteration order of the map. Empty hyperedges have no
+        // member to swap
+        let mut ids: Vec<EdgeID> = self
+            .edge_list
+            .iter()
+            .filter(|(_, hyperedge)| !hyperedge.nodes.is_empty())
+            .map(|(edge_id, _)| *edge_id)
+            .collect();
+        ids.sort_unstable_by(|a, b| self.edge_list[a].nodes.cmp(&self.edge_list[b].nodes));
+
+        let mut res = 0;
+        if ids.len() < 2 {
+            return res;
+        }
+
+        for _ in 0..n_swaps {
+            let i = rng.gen_index(ids.len());
+            let j = rng.gen_index(ids.len());
+            if i == j {
+                continue;
+            }
+
+            let (first, second) = (&self.edge_list[&ids[i]], &self.edge_list[&ids[j]]);
+            let (a, b) = (rng.gen_index(first.nodes.len()), rng.gen_index(second.nodes.len()));
+            let (u, v) = (first.nodes[a], second.nodes[b]);
+            // Each member has to leave its hyperedge and join the other one, otherwise the degrees would change
+            let count = |nodes: &[Node], node: Node| nodes.iter().filter(|other| **other == node).count();
+            let repeated = count(&first.nodes, u) > 1 || count(&second.nodes, v) > 1;
+            if first.nodes.contains(&v) || second.nodes.contains(&u) || repeated {
+                continue;
+            }
+
+            let mut new_first = first.nodes.clone();
+            let mut new_second = second.nodes.clone();
+            new_first[a] = v;
+            new_second[b] = u;
+            if self.check_edge(&new_first) || self.check_edge(&new_second) || new_first == new_second {
+                continue;
+            }
+
+            for (k, new_edge) in [(i, new_first), (j, new_second)] {
+                let old = self.edge_list.remove(&ids[k]).unwrap();
+                for node in old.nodes.iter() {
+                    if let Some(edges) = self.incidence_list.get_mut(node) {
+                        edges.remove(&ids[k]);
+                    }
+                }
+
+                Hypergraph::compute_add_edge(self, &new_edge, old.weight);
+                let new_id = Hypergraph::compute_edge_id(&new_edge);
+                if let Some(hyperedge) = self.edge_list.get_mut(&new_id) {
+                    hyperedge.multiplicity = old.multiplicity;
+                }
+                self.compute_move_edge_attrs(ids[k], new_id);
+                self.compute_move_edge_times(ids[k], new_id);
+                ids[k] = new_id;
+            }
+            res += 1;
+        }
+
+        res
+    }
+}
+
+/// Effectively draws a node not in `edge`, with probability proportional to its weight among the remaining ones.
+/// There must be at least one remaining node.
+fn compute_draw_remaining(nodes: &[(Node, f64)], edge: &[Node], rng: &mut impl Rng) -> Node {
+    let remaining = || nodes.iter().filter(|(node, _)| !edge.contains(node));
+    let total: f64 = remaining().map(|(_, weight)| weight).sum();
+
+    let target = rng.gen_f64() * total;
+    let mut acc = 0_f64;
+    let mut res = None;
+    for (node, weight) in remaining() {
+        acc += weight;
+        res = Some(*node);
+        if acc > target {
+            break;
+        }
+    }
+    // The last remaining node when the target is not reached because of rounding
+    res.unwrap()
+}
+
+/// Effectively computes `C(n, k)`, saturating at `u128::MAX`.
+fn compute_binomial(n: usize, k: usize) -> u128 {
+    if k > n {
+        return 0;
+    }
+
+    let k = k.min(n - k) as u128;
+    let mut res = 1_u128;
+    for i in 0..k {
+        // Exact at every step: the product of i+1 consecutive integers is divisible by (i+1)!
+        match res.checked_mul(n as u128 - i) {
+            Some(product) => res = product / (i + 1),
+            None => return u128::MAX,
+        }
+    }
+    res
+}
+
+/// `type Node = i64`
+///
+/// Effectively samples `k` distinct nodes among `0..n` with Floyd's algorithm, sorted in ascending order.
+fn compute_sample(n: usize, k: usize, rng: &mut impl Rng) -> Vec<Node> {
+    let mut chosen: AHashSet<usize> = AHashSet::with_capacity(k);
+
+    for j in n - k..n {
+        let candidate = rng.gen_index(j + 1);
+        if !chosen.insert(candidate) {
+            chosen.insert(j);
+        }
+    }
+
+    let mut res: Vec<Node> = chosen.into_iter().map(|node| node as Node).collect();
+    res.sort_unstable();
+    res
+}
+
+/// `type Node = i64`
+///
+/// Effectively computes the subset of `k` nodes among `0..n` with the given rank in lexicographic order.
+fn compute_unrank(n: usize, k: usize, mut rank: u128) -> Vec<Node> {
+    let mut res = Vec::with_capacity(k);
+    let mut node = 0;
+
+    while res.len() < k {
+        // Number of subsets starting with the nodes chosen so far followed by `node`
+        let count = compute_binomial(n - node - 1, k - res.len() - 1);
+        if rank < count {
+            res.push(node as Node);
+        } else {
+            rank -= count;
+        }
+        node += 1;
+    }
+    res
+}
+
+/// Effectively shuffles `array` in place with the Fisher-Yates algorithm.
+pub(crate) fn compute_shuffle<T>(array: &mut [T], rng: &mut (impl Rng + ?Sized)) {
+    for i in (1..array.len()).rev() {
+        array.swap(i, rng.gen_index(i + 1));
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use crate::HypergraphData;
+
+    #[test]
+    fn test_random_uniform() {
+        let mut rng = SplitMix64::new(27);
+        let hg = random_uniform(20, 3, 50, &mut rng).unwrap();
+
+        assert_eq!(hg.num_nodes(), 20);
+        assert_eq!(hg.num_edges(), 50);
+        assert_eq!(hg.is_uniform(), Some(3));
+        assert!(hg.get_edges().unwrap().iter().all(|edge| edge.iter().all(|node| (0..20).contains(node))));
+
+        // Every hyperedge of size 2 among 5 nodes
+        let hg = random_uniform(5, 2, 10, &mut rng).unwrap();
+        assert_eq!(hg.num_edges(), 10);
+    }
+
+    #[test]
+    fn test_random_uniform_errors() {
+        let mut rng = SplitMix64::new(27);
+
+        assert_eq!(random_uniform(5, 2, 11, &mut rng).unwrap_err(), HypergraphError::TooManyEdges);
+        assert_eq!(random_uniform(3, 4, 1, &mut rng).unwrap_err(), HypergraphError::TooManyEdges);
+        assert_eq!(random_uniform(3, 0, 1, &mut rng).unwrap_err(), HypergraphError::ZeroEdgeSize);
+        assert_eq!(random_uniform(3, 4, 0, &mut rng).unwrap().num_edges(), 0);
+    }
+
+    #[test]
+    fn test_random_uniform_determinism() {
+        let a = random_uniform(30, 4, 40, &mut SplitMix64::new(7)).unwrap();
+        let b = random_uniform(30, 4, 40, &mut SplitMix64::new(7)).unwrap();
+        let c = random_uniform(30, 4, 40, &mut SplitMix64::new(8)).unwrap();
+
+        assert_eq!(HypergraphData::from(&a), HypergraphData::from(&b));
+        assert_ne!(HypergraphData::from(&a), HypergraphData::from(&c));
+    }
+
+    #[test]
+    fn test_random_gnp() {
+        let (n, probs) = (30, [(2, 0.1), (3, 0.01)]);
+        let (expected_2, expected_3) = (435.0 * 0.1, 4060.0 * 0.01);
+
+        for seed in 0..5 {
+            let hg = random_gnp(n, &probs, &mut SplitMix64::new(seed));
+            let sizes = hg.get_sizes().unwrap();
+            let count = |k| sizes.iter().filter(|size| **size == k).count() as f64;
+
+            assert_eq!(hg.num_nodes(), 30);
+            assert!((count(2) - expected_2).abs() < 4.0 * (expected_2 * 0.9_f64).sqrt());
+            assert!((count(3) - expected_3).abs() < 4.0 * (expected_3 * 0.99_f64).sqrt());
+            assert_eq!(count(2) + count(3), hg.num_edges() as f64);
+        }
+
+        // Huge number of candidate subsets, C(60, 20) ~ 4.2e15, with a handful of expected hits
+        let hg = random_gnp(60, &[(20, 1e-15)], &mut SplitMix64::new(27));
+        assert!(hg.num_edges() < 20);
+        assert!(hg.get_sizes().unwrap_or_default().iter().all(|size| *size == 20));
+    }
+
+    #[test]
+    fn test_random_gnp_edge_cases() {
+        let mut rng = SplitMix64::new(27);
+
+        assert_eq!(random_gnp(5, &[(6, 1.0)], &mut rng).num_edges(), 0);
+        assert_eq!(random_gnp(5, &[(0, 1.0), (2, 0.0), (2, f64::NAN)], &mut rng).num_edges(), 0);
+        assert_eq!(random_gnp(5, &[(2, 1.0), (5, 1.0)], &mut rng).num_edges(), 11);
+        assert_eq!(compute_unrank(5, 2, 0), vec![0, 1]);
+        assert_eq!(compute_unrank(5, 2, 9), vec![3, 4]);
+        assert_eq!(compute_unrank(5, 3, 4), vec![0, 2, 4]);
+
+        // 1 - p rounds to 1, which must not make every subset a hit
+        for p in [1e-17, 1e-300, f64::MIN_POSITIVE / 4.0] {
+            assert!(random_gnp(30, &[(3, p)], &mut rng).num_edges() < 5);
+        }
+    }
+
+    fn degree(hg: &Hypergraph, node: Node) -> usize {
+        hg.get_incident_edges(node, None, None).unwrap().unwrap().len()
+    }
+
+    #[test]
+    fn test_configuration_model() {
+        let degrees: Vec<(Node, usize)> = (0..40).map(|node| (node, 1 + node as usize % 4)).collect();
+        let sizes: Vec<usize> = (0..30).map(|i| if i % 3 == 0 { 4 } else { 3 }).collect();
+        let mut expected_sizes = sizes.clone();
+        expected_sizes.sort_unstable();
+
+        let hg = configuration_model(&degrees, &sizes, &mut SplitMix64::new(27)).unwrap();
+
+        let mut got_sizes = hg.get_sizes().unwrap();
+        got_sizes.sort_unstable();
+        assert_eq!(got_sizes, expected_sizes);
+        assert!(degrees.iter().all(|(node, d)| degree(&hg, *node) == *d));
+
+        let other = configuration_model(&degrees, &sizes, &mut SplitMix64::new(27)).unwrap();
+        assert_eq!(HypergraphData::from(&hg), HypergraphData::from(&other));
+    }
+
+    #[test]
+    fn test_configuration_model_edge_cases() {
+        let mut rng = SplitMix64::new(27);
+
+        assert_eq!(
+            configuration_model(&[(0, 2), (1, 1)], &[2], &mut rng).unwrap_err(),
+            HypergraphError::DegreeSizeMismatch
+        );
+
+        // The only valid matching, reached through rewiring
+        let hg = configuration_model(&[(0, 2), (1, 2), (2, 0)], &[2, 2, 0], &mut rng).unwrap();
+        assert_eq!(hg.num_nodes(), 3);
+        assert_eq!(hg.num_edges(), 1);
+        assert!(hg.check_edge(&[0, 1]));
+        assert_eq!(degree(&hg, 2), 0);
+
+        // A repetition which cannot be resolved
+        let hg = configuration_model(&[(0, 2)], &[2], &mut rng).unwrap();
+        assert!(hg.check_edge(&[0]));
+    }
+
+    #[test]
+    fn test_chung_lu() {
+        let mut expected_degrees: AHashMap<Node, f64> = (0..50).map(|node| (node, (1 + node % 5) as f64)).collect();
+        expected_degrees.insert(50, 0_f64);
+        let sizes = vec![3; 50];
+
+        // Average degree of the nodes of every expected degree, over several samples
+        let mut totals = [0_f64; 5];
+        let seeds = 40;
+        for seed in 0..seeds {
+            let hg = chung_lu(&expected_degrees, &sizes, true, &mut SplitMix64::new(seed));
+
+            assert_eq!(hg.num_nodes(), 51);
+            assert_eq!(hg.num_edges(), 50);
+            assert_eq!(degree(&hg, 50), 0);
+            for node in 0..50 {
+                totals[node as usize % 5] += degree(&hg, node) as f64;
+            }
+        }
+
+        for (i, total) in totals.iter().enumerate() {
+            let mean = total / (10 * seeds) as f64;
+            assert!((mean - (i + 1) as f64).abs() < 0.5);
+        }
+    }
+
+    #[test]
+    fn test_chung_lu_duplicates() {
+        let expected_degrees: AHashMap<Node, f64> = [(0, 1.0), (1, 1.0), (2, 1.0), (3, -1.0)].into_iter().collect();
+
+        // Only three hyperedges of size 2 exist among the nodes with positive degree
+        let resampled = chung_lu(&expected_degrees, &[2; 3], true, &mut SplitMix64::new(27));
+        let collapsed = chung_lu(&expected_degrees, &[2; 20], false, &mut SplitMix64::new(27));
+        assert_eq!(resampled.num_edges(), 3);
+        assert_eq!(collapsed.num_edges(), 3);
+
+        // Oversized hyperedges are reduced to the nodes with positive degree
+        let hg = chung_lu(&expected_degrees, &[10], true, &mut SplitMix64::new(27));
+        assert!(hg.check_edge(&[0, 1, 2]));
+        assert_eq!(degree(&hg, 3), 0);
+    }
+
+    #[test]
+    fn test_chung_lu_negligible_degree() {
+        // The second node is never drawn with replacement, as its weight vanishes next to the first one
+        let expected_degrees: AHashMap<Node, f64> = [(1, 1.0), (2, 1e-30)].into_iter().collect();
+        let hg = chung_lu(&expected_degrees, &[2], true, &mut SplitMix64::new(27));
+        assert!(hg.check_edge(&[1, 2]));
+
+        let expected_degrees: AHashMap<Node, f64> = [(1, 1.0), (2, 1e-30), (3, 1e-30), (4, 1.0)].into_iter().collect();
+        let hg = chung_lu(&expected_degrees, &[4, 3], false, &mut SplitMix64::new(27));
+        assert!(hg.check_edge(&[1, 2, 3, 4]));
+        assert_eq!(hg.num_edges(), 2);
+    }
+
+    #[test]
+    fn test_shuffle_edges() {
+        let mut hg = Hypergraph::new(true);
+        let mut rng = SplitMix64::new(27);
+        for (i, edge) in random_uniform(40, 3, 60, &mut rng).unwrap().get_edges().unwrap().iter().enumerate() {
+            hg.add_edge_weighted(edge, i as f64);
+        }
+        let before = HypergraphData::from(&hg);
+
+        let swaps = hg.shuffle_edges(10_000, &mut rng);
+        let after = HypergraphData::from(&hg);
+
+        assert!(swaps > 0);
+        assert_ne!(before.edges, after.edges);
+        assert_eq!(before.nodes, after.nodes);
+        assert!(before.nodes.iter().all(|node| degree(&hg, *node) == {
+            before.edges.iter().filter(|(edge, _)| edge.contains(node)).count()
+        }));
+
+        let mut sizes_before: Vec<usize> = before.edges.iter().map(|(edge, _)| edge.len()).collect();
+        let mut sizes_after = hg.get_sizes().unwrap();
+        sizes_before.sort_unstable();
+        sizes_after.sort_unstable();
+        assert_eq!(sizes_before, sizes_after);
+
+        // Weights are carried along with the hyperedges
+        let mut weights = hg.get_weights().unwrap();
+        weights.sort_by(|a, b| a.total_cmp(b));
+        assert_eq!(weights, (0..60).map(|i| i as f64).collect::<Vec<f64>>());
+    }
+
+    #[test]
+    fn test_shuffle_edges_no_valid_swaps() {
+        let mut hg = Hypergraph::from(&[vec![0, 1], vec![0, 1, 2]]);
+        hg.add_node(3);
+
+        assert_eq!(hg.shuffle_edges(100, &mut SplitMix64::new(27)), 0);
+        assert!(hg.check_edge(&[0, 1]));
+        assert!(hg.check_edge(&[0, 1, 2]));
+    }
+
+    #[test]
+    fn test_shuffle_edges_degenerate() {
+        // An empty hyperedge is never picked, and the repeated node cannot be swapped
+        let mut hg = Hypergraph::from(&[vec![1, 2], vec![], vec![3, 4], vec![5, 5, 6], vec![7, 8, 9]]);
+        let degrees: Vec<usize> = (1..10).map(|node| degree(&hg, node)).collect();
+
+        assert!(hg.shuffle_edges(1000, &mut SplitMix64::new(1)) > 0);
+        assert!(hg.check_edge(&[]));
+        assert_eq!((1..10).map(|node| degree(&hg, node)).collect::<Vec<usize>>(), degrees);
+        let twice = |edge: &Vec<Node>| edge.iter().filter(|node| **node == 5).count() == 2;
+        assert_eq!(hg.get_edges().unwrap().iter().filter(|edge| twice(edge)).count(), 1);
+    }
+
+    #[test]
+    fn test_rng() {
+        let mut rng = SplitMix64::new(0);
+        let mut counts = [0_usize; 4];
+        for _ in 0..4000 {
+            counts[rng.gen_index(4)] += 1;
+        }
+        assert!(counts.iter().all(|count| (850..1150).contains(count)));
+
+        let value = rng.gen_f64();
+        assert!((0.0..1.0).contains(&value));
+    }
+}
@@ -4,6 +4,32 @@ use std::{
     hash::{Hash, Hasher},
 };
 
+/// Stable handle of a hyperedge in a hypergraph, computed by hashing its nodes.  
+///
+/// The same hyperedge (the same nodes, in the same order) always gets the same `EdgeID`, so handles can be stored in
+/// place of the nodes, and stay valid until the hyperedge is removed. The value is only meaningful for the hypergraphs
+/// of this crate; the conversions from and to `u64` are meant for implementors of `HypergraphOps`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct EdgeID(u64);
+
+impl From<u64> for EdgeID {
+    fn from(value: u64) -> Self {
+        Self(value)
+    }
+}
+
+impl From<EdgeID> for u64 {
+    fn from(value: EdgeID) -> Self {
+        value.0
+    }
+}
+
+impl Display for EdgeID {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:#018x}", self.0)
+    }
+}
+
 /// Represents a (weighted) hyperedge in a hypergraph.  
 ///
 /// A hyperedge is an edge that can link any number of nodes, as opposed to standard graph edges that only   
@@ -23,6 +49,9 @@ pub struct Hyperedge {
 
     /// Optional weight for the hyperedge.
     pub weight: f64,
+
+    /// Number of instances of the hyperedge, at least 1 (see `Hypergraph::add_edge_multi`).
+    pub multiplicity: usize,
 }
 
 impl Hyperedge {
@@ -30,13 +59,13 @@ impl Hyperedge {
     ///
     /// # Parameters
     /// - `nodes` : `Rc<RefCell<Vec<Node>>>` - Nodes which are incident to this hyperedge. The smart pointers are needed   
-    /// to achieve multiple reference (`Rc`) and interior mutability (`RefCell`).
+    ///   to achieve multiple reference (`Rc`) and interior mutability (`RefCell`).
     /// - `weight` : `f64` - Weight of the hyperedge.
     ///
     /// # Returns  
-    /// - `Self` - A new instance of `Hyperedge`.
+    /// - `Self` - A new instance of `Hyperedge`, with multiplicity 1.
     pub fn new(nodes: Vec<Node>, weight: f64) -> Self {
-        Self { nodes, weight }
+        Self { nodes, weight, multiplicity: 1 }
     }
 
     /// Change the weight of this hyperedge.
@@ -51,6 +80,46 @@ impl Hyperedge {
     }
 }
 
+/// Policy used to merge the weights when a hyperedge which is already in a hypergraph is added again.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MergePolicy {
+    /// The new weight replaces the existing one.
+    #[default]
+    Replace,
+
+    /// The weights are summed, eg to count repeated interactions.
+    Sum,
+
+    /// The largest weight is kept.
+    Max,
+
+    /// The smallest weight is kept.
+    Min,
+
+    /// The existing weight is kept, and the new one is ignored.
+    KeepExisting,
+}
+
+impl MergePolicy {
+    /// Merges the weight of a hyperedge already in the hypergraph with the weight it is added with.
+    ///
+    /// # Parameters
+    /// - `existing` : `f64` - The weight of the hyperedge in the hypergraph.
+    /// - `new` : `f64` - The weight the hyperedge is added with.
+    ///
+    /// # Returns
+    /// - `f64` - The merged weight.
+    pub fn merge(self, existing: f64, new: f64) -> f64 {
+        match self {
+            Self::Replace => new,
+            Self::Sum => existing + new,
+            Self::Max => existing.max(new),
+            Self::Min => existing.min(new),
+            Self::KeepExisting => existing,
+        }
+    }
+}
+
 impl Hash for Hyperedge {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.nodes.hash(state)
@@ -68,6 +137,7 @@ impl Clone for Hyperedge {
         Self {
             nodes: self.nodes.clone(), // O(n)
             weight: self.weight,
+            multiplicity: self.multiplicity,
         }
     }
 }
@@ -1,25 +1,225 @@
 use std::fmt::{Debug, Display};
 
-use super::Hypergraph;
+use ahash::{AHashSet, RandomState};
+
+use super::hyperedge::Hyperedge;
+use super::{EdgeID, Hypergraph, HypergraphError, Node, SEED1, SEED2, SEED3, SEED4};
+
+/// `type Node = i64`  
+///
+/// Read-only primitives needed by the algorithms of this crate.  
+///
+/// The analytics modules (`visits`, `cc`, ...) are written against this trait instead of the concrete `Hypergraph`,  
+/// so that any representation of a hypergraph exposing these accessors can be traversed and analysed.
+pub trait HypergraphOps {
+    /// Returns an iterator over the nodes of the hypergraph.
+    fn nodes(&self) -> impl Iterator<Item = Node> + '_;
+
+    /// Returns the number of hyperedges incident to `node`, or `None` if the node is not in the hypergraph.
+    fn degree(&self, node: Node) -> Option<usize>;
+
+    /// Returns an iterator over the `EdgeID`s of the hyperedges incident to `node`, or `None` if the node is not in  
+    /// the hypergraph.
+    fn incident_edge_ids(&self, node: Node) -> Option<impl Iterator<Item = EdgeID> + '_>;
+
+    /// Returns the nodes of the hyperedge identified by `edge_id`, or `None` if there is no such hyperedge.
+    fn edge_nodes(&self, edge_id: EdgeID) -> Option<&[Node]>;
+
+    /// Returns the weight of the hyperedge identified by `edge_id`, or `None` if there is no such hyperedge.
+    fn edge_weight(&self, edge_id: EdgeID) -> Option<f64>;
+
+    /// Returns the number of nodes of the hypergraph.
+    fn num_nodes(&self) -> usize;
+
+    /// Returns the number of hyperedges of the hypergraph.
+    fn num_edges(&self) -> usize;
+
+    /// Checks if a node is in the hypergraph.
+    fn contains_node(&self, node: Node) -> bool {
+        self.degree(node).is_some()
+    }
+
+    /// Returns the `EdgeID` of the hyperedge made of exactly `edge`, in the same order, or `None` if there is no such
+    /// hyperedge. By default, the hyperedges incident to the first node of `edge` are compared one by one.
+    fn edge_id(&self, edge: &[Node]) -> Option<EdgeID> {
+        let first = edge.first()?;
+        self.incident_edge_ids(*first)?.find(|edge_id| self.edge_nodes(*edge_id) == Some(edge))
+    }
+
+    /// `type Node = i64`  
+    ///
+    /// Gives the neighbors of a specific node, ie the nodes sharing at least one hyperedge with it.  
+    ///
+    /// The convention is `order == size - 1`.
+    ///
+    /// # Parameters
+    /// - `node` : `Node` - The node of interest.
+    /// - `order` : `Option<usize>` - The order of the hyperedges to consider.
+    /// - `size` : `Option<usize>` - The size of the hyperedges to consider.
+    ///
+    /// # Returns
+    /// - `Result<Option<Vec<Node>>, HypergraphError>` - `Ok` containing `Some` list of neighbors of `node`, or containing `None` if  
+    ///   the node provided is not in the hypergraph. Returns `Err(HypergraphError::BothOrderAndSize)` if both `order` and `size`  
+    ///   are provided.
+    ///
+    /// # Performance
+    /// - `O(d*k)`, where `d` is the degree of the node and `k` the maximum size of its incident hyperedges.
+    fn neighbors(&self, node: Node, order: Option<usize>, size: Option<usize>) -> Result<Option<Vec<Node>>, HypergraphError> {
+        if order.is_some() && size.is_some() {
+            return Err(HypergraphError::BothOrderAndSize);
+        }
+        // Only one between order and size can be specified here
+        let filter = order.map(|val| val + 1).or(size);
+
+        Ok(self.neighbors_filtered(node, move |nodes, _| filter.is_none_or(|len| nodes.len() == len)))
+    }
+
+    /// `type Node = i64`  
+    ///
+    /// Gives the neighbors of a specific node, as `neighbors`, through the hyperedges accepted by `filter` only.
+    ///
+    /// # Parameters
+    /// - `node` : `Node` - The node of interest.
+    /// - `filter` : `impl Fn(&[Node], f64) -> bool` - Given the nodes and the weight of a hyperedge, whether to consider
+    ///   it.
+    ///
+    /// # Returns
+    /// - `Option<Vec<Node>>` - `Some` list of neighbors of `node`, or `None` if the node is not in the hypergraph.
+    ///
+    /// # Performance
+    /// - `O(d*k)`, where `d` is the degree of the node and `k` the maximum size of its incident hyperedges.
+    fn neighbors_filtered(&self, node: Node, filter: impl Fn(&[Node], f64) -> bool) -> Option<Vec<Node>> {
+        // We don't consider the node itself as a neighbor
+        let mut seen = AHashSet::from([node]);
+
+        let edge_ids = self.incident_edge_ids(node)?;
+        let mut res = Vec::new();
+        for edge_id in edge_ids {
+            let (Some(nodes), Some(weight)) = (self.edge_nodes(edge_id), self.edge_weight(edge_id)) else {
+                continue;
+            };
+            if filter(nodes, weight) {
+                res.extend(nodes.iter().copied().filter(|neighbor| seen.insert(*neighbor)));
+            }
+        }
+
+        Some(res)
+    }
+
+    /// `type Node = i64`  
+    ///
+    /// Returns a lazy iterator over the neighbors of a specific node, walking its incident hyperedges. Every neighbor
+    /// is yielded once, in no particular order, and the iterator is empty if the node is not in the hypergraph.
+    ///
+    /// # Parameters
+    /// - `node` : `Node` - The node of interest.
+    /// - `size` : `Option<usize>` - `Some` size of the hyperedges to consider. If `None` all hyperedges are considered.
+    ///
+    /// # Returns
+    /// - `impl Iterator<Item = Node> + '_` - The iterator over the neighbors of `node`.
+    ///
+    /// # Performance
+    /// - `O(d*k)` to consume it, where `d` is the degree of the node and `k` the maximum size of its incident hyperedges.
+    fn iter_neighbors_with(&self, node: Node, size: Option<usize>) -> impl Iterator<Item = Node> + '_ {
+        // We don't consider the node itself as a neighbor
+        let mut seen = AHashSet::from([node]);
+
+        self.incident_edge_ids(node)
+            .into_iter()
+            .flatten()
+            .filter_map(|edge_id| self.edge_nodes(edge_id))
+            .filter(move |nodes| size.is_none_or(|len| nodes.len() == len))
+            .flat_map(|nodes| nodes.iter().copied())
+            .filter(move |neighbor| seen.insert(*neighbor))
+    }
+}
+
+impl HypergraphOps for Hypergraph {
+    fn nodes(&self) -> impl Iterator<Item = Node> + '_ {
+        self.iter_nodes()
+    }
+
+    fn degree(&self, node: Node) -> Option<usize> {
+        self.incidence_list.get(&node).map(|edge_ids| edge_ids.len())
+    }
+
+    fn incident_edge_ids(&self, node: Node) -> Option<impl Iterator<Item = EdgeID> + '_> {
+        self.incidence_list.get(&node).map(|edge_ids| edge_ids.iter().copied())
+    }
+
+    fn edge_nodes(&self, edge_id: EdgeID) -> Option<&[Node]> {
+        self.edge_list.get(&edge_id).map(|hyperedge| hyperedge.nodes.as_slice())
+    }
+
+    fn edge_weight(&self, edge_id: EdgeID) -> Option<f64> {
+        self.edge_list.get(&edge_id).map(|hyperedge| hyperedge.weight)
+    }
+
+    fn edge_id(&self, edge: &[Node]) -> Option<EdgeID> {
+        Hypergraph::edge_id(self, edge)
+    }
+
+    fn num_nodes(&self) -> usize {
+        self.incidence_list.len()
+    }
+
+    fn num_edges(&self) -> usize {
+        self.edge_list.len()
+    }
+}
 
 impl Debug for Hypergraph {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let _ = write!(f, "{{\n\t{:?},\n\t", self.get_nodes());
+        compute_fmt_debug(self, f, false)
+    }
+}
 
-        let _ = write!(f, "[\n\t\t");
+/// Debug view of a `Hypergraph` with sorted nodes and hyperedges, returned by `Hypergraph::debug_sorted`.
+struct SortedDebug<'a>(&'a Hypergraph);
 
-        let mut index = 0 as usize;
-        for hyperedge in self.edge_list.values() {
-            let _ = write!(f, "{:?}", hyperedge);
-            if index + 1 < self.edge_list.values().len() {
-                let _ = write!(f, ",\n\t\t");
-            } else {
-                let _ = write!(f, "\n\t]\n");
-            }
-            index += 1;
+impl Debug for SortedDebug<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        compute_fmt_debug(self.0, f, true)
+    }
+}
+
+impl Hypergraph {
+    /// Returns a view of the hypergraph whose `Debug` output lists the nodes in ascending order and the hyperedges
+    /// lexicographically, so that equal hypergraphs are always printed the same way (unlike `{:?}` on the hypergraph,
+    /// which follows the order of the underlying hashmaps).
+    ///
+    /// # Returns
+    /// - `impl Debug + '_` - The sorted view of the hypergraph.
+    ///
+    /// # Performance
+    /// - `O(n*log(n) + m*log(m)*k)` to format it, where `n` and `m` are the number of nodes and hyperedges, and `k` the
+    ///   maximum size of a hyperedge.
+    pub fn debug_sorted(&self) -> impl Debug + '_ {
+        SortedDebug(self)
+    }
+}
+
+/// Effectively formats the hypergraph for `Debug`, optionally with sorted nodes and hyperedges.
+fn compute_fmt_debug(hg: &Hypergraph, f: &mut std::fmt::Formatter<'_>, sorted: bool) -> std::fmt::Result {
+    let nodes = if sorted { hg.get_nodes_sorted() } else { hg.get_nodes() };
+    let mut hyperedges: Vec<&Hyperedge> = hg.edge_list.values().collect();
+    if sorted {
+        hyperedges.sort_by(|a, b| a.nodes.cmp(&b.nodes));
+    }
+
+    let _ = write!(f, "{{\n\t{:?},\n\t", nodes);
+
+    let _ = write!(f, "[\n\t\t");
+
+    for (index, hyperedge) in hyperedges.iter().enumerate() {
+        let _ = write!(f, "{:?}", hyperedge);
+        if index + 1 < hyperedges.len() {
+            let _ = write!(f, ",\n\t\t");
+        } else {
+            let _ = write!(f, "\n\t]\n");
         }
-        write!(f, "}}")
     }
+    write!(f, "}}")
 }
 
 impl Display for Hypergraph {
@@ -37,8 +237,368 @@ impl Clone for Hypergraph {
     fn clone(&self) -> Self {
         Self {
             weighted: self.weighted,
+            merge_policy: self.merge_policy,
             incidence_list: self.incidence_list.clone(),
             edge_list: self.edge_list.clone(),
+            node_attrs: self.node_attrs.clone(),
+            edge_attrs: self.edge_attrs.clone(),
+            edge_times: self.edge_times.clone(),
         }
     }
 }
+
+impl PartialEq for Hypergraph {
+    /// Two hypergraphs are equal if they are both weighted or both unweighted, and have the same nodes and the same
+    /// hyperedges with exactly the same weights and multiplicities. The internal layout of the hashmaps (and so the
+    /// insertion order) does not matter, while the merge policy and the attributes are not compared. See `approx_eq` to
+    /// compare weights with a tolerance.
+    fn eq(&self, other: &Self) -> bool {
+        compute_eq(self, other, |a, b| a == b)
+    }
+}
+
+// Weights are always finite (NaN is rejected or clamped on insertion), so the equality is reflexive
+impl Eq for Hypergraph {}
+
+impl Hypergraph {
+    /// Checks if two hypergraphs are equal as in `==`, but with weights compared up to an absolute tolerance.
+    ///
+    /// # Parameters
+    /// - `other` : `&Hypergraph` - The hypergraph to compare with.
+    /// - `tol` : `f64` - The maximum absolute difference between the weights of the same hyperedge.
+    ///
+    /// # Returns
+    /// - `bool` - `true` if the hypergraphs are equal up to `tol`, `false` otherwise.
+    ///
+    /// # Performance
+    /// - `O(n + m*k)`, where `n` and `m` are the number of nodes and hyperedges, and `k` the maximum size of a hyperedge.
+    pub fn approx_eq(&self, other: &Hypergraph, tol: f64) -> bool {
+        compute_eq(self, other, |a, b| (a - b).abs() <= tol)
+    }
+
+    /// Returns a fingerprint of the whole hypergraph, meant for caching and deduplication: equal hypergraphs (see `==`)
+    /// always have the same fingerprint, independently of the insertion order and of the layout of the hashmaps.
+    ///
+    /// Every hyperedge is hashed with its weight and its multiplicity, like its `EdgeID` (so with its nodes in their
+    /// order), and the hashes of the hyperedges and of the nodes are combined by wrapping sums, which do not depend on
+    /// the iteration order. The weighted flag is mixed in as well. The hashes use the fixed seeds of the `EdgeID`s, so the fingerprint is the
+    /// same across processes, but it may change with the version of the crate or of `ahash`.
+    ///
+    /// # Returns
+    /// - `u64` - The fingerprint of the hypergraph.
+    ///
+    /// # Performance
+    /// - `O(n + m*k)`, where `n` and `m` are the number of nodes and hyperedges, and `k` the maximum size of a hyperedge.
+    pub fn fingerprint(&self) -> u64 {
+        let hasher_factory = RandomState::with_seeds(SEED1, SEED2, SEED3, SEED4);
+
+        let nodes = self.incidence_list.keys().fold(0_u64, |acc, node| acc.wrapping_add(hasher_factory.hash_one(node)));
+        let edges = self.edge_list.values().fold(0_u64, |acc, hyperedge| {
+            // 0.0 and -0.0 are equal weights, so they must give the same hash
+            let weight = if hyperedge.weight == 0_f64 { 0_f64 } else { hyperedge.weight };
+            acc.wrapping_add(hasher_factory.hash_one((&hyperedge.nodes, weight.to_bits(), hyperedge.multiplicity)))
+        });
+
+        hasher_factory.hash_one((self.weighted, self.incidence_list.len(), nodes, self.edge_list.len(), edges))
+    }
+}
+
+/// Effectively compares two hypergraphs, with the weights of the same hyperedge compared by `same_weight`.
+fn compute_eq(hg: &Hypergraph, other: &Hypergraph, same_weight: impl Fn(f64, f64) -> bool) -> bool {
+    hg.weighted == other.weighted
+        && hg.incidence_list.len() == other.incidence_list.len()
+        && hg.edge_list.len() == other.edge_list.len()
+        && hg.incidence_list.keys().all(|node| other.incidence_list.contains_key(node))
+        && hg.edge_list.iter().all(|(edge_id, hyperedge)| {
+            other.edge_list.get(edge_id).is_some_and(|theirs| {
+                // EdgeIDs are hashes, so the nodes are compared as well
+                theirs.nodes == hyperedge.nodes
+                    && theirs.multiplicity == hyperedge.multiplicity
+                    && same_weight(hyperedge.weight, theirs.weight)
+            })
+        })
+}
+
+impl Default for Hypergraph {
+    /// Creates an empty, unweighted hypergraph, as `Hypergraph::new(false)`.
+    fn default() -> Self {
+        Self::new(false)
+    }
+}
+
+impl FromIterator<Vec<Node>> for Hypergraph {
+    /// Creates an unweighted hypergraph from the hyperedges yielded by the iterator.
+    fn from_iter<T: IntoIterator<Item = Vec<Node>>>(iter: T) -> Self {
+        let mut res = Self::new(false);
+        res.extend(iter);
+        res
+    }
+}
+
+impl FromIterator<(Vec<Node>, f64)> for Hypergraph {
+    /// Creates a weighted hypergraph from the `(hyperedge, weight)` pairs yielded by the iterator. The weights of
+    /// repeated hyperedges are merged as in `add_edge_weighted`.
+    fn from_iter<T: IntoIterator<Item = (Vec<Node>, f64)>>(iter: T) -> Self {
+        let mut res = Self::new(true);
+        res.extend(iter);
+        res
+    }
+}
+
+impl Extend<Vec<Node>> for Hypergraph {
+    /// Adds the hyperedges yielded by the iterator, as `add_edge`.
+    fn extend<T: IntoIterator<Item = Vec<Node>>>(&mut self, iter: T) {
+        for edge in iter {
+            self.add_edge(&edge);
+        }
+    }
+}
+
+impl Extend<(Vec<Node>, f64)> for Hypergraph {
+    /// Adds the `(hyperedge, weight)` pairs yielded by the iterator, as `add_edge_weighted`.
+    fn extend<T: IntoIterator<Item = (Vec<Node>, f64)>>(&mut self, iter: T) {
+        for (edge, weight) in iter {
+            self.add_edge_weighted(&edge, weight);
+        }
+    }
+}
+
+/// `type Node = i64`
+///
+/// Consuming iterator over the hyperedges of a `Hypergraph`, returned by `into_iter`.
+type IntoEdges = std::iter::Map<std::collections::hash_map::IntoValues<EdgeID, Hyperedge>, fn(Hyperedge) -> (Vec<Node>, f64)>;
+
+impl IntoIterator for Hypergraph {
+    type Item = (Vec<Node>, f64);
+    type IntoIter = IntoEdges;
+
+    /// Drains the hypergraph into its `(hyperedge, weight)` pairs, in no particular order. The weights are 0 if the
+    /// hypergraph is not weighted, and isolated nodes are not yielded.
+    fn into_iter(self) -> Self::IntoIter {
+        self.edge_list.into_values().map(|hyperedge| (hyperedge.nodes, hyperedge.weight))
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use crate::cc::_ccs;
+    use crate::visits::{_bfs, _dfs, edge_bfs};
+
+    /// Minimal alternative representation, used to check that the algorithms only rely on `HypergraphOps`.
+    struct EdgeVecHypergraph {
+        nodes: Vec<Node>,
+        edges: Vec<(Vec<Node>, f64)>,
+    }
+
+    impl HypergraphOps for EdgeVecHypergraph {
+        fn nodes(&self) -> impl Iterator<Item = Node> + '_ {
+            self.nodes.iter().copied()
+        }
+
+        fn degree(&self, node: Node) -> Option<usize> {
+            self.incident_edge_ids(node).map(|ids| ids.count())
+        }
+
+        fn incident_edge_ids(&self, node: Node) -> Option<impl Iterator<Item = EdgeID> + '_> {
+            if self.nodes.contains(&node) {
+                Some((0..self.edges.len() as u64).filter(move |id| self.edges[*id as usize].0.contains(&node)).map(EdgeID::from))
+            } else {
+                None
+            }
+        }
+
+        fn edge_nodes(&self, edge_id: EdgeID) -> Option<&[Node]> {
+            self.edges.get(u64::from(edge_id) as usize).map(|(nodes, _)| nodes.as_slice())
+        }
+
+        fn edge_weight(&self, edge_id: EdgeID) -> Option<f64> {
+            self.edges.get(u64::from(edge_id) as usize).map(|(_, weight)| *weight)
+        }
+
+        fn num_nodes(&self) -> usize {
+            self.nodes.len()
+        }
+
+        fn num_edges(&self) -> usize {
+            self.edges.len()
+        }
+    }
+
+    fn build_both() -> (Hypergraph, EdgeVecHypergraph) {
+        let edges = vec![vec![1, 2, 3], vec![3, 4], vec![5, 6], vec![6, 7, 8]];
+
+        let mut hg = Hypergraph::from(&edges);
+        hg.add_node(9);
+
+        let mut nodes = hg.get_nodes();
+        nodes.sort();
+        let other = EdgeVecHypergraph {
+            nodes,
+            edges: edges.into_iter().map(|edge| (edge, 0_f64)).collect(),
+        };
+
+        (hg, other)
+    }
+
+    fn sorted_components(hg: &impl HypergraphOps, order: Option<usize>, size: Option<usize>) -> Vec<Vec<Node>> {
+        let mut res: Vec<Vec<Node>> = _ccs(hg, order, size)
+            .unwrap()
+            .into_iter()
+            .map(|cc| {
+                let mut cc: Vec<Node> = cc.into_iter().collect();
+                cc.sort();
+                cc
+            })
+            .collect();
+        res.sort();
+        res
+    }
+
+    #[test]
+    fn test_ops_primitives() {
+        let (hg, other) = build_both();
+
+        assert_eq!(HypergraphOps::num_nodes(&hg), other.num_nodes());
+        assert_eq!(HypergraphOps::num_edges(&hg), other.num_edges());
+
+        for node in other.nodes() {
+            assert_eq!(hg.degree(node), other.degree(node));
+
+            let mut expected = other.neighbors(node, None, None).unwrap().unwrap();
+            let mut result = hg.neighbors(node, None, None).unwrap().unwrap();
+            expected.sort();
+            result.sort();
+            assert_eq!(result, expected);
+        }
+
+        assert_eq!(hg.degree(42), None);
+        assert_eq!(other.degree(42), None);
+        assert_eq!(hg.neighbors(1, Some(1), Some(2)).unwrap_err(), HypergraphError::BothOrderAndSize);
+    }
+
+    #[test]
+    fn test_ops_same_visits() {
+        let (hg, other) = build_both();
+
+        for node in other.nodes() {
+            assert_eq!(_bfs(&hg, node, None, None, None), _bfs(&other, node, None, None, None));
+            assert_eq!(_dfs(&hg, node, None, None, None), _dfs(&other, node, None, None, None));
+            assert_eq!(_bfs(&hg, node, Some(1), None, Some(2)), _bfs(&other, node, Some(1), None, Some(2)));
+            assert_eq!(_dfs(&hg, node, Some(1), Some(2), None), _dfs(&other, node, Some(1), Some(2), None));
+        }
+    }
+
+    #[test]
+    fn test_ops_same_components() {
+        let (hg, other) = build_both();
+
+        let expected = vec![vec![1, 2, 3, 4], vec![5, 6, 7, 8], vec![9]];
+        assert_eq!(sorted_components(&hg, None, None), expected);
+        assert_eq!(sorted_components(&other, None, None), expected);
+        assert_eq!(_ccs(&other, Some(1), Some(2)).unwrap_err(), HypergraphError::BothOrderAndSize);
+    }
+
+    #[test]
+    fn test_ops_same_edge_visits() {
+        let (hg, other) = build_both();
+        fn visit(hg: &impl HypergraphOps, start: &[Node]) -> Vec<Vec<Node>> {
+            edge_bfs(hg, start, 1, None).into_iter().map(|edge_id| hg.edge_nodes(edge_id).unwrap().to_vec()).collect()
+        }
+
+        // The ids differ, the hyperedges they identify do not
+        assert_eq!(HypergraphOps::edge_id(&other, &[3, 4]), Some(EdgeID::from(1)));
+        assert_eq!(HypergraphOps::edge_id(&other, &[4, 3]), None);
+        assert_eq!(HypergraphOps::edge_id(&other, &[]), None);
+        for start in [vec![1, 2, 3], vec![6, 7, 8]] {
+            assert_eq!(visit(&other, &start), visit(&hg, &start));
+            assert_eq!(visit(&other, &start).len(), 2);
+        }
+        assert!(edge_bfs(&other, &[9], 1, None).is_empty());
+    }
+
+    #[test]
+    fn test_edge_id_containers() {
+        use crate::{SEED1, SEED2, SEED3, SEED4};
+
+        // The edgeID of a Vec is unchanged by taking the hyperedge as a slice
+        let edge: Vec<Node> = vec![3, 1, 2];
+        let previous = ahash::RandomState::with_seeds(SEED1, SEED2, SEED3, SEED4).hash_one(&edge);
+        assert_eq!(u64::from(Hypergraph::compute_edge_id(&edge)), previous);
+        assert_eq!(u64::from(Hypergraph::compute_edge_id(&[3, 1, 2])), previous);
+        assert_eq!(u64::from(Hypergraph::compute_edge_id(&edge[..])), previous);
+        assert_ne!(u64::from(Hypergraph::compute_edge_id(&[1, 2, 3])), previous);
+    }
+
+    #[test]
+    fn test_eq() {
+        let a = Hypergraph::from_weighted(&[vec![1, 2, 3], vec![3, 4], vec![5]], &[1.0, 2.0, 3.0]);
+        let mut b = Hypergraph::new(true);
+        b.add_edge_weighted(&[5], 3.0);
+        b.add_edge_weighted(&[3, 4], 2.0);
+        b.add_edge_weighted(&[1, 2, 3], 1.0);
+        assert_eq!(a, b);
+        assert_eq!(a, a.clone());
+
+        // A single weight, hyperedge, node or the weighted flag make them differ
+        let mut c = b.clone();
+        c.set_weight(&[3, 4], 2.5).unwrap();
+        assert_ne!(a, c);
+        assert!(a.approx_eq(&c, 0.5));
+        assert!(!a.approx_eq(&c, 0.1));
+
+        let mut c = b.clone();
+        c.remove_edge(&[5]);
+        c.add_edge_weighted(&[5, 6], 3.0);
+        assert_ne!(a, c);
+        assert!(!a.approx_eq(&c, 10.0));
+
+        let mut c = b.clone();
+        c.remove_edge(&[3, 4]);
+        c.add_edge_weighted(&[4, 3], 2.0);
+        assert_ne!(a, c);
+
+        let mut c = b.clone();
+        c.add_node(9);
+        assert_ne!(a, c);
+
+        assert_ne!(a.clone().to_unweighted(), b.clone().to_unweighted().to_weighted(0.0));
+        assert_eq!(Hypergraph::from(&[vec![1, 2]]), Hypergraph::from(&[vec![1, 2]]));
+        assert_ne!(Hypergraph::new(true), Hypergraph::new(false));
+    }
+
+    #[test]
+    fn test_fingerprint() {
+        let build = |edges: &[Vec<Node>], weights: &[f64]| {
+            let mut hg = Hypergraph::from_weighted(edges, weights);
+            hg.add_node(9);
+            hg
+        };
+        let a = build(&[vec![1, 2, 3], vec![3, 4], vec![5]], &[1.0, 2.0, 0.0]);
+        let b = build(&[vec![5], vec![3, 4], vec![1, 2, 3]], &[-0.0, 2.0, 1.0]);
+        assert_eq!(a, b);
+        assert_eq!(a.fingerprint(), b.fingerprint());
+        assert_eq!(a.fingerprint(), a.clone().fingerprint());
+
+        // A single perturbation changes the fingerprint
+        let mut c = a.clone();
+        c.set_weight(&[3, 4], 2.5).unwrap();
+        assert_ne!(a.fingerprint(), c.fingerprint());
+
+        let mut c = a.clone();
+        c.add_edge_weighted(&[4, 3], 2.0);
+        assert_ne!(a.fingerprint(), c.fingerprint());
+
+        let mut c = a.clone();
+        c.add_node(10);
+        assert_ne!(a.fingerprint(), c.fingerprint());
+
+        let mut c = a.clone();
+        c.add_edge_multi(&[3, 4]);
+        assert_ne!(a, c);
+        assert_ne!(a.fingerprint(), c.fingerprint());
+
+        assert_ne!(a.fingerprint(), a.clone().to_unweighted().fingerprint());
+        assert_ne!(Hypergraph::new(true).fingerprint(), Hypergraph::new(false).fingerprint());
+    }
+}
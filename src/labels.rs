@@ -0,0 +1,335 @@
+use ahash::AHashMap;
+
+use super::{Hypergraph, Node};
+
+/// `type Node = i64`
+///
+/// A `Hypergraph` whose nodes are identified by string labels, eg the names of the authors in a co-authorship
+/// dataset.
+///
+/// Every label gets a fresh `Node` the first time it is seen, and keeps it as long as the node is in the hypergraph.
+/// The ids are allocated in increasing order from 0 and never reused, so a label which is removed and added again gets
+/// a new id. The removals are mirrored on the labels, so that the mapping only contains the nodes of the hypergraph.
+///
+/// The underlying hypergraph is available through `hypergraph`, to run any algorithm of the crate, whose results can
+/// be translated back with `label_of`.
+#[derive(Debug, Clone, Default)]
+pub struct LabeledHypergraph {
+    /// The hypergraph over the ids of the labels.
+    hg: Hypergraph,
+
+    /// Maps each label to its node.
+    nodes: AHashMap<String, Node>,
+
+    /// Maps each node to its label.
+    labels: AHashMap<Node, String>,
+
+    /// The id of the next new label.
+    next: Node,
+}
+
+impl LabeledHypergraph {
+    /// Creates a new, empty `LabeledHypergraph`.
+    ///
+    /// # Parameters
+    /// - `weighted`: `bool` - Specifies whether the hypergraph is weighted (`true`), or nor (`false`).
+    ///
+    /// # Returns
+    /// - `Self` - A new instance of `LabeledHypergraph`.
+    pub fn new(weighted: bool) -> Self {
+        Self {
+            hg: Hypergraph::new(weighted),
+            ..Default::default()
+        }
+    }
+
+    /// Returns the underlying hypergraph, whose nodes are the ids of the labels.
+    ///
+    /// # Returns
+    /// - `&Hypergraph` - The hypergraph.
+    pub fn hypergraph(&self) -> &Hypergraph {
+        &self.hg
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Returns the label of a node.
+    ///
+    /// # Parameters
+    /// - `node` : `Node` - The node.
+    ///
+    /// # Returns
+    /// - `Option<&str>` - The label of the node, `None` if the node is not in the hypergraph.
+    ///
+    /// # Performance
+    /// - `O(1)` expected.
+    pub fn label_of(&self, node: Node) -> Option<&str> {
+        self.labels.get(&node).map(|label| label.as_str())
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Returns the node of a label.
+    ///
+    /// # Parameters
+    /// - `label` : `&str` - The label.
+    ///
+    /// # Returns
+    /// - `Option<Node>` - The node of the label, `None` if the label is not in the hypergraph.
+    ///
+    /// # Performance
+    /// - `O(l)`, where `l` is the length of the label.
+    pub fn node_of(&self, label: &str) -> Option<Node> {
+        self.nodes.get(label).copied()
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Adds a node by its label, allocating a new id if the label is not in the hypergraph yet.
+    ///
+    /// # Parameters
+    /// - `label` : `&str` - The label of the node.
+    ///
+    /// # Returns
+    /// - `Node` - The node of the label.
+    ///
+    /// # Performance
+    /// - `O(l)`, where `l` is the length of the label.
+    pub fn add_node_labeled(&mut self, label: &str) -> Node {
+        if let Some(node) = self.nodes.get(label) {
+            return *node;
+        }
+
+        let node = self.next;
+        self.next += 1;
+        self.nodes.insert(label.to_string(), node);
+        self.labels.insert(node, label.to_string());
+        self.hg.add_node(node);
+        node
+    }
+
+    /// Adds a hyperedge, with weight 0, by the labels of its nodes, as `Hypergraph::add_edge`. The labels which are
+    /// not in the hypergraph yet get a new id.
+    ///
+    /// # Parameters
+    /// - `edge` : `&[&str]` - The labels of the nodes of the hyperedge.
+    ///
+    /// # Returns
+    /// - `bool` - `true` if the hyperedge was not already in, `false` otherwise.
+    ///
+    /// # Performance
+    /// - `O(k*l)`, where `k` is the size of the hyperedge and `l` the maximum length of a label.
+    pub fn add_edge_labeled(&mut self, edge: &[&str]) -> bool {
+        self.add_edge_labeled_weighted(edge, 0.0)
+    }
+
+    /// Adds a weighted hyperedge by the labels of its nodes, as `Hypergraph::add_edge_weighted`. The labels which
+    /// are not in the hypergraph yet get a new id.
+    ///
+    /// # Parameters
+    /// - `edge` : `&[&str]` - The labels of the nodes of the hyperedge.
+    /// - `weight` : `f64` - The weight of the hyperedge.
+    ///
+    /// # Returns
+    /// - `bool` - `true` if the hyperedge was not already in, `false` otherwise.
+    ///
+    /// # Performance
+    /// - `O(k*l)`, where `k` is the size of the hyperedge and `l` the maximum length of a label.
+    pub fn add_edge_labeled_weighted(&mut self, edge: &[&str], weight: f64) -> bool {
+        let nodes: Vec<Node> = edge.iter().map(|label| self.add_node_labeled(label)).collect();
+        self.hg.add_edge_weighted(&nodes, weight)
+    }
+
+    /// Returns the list of all hyperedges, with the labels of their nodes, as `Hypergraph::get_edges`.
+    ///
+    /// # Returns
+    /// - `Option<Vec<Vec<&str>>>` - `Some` list of the hyperedges if at least one of them exists in the hypergraph.
+    ///   `None` otherwise.
+    ///
+    /// # Performance
+    /// - `O(m*k)`, where `m` is the number of hyperedges and `k` the maximum size of a hyperedge.
+    pub fn get_edges_labeled(&self) -> Option<Vec<Vec<&str>>> {
+        self.hg.get_edges().map(|edges| edges.into_iter().map(|edge| self.compute_labels(edge)).collect())
+    }
+
+    /// Checks if a hyperedge is in the hypergraph, by the labels of its nodes, as `Hypergraph::check_edge`.
+    ///
+    /// # Parameters
+    /// - `edge` : `&[&str]` - The labels of the nodes of the hyperedge.
+    ///
+    /// # Returns
+    /// - `bool` - `true` if the hyperedge is in the hypergraph, `false` otherwise.
+    ///
+    /// # Performance
+    /// - `O(k*l)`, where `k` is the size of the hyperedge and `l` the maximum length of a label.
+    pub fn check_edge_labeled(&self, edge: &[&str]) -> bool {
+        self.compute_nodes(edge).is_some_and(|nodes| self.hg.check_edge(&nodes))
+    }
+
+    /// Weakly removes a hyperedge by the labels of its nodes, as `Hypergraph::remove_edge`. Its nodes, and so their
+    /// labels, are kept.
+    ///
+    /// # Parameters
+    /// - `edge` : `&[&str]` - The labels of the nodes of the hyperedge.
+    ///
+    /// # Returns
+    /// - `bool` - `true` if the hyperedge was in the hypergraph, `false` otherwise.
+    ///
+    /// # Performance
+    /// - `O(k*l)`, where `k` is the size of the hyperedge and `l` the maximum length of a label.
+    pub fn remove_edge_labeled(&mut self, edge: &[&str]) -> bool {
+        match self.compute_nodes(edge) {
+            Some(nodes) => self.hg.remove_edge(&nodes),
+            None => false,
+        }
+    }
+
+    /// Weakly removes a node by its label, as `Hypergraph::remove_node`, and forgets the label.
+    ///
+    /// # Parameters
+    /// - `label` : `&str` - The label of the node.
+    ///
+    /// # Returns
+    /// - `bool` - `true` if the label was in the hypergraph, `false` otherwise.
+    ///
+    /// # Performance
+    /// - `O(d*k)`, where `d` is the degree of the node and `k` the maximum size of its incident hyperedges.
+    pub fn remove_node_labeled(&mut self, label: &str) -> bool {
+        match self.compute_forget(label) {
+            Some(node) => self.hg.remove_node(node),
+            None => false,
+        }
+    }
+
+    /// Strongly removes a node by its label, as `Hypergraph::strong_remove_node`, and forgets the label.
+    ///
+    /// # Parameters
+    /// - `label` : `&str` - The label of the node.
+    ///
+    /// # Returns
+    /// - `bool` - `true` if the label was in the hypergraph, `false` otherwise.
+    ///
+    /// # Performance
+    /// - `O(d*k)`, where `d` is the degree of the node and `k` the maximum size of its incident hyperedges.
+    pub fn strong_remove_node_labeled(&mut self, label: &str) -> bool {
+        match self.compute_forget(label) {
+            Some(node) => self.hg.strong_remove_node(node),
+            None => false,
+        }
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Returns the mapping between the nodes and their labels, eg to store it next to an export of `hypergraph`.
+    ///
+    /// # Returns
+    /// - `Vec<(Node, &str)>` - The `(node, label)` pairs, sorted by node.
+    ///
+    /// # Performance
+    /// - `O(n*log(n))`, where `n` is the number of nodes.
+    pub fn labels(&self) -> Vec<(Node, &str)> {
+        let mut res: Vec<(Node, &str)> = self.labels.iter().map(|(node, label)| (*node, label.as_str())).collect();
+        res.sort_unstable_by_key(|(node, _)| *node);
+        res
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Effectively translates a list of nodes into their labels.
+    fn compute_labels(&self, nodes: &[Node]) -> Vec<&str> {
+        nodes.iter().map(|node| self.labels[node].as_str()).collect()
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Effectively translates a list of labels into their nodes, `None` if one of them is not in the hypergraph.
+    fn compute_nodes(&self, labels: &[&str]) -> Option<Vec<Node>> {
+        labels.iter().map(|label| self.node_of(label)).collect()
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Effectively removes a label from the mapping, returning its node.
+    fn compute_forget(&mut self, label: &str) -> Option<Node> {
+        let node = self.nodes.remove(label)?;
+        self.labels.remove(&node);
+        Some(node)
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    fn build() -> LabeledHypergraph {
+        let mut hg = LabeledHypergraph::new(true);
+        hg.add_edge_labeled_weighted(&["ada", "alan", "grace"], 1.0);
+        hg.add_edge_labeled_weighted(&["grace", "edsger"], 2.0);
+        hg.add_edge_labeled(&["ada"]);
+        hg
+    }
+
+    fn sorted(mut edges: Vec<Vec<&str>>) -> Vec<Vec<&str>> {
+        edges.sort();
+        edges
+    }
+
+    #[test]
+    fn test_labels_ids() {
+        let mut hg = build();
+
+        assert_eq!(hg.labels(), vec![(0, "ada"), (1, "alan"), (2, "grace"), (3, "edsger")]);
+        assert_eq!(hg.node_of("grace"), Some(2));
+        assert_eq!(hg.label_of(3), Some("edsger"));
+        assert_eq!(hg.node_of("barbara"), None);
+        assert_eq!(hg.label_of(9), None);
+
+        // Repeated labels keep their id
+        assert!(!hg.add_edge_labeled_weighted(&["ada", "alan", "grace"], 5.0));
+        assert_eq!(hg.add_node_labeled("alan"), 1);
+        assert_eq!(hg.add_node_labeled("barbara"), 4);
+        assert_eq!(hg.hypergraph().get_weight(&[0, 1, 2]), Some(5.0));
+        assert_eq!(hg.hypergraph().num_nodes(), 5);
+    }
+
+    #[test]
+    fn test_labels_edges() {
+        let mut hg = build();
+
+        assert_eq!(
+            sorted(hg.get_edges_labeled().unwrap()),
+            vec![vec!["ada"], vec!["ada", "alan", "grace"], vec!["grace", "edsger"]]
+        );
+        assert!(hg.check_edge_labeled(&["grace", "edsger"]));
+        assert!(!hg.check_edge_labeled(&["edsger", "grace"]));
+        assert!(!hg.check_edge_labeled(&["grace", "barbara"]));
+
+        assert!(hg.remove_edge_labeled(&["grace", "edsger"]));
+        assert!(!hg.remove_edge_labeled(&["grace", "edsger"]));
+        assert!(!hg.remove_edge_labeled(&["barbara"]));
+        // The nodes of a removed hyperedge keep their labels
+        assert_eq!(hg.node_of("edsger"), Some(3));
+        assert_eq!(LabeledHypergraph::new(false).get_edges_labeled(), None);
+    }
+
+    #[test]
+    fn test_labels_removal() {
+        let mut hg = build();
+
+        assert!(hg.remove_node_labeled("alan"));
+        assert!(!hg.remove_node_labeled("alan"));
+        assert_eq!(hg.node_of("alan"), None);
+        assert_eq!(hg.label_of(1), None);
+        assert!(hg.check_edge_labeled(&["ada", "grace"]));
+
+        assert!(hg.strong_remove_node_labeled("grace"));
+        assert_eq!(sorted(hg.get_edges_labeled().unwrap()), vec![vec!["ada"]]);
+        assert_eq!(hg.labels(), vec![(0, "ada"), (3, "edsger")]);
+        assert_eq!(hg.hypergraph().num_nodes(), 2);
+
+        // A label added again gets a new id
+        hg.add_edge_labeled(&["alan", "ada"]);
+        assert_eq!(hg.node_of("alan"), Some(4));
+        assert_eq!(hg.labels(), vec![(0, "ada"), (3, "edsger"), (4, "alan")]);
+    }
+}
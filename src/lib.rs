@@ -1,15 +1,58 @@
 mod hyperedge;
+mod attributes;
+mod labels;
+mod temporal;
+mod multiplicity;
 mod hypergraph_traits;
 pub mod visits;
-mod cc;
+pub mod cc;
+mod matrices;
+mod serialization;
+mod formats;
+mod expansions;
+mod centrality;
+mod paths;
+mod cores;
+mod statistics;
+mod communities;
+mod simplicial;
+mod acyclicity;
+mod combinatorics;
+mod contraction;
+mod directed;
+mod cuts;
+mod pruning;
+mod queries;
+mod removal;
+mod memory;
+#[cfg(feature = "rayon")]
+mod parallel;
+mod link_prediction;
+mod error;
+#[cfg(feature = "rand")]
+pub mod generators;
 
 // One of the fastest and secure non cryptographic hash for rust
 use ahash::{AHashMap, AHashSet, RandomState};
 
-use std::hash::{BuildHasher, Hash, Hasher};
-
 use hyperedge::Hyperedge;
 
+pub use attributes::AttrValue;
+pub use error::HypergraphError;
+pub use hyperedge::{EdgeID, MergePolicy};
+pub use hypergraph_traits::HypergraphOps;
+pub use serialization::HypergraphData;
+pub use directed::DirectedHypergraph;
+pub use formats::DotStyle;
+pub use labels::LabeledHypergraph;
+pub use expansions::StarVertex;
+pub use link_prediction::LinkPred;
+pub use memory::MemoryReport;
+pub use removal::{NodeRemovalOptions, NodeRemovalReport, PruneReport};
+pub use simplicial::SubEdges;
+#[cfg(feature = "csv")]
+pub use formats::CsvOptions;
+
 // Seeds for computing the hash value for a hyperedge, ie its EdgeID.
 const SEED1: u64 = 0x243F6A8885A308D3;
 const SEED2: u64 = 0x13198A2E03707344;
@@ -18,9 +61,8 @@ const SEED4: u64 = 0x082EFA98EC4E6C89;
 
 // Defined data type
 type Node = i64;
-type EdgeID = u64;
 
-type IterEdges<'a> = std::collections::hash_map::Values<'a, u64, Hyperedge>;
+type IterEdges<'a> = std::collections::hash_map::Values<'a, EdgeID, Hyperedge>;
 
 /// Core struct to represent a hypergraph.   
 /// Hypergraphs are a generalization of graphs, where each edge can connect multiple nodes
@@ -43,11 +85,16 @@ type IterEdges<'a> = std::collections::hash_map::Values<'a, u64, Hyperedge>;
 /// # User Interaction
 /// The user communicates via hyperedges, not `EdgeID`'s, meaning that he will provide a concrete set of nodes whenever he  
 /// calls a method which requires a hyperedge. Internally, the hypergraph computes the `EdgeID` for the hyperedge provided,  
-/// and operates on that ID.
+/// and operates on that ID.  
+/// The `EdgeID`s are also exposed as handles (see `edge_id`, `get_edge_by_id`, `remove_edge_by_id`), which avoid
+/// hashing the nodes again on every lookup.
 pub struct Hypergraph {
     /// States if the hypergraphs is weighted.
     weighted: bool,
 
+    /// Policy used to merge the weights when a hyperedge already in the hypergraph is added again.
+    merge_policy: MergePolicy,
+
     /// Maps each node to a set of `EdgeID`s of the hyperedges it connects to.
     /// This efficient storage mechanism reduces memory usage by avoiding the need
     /// to store full sets of edges for each node, enabling faster operations.
@@ -57,6 +104,16 @@ pub struct Hypergraph {
     /// By storing hyperedges indexed by their unique IDs, this design allows for
     /// rapid access to hyperedge data without redundant storage, with a concrete `O(1)` hash.
     edge_list: AHashMap<EdgeID, Hyperedge>,
+
+    /// Maps each node to its attributes, by name. Nodes without attributes have no entry.
+    node_attrs: AHashMap<Node, AHashMap<String, AttrValue>>,
+
+    /// Maps the `EdgeID` of each hyperedge to its attributes, by name. Hyperedges without attributes have no entry.
+    edge_attrs: AHashMap<EdgeID, AHashMap<String, AttrValue>>,
+
+    /// Maps the `EdgeID` of each hyperedge to the sorted times at which it is active. Hyperedges added without a time
+    /// have no entry.
+    edge_times: AHashMap<EdgeID, Vec<i64>>,
 }
 
 impl Hypergraph {
@@ -75,13 +132,49 @@ impl Hypergraph {
     /// # Returns
     /// - `Self` - A new instance of `Hypergraph`.
     pub fn new(weighted: bool) -> Self {
+        Self::with_merge_policy(weighted, MergePolicy::Replace)
+    }
+
+    /// Creates a new, empty `Hypergraph` with the given default merge policy.  
+    ///
+    /// The merge policy decides the weight of a hyperedge which is added when it is already in the hypergraph (see
+    /// `MergePolicy`). Hypergraphs created by `new` replace the weight.
+    ///
+    /// # Parameters
+    /// - `weighted`: `bool` - Specifies whether the hypergraph is weighted (`true`), or nor (`false`).
+    /// - `merge_policy`: `MergePolicy` - The default merge policy.
+    ///
+    /// # Returns
+    /// - `Self` - A new instance of `Hypergraph`.
+    pub fn with_merge_policy(weighted: bool, merge_policy: MergePolicy) -> Self {
         Self {
             weighted,
+            merge_policy,
             incidence_list: AHashMap::new(),
             edge_list: AHashMap::new(),
+            node_attrs: AHashMap::new(),
+            edge_attrs: AHashMap::new(),
+            edge_times: AHashMap::new(),
         }
     }
 
+    /// Creates a new, empty `Hypergraph` with room for at least `nodes` nodes and `edges` hyperedges, so that bulk
+    /// loading does not rehash the underlying hashmaps.  
+    ///
+    /// # Parameters
+    /// - `weighted`: `bool` - Specifies whether the hypergraph is weighted (`true`), or nor (`false`).
+    /// - `nodes`: `usize` - The number of nodes to make room for.
+    /// - `edges`: `usize` - The number of hyperedges to make room for.
+    ///
+    /// # Returns
+    /// - `Self` - A new instance of `Hypergraph`.
+    pub fn with_capacity(weighted: bool, nodes: usize, edges: usize) -> Self {
+        let mut res = Self::new(weighted);
+        res.reserve_nodes(nodes);
+        res.reserve_edges(edges);
+        res
+    }
+
     /// `type Node = i64`
     ///
     /// Creates an unweighted `Hypergraph` from a list of hyperedges.  
@@ -94,7 +187,7 @@ impl Hypergraph {
     /// # Returns
     /// - `Self` - A new instance of `Hypergraph`.
     pub fn from(_edge_list: &[Vec<Node>]) -> Self {
-        let mut result = Self::new(false);
+        let mut result = Self::with_capacity(false, 0, _edge_list.len());
 
         for edge in _edge_list.iter() {
             let edge_id = Self::compute_edge_id(edge);
@@ -108,15 +201,20 @@ impl Hypergraph {
 
     /// `type Node = i64`
     ///
-    /// Creates a weighted `Hypergraph` from a list of hyperedges.
+    /// Creates a weighted `Hypergraph` from a list of hyperedges, where the i-th hyperedge receives the i-th weight.
     ///
     /// Let `n`, `m` be the length of `_edge_list` and `weights` respectively. Consider this three cases:   
     /// - `n` > `m`: then the first m hyperedges will receive its corresponding weight, and the last `n-m` will be   
-    /// initialized with 0 as their weight;
+    ///   initialized with 0 as their weight;
     /// - `n` = `m`: then every hyperedge will receive its corresponding weight;
     /// - `n` < `m`: same as above; there will simply be some weights which will not be assigned.  
     ///
-    /// If `_edge_list` contains duplicates, the considered hyperedge, with its weight, will be the first encountered in the list.
+    /// Use `try_from_weighted` to reject lengths which differ, instead. NaN and infinite weights are clamped, as in
+    /// `add_edge_weighted`.
+    ///
+    /// If `_edge_list` contains duplicates, the hyperedge is added once, and its weight is the last one encountered in
+    /// the list (see `from_weighted_with_policy` to merge the weights differently).
+    ///
     /// # Parameters
     /// - `_edge_list`: `&[Vec<i64>]` - List of hyperedges.
     /// - `weights`: `&[f64]` - Weights for the hyperedges.
@@ -124,21 +222,101 @@ impl Hypergraph {
     /// # Returns
     /// - `Self` - A new instance of `Hypergraph`.
     pub fn from_weighted(_edge_list: &[Vec<Node>], weights: &[f64]) -> Self {
-        let mut result = Self::new(true);
+        Self::from_weighted_with_policy(_edge_list, weights, MergePolicy::Replace)
+    }
 
-        let mut index_weigth = 0 as usize;
-        let mut next;
+    /// `type Node = i64`
+    ///
+    /// Creates a weighted `Hypergraph` from a list of hyperedges, where the i-th hyperedge receives the i-th weight.
+    /// Unlike `from_weighted`, the lengths of `_edge_list` and `weights` must be equal.
+    ///
+    /// If `_edge_list` contains duplicates, the hyperedge is added once, and its weight is the last one encountered in
+    /// the list.
+    ///
+    /// # Parameters
+    /// - `_edge_list`: `&[Vec<i64>]` - List of hyperedges.
+    /// - `weights`: `&[f64]` - Weights for the hyperedges.
+    ///
+    /// # Returns
+    /// - `Result<Self, HypergraphError>` - A new instance of `Hypergraph`, `WeightCountMismatch` if the lengths differ,
+    ///   or `NonFiniteWeight` if a weight is NaN or infinite.
+    pub fn try_from_weighted(_edge_list: &[Vec<Node>], weights: &[f64]) -> Result<Self, HypergraphError> {
+        if _edge_list.len() != weights.len() {
+            return Err(HypergraphError::WeightCountMismatch(_edge_list.len(), weights.len()));
+        }
+        if weights.iter().any(|weight| !weight.is_finite()) {
+            return Err(HypergraphError::NonFiniteWeight);
+        }
+        Ok(Self::from_weighted(_edge_list, weights))
+    }
 
-        for edge in _edge_list.iter() {
-            // This index increases indipendently
-            if index_weigth + 1 < weights.len() {
-                next = weights[index_weigth];
-                index_weigth += 1;
-            } else {
-                next = 0_f64;
+    /// `type Node = i64`
+    ///
+    /// Creates a weighted `Hypergraph` from a list of hyperedges, merging the weights of repeated hyperedges with
+    /// `merge_policy`, which becomes the default merge policy of the hypergraph.
+    ///
+    /// The i-th hyperedge receives the i-th weight; if `weights` is shorter than `_edge_list`, the remaining hyperedges
+    /// receive 0, and the weights in excess are ignored.
+    ///
+    /// # Parameters
+    /// - `_edge_list`: `&[Vec<i64>]` - List of hyperedges.
+    /// - `weights`: `&[f64]` - Weights for the hyperedges.
+    /// - `merge_policy`: `MergePolicy` - The policy for the weights of repeated hyperedges.
+    ///
+    /// # Returns
+    /// - `Self` - A new instance of `Hypergraph`.
+    pub fn from_weighted_with_policy(_edge_list: &[Vec<Node>], weights: &[f64], merge_policy: MergePolicy) -> Self {
+        let mut result = Self::with_merge_policy(true, merge_policy);
+        result.reserve_edges(_edge_list.len());
+        result.add_edges_weighted(_edge_list, weights);
+        result
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Creates an unweighted `Hypergraph` from a bipartite membership list, the inverse of the star expansion.
+    ///
+    /// Every pair `(node, group)` states that `node` belongs to `group`, and every group becomes a hyperedge containing
+    /// all its nodes, in the order they appear in `memberships`. Groups with a single node become hyperedges of size 1,
+    /// repeated pairs are counted once, and groups with the same nodes in the same order produce a single hyperedge.
+    ///
+    /// # Parameters
+    /// - `memberships`: `&[(Node, i64)]` - List of `(node, group)` pairs.
+    ///
+    /// # Returns
+    /// - `Self` - A new instance of `Hypergraph`.
+    pub fn from_bipartite(memberships: &[(Node, i64)]) -> Self {
+        let mut result = Self::new(false);
+
+        for (_, edge) in Self::compute_groups(memberships) {
+            if !result.check_edge(&edge) {
+                Self::compute_add_edge(&mut result, &edge, 0_f64);
             }
+        }
+        result
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Creates a weighted `Hypergraph` from a bipartite membership list, as `from_bipartite`, giving each hyperedge the
+    /// weight of its group in `weights`, or 0 if the group has no weight.
+    ///
+    /// If several groups produce the same hyperedge, the considered weight is the one of the first group encountered.
+    ///
+    /// # Parameters
+    /// - `memberships`: `&[(Node, i64)]` - List of `(node, group)` pairs.
+    /// - `weights`: `&AHashMap<i64, f64>` - Weight of each group.
+    ///
+    /// # Returns
+    /// - `Self` - A new instance of `Hypergraph`.
+    pub fn from_bipartite_weighted(memberships: &[(Node, i64)], weights: &AHashMap<i64, f64>) -> Self {
+        let mut result = Self::new(true);
 
-            Self::compute_add_edge(&mut result, edge, next);
+        for (group, edge) in Self::compute_groups(memberships) {
+            if !result.check_edge(&edge) {
+                let weight = weights.get(&group).copied().unwrap_or(0_f64);
+                Self::compute_add_edge(&mut result, &edge, weight);
+            }
         }
         result
     }
@@ -173,19 +351,20 @@ impl Hypergraph {
     /// - `order` : `Option<usize>` - Order of interest, optional.
     /// - `size` : `Option<usize>` - Size of interest, optional.
     /// - `up_to` : `bool` - If `true`, then the hyperedges considered are the ones which respect the `≤` relation, with respect   
-    /// to their order/size. Otherwise the choice is based on the `=` relation.
+    ///   to their order/size. Otherwise the choice is based on the `=` relation.
     ///
     /// # Returns
-    /// - `Result<usize, &str>` - `Ok` containing the number of selected hyperedges, if one, and only one, between `order`   
-    /// and `size` is provided. `Err` containing an error message otherwise. 
+    /// - `Result<usize, HypergraphError>` - `Ok` containing the number of selected hyperedges, if one, and only one, between `order`   
+    ///   and `size` is provided. `Err` containing `HypergraphError::BothOrderAndSize` or
+    ///   `HypergraphError::NeitherOrderNorSize` otherwise.
     ///
     /// # Performance
     /// - `O(m)`, where `m` denotes the number of hyperedges of the hypergraph.
-    pub fn num_edges_with(&self, order: Option<usize>, size: Option<usize>, up_to: bool) -> Result<usize, &str> {
-        if order != None && size != None {
-            Err("Order and size cannot be both specified") 
-        } else if order == None && size == None {
-            Err("At least one between orders and sizes should be specified")
+    pub fn num_edges_with(&self, order: Option<usize>, size: Option<usize>, up_to: bool) -> Result<usize, HypergraphError> {
+        if order.is_some() && size.is_some() {
+            Err(HypergraphError::BothOrderAndSize) 
+        } else if order.is_none() && size.is_none() {
+            Err(HypergraphError::NeitherOrderNorSize)
         } else {
             let mut res = 0;
 
@@ -215,21 +394,25 @@ impl Hypergraph {
 
     /// Returns the weight of a specific hyperedge.
     ///
+    /// Hyperedges of an unweighted hypergraph have no weight, so that a missing weight cannot be mistaken for a real
+    /// weight `0`.
+    ///
     /// # Parameters
-    /// - 'edge' : `&Vec<Node>` - The Hyperedge.
+    /// - `edge` : `&[Node]` - The Hyperedge.
     ///
     /// # Returns
-    /// - `Option<f64>` - `Some` weight of the hyperedge. Returns `None` if the hyperedge is not in the hypergraph.
+    /// - `Option<f64>` - `Some` weight of the hyperedge. Returns `None` if the hyperedge is not in the hypergraph, or if
+    ///   the hypergraph is not weighted.
     ///
     /// # Performance
     /// - `O(1)`
-    pub fn get_weight(&self, edge: &Vec<Node>) -> Option<f64> {
+    pub fn get_weight(&self, edge: &[Node]) -> Option<f64> {
+        if !self.weighted {
+            return None;
+        }
         let edge_id = Self::compute_edge_id(edge);
 
-        match self.edge_list.get(&edge_id) {
-            Some(edge) => Some((*edge).weight),
-            _ => None,
-        }
+        self.edge_list.get(&edge_id).map(|edge| edge.weight)
     }
 
     /// `type Node = i64`
@@ -237,16 +420,24 @@ impl Hypergraph {
     /// Sets the weight of a specific hyperedge.
     ///
     /// # Parameters
-    /// - `edge` : `&Vec<Node>` - Hyperedge for which the weight has to be modified.
+    /// - `edge` : `&[Node]` - Hyperedge for which the weight has to be modified.
     /// - `new_weight` : `f64` - The new weight for the hyperedge.
     ///
     /// # Returns
-    /// - `Result<f64, ()>` : `Ok` containing the previous weight of the provided hyperedge, if it exists in the hypergraphs.   
-    /// Returns `Err` containing `()` if the specified hyperedge is not in the hypergraph.
+    /// - `Result<f64, HypergraphError>` : `Ok` containing the previous weight of the provided hyperedge, if it exists in the hypergraphs.   
+    ///   Returns `Err(HypergraphError::NotWeighted)` if the hypergraph is not weighted, `Err(HypergraphError::EdgeNotFound)`
+    ///   if the specified hyperedge is not in the hypergraph, `Err(HypergraphError::NonFiniteWeight)` if `new_weight` is
+    ///   NaN or infinite.
     ///
     /// # Performance
     /// - `O(1)`
-    pub fn set_weight(&mut self, edge: &Vec<Node>, new_weight: f64) -> Result<f64, ()> {
+    pub fn set_weight(&mut self, edge: &[Node], new_weight: f64) -> Result<f64, HypergraphError> {
+        if !self.weighted {
+            return Err(HypergraphError::NotWeighted);
+        }
+        if !new_weight.is_finite() {
+            return Err(HypergraphError::NonFiniteWeight);
+        }
         let edge_id = Self::compute_edge_id(edge);
 
         match self.edge_list.get_mut(&edge_id) {
@@ -255,7 +446,33 @@ impl Hypergraph {
                 edge.set_weight(new_weight);
                 Ok(prev)
             }
-            _ => Err(()),
+            _ => Err(HypergraphError::EdgeNotFound),
+        }
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Checks that every weight of the hypergraph is finite, ie neither NaN nor infinite.
+    ///
+    /// # Returns
+    /// - `Result<(), Vec<(&Vec<Node>, f64)>>` - `Ok(())` if every weight is finite, otherwise `Err` with the hyperedges
+    ///   with a non-finite weight, sorted, each with its weight.
+    ///
+    /// # Performance
+    /// - `O(m*log(m))`, where `m` is the number of hyperedges.
+    pub fn validate_weights(&self) -> Result<(), Vec<(&Vec<Node>, f64)>> {
+        let mut invalid: Vec<(&Vec<Node>, f64)> = self
+            .edge_list
+            .values()
+            .filter(|hyperedge| !hyperedge.weight.is_finite())
+            .map(|hyperedge| (&hyperedge.nodes, hyperedge.weight))
+            .collect();
+
+        if invalid.is_empty() {
+            Ok(())
+        } else {
+            invalid.sort_by(|a, b| a.0.cmp(b.0));
+            Err(invalid)
         }
     }
 
@@ -264,12 +481,13 @@ impl Hypergraph {
     /// The returned list may contain duplicates of the weights.
     ///
     /// # Returns
-    /// - `Option<Vec<f64>>` - `Some` list with the weights if there are hyperedges. Returns `None` if the hypergraph has no hyperedges.
+    /// - `Option<Vec<f64>>` - `Some` list with the weights if there are hyperedges. Returns `None` if the hypergraph has no hyperedges,
+    ///   or if it is not weighted.
     ///
     /// # Performance
     /// - `O(m)`, where `m` is the number of hyperedges of the hypergraph.
     pub fn get_weights(&self) -> Option<Vec<f64>> {
-        if self.edge_list.is_empty() {
+        if self.edge_list.is_empty() || !self.weighted {
             None
         } else {
             let mut res = Vec::new();
@@ -291,20 +509,23 @@ impl Hypergraph {
     /// - `order` : `Option<usize>` - The order of interest (optional).
     /// - `size` : `Option<usize>` - The size of interest (optional).
     /// - `up_to` : `bool` - If `true`, it specifies to consider hyperedges with order/size less than or equal to the provided   
-    /// order\size. If `false` the method considers only hyperedges with an equal order/size to the order/size provided.
+    ///   order\size. If `false` the method considers only hyperedges with an equal order/size to the order/size provided.
     ///
     /// # Returns
-    /// - `Result<Option<Vec<f64>>, &str>` - `Ok` containing `Some` list with the weights of the selected hyperedges, or    
-    /// containing `None` if no such hyperedges exist, if one, and only one, between `order` and `size` is provided.   
-    /// Returns `Err` containing an error message otherwise.
+    /// - `Result<Option<Vec<f64>>, HypergraphError>` - `Ok` containing `Some` list with the weights of the selected hyperedges, or    
+    ///   containing `None` if no such hyperedges exist or the hypergraph is not weighted, if one, and only one, between   
+    ///   `order` and `size` is provided. Returns `Err` containing `HypergraphError::BothOrderAndSize` or   
+    ///   `HypergraphError::NeitherOrderNorSize` otherwise.
     ///
     /// # Performance
     /// - `O(m)`, where `m` is the number of hyperedges of the hypergraph.
-    pub fn get_weights_with(&self, order: Option<usize>, size: Option<usize>, up_to: bool) -> Result<Option<Vec<f64>>, &str> {
-        if order != None && size != None {
-            Err("Order and size cannot be both specified")
-        } else if order == None && size == None {
-            Err("Order and size cannot be both None")
+    pub fn get_weights_with(&self, order: Option<usize>, size: Option<usize>, up_to: bool) -> Result<Option<Vec<f64>>, HypergraphError> {
+        if order.is_some() && size.is_some() {
+            Err(HypergraphError::BothOrderAndSize)
+        } else if order.is_none() && size.is_none() {
+            Err(HypergraphError::NeitherOrderNorSize)
+        } else if !self.weighted {
+            Ok(None)
         } else {
             let mut res = Vec::new();
 
@@ -343,7 +564,7 @@ impl Hypergraph {
     ///
     /// # Returns
     /// - `Option<Vec<i64>>` - `Some` list with the orders of all hyperedges if there are hyperedges; `None` if  
-    /// the hypergraph is empty.
+    ///   the hypergraph is empty.
     ///
     /// # Performance
     /// - `O(m)`, where `m` denotes the number of hyperedges.
@@ -366,7 +587,7 @@ impl Hypergraph {
     ///
     /// # Returns
     /// - `Option<Vec<usize>>` - `Some` list with the orders of all hyperedges if there are hyperedges; `None` if  
-    /// the hypergraph is empty.
+    ///   the hypergraph is empty.
     ///
     /// # Performance
     /// - `O(m)`, where `m` denotes the number of hyperedges.
@@ -421,12 +642,48 @@ impl Hypergraph {
     /// # Performance
     /// - `O(n)`, where `n` is the number of nodes of the hypergraph.
     pub fn get_nodes(&self) -> Vec<Node> {
-        let mut res = Vec::new();
-        self.incidence_list.keys().for_each(|node_id| {
-            res.push(*node_id);
-        });
+        self.iter_nodes().collect()
+    }
 
-        res 
+    /// `type Node = i64`  
+    ///
+    /// Returns a list with all the nodes of the hypergraph, in ascending order, so that the output does not depend on
+    /// the order of the underlying hashmaps.
+    ///
+    /// # Returns
+    /// - `Vec<Node>` - The sorted list of the nodes of the hyperegraph.
+    ///
+    /// # Performance
+    /// - `O(n*log(n))`, where `n` is the number of nodes of the hypergraph.
+    pub fn get_nodes_sorted(&self) -> Vec<Node> {
+        let mut res = self.get_nodes();
+        res.sort_unstable();
+        res
+    }
+
+    /// `type Node = i64`  
+    ///
+    /// Returns an iterator over the nodes of the hypergraph, without allocating a list as `get_nodes` does.
+    ///
+    /// # Examples
+    /// ```
+    /// use hgraph::Hypergraph;
+    ///
+    /// let hg = Hypergraph::from(&[vec![1, 2, 3], vec![3, 4]]);
+    /// let mut sum = 0;
+    /// for node in hg.iter_nodes() {
+    ///     sum += node;
+    /// }
+    /// assert_eq!(sum, 10);
+    /// ```
+    ///
+    /// # Returns
+    /// - `impl Iterator<Item = Node> + '_` - The iterator over the nodes, in no particular order.
+    ///
+    /// # Performance
+    /// - `O(1)` to create the iterator, `O(n)` to consume it, where `n` is the number of nodes of the hypergraph.
+    pub fn iter_nodes(&self) -> impl Iterator<Item = Node> + '_ {
+        self.incidence_list.keys().copied()
     }
 
     /// `type Node = i64`  
@@ -435,7 +692,7 @@ impl Hypergraph {
     /// 
     /// # Returns 
     /// - `Option<Vec<&Vec<Node>>>` - `Some` list of references to all the hyperedges if at least one of them exists in   
-    /// the hypergraph. `None` otherwise. 
+    ///   the hypergraph. `None` otherwise. 
     /// 
     /// # Performance
     /// - `O(m)`
@@ -452,6 +709,56 @@ impl Hypergraph {
         }
     }
 
+    /// `type Node = i64`  
+    /// 
+    /// Returns the list of all hyperedges in the hypergraph, each with its nodes in ascending order, sorted
+    /// lexicographically, so that the output does not depend on the order of the underlying hashmaps.   
+    /// 
+    /// Since the nodes are sorted, hyperedges with the same nodes in a different order (eg `[1, 2]` and `[2, 1]`) appear
+    /// as repeated entries.
+    /// 
+    /// # Returns 
+    /// - `Option<Vec<Vec<Node>>>` - `Some` sorted list of the sorted hyperedges if at least one of them exists in the
+    ///   hypergraph. `None` otherwise. 
+    /// 
+    /// # Performance
+    /// - `O(m*log(m)*k)`, where `m` is the number of hyperedges and `k` the maximum size of a hyperedge.
+    pub fn get_edges_sorted(&self) -> Option<Vec<Vec<Node>>> {
+        self.get_edges().map(|edges| {
+            let mut res: Vec<Vec<Node>> = edges
+                .into_iter()
+                .map(|edge| {
+                    let mut edge = edge.clone();
+                    edge.sort_unstable();
+                    edge
+                })
+                .collect();
+            res.sort_unstable();
+            res
+        })
+    }
+
+    /// `type Node = i64`  
+    /// 
+    /// Returns the list of all hyperedges in the hypergraph, each with its weight, read directly from the hypergraph
+    /// instead of hashing the hyperedge again as `get_weight` does.   
+    /// 
+    /// Unlike `get_weight`, which returns `None` on an unweighted hypergraph, the weights are then `0`.
+    /// 
+    /// # Returns 
+    /// - `Option<Vec<(&Vec<Node>, f64)>>` - `Some` list of the hyperedges with their weights (0 if the hypergraph is not
+    ///   weighted) if at least one of them exists in the hypergraph. `None` otherwise. 
+    /// 
+    /// # Performance
+    /// - `O(m)`
+    pub fn get_edges_weighted(&self) -> Option<Vec<(&Vec<Node>, f64)>> {
+        if self.edge_list.is_empty() {
+            None
+        } else {
+            Some(self.edge_list.values().map(|hyperedge| (&hyperedge.nodes, hyperedge.weight)).collect())
+        }
+    }
+
     /// Returns references of the selected hyperedges.
     /// 
     /// The convention is `order == size - 1`  
@@ -460,20 +767,20 @@ impl Hypergraph {
     /// - `order` : `Option<usize>` - The order of interest (optional).
     /// - `size` : `Option<usize>` - The size of interest (optional).
     /// - `up_to` : `bool` - If `true`, it specifies to consider hyperedges with order/size less than or equal to the provided   
-    /// order\size. If `false` the method considers only hyperedges with an equal order/size to the order/size provided.
+    ///   order\size. If `false` the method considers only hyperedges with an equal order/size to the order/size provided.
     ///
     /// # Returns
-    /// - `Result<Option<Vec<&Vec<Node>>>, &str>` - `Ok` containing `Some` list with the references of the selected hyperedges, or    
-    /// containing `None` if no such hyperedges exist, if one, and only one, between `order` and `size` is provided.   
-    /// Returns `Err` containing an error message otherwise.
+    /// - `Result<Option<Vec<&Vec<Node>>>, HypergraphError>` - `Ok` containing `Some` list with the references of the selected hyperedges, or    
+    ///   containing `None` if no such hyperedges exist, if one, and only one, between `order` and `size` is provided.   
+    ///   Returns `Err` containing `HypergraphError::BothOrderAndSize` or `HypergraphError::NeitherOrderNorSize` otherwise.
     ///
     /// # Performance
     /// - `O(m)`, where `m` is the number of hyperedges of the hypergraph.
-    pub fn get_edges_with(&self, order: Option<usize>, size: Option<usize>, up_to: bool) -> Result<Option<Vec<&Vec<Node>>>, &str> {
-        if order != None && size != None {
-            Err("Order and size cannot be both specified")
-        } else if order == None && size == None {
-            Err("Order and size cannot be both None")
+    pub fn get_edges_with(&self, order: Option<usize>, size: Option<usize>, up_to: bool) -> Result<Option<Vec<&Vec<Node>>>, HypergraphError> {
+        if order.is_some() && size.is_some() {
+            Err(HypergraphError::BothOrderAndSize)
+        } else if order.is_none() && size.is_none() {
+            Err(HypergraphError::NeitherOrderNorSize)
         } else {
             let mut res = Vec::new();
 
@@ -511,56 +818,67 @@ impl Hypergraph {
     /// - `size` : `Option<usize>` - The size of the hyperedges to consider. 
     ///
     /// # Returns
-    /// - `Result<Option<Vec<Node>>, &str>` - `Ok` containing `Some` list of neighbors of `node`, or containing `None` if   
-    /// the node provided is not in the hypergraph. Returns `Err` containing an error message if both `order` and `size`    
-    /// are provided.
+    /// - `Result<Option<Vec<Node>>, HypergraphError>` - `Ok` containing `Some` list of neighbors of `node`, or containing `None` if   
+    ///   the node provided is not in the hypergraph. Returns `Err(HypergraphError::BothOrderAndSize)` if both `order` and `size`    
+    ///   are provided.
     ///
     /// # Performance  
     /// - `O(n*m)`, where `n` and `m` are the number of nodes and hyperedges, respectively, of the hypergraph.
-    pub fn get_neighbors(&self, node: Node, order: Option<usize>, size: Option<usize>) -> Result<Option<Vec<Node>>, &str> {
-        // Both order and size are specified
-        if order != None && size != None {
-            Err("Order and size cannot be both specified")
-        } else {
-            match self.incidence_list.get(&node) {
-                    Some(incidence_list) => {
-                        let mut res = AHashSet::new();
-
-                        // Both order and size are not specified
-                        if order == None && size == None {
-                            for edge_id in incidence_list.iter() {
-                                let edge_now = &self.edge_list.get(edge_id).unwrap().nodes;
-                                edge_now.iter().for_each(|v| {
-                                    res.insert(*v);
-                                });
-                            }
-                        } else {
-                            let filter = if let Some(val) = order {
-                                // Only Order is specified
-                                val + 1
-                            } else {
-                                // Only Size is specified
-                                size.unwrap()
-                            };
-
-                            for edge_id in incidence_list.iter() {
-                                let edge_now = &self.edge_list.get(edge_id).unwrap().nodes;
-                                if edge_now.len() == filter {
-                                    edge_now.iter().for_each(|v| {
-                                        res.insert(*v);
-                                    });
-                                }
-                            }
-                        }
-                        // We don't consider the node itself as a neighbor
-                        res.remove(&node);
-
-                        //O(n), but is necessary to not return a AHashSet
-                        Ok(Some(res.into_iter().collect::<Vec<Node>>()))
-                    },
-                 _ => Ok(None),
-            }
-        }
+    pub fn get_neighbors(&self, node: Node, order: Option<usize>, size: Option<usize>) -> Result<Option<Vec<Node>>, HypergraphError> {
+        HypergraphOps::neighbors(self, node, order, size)
+    }
+
+    /// `type Node = i64`  
+    ///
+    /// Gives the neighbors of a specific node, through the hyperedges accepted by `filter` only, eg
+    /// `|nodes, weight| nodes.len() == 3 && weight > 1.0`. `get_neighbors` is the same with a filter on the size.
+    ///
+    /// # Parameters
+    /// - `node` : `Node` - The node of interest.
+    /// - `filter` : `impl Fn(&[Node], f64) -> bool` - Given the nodes and the weight of a hyperedge, whether to consider
+    ///   it.
+    ///
+    /// # Returns
+    /// - `Option<Vec<Node>>` - `Some` list of neighbors of `node`, or `None` if the node is not in the hypergraph.
+    ///
+    /// # Performance
+    /// - `O(d*k)`, where `d` is the degree of the node and `k` the maximum size of its incident hyperedges.
+    pub fn get_neighbors_filtered(&self, node: Node, filter: impl Fn(&[Node], f64) -> bool) -> Option<Vec<Node>> {
+        HypergraphOps::neighbors_filtered(self, node, filter)
+    }
+
+    /// `type Node = i64`  
+    ///
+    /// Returns a lazy iterator over the neighbors of a specific node, ie the nodes sharing at least one hyperedge with
+    /// it. Unlike `get_neighbors`, no list is allocated: every neighbor is yielded once, deduplicated on the fly.
+    ///
+    /// # Parameters
+    /// - `node` : `Node` - The node of interest.
+    ///
+    /// # Returns
+    /// - `impl Iterator<Item = Node> + '_` - The iterator over the neighbors, in no particular order. It is empty if the
+    ///   node is not in the hypergraph.
+    ///
+    /// # Performance
+    /// - `O(d*k)` to consume it, where `d` is the degree of the node and `k` the maximum size of its incident hyperedges.
+    pub fn iter_neighbors(&self, node: Node) -> impl Iterator<Item = Node> + '_ {
+        self.iter_neighbors_with(node, None)
+    }
+
+    /// `type Node = i64`  
+    ///
+    /// Returns the number of neighbors of a specific node, without allocating them.
+    ///
+    /// # Parameters
+    /// - `node` : `Node` - The node of interest.
+    ///
+    /// # Returns
+    /// - `Option<usize>` - `Some` number of neighbors of `node`, `None` if the node is not in the hypergraph.
+    ///
+    /// # Performance
+    /// - `O(d*k)`, where `d` is the degree of the node and `k` the maximum size of its incident hyperedges.
+    pub fn num_neighbors(&self, node: Node) -> Option<usize> {
+        self.incidence_list.contains_key(&node).then(|| self.iter_neighbors(node).count())
     }
 
     /// `type Node = i64`  
@@ -575,51 +893,43 @@ impl Hypergraph {
     /// - `size` : `Option<usize>` - The size of the hyperedges to consider. 
     ///
     /// # Returns
-    /// - `Result<Option<Vec<&Vec<Node>>>, &str>` : `Ok` containing `Some` immutable references to the hyperedges which are   
-    /// incident to the given `node`, or containing `None` if the node does not exists in the hypergraph. Returns `Err` containing  
-    /// an error message if both `order` and `size` are provided. 
+    /// - `Result<Option<Vec<&Vec<Node>>>, HypergraphError>` : `Ok` containing `Some` immutable references to the hyperedges which are   
+    ///   incident to the given `node`, or containing `None` if the node does not exists in the hypergraph. Returns `Err` containing  
+    ///   `HypergraphError::BothOrderAndSize` if both `order` and `size` are provided. 
     ///
     /// # Performance
     /// - `O(m)`, where `m` is the number of hyperedges of the hyperegraph.
-    pub fn get_incident_edges(&self, node: Node, order: Option<usize>, size: Option<usize>) -> Result<Option<Vec<&Vec<Node>>>, &str> {
-        if order != None && size != None {
-            Err("Order and size cannot be both specified")    
-        } else {
-            match self.incidence_list.get(&node) {
-                Some(incidence_list) => {
-                    let mut res = Vec::new();
-
-                    // Both order and size are not specified
-                    if order == None && size == None {
-                        // O(m)
-                        incidence_list.iter().for_each(|edge_id| {
-                            let hyperedge = self.edge_list.get(edge_id).unwrap();
-
-                            res.push(&hyperedge.nodes)
-                        });
-                    } else {
-                        let filter = if let Some(val) = order {
-                            // Only order is specified 
-                            val + 1
-                        } else {
-                            // Only Size is specified 
-                            size.unwrap()
-                        };
-                        // O(m)
-                        incidence_list.iter().for_each(|edge_id| {
-                            if (&self.edge_list.get(edge_id).unwrap().nodes).len() == filter {
-                                let hyperedge = self.edge_list.get(edge_id).unwrap();
-        
-                                res.push(&hyperedge.nodes)
-                            }
-                        });
-                    }
-                    
-                    Ok(Some(res))
-                }
-                _ => Ok(None),
-            }
-        }
+    pub fn get_incident_edges(&self, node: Node, order: Option<usize>, size: Option<usize>) -> Result<Option<Vec<&Vec<Node>>>, HypergraphError> {
+        self.compute_incident_hyperedges(node, order, size)
+            .map(|hyperedges| hyperedges.map(|hyperedges| hyperedges.map(|hyperedge| &hyperedge.nodes).collect()))
+    }
+
+    /// `type Node = i64`  
+    ///
+    /// Get the hyperedges which are incident to a specific node, each with its weight, read directly from the hypergraph
+    /// instead of hashing the hyperedge again as `get_weight` does.  
+    /// 
+    /// Unlike `get_weight`, which returns `None` on an unweighted hypergraph, the weights are then `0`.
+    /// 
+    /// The convention is `order == size - 1`. 
+    ///
+    /// # Parameters
+    /// - `node` : `Node` - Node in the hypergraph.
+    /// - `order` : `Option<usize>` - The order of the hyperedges to consider. 
+    /// - `size` : `Option<usize>` - The size of the hyperedges to consider. 
+    ///
+    /// # Returns
+    /// - `Result<Option<Vec<(&Vec<Node>, f64)>>, HypergraphError>` : `Ok` containing `Some` hyperedges incident to `node`
+    ///   with their weights (0 if the hypergraph is not weighted), or containing `None` if the node does not exists in
+    ///   the hypergraph. Returns `Err(HypergraphError::BothOrderAndSize)` if both `order` and `size` are provided. 
+    ///
+    /// # Performance
+    /// - `O(d)`, where `d` is the degree of the node.
+    #[allow(clippy::type_complexity)]
+    pub fn get_incident_edges_weighted(&self, node: Node, order: Option<usize>, size: Option<usize>) -> Result<Option<Vec<(&Vec<Node>, f64)>>, HypergraphError> {
+        self.compute_incident_hyperedges(node, order, size).map(|hyperedges| {
+            hyperedges.map(|hyperedges| hyperedges.map(|hyperedge| (&hyperedge.nodes, hyperedge.weight)).collect())
+        })
     }
 
 
@@ -676,23 +986,132 @@ impl Hypergraph {
         self.weighted
     }
 
+    /// Converts the hypergraph into a weighted one, with the same nodes and hyperedges.  
+    ///
+    /// If the hypergraph was not weighted, every hyperedge receives `default_weight`; otherwise the hypergraph is
+    /// returned unchanged.
+    ///
+    /// # Parameters
+    /// - `default_weight` : `f64` - The weight of the hyperedges of an unweighted hypergraph.
+    ///
+    /// # Returns
+    /// - `Hypergraph` - The weighted hypergraph.
+    ///
+    /// # Performance
+    /// - `O(m)`, where `m` is the number of hyperedges.
+    pub fn to_weighted(mut self, default_weight: f64) -> Hypergraph {
+        if !self.weighted {
+            self.weighted = true;
+            let default_weight = Self::compute_finite_weight(default_weight);
+            self.edge_list.values_mut().for_each(|hyperedge| hyperedge.set_weight(default_weight));
+        }
+        self
+    }
+
+    /// Converts the hypergraph into an unweighted one, with the same nodes and hyperedges, whose weights are zeroed.
+    ///
+    /// # Returns
+    /// - `Hypergraph` - The unweighted hypergraph.
+    ///
+    /// # Performance
+    /// - `O(m)`, where `m` is the number of hyperedges.
+    pub fn to_unweighted(mut self) -> Hypergraph {
+        self.weighted = false;
+        self.edge_list.values_mut().for_each(|hyperedge| hyperedge.set_weight(0_f64));
+        self
+    }
+
+    /// Returns the default merge policy of the hypergraph, used when a hyperedge already in the hypergraph is added
+    /// again.
+    ///
+    /// # Returns
+    /// - `MergePolicy` - The default merge policy.
+    ///
+    /// # Performance
+    /// - `O(1)`
+    pub fn merge_policy(&self) -> MergePolicy {
+        self.merge_policy
+    }
+
+    /// Sets the default merge policy of the hypergraph, used when a hyperedge already in the hypergraph is added
+    /// again.
+    ///
+    /// # Parameters
+    /// - `merge_policy` : `MergePolicy` - The new default merge policy.
+    ///
+    /// # Returns
+    /// - `()`
+    pub fn set_merge_policy(&mut self, merge_policy: MergePolicy) {
+        self.merge_policy = merge_policy;
+    }
+
     /// `type Node = i64`  
     ///
     /// Check if a hyperedge is in the hypergraph.  
     ///
     /// # Parameters
-    /// - `edge` : `&Vec<Node>` - Hyperedge to be checked.  
+    /// - `edge` : `&[Node]` - Hyperedge to be checked.  
     ///
     /// # Returns
     /// - `bool` : `true` if `edge` is in the hypergraph, `false` otherwise.
     ///
     /// # Performance
     /// - `O(n)`, where `n` is the number of nodes of the hypergraph.
-    pub fn check_edge(&self, edge: &Vec<Node>) -> bool {
+    pub fn check_edge(&self, edge: &[Node]) -> bool {
         let edge_id = Self::compute_edge_id(edge); 
         self.edge_list.contains_key(&edge_id)
     }
 
+    /// `type Node = i64`  
+    ///
+    /// Returns the `EdgeID` of a hyperedge, if it is in the hypergraph.
+    ///
+    /// # Parameters
+    /// - `edge` : `&[Node]` - The hyperedge.
+    ///
+    /// # Returns
+    /// - `Option<EdgeID>` - `Some` handle of the hyperedge, `None` if the hyperedge is not in the hypergraph.
+    ///
+    /// # Performance
+    /// - `O(n)`, where `n` is the length of the hyperedge.
+    pub fn edge_id(&self, edge: &[Node]) -> Option<EdgeID> {
+        let edge_id = Self::compute_edge_id(edge);
+        self.edge_list.contains_key(&edge_id).then_some(edge_id)
+    }
+
+    /// Checks if the hyperedge identified by `id` is in the hypergraph.
+    ///
+    /// # Parameters
+    /// - `id` : `EdgeID` - The handle of the hyperedge.
+    ///
+    /// # Returns
+    /// - `bool` - `true` if the hyperedge is in the hypergraph, `false` otherwise (eg if it was removed).
+    ///
+    /// # Performance
+    /// - `O(1)`
+    pub fn contains_edge_id(&self, id: EdgeID) -> bool {
+        self.edge_list.contains_key(&id)
+    }
+
+    /// `type Node = i64`  
+    ///
+    /// Returns the hyperedge identified by `id`, with its weight.
+    ///
+    /// Unlike `get_weight`, which returns `None` on an unweighted hypergraph, the weight is then `0`.
+    ///
+    /// # Parameters
+    /// - `id` : `EdgeID` - The handle of the hyperedge.
+    ///
+    /// # Returns
+    /// - `Option<(&Vec<Node>, f64)>` - `Some` nodes and weight of the hyperedge (0 if the hypergraph is not weighted),
+    ///   `None` if the hyperedge is not in the hypergraph.
+    ///
+    /// # Performance
+    /// - `O(1)`
+    pub fn get_edge_by_id(&self, id: EdgeID) -> Option<(&Vec<Node>, f64)> {
+        self.edge_list.get(&id).map(|hyperedge| (&hyperedge.nodes, hyperedge.weight))
+    }
+
     /// Check if a node is in the hypergraph.
     ///
     /// # Parameters
@@ -710,30 +1129,34 @@ impl Hypergraph {
     /// 
     /// Add a hyperedge, with default weight set to 0, to the hypergraph.
     ///
-    /// If the hyperedge was already present, then its weight is updated.  
+    /// If the hyperedge was already present, then its weight is merged according to the merge policy (by default, replaced).  
     ///
     /// # Parameters
-    /// - `edge` : `&Vec<Node>` - Hyperedge to insert.
+    /// - `edge` : `&[Node]` - Hyperedge to insert.
     ///
     /// # Returns
     /// - `bool` - `false` if the hyperedge was already in, `true` otherwise. 
     ///
     /// # Performance
     /// - `O(n)`, where `n` is the length of the hyperedge.
-    pub fn add_edge(&mut self, edge: &Vec<Node>) -> bool {
-        Self::compute_add_edge(self, &edge.to_vec(), 0_f64)
+    pub fn add_edge(&mut self, edge: &[Node]) -> bool {
+        Self::compute_add_edge(self, edge, 0_f64)
     }
 
     /// `type Node = i64`
     ///
-    /// Add a hyperedge to the hypergraph. If the hyperedge is already in the hypergraph, its weight is updated.  
+    /// Add a hyperedge to the hypergraph. If the hyperedge is already in the hypergraph, its weight is merged according
+    /// to the merge policy (by default, replaced).  
     ///
-    /// If the hyperedge was already present, then its weight is updated.  
+    /// If the hypergraph is not weighted, the hyperedge is added anyway and `weight` is ignored (it is stored as 0). Use
+    /// `try_add_edge_weighted` to get an error instead.  
     ///
-    /// If the hypergraph is not weighted and a `weight > 0` is provided, then `weight` will be set to 0.  
+    /// NaN and infinite weights are clamped, so that the weights stay comparable: NaN is stored as 0, and `inf`
+    /// (`-inf`) as `f64::MAX` (`f64::MIN`). The same holds for the merged weight, if the hyperedge was already present.
+    /// `try_add_edge_weighted` rejects them, instead.  
     ///
     /// # Parameters
-    /// - `edge` : `&Vec<Node>` - Hyperedge to insert.
+    /// - `edge` : `&[Node]` - Hyperedge to insert.
     /// - `weight` : `f64` - Weight of the hyperedge.
     ///
     /// # Returns
@@ -741,11 +1164,75 @@ impl Hypergraph {
     ///
     /// # Performance
     /// - `O(n)`, where `n` is the length of the hyperedge.
-    pub fn add_edge_weighted(&mut self, edge: &Vec<Node>, mut weight: f64) -> bool {
+    pub fn add_edge_weighted(&mut self, edge: &[Node], mut weight: f64) -> bool {
         if !self.weighted {
             weight = 0_f64;
         }
-        Self::compute_add_edge(self,&edge.to_vec(), weight) 
+        Self::compute_add_edge(self, edge, weight) 
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Add a hyperedge to a weighted hypergraph. If the hyperedge is already in the hypergraph, its weight is merged
+    /// according to the merge policy (by default, replaced).  
+    ///
+    /// Unlike `add_edge_weighted`, the hyperedge is not added to an unweighted hypergraph, nor with a NaN or infinite
+    /// weight.
+    ///
+    /// # Parameters
+    /// - `edge` : `&[Node]` - Hyperedge to insert.
+    /// - `weight` : `f64` - Weight of the hyperedge.
+    ///
+    /// # Returns
+    /// - `Result<bool, HypergraphError>` - `Ok(false)` if the hyperedge was already in, `Ok(true)` otherwise. Returns
+    ///   `Err(HypergraphError::NotWeighted)` if the hypergraph is not weighted, `Err(HypergraphError::NonFiniteWeight)`
+    ///   if `weight` is NaN or infinite.
+    ///
+    /// # Performance
+    /// - `O(n)`, where `n` is the length of the hyperedge.
+    pub fn try_add_edge_weighted(&mut self, edge: &[Node], weight: f64) -> Result<bool, HypergraphError> {
+        if !self.weighted {
+            return Err(HypergraphError::NotWeighted);
+        }
+        if !weight.is_finite() {
+            return Err(HypergraphError::NonFiniteWeight);
+        }
+        Ok(Self::compute_add_edge(self, edge, weight))
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Add a hyperedge to the hypergraph, as `add_edge`, returning its handle.
+    ///
+    /// # Parameters
+    /// - `edge` : `&[Node]` - Hyperedge to insert.
+    ///
+    /// # Returns
+    /// - `EdgeID` - The handle of the hyperedge, the same if it was already in.
+    ///
+    /// # Performance
+    /// - `O(n)`, where `n` is the length of the hyperedge.
+    pub fn insert_edge(&mut self, edge: &[Node]) -> EdgeID {
+        self.add_edge(edge);
+        Self::compute_edge_id(edge)
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Add a hyperedge to the hypergraph, as `add_edge_weighted`, returning its handle.
+    ///
+    /// # Parameters
+    /// - `edge` : `&[Node]` - Hyperedge to insert.
+    /// - `weight` : `f64` - Weight of the hyperedge.
+    ///
+    /// # Returns
+    /// - `EdgeID` - The handle of the hyperedge, the same if it was already in.
+    ///
+    /// # Performance
+    /// - `O(n)`, where `n` is the length of the hyperedge.
+    pub fn insert_edge_weighted(&mut self, edge: &[Node], weight: f64) -> EdgeID {
+        self.add_edge_weighted(edge, weight);
+        Self::compute_edge_id(edge)
     }
 
     /// `type Node = i64`
@@ -755,7 +1242,7 @@ impl Hypergraph {
     /// If `edges` contains duplicates, the considered hyperedge, with its weight, will be the last encountered in the list. This
     /// does not affect the result, since every hyperedge in the list will have 0 as its weight.      
     ///
-    /// If a hyperedge was already present, then its weight is updated.
+    /// If a hyperedge was already present, then its weight is merged according to the merge policy (by default, replaced).
     ///
     /// # Parameters
     /// - `edges` : `&[Vec<Node>]` - Hyperedges to insert.
@@ -775,17 +1262,17 @@ impl Hypergraph {
 
     /// `type Node = i64`
     ///
-    /// Add a list of hyperedges to the hypergraph. If a hyperedge is already in the hypergraph, its weight is updated.
+    /// Add a list of hyperedges to the hypergraph.
     ///
     /// Let `n`, `m` be the length of `edges` and `weights` respectively. Consider this three cases:   
     /// - `n` > `m`: then the first m hyperedges will receive its corresponding weight, and the last `n-m` will be   
-    /// initialized with 0 as their weight;
+    ///   initialized with 0 as their weight;
     /// - `n` = `m`: then every hyperedge will receive its corresponding weight;
     /// - `n` < `m`: same as above; there will simply be some weights which will not be assigned.  
     ///
-    /// If `edges` contains duplicates, the considered hyperedge, with its weight, will be the last encountered in the list.   
-    ///
-    /// If a hyperedge was already present, then its weight is updated.
+    /// If `edges` contains duplicates, or a hyperedge was already present, then its weight is merged according to the merge
+    /// policy (by default, replaced by the last one encountered in the list). If the hypergraph is not weighted, the
+    /// weights are ignored, and NaN and infinite weights are clamped, as in `add_edge_weighted`.
     ///
     /// # Parameters
     /// - `edges` : `&[Vec<Node>]` - Hyperedges to insert.
@@ -797,21 +1284,64 @@ impl Hypergraph {
     /// # Performance
     /// - `O(n*m)`, where `n` is the max length of an edge, `m` is the number of hyperedges.
     pub fn add_edges_weighted(&mut self, edges: &[Vec<Node>], weights: &[f64]) -> bool {
-        let mut index = 0;
-        let mut next;
         let mut res = true;
+        for (index, edge) in edges.iter().enumerate() {
+            // The i-th hyperedge receives the i-th weight, if any
+            let weight = weights.get(index).copied().unwrap_or(0_f64);
+            res &= self.add_edge_weighted(edge, weight);
+        }
+        res
+    }
 
-        for edge in edges.iter() {
-            if index < weights.len() {
-                next = weights[index];
-            } else {
-                next = 0_f64;
-            }
+    /// `type Node = i64`
+    ///
+    /// Add a hyperedge to the hypergraph. If the hyperedge is already in the hypergraph, its weight is merged with
+    /// `weight` according to `policy`, instead of the default merge policy of the hypergraph.  
+    ///
+    /// If the hypergraph is not weighted, `weight` is ignored (it is stored as 0), as in `add_edge_weighted`.
+    ///
+    /// # Parameters
+    /// - `edge` : `&[Node]` - Hyperedge to insert.
+    /// - `weight` : `f64` - Weight of the hyperedge.
+    /// - `policy` : `MergePolicy` - The policy for the weight if the hyperedge is already in.
+    ///
+    /// # Returns
+    /// - `bool` - `false` if the hyperedge was already in, `true` otherwise.
+    ///
+    /// # Performance
+    /// - `O(n)`, where `n` is the length of the hyperedge.
+    pub fn add_edge_with_policy(&mut self, edge: &[Node], mut weight: f64, policy: MergePolicy) -> bool {
+        if !self.weighted {
+            weight = 0_f64;
+        }
+        Self::compute_add_edge_with(self, edge, weight, policy)
+    }
 
-            res &= Self::compute_add_edge(self, edge, next);
-            index += 1;
+    /// `type Node = i64`
+    ///
+    /// Add a list of hyperedges to the hypergraph, merging the weights of the hyperedges already in the hypergraph, or
+    /// repeated in `edges`, according to `policy` instead of the default merge policy of the hypergraph.
+    ///
+    /// The i-th hyperedge receives the i-th weight; if `weights` is shorter than `edges`, the remaining hyperedges
+    /// receive 0. If the hypergraph is not weighted, the weights are ignored.
+    ///
+    /// # Parameters
+    /// - `edges` : `&[Vec<Node>]` - Hyperedges to insert.
+    /// - `weights` : `&[f64]` - Weights of the hyperedges.
+    /// - `policy` : `MergePolicy` - The policy for the weights of the hyperedges already in.
+    ///
+    /// # Returns
+    /// - `bool` - `true` if all hyperedges were not already in, `false` otherwise.
+    ///
+    /// # Performance
+    /// - `O(n*m)`, where `n` is the max length of an edge, `m` is the number of hyperedges.
+    pub fn add_edges_with_policy(&mut self, edges: &[Vec<Node>], weights: &[f64], policy: MergePolicy) -> bool {
+        let mut res = true;
+        for (index, edge) in edges.iter().enumerate() {
+            let weight = weights.get(index).copied().unwrap_or(0_f64);
+            res &= self.add_edge_with_policy(edge, weight, policy);
         }
-        res 
+        res
     }
 
     /// `type Node = i64`    
@@ -822,28 +1352,46 @@ impl Hypergraph {
     /// If the node provided is not in the hypergraph, nothing happens for it.  
     ///
     /// # Parameters
-    /// - `edge` : `&Vec<Node>` - The hyperedge to remove.
+    /// - `edge` : `&[Node]` - The hyperedge to remove.
     ///
     /// # Returns
     /// - `bool` - `true` if the hyperedge was in the hypergraph, `false` otherwise.
     ///
     /// # Performance
     /// - `O(n)`, where `n` is the order of the hyperedge provided, ie its length.
-    pub fn remove_edge(&mut self, edge: &Vec<Node>) -> bool {
-        let edge_id = Self::compute_edge_id(edge);
-
-        if !self.edge_list.contains_key(&edge_id) {
-            false
-        } else {
-            // Update incidence_list, O(n)
-            for (_, edge_list) in self.incidence_list.iter_mut() {
-                edge_list.remove(&edge_id);
-            }
+    pub fn remove_edge(&mut self, edge: &[Node]) -> bool {
+        self.remove_edge_by_id(Self::compute_edge_id(edge))
+    }
 
-            // Update edge_list, O(1)
-            self.edge_list.remove(&edge_id);
+    /// Weakly deletion of the hyperedge identified by `id` from the hypergraph (see `remove_edge`).  
+    ///
+    /// After the removal, `id` is no longer valid, until the same hyperedge is added again.
+    ///
+    /// # Parameters
+    /// - `id` : `EdgeID` - The handle of the hyperedge to remove.
+    ///
+    /// # Returns
+    /// - `bool` - `true` if the hyperedge was in the hypergraph, `false` otherwise.
+    ///
+    /// # Performance
+    /// - `O(k)`, where `k` is the size of the hyperedge, since only the incidence lists of its nodes are updated.
+    pub fn remove_edge_by_id(&mut self, id: EdgeID) -> bool {
+        // Update edge_list, O(1)
+        match self.edge_list.remove(&id) {
+            None => false,
+            Some(hyperedge) => {
+                self.edge_attrs.remove(&id);
+                self.edge_times.remove(&id);
+
+                // Update incidence_list, O(k). The nodes may have been already removed, eg by `remove_node`
+                for node in hyperedge.nodes.iter() {
+                    if let Some(edge_ids) = self.incidence_list.get_mut(node) {
+                        edge_ids.remove(&id);
+                    }
+                }
 
-            true
+                true
+            }
         }
     }
 
@@ -861,8 +1409,7 @@ impl Hypergraph {
     /// - `bool` - `true` if all the hyperedges provided were in the hypergraph, `false` otherwise. 
     ///
     /// # Performance
-    /// - `O(n*l)`, where `n` is the number of nodes, `l` is the length of `edges`. We are assuming that the list provided  
-    /// contains only hyperedges which are in the hypergraph.
+    /// - `O(k*l)`, where `k` is the maximum size of a hyperedge, `l` is the length of `edges`.
     pub fn remove_edges(&mut self, edges: &[Vec<Node>]) -> bool {
         let mut res = true;
 
@@ -873,9 +1420,6 @@ impl Hypergraph {
         res 
     }
 
-    // =======================================================================
-    //                      We need to update the EdgeID'a
-    // =======================================================================
     /// `type Node = i64`.    
     ///
     /// Weakly removes a node from the hypergraph.  
@@ -883,6 +1427,10 @@ impl Hypergraph {
     /// Weakly deletion of node `v` from hypergraph `H = (V,E)` consists of removing `v` from `V` and from every hyperedge   
     /// `E` such that `v` is in `E`.  
     ///
+    /// The hyperedges are shrunk in place, so their `EdgeID` changes. A hyperedge which becomes equal to another one is
+    /// merged into it, with the weights merged according to the merge policy, while a hyperedge which becomes empty is
+    /// dropped. Use `remove_node_with` to choose what happens, and to know how many hyperedges were affected.  
+    ///
     /// If the node provided is not in the hypergraph, nothing happens for it.  
     ///
     /// # Parameters
@@ -892,34 +1440,10 @@ impl Hypergraph {
     /// - `bool` - `true` if the node was in the hypergraph, `false` otherwise.
     ///
     /// # Performance
-    /// - `O(n*m)`, where `n` is the number of nodes, `m` is the number of hyperedges.
-    ///
-    /// # Notes   
-    /// If we would have used a hash-based collection, we could achieve this in `O(m)`.
+    /// - `O(d*k)`, where `d` is the degree of the node and `k` the maximum size of its incident hyperedges.
     pub fn remove_node(&mut self, node: Node) -> bool {
-        if !self.incidence_list.contains_key(&node) {
-            false
-        } else {
-            // Update incidence_list, O(1)
-            let edges = self.incidence_list.remove(&node).unwrap();
-
-            // O(m)
-            for edge_id in edges.iter() {
-                // O(n)
-                let mut edge_now = self.edge_list.get(edge_id).unwrap().clone();
-
-                // O(n)
-                self.remove_edge(&edge_now.nodes);
-
-                // O(n)
-                edge_now.nodes.retain(|x| *x != node);
-
-                // O(n)
-                self.add_edge_weighted(&edge_now.nodes, edge_now.weight);
-            }
-
-            true
-        }
+        let options = NodeRemovalOptions { on_duplicate: self.merge_policy, ..Default::default() };
+        self.remove_node_with(node, &options).removed_nodes == 1
     }
 
     /// `type Node = i64`  
@@ -932,15 +1456,14 @@ impl Hypergraph {
     /// - `nodes` : `&[Node]` - List of the nodes to be removed.
     ///
     /// # Returns
-    /// - `()`
+    /// - `NodeRemovalReport` - How many nodes were removed, and how many hyperedges were shrunk, merged and dropped.
     ///
     /// # Performance
-    /// - `O(l*n*m)`, where `l` is the length of the list of nodes, `n` is the number of nodes, `m` is the   
-    /// number of edges. We are assuming that the list provided contains only nodes which are in the hypergraph.  
-    pub fn remove_nodes(&mut self, nodes: &[Node]) {
-        for node in nodes.iter() {
-            self.remove_node(*node);
-        }
+    /// - `O(l*d*k)`, where `l` is the length of the list of nodes, `d` is the maximum degree and `k` the maximum size of
+    ///   a hyperedge.
+    pub fn remove_nodes(&mut self, nodes: &[Node]) -> NodeRemovalReport {
+        let options = NodeRemovalOptions { on_duplicate: self.merge_policy, ..Default::default() };
+        self.remove_nodes_with(nodes, &options)
     }
 
     /// `type Node = i64`  
@@ -959,32 +1482,13 @@ impl Hypergraph {
     /// - `bool` : `true` if the node was in the hypergraph, `false` otherwise.
     ///
     /// # Performance
-    /// - `O(n*m)`, where `n` and `m` are the number of nodes and the number of hyperedges in the hypergraph, respectively.
+    /// - `O(d*k)`, where `d` is the degree of the node and `k` the maximum size of its incident hyperedges.
     pub fn strong_remove_node(&mut self, node: Node) -> bool {
-        if !self.incidence_list.contains_key(&node) {
-            false
-        } else {
-            // Update incidence_list, O(1)
-            let edges = self.incidence_list.remove(&node).unwrap();
-
-            // We could have re-used the function Self::remove_edges, but this is more efficient, because it does not require to
-            // convert `edges`, which is AHashSet<EdgeID> to a &Vec<&Vec<Node>>, which is O(m).
-
-            // Update incidence_list, O(n*m)
-            for (_, set) in self.incidence_list.iter_mut() {
-                for edge_id in edges.iter() {
-                    set.remove(edge_id);
-                }
-            }
-
-            // Update edge_list, O(m)
-            for edge_id in edges.iter() {
-                self.edge_list.remove(edge_id);
-            }
-
-            true
-        }
+        let res = self.incidence_list.contains_key(&node);
+        self.strong_remove_nodes(&[node]);
+        res
     }
+
     /// `type Node = i64`    
     ///
     /// Strongly removes a list of nodes from the hypergraph.   
@@ -993,76 +1497,122 @@ impl Hypergraph {
     ///
     /// If the list provided contains nodes which are not in the hypergraph, nothing happens for them.
     ///
+    /// The removal is done in a single pass: the hyperedges incident to any of the nodes are collected first, and each
+    /// one is removed once, updating only the incidence lists of its own nodes.
+    ///
     /// # Parameters
     /// - `nodes` : `&[Node]` - List of the nodes to be removed.
     ///
     /// # Returns  
-    /// - `()`  
+    /// - `usize` - The number of removed hyperedges.
     ///
     /// # Performance
-    /// - `O(l*n*m)`, where `l` is the length of the list `nodes`, `n` is the number of nodes, `m` is the   
-    /// number of edges. We are assuming that the list provided contains only nodes which are in the hypergraph.
-    pub fn strong_remove_nodes(&mut self, nodes: &[Node]) {
+    /// - `O(l + e*k)`, where `l` is the length of the list `nodes`, `e` is the number of hyperedges incident to them
+    ///   and `k` the maximum size of a hyperedge.
+    pub fn strong_remove_nodes(&mut self, nodes: &[Node]) -> usize {
+        // O(e)
+        let edge_ids: AHashSet<EdgeID> = nodes
+            .iter()
+            .filter_map(|node| self.incidence_list.get(node))
+            .flat_map(|edge_ids| edge_ids.iter().copied())
+            .collect();
+
+        // O(e*k)
+        for edge_id in edge_ids.iter() {
+            self.remove_edge_by_id(*edge_id);
+        }
+
+        // O(l)
         for node in nodes.iter() {
-            self.strong_remove_node(*node);
+            self.incidence_list.remove(node);
+            self.node_attrs.remove(node);
         }
+
+        edge_ids.len()
     }
 
     /// `type Node = i64`   
     ///
     /// Returns a subhypergraph induced by the nodes in the list.   
     ///
+    /// The nodes of the list which are not in the hypergraph are ignored, so the result is always a subhypergraph of
+    /// the original one (see `is_subhypergraph_of`).
+    ///
+    /// Only the hyperedges incident to the given nodes are checked (see `edges_within`), so small subhypergraphs of
+    /// large hypergraphs are cheap.
+    ///
     /// # Parameters
-    /// - `nodes` : `&Vec<Node>` - List of nodes to be included in the subhypergraph.
+    /// - `nodes` : `&[Node]` - List of nodes to be included in the subhypergraph.
     ///
     /// # Returns
     /// - `Self` - Induced subhypergraph.  
     ///
     /// # Performance
-    /// - `O(n*m)`, where `n` and `m` are the number of nodes and the number of hyperedges of the original hypergraph.
-    pub fn subhypergraph(&self, nodes: &Vec<Node>) -> Self {
-        let mut res = Self::new(self.weighted);
+    /// - `O(d*k)`, where `d` is the sum of the degrees of the given nodes and `k` the maximum size of a hyperedge.
+    pub fn subhypergraph(&self, nodes: &[Node]) -> Self {
+        let mut res = Self::with_merge_policy(self.weighted, self.merge_policy);
 
         // O(n)
-        res.add_nodes(nodes);
-
-        let nodes_as_set = Self::compute_vec_to_set(nodes);
-
-        // O(m)
-        for edge in self.edge_list.values() {
-            // O(n)
-            let edge_as_set = Self::compute_vec_to_set(&edge.nodes);
+        for node in nodes.iter().filter(|node| self.incidence_list.contains_key(node)) {
+            res.add_node(*node);
+        }
 
-            // O(n)
-            if edge_as_set.is_subset(&nodes_as_set) {
-                res.add_edge_weighted(&edge.nodes, edge.weight);
-            }
+        // O(d*k)
+        for (edge, weight) in self.edges_within(nodes) {
+            res.add_edge_weighted(edge, weight);
         }
+        self.compute_copy_metadata(&mut res);
 
+        debug_assert!(res.is_subhypergraph_of(self, true));
         res
     }
 
+    /// Checks if the hypergraph is a subhypergraph of another one, ie if every node (isolated ones included) and every
+    /// hyperedge of `self` is in `other`. Hyperedges are compared by their nodes, in their order, as in `check_edge`.
+    ///
+    /// The empty hypergraph is a subhypergraph of every hypergraph.
+    ///
+    /// # Parameters
+    /// - `other` : `&Hypergraph` - The hypergraph which should contain `self`.
+    /// - `strict_weights` : `bool` - If `true`, every hyperedge must also have the same weight in `other`.
+    ///
+    /// # Returns
+    /// - `bool` - `true` if `self` is a subhypergraph of `other`, `false` otherwise.
+    ///
+    /// # Performance
+    /// - `O(n + m*k)`, where `n` and `m` are the number of nodes and hyperedges of `self`, and `k` the maximum size of
+    ///   a hyperedge.
+    pub fn is_subhypergraph_of(&self, other: &Hypergraph, strict_weights: bool) -> bool {
+        self.incidence_list.keys().all(|node| other.incidence_list.contains_key(node))
+            && self.edge_list.iter().all(|(edge_id, hyperedge)| {
+                other.edge_list.get(edge_id).is_some_and(|theirs| {
+                    theirs.nodes == hyperedge.nodes && (!strict_weights || theirs.weight == hyperedge.weight)
+                })
+            })
+    }
+
     /// Returns a subhypergraph induced by the hyperedges of a specific order.
     ///
     /// # Parameters
     /// - `orders` : `Option<&Vec<usize>>` - List of orders of the hyperedges to be included in the subhypergraph (optional).
     /// - `sizes` : `Option<&Vec<usize>>` - List of sizes of the hyperedges to be included in the subhypergraph (optional).
     /// - `keep_nodes` : `bool` - If `true`, the nodes of the original hypergraph are kept in the subhypergraph. If `false`,  
-    ///  only the hyperedges are kept. 
+    ///   only the hyperedges are kept. 
     ///
     /// # Returns
     /// - `Result<Self>` - `Ok` containing the induced subhypergraph if one, and exactly one, between `orders` and `sizes`   
-    /// is provided. `Err` containing an error message otherwise. 
+    ///   is provided. `Err` containing `HypergraphError::BothOrderAndSize` or
+    ///   `HypergraphError::NeitherOrderNorSize` otherwise.
     ///
     /// # Performance
     /// - `O(n*m)`, where `n` and `m` are the number of nodes and hyperedges, respectively, of the original hypergraph.
-    pub fn subhypergraph_by_orders(&self, orders: Option<&Vec<usize>>, sizes: Option<&Vec<usize>>, keep_nodes: bool) -> Result<Self, &str> {
-        if orders == None && sizes == None {
-            Err("At least one between orders and sizes should be specified")
-        } else if orders != None && sizes != None {
-            Err("Orders and sizes cannot be both specified")
+    pub fn subhypergraph_by_orders(&self, orders: Option<&Vec<usize>>, sizes: Option<&Vec<usize>>, keep_nodes: bool) -> Result<Self, HypergraphError> {
+        if orders.is_none() && sizes.is_none() {
+            Err(HypergraphError::NeitherOrderNorSize)
+        } else if orders.is_some() && sizes.is_some() {
+            Err(HypergraphError::BothOrderAndSize)
         } else {
-            let mut res = Hypergraph::new(self.weighted);
+            let mut res = Hypergraph::with_merge_policy(self.weighted, self.merge_policy);
 
             if keep_nodes {
                 res.add_nodes(&self.get_nodes());
@@ -1088,16 +1638,93 @@ impl Hypergraph {
                     res.add_edge_weighted(&hyperedge.nodes, hyperedge.weight);
                 }
             }
+            self.compute_copy_metadata(&mut res);
 
+            debug_assert!(res.is_subhypergraph_of(self, true));
             Ok(res)
         }
     }
 
+    /// `type Node = i64`
+    ///
+    /// Returns the subhypergraph made of exactly the given hyperedges, with their weights in the original hypergraph.
+    /// Hyperedges are looked up by their nodes, in their order, as in `check_edge`.
+    ///
+    /// # Parameters
+    /// - `edges` : `&[Vec<Node>]` - List of hyperedges to be included in the subhypergraph.
+    /// - `skip_missing` : `bool` - If `true`, the hyperedges which are not in the hypergraph are skipped. If `false`,
+    ///   they make the method fail.
+    /// - `keep_all_nodes` : `bool` - If `true`, all the nodes of the original hypergraph are kept in the subhypergraph.
+    ///   If `false`, only the nodes of the selected hyperedges are.
+    ///
+    /// # Returns
+    /// - `Result<Self, HypergraphError>` - `Ok` containing the subhypergraph. Returns
+    ///   `Err(HypergraphError::EdgeNotFound)` if a hyperedge is not in the hypergraph and `skip_missing` is `false`.
+    ///
+    /// # Performance
+    /// - `O(e*k)`, where `e` is the number of given hyperedges and `k` their maximum size, plus `O(n)` if
+    ///   `keep_all_nodes` is `true`.
+    pub fn subhypergraph_by_edges(&self, edges: &[Vec<Node>], skip_missing: bool, keep_all_nodes: bool) -> Result<Self, HypergraphError> {
+        let mut res = Hypergraph::with_merge_policy(self.weighted, self.merge_policy);
+
+        if keep_all_nodes {
+            res.add_nodes(&self.get_nodes());
+        }
+
+        for edge in edges.iter() {
+            match self.edge_list.get(&Self::compute_edge_id(edge)) {
+                Some(hyperedge) => {
+                    res.add_edge_weighted(&hyperedge.nodes, hyperedge.weight);
+                }
+                None if skip_missing => {}
+                None => return Err(HypergraphError::EdgeNotFound),
+            }
+        }
+        self.compute_copy_metadata(&mut res);
+
+        debug_assert!(res.is_subhypergraph_of(self, true));
+        Ok(res)
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Returns the subhypergraph made of the hyperedges which satisfy a predicate, eg on their weight, their size or
+    /// their nodes.
+    ///
+    /// # Parameters
+    /// - `f` : `F: Fn(&[Node], f64) -> bool` - The predicate, called with the nodes and the weight (0 if the hypergraph
+    ///   is not weighted) of each hyperedge.
+    /// - `keep_nodes` : `bool` - If `true`, the nodes of the original hypergraph are kept in the subhypergraph. If
+    ///   `false`, only the hyperedges are kept.
+    ///
+    /// # Returns
+    /// - `Self` - The subhypergraph of the hyperedges satisfying `f`.
+    ///
+    /// # Performance
+    /// - `O(m*k)`, plus the cost of `f` for each hyperedge, where `m` is the number of hyperedges and `k` their
+    ///   maximum size.
+    pub fn subhypergraph_filter<F: Fn(&[Node], f64) -> bool>(&self, f: F, keep_nodes: bool) -> Self {
+        let mut res = Hypergraph::with_merge_policy(self.weighted, self.merge_policy);
+
+        if keep_nodes {
+            res.add_nodes(&self.get_nodes());
+        }
+
+        // O(m)
+        for hyperedge in self.edge_list.values().filter(|hyperedge| f(&hyperedge.nodes, hyperedge.weight)) {
+            Self::compute_add_edge(&mut res, &hyperedge.nodes, hyperedge.weight);
+        }
+        self.compute_copy_metadata(&mut res);
+
+        debug_assert!(res.is_subhypergraph_of(self, true));
+        res
+    }
+
     /// Returns the distribution of the orders of the hyperedges in the hypergraph.
     ///
     /// # Returns
     /// - `AHashMap<usize, usize>` - The dictionary which stores the orders as keys, and the number of occurrences for that   
-    /// specific order as values.
+    ///   specific order as values.
     ///
     /// # Performance
     /// - `O(m)`, where `m` is the number of hyperedges in the hypergraph.
@@ -1118,17 +1745,17 @@ impl Hypergraph {
     ///
     /// Gives an iterator over the hyperedges in the hypergraph.   
     ///
-    /// The hyperedges come also with their weight.
+    /// The hyperedges come also with their weight, while their attributes are returned by `edge_attrs`.
     ///
     /// # Returns
     /// - `IterEdges` : The iterator over the hyperedges, which are stored as `Hyperedge`.
     ///
     /// # Performance  
     /// - `O(1)`
-    pub fn iter_edges(&self) -> IterEdges {
+    pub fn iter_edges(&self) -> IterEdges<'_> {
         // This iterator, as specified by the lifetime symbol '_', is an iterator over borrowed values, so
         // it does not take ownership
-        self.edge_list.values().into_iter()
+        self.edge_list.values()
     }
 
     /// Checks wether the hypergraph is uniform, ie all hyperedges have the same order.
@@ -1141,10 +1768,10 @@ impl Hypergraph {
     /// # Performance  
     /// - `O(m)`, where `m` is the number of hyperedges.
     pub fn is_uniform(&self) -> Option<usize> {
-        if self.edge_list.len() == 0 {
+        if self.edge_list.is_empty() {
             Some(0)
         } else {
-            let mut edges = self.edge_list.values().into_iter();
+            let mut edges = self.edge_list.values();
             // Order of the "first" hyperedge in edge_list
             let length = edges.next().unwrap().nodes.len();
 
@@ -1166,6 +1793,50 @@ impl Hypergraph {
     pub fn clear(&mut self) {
         self.incidence_list.clear();
         self.edge_list.clear();
+        self.node_attrs.clear();
+        self.edge_attrs.clear();
+        self.edge_times.clear();
+    }
+
+    /// Reserves room for at least `additional` more nodes, see `AHashMap::reserve`.
+    ///
+    /// # Parameters
+    /// - `additional` : `usize` - The number of nodes to make room for.
+    ///
+    /// # Returns
+    /// - `()`
+    pub fn reserve_nodes(&mut self, additional: usize) {
+        self.incidence_list.reserve(additional);
+    }
+
+    /// Reserves room for at least `additional` more hyperedges, see `AHashMap::reserve`.
+    ///
+    /// # Parameters
+    /// - `additional` : `usize` - The number of hyperedges to make room for.
+    ///
+    /// # Returns
+    /// - `()`
+    pub fn reserve_edges(&mut self, additional: usize) {
+        self.edge_list.reserve(additional);
+    }
+
+    /// Removes all the hyperedges, keeping the nodes, which become isolated. Unlike `clear`, the nodes do not have to
+    /// be added again, eg to rebuild the hyperedges over a fixed set of nodes.
+    ///
+    /// Keeps the allocated memory for reuse, and the weighted flag.
+    ///
+    /// # Returns
+    /// - `()`
+    ///
+    /// # Performance
+    /// - `O(n + m)`, where `n` and `m` are the number of nodes and hyperedges.
+    pub fn clear_edges(&mut self) {
+        for edge_ids in self.incidence_list.values_mut() {
+            edge_ids.clear();
+        }
+        self.edge_list.clear();
+        self.edge_attrs.clear();
+        self.edge_times.clear();
     }
 
     /*
@@ -1179,7 +1850,7 @@ impl Hypergraph {
     /// Effectively computes the (weigted) add of a hyperedge to the hypergraph.
     ///
     /// # Parameters
-    /// - `edge` : `&Vec<Node>` - Hyperedge to be inserted.
+    /// - `edge` : `&[Node]` - Hyperedge to be inserted.
     /// - `weight` : `f64` - Weight of the hyperedge.
     ///
     /// # Returns  
@@ -1187,14 +1858,37 @@ impl Hypergraph {
     ///
     /// # Performance
     /// - `O(n)`, where `n` is the number of nodes.
-    fn compute_add_edge(hg: &mut Hypergraph, edge: &Vec<Node>, weight: f64) -> bool {
-        let edge_id = Self::compute_edge_id(edge);
+    fn compute_add_edge(hg: &mut Hypergraph, edge: &[Node], weight: f64) -> bool {
+        let policy = hg.merge_policy;
+        Self::compute_add_edge_with(hg, edge, weight, policy)
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Effectively computes the (weigted) add of a hyperedge to the hypergraph, merging the weight with `policy` if the
+    /// hyperedge is already in.
+    fn compute_add_edge_with(hg: &mut Hypergraph, edge: &[Node], weight: f64, policy: MergePolicy) -> bool {
+        Self::compute_add_edge_with_id(hg, edge, Self::compute_edge_id(edge), weight, policy)
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Effectively adds a hyperedge whose `EdgeID` is already known, eg because it was computed in parallel, see
+    /// `compute_add_edge_with`.
+    fn compute_add_edge_with_id(
+        hg: &mut Hypergraph,
+        edge: &[Node],
+        edge_id: EdgeID,
+        weight: f64,
+        policy: MergePolicy,
+    ) -> bool {
+        let weight = Self::compute_finite_weight(weight);
 
         if !hg.edge_list.contains_key(&edge_id) {
             // Edge not already in
 
             // Update edge_list, O(1)
-            let hyperedge = Hyperedge::new(edge.clone(), weight);
+            let hyperedge = Hyperedge::new(edge.to_vec(), weight);
             hg.edge_list.insert(edge_id, hyperedge);
 
             // Update incidence_list, O(n)
@@ -1212,34 +1906,55 @@ impl Hypergraph {
             }
             true 
         } else {
-            // If the edge is already in, its weight is updated
+            // If the edge is already in, its weight is merged
             hg.edge_list.entry(edge_id).and_modify(|hyperedge| {
-                hyperedge.set_weight(weight);
+                hyperedge.set_weight(Self::compute_finite_weight(policy.merge(hyperedge.weight, weight)));
             });
             false  
         }
     }
 
-    /// `type EdgeID = u64`    
     /// `type Node = i64`
     ///
     /// Effectively computes the edgeID for a Hyperedge.  
     ///
+    /// The hyperedge is hashed as a slice, which is how `Vec<Node>` and `[Node; N]` are hashed as well, so the edgeID
+    /// does not depend on the container of the nodes.
+    ///
     /// # Parameters  
-    /// - `edge` : `Vec<Node>` - hyperedge for which the edgeID is needed.
+    /// - `edge` : `&[Node]` - hyperedge for which the edgeID is needed.
     ///
     /// # Returns
-    /// - `u64`- The computed edgeID  
+    /// - `EdgeID`- The computed edgeID  
     ///
     /// # Performance  
     /// - The implementation of the hashing function for `Vec<T>` is the one of the standard library, so `O(n)`, where `n` is the   
-    /// length of the array. (?)
-    fn compute_edge_id(edge: &Vec<Node>) -> EdgeID {
+    ///   length of the array. (?)
+    fn compute_edge_id(edge: &[Node]) -> EdgeID {
         let hasher_factory = RandomState::with_seeds(SEED1, SEED2, SEED3, SEED4);
-        let mut hasher = hasher_factory.build_hasher();
-        edge.hash(&mut hasher);
+        EdgeID::from(hasher_factory.hash_one(edge))
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Effectively groups a bipartite membership list: returns every group, in order of first appearance, with its
+    /// nodes in order of appearance and without repetitions.
+    fn compute_groups(memberships: &[(Node, i64)]) -> Vec<(i64, Vec<Node>)> {
+        let mut index: AHashMap<i64, usize> = AHashMap::new();
+        let mut groups: Vec<(i64, Vec<Node>)> = Vec::new();
+        let mut seen: AHashSet<(Node, i64)> = AHashSet::new();
+
+        for (node, group) in memberships.iter() {
+            let i = *index.entry(*group).or_insert_with(|| {
+                groups.push((*group, Vec::new()));
+                groups.len() - 1
+            });
 
-        hasher.finish()
+            if seen.insert((*node, *group)) {
+                groups[i].1.push(*node);
+            }
+        }
+        groups
     }
 
     /// `type Node = i64`  
@@ -1247,14 +1962,14 @@ impl Hypergraph {
     /// Effectively computes the conversion of an array to an hashset.
     ///
     /// # Parameters
-    /// - `array` : `&Vec<Node>` - Array to be converted.
+    /// - `array` : `&[Node]` - Array to be converted.
     ///
     /// # Returns
     /// - `AHashSet<Node>` - The corresponding hashset.
     ///
     /// # Performance
     /// - `O(n)`, where `n` is the length of the array.
-    fn compute_vec_to_set(array: &Vec<Node>) -> AHashSet<Node> {
+    fn compute_vec_to_set(array: &[Node]) -> AHashSet<Node> {
         let mut res = AHashSet::new();
 
         for v in array.iter() {
@@ -1263,6 +1978,52 @@ impl Hypergraph {
 
         res
     }
+
+    /// `type Node = i64`  
+    ///
+    /// Effectively computes the hyperedges incident to a node, with the given order or size, if any.
+    ///
+    /// # Returns
+    /// - `Result<Option<impl Iterator<Item = &Hyperedge>>, HypergraphError>` - `Ok` containing `Some` incident
+    ///   hyperedges, `None` if the node is not in the hypergraph. Returns `Err(HypergraphError::BothOrderAndSize)` if
+    ///   both `order` and `size` are specified.
+    fn compute_incident_hyperedges(
+        &self,
+        node: Node,
+        order: Option<usize>,
+        size: Option<usize>,
+    ) -> Result<Option<impl Iterator<Item = &Hyperedge>>, HypergraphError> {
+        if order.is_some() && size.is_some() {
+            return Err(HypergraphError::BothOrderAndSize);
+        }
+        let filter = order.map(|val| val + 1).or(size);
+
+        Ok(self.incidence_list.get(&node).map(move |edge_ids| {
+            edge_ids
+                .iter()
+                .map(|edge_id| &self.edge_list[edge_id])
+                .filter(move |hyperedge| filter.is_none_or(|len| hyperedge.nodes.len() == len))
+        }))
+    }
+
+    /// Effectively computes the clamp of a weight to a finite value: NaN becomes 0, while infinite values become the
+    /// largest (or lowest) finite `f64`.
+    ///
+    /// # Parameters
+    /// - `weight` : `f64` - The weight to be clamped.
+    ///
+    /// # Returns
+    /// - `f64` - The finite weight.
+    ///
+    /// # Performance
+    /// - `O(1)`
+    fn compute_finite_weight(weight: f64) -> f64 {
+        if weight.is_nan() {
+            0_f64
+        } else {
+            weight.clamp(f64::MIN, f64::MAX)
+        }
+    }
 }
 
 /*
@@ -0,0 +1,137 @@
+use ahash::AHashSet;
+
+use super::{Hypergraph, Node};
+
+/// Score used by `link_prediction_scores` to rank the pairs of nodes which may co-occur in a future hyperedge.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LinkPred {
+    /// The number of neighbors shared by the two nodes.
+    CommonNeighbors,
+
+    /// The sum of `1 / ln(d)` over the neighbors shared by the two nodes, where `d` is the number of neighbors of the
+    /// shared neighbor, so that the rarely connected ones count more.
+    AdamicAdar,
+}
+
+impl Hypergraph {
+    /// `type Node = i64`
+    ///
+    /// Returns the link prediction score of each pair of nodes, where two nodes are neighbors if they co-occur in at
+    /// least one hyperedge (as in `get_neighbors`).
+    ///
+    /// A pair with a node which is not in the hypergraph has score 0, as a node with no neighbors.
+    ///
+    /// # Parameters
+    /// - `pairs` : `&[(Node, Node)]` - The pairs of nodes to score.
+    /// - `method` : `LinkPred` - The score to compute.
+    ///
+    /// # Returns
+    /// - `Vec<f64>` - The score of each pair, in the same order as `pairs`.
+    ///
+    /// # Performance
+    /// - `O(p*d*k)`, where `p` is the number of pairs, `d` the maximum degree and `k` the maximum size of a hyperedge,
+    ///   plus the same cost for each shared neighbor with `LinkPred::AdamicAdar`.
+    pub fn link_prediction_scores(&self, pairs: &[(Node, Node)], method: LinkPred) -> Vec<f64> {
+        pairs
+            .iter()
+            .map(|(u, v)| {
+                let neighbors_u: AHashSet<Node> = self.iter_neighbors(*u).collect();
+                let common = self.iter_neighbors(*v).filter(|node| neighbors_u.contains(node));
+
+                match method {
+                    LinkPred::CommonNeighbors => common.count() as f64,
+                    LinkPred::AdamicAdar => common
+                        .map(|node| {
+                            // A shared neighbor is adjacent to both nodes, so it has at least 2 neighbors
+                            let degree = self.iter_neighbors(node).count() as f64;
+                            1_f64 / degree.ln()
+                        })
+                        .sum(),
+                }
+            })
+            .collect()
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Returns the pairs of nodes at distance 2, ie which do not co-occur in any hyperedge but share a neighbor. These
+    /// are the natural candidates to be scored by `link_prediction_scores`.
+    ///
+    /// # Parameters
+    /// - `max` : `usize` - The maximum number of pairs to return.
+    ///
+    /// # Returns
+    /// - `Vec<(Node, Node)>` - The pairs `(u, v)` with `u < v`, sorted, stopping at the first `max` ones.
+    ///
+    /// # Performance
+    /// - `O(n*log(n) + n*(d*k)^2)`, where `n` is the number of nodes, `d` the maximum degree and `k` the maximum size
+    ///   of a hyperedge.
+    pub fn candidate_pairs(&self, max: usize) -> Vec<(Node, Node)> {
+        let mut res = Vec::new();
+
+        for u in self.get_nodes_sorted() {
+            if res.len() >= max {
+                break;
+            }
+
+            let neighbors_u: AHashSet<Node> = self.iter_neighbors(u).collect();
+            let mut candidates: Vec<Node> = neighbors_u
+                .iter()
+                .flat_map(|neighbor| self.iter_neighbors(*neighbor))
+                .filter(|v| *v > u && !neighbors_u.contains(v))
+                .collect();
+            candidates.sort_unstable();
+            candidates.dedup();
+
+            res.extend(candidates.into_iter().take(max - res.len()).map(|v| (u, v)));
+        }
+
+        res
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    fn example() -> Hypergraph {
+        // 1 - 2 - 3 in a triangle, 3 - 4, 4 - 5 - 6 in a triangle
+        Hypergraph::from(&[vec![1, 2, 3], vec![3, 4], vec![4, 5, 6]])
+    }
+
+    #[test]
+    fn test_link_prediction_scores() {
+        let hg = example();
+        let pairs = [(1, 4), (4, 1), (2, 5), (3, 5), (1, 2), (1, 7), (8, 7)];
+
+        assert_eq!(
+            hg.link_prediction_scores(&pairs, LinkPred::CommonNeighbors),
+            vec![1.0, 1.0, 0.0, 1.0, 1.0, 0.0, 0.0]
+        );
+
+        // Neighbors: 3 has {1, 2, 4}, 4 has {3, 5, 6}
+        let scores = hg.link_prediction_scores(&pairs, LinkPred::AdamicAdar);
+        let expected = [1.0 / 3_f64.ln(), 1.0 / 3_f64.ln(), 0.0, 1.0 / 3_f64.ln(), 1.0 / 3_f64.ln(), 0.0, 0.0];
+        for (score, expected) in scores.iter().zip(expected) {
+            assert!((score - expected).abs() < 1e-12);
+        }
+
+        let hg = Hypergraph::from(&[vec![1, 2], vec![2, 3], vec![1, 4], vec![4, 3], vec![4, 5]]);
+        let scores = hg.link_prediction_scores(&[(1, 3)], LinkPred::AdamicAdar);
+        assert!((scores[0] - (1.0 / 2_f64.ln() + 1.0 / 3_f64.ln())).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_candidate_pairs() {
+        let hg = example();
+
+        assert_eq!(hg.candidate_pairs(usize::MAX), vec![(1, 4), (2, 4), (3, 5), (3, 6)]);
+        assert_eq!(hg.candidate_pairs(3), vec![(1, 4), (2, 4), (3, 5)]);
+        assert!(hg.candidate_pairs(0).is_empty());
+
+        let pairs = hg.candidate_pairs(usize::MAX);
+        for score in hg.link_prediction_scores(&pairs, LinkPred::CommonNeighbors) {
+            assert!(score > 0.0);
+        }
+    }
+}
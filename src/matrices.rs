@@ -0,0 +1,196 @@
+use ahash::AHashMap;
+
+use super::{Hypergraph, Node};
+
+impl Hypergraph {
+    /// `type Node = i64`
+    ///
+    /// Returns the Laplacian of the hypergraph as a sparse matrix.
+    ///
+    /// Two variants are available:
+    /// - combinatorial (`normalized == false`): `L = D - A`, where `A` is the weighted adjacency matrix of the clique
+    ///   expansion (every hyperedge `e` adds `w(e)` to `A[u][v]` for each pair of distinct members `u`, `v`) and `D` is
+    ///   the diagonal matrix of the row sums of `A`;
+    /// - normalized (`normalized == true`): the Zhou et al. hypergraph Laplacian
+    ///   `L = I - Dv^(-1/2) H W De^(-1) H^T Dv^(-1/2)`, where `Dv(v)` is the sum of the weights of the hyperedges incident
+    ///   to `v` and `De(e) = |e|`.
+    ///
+    /// If the hypergraph is not weighted, every hyperedge counts with weight `1`. A node repeated in a hyperedge counts
+    /// as a single member. Isolated nodes (and, in the normalized case, nodes whose incident hyperedges have total
+    /// weight `0`) correspond to zero rows and columns.
+    ///
+    /// # Parameters
+    /// - `normalized` : `bool` - If `true` the normalized Laplacian is computed, the combinatorial one otherwise.
+    ///
+    /// # Returns
+    /// - `(Vec<Node>, Vec<(usize, usize, f64)>)` - The nodes sorted in ascending order, which gives the index of each
+    ///   node in the matrix, and the non-zero entries as `(row, column, value)` triplets, sorted by row and column.
+    ///
+    /// # Performance
+    /// - `O(n*log(n) + m*k*k)`, where `n` and `m` are the number of nodes and hyperedges, and `k` the maximum size of a hyperedge.
+    pub fn laplacian(&self, normalized: bool) -> (Vec<Node>, Vec<(usize, usize, f64)>) {
+        let mut nodes = self.get_nodes();
+        nodes.sort_unstable();
+
+        let index: AHashMap<Node, usize> = nodes.iter().enumerate().map(|(i, node)| (*node, i)).collect();
+        let mut entries: AHashMap<(usize, usize), f64> = AHashMap::new();
+
+        // Repeated members count once, as in the incidence matrix
+        let hyperedges: Vec<(Vec<usize>, f64)> = self
+            .edge_list
+            .values()
+            .map(|hyperedge| {
+                let mut members: Vec<usize> = hyperedge.nodes.iter().map(|node| index[node]).collect();
+                members.sort_unstable();
+                members.dedup();
+                (members, self.compute_expansion_weight(hyperedge.weight))
+            })
+            .collect();
+
+        if normalized {
+            // Dv, the weighted degree of every node
+            let mut degrees = vec![0_f64; nodes.len()];
+            for (members, weight) in hyperedges.iter() {
+                for i in members.iter() {
+                    degrees[*i] += weight;
+                }
+            }
+
+            for (i, degree) in degrees.iter().enumerate() {
+                if *degree > 0_f64 {
+                    entries.insert((i, i), 1_f64);
+                }
+            }
+
+            // O(m*k*k)
+            for (members, weight) in hyperedges.iter() {
+                let size = members.len() as f64;
+
+                for i in members.iter() {
+                    for j in members.iter() {
+                        if degrees[*i] > 0_f64 && degrees[*j] > 0_f64 {
+                            let value = weight / (size * (degrees[*i] * degrees[*j]).sqrt());
+                            *entries.entry((*i, *j)).or_insert(0_f64) -= value;
+                        }
+                    }
+                }
+            }
+        } else {
+            // O(m*k*k)
+            for (members, weight) in hyperedges.iter() {
+                for i in members.iter() {
+                    for j in members.iter().filter(|j| *j != i) {
+                        *entries.entry((*i, *j)).or_insert(0_f64) -= weight;
+                        *entries.entry((*i, *i)).or_insert(0_f64) += weight;
+                    }
+                }
+            }
+        }
+
+        let mut triplets: Vec<(usize, usize, f64)> = entries
+            .into_iter()
+            .filter(|(_, value)| *value != 0_f64)
+            .map(|((i, j), value)| (i, j, value))
+            .collect();
+        triplets.sort_unstable_by_key(|(i, j, _)| (*i, *j));
+
+        (nodes, triplets)
+    }
+
+    /// Effectively computes the weight a hyperedge contributes to the weighted computations (Laplacian, expansions,
+    /// random walks): its weight if the hypergraph is weighted, `1` otherwise.
+    pub(crate) fn compute_expansion_weight(&self, weight: f64) -> f64 {
+        if self.weighted {
+            weight
+        } else {
+            1_f64
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    fn entry(triplets: &[(usize, usize, f64)], i: usize, j: usize) -> f64 {
+        triplets
+            .iter()
+            .find(|(row, col, _)| *row == i && *col == j)
+            .map_or(0_f64, |(_, _, value)| *value)
+    }
+
+    fn build() -> Hypergraph {
+        let mut hg = Hypergraph::from(&[vec![0, 1, 2], vec![2, 3]]);
+        hg.add_node(4);
+        hg
+    }
+
+    #[test]
+    fn test_laplacian_combinatorial() {
+        let (nodes, triplets) = build().laplacian(false);
+
+        assert_eq!(nodes, vec![0, 1, 2, 3, 4]);
+        assert_eq!(entry(&triplets, 0, 0), 2.0);
+        assert_eq!(entry(&triplets, 2, 2), 3.0);
+        assert_eq!(entry(&triplets, 3, 3), 1.0);
+        assert_eq!(entry(&triplets, 0, 1), -1.0);
+        assert_eq!(entry(&triplets, 2, 3), -1.0);
+        assert_eq!(entry(&triplets, 3, 2), -1.0);
+        assert_eq!(entry(&triplets, 0, 3), 0.0);
+
+        // The isolated node has a zero row
+        assert!(triplets.iter().all(|(i, j, _)| *i != 4 && *j != 4));
+
+        // Rows of a combinatorial Laplacian sum to zero
+        for row in 0..nodes.len() {
+            let sum: f64 = triplets.iter().filter(|(i, _, _)| *i == row).map(|(_, _, v)| *v).sum();
+            assert_eq!(sum, 0.0);
+        }
+    }
+
+    #[test]
+    fn test_laplacian_combinatorial_weighted() {
+        let mut hg = Hypergraph::new(true);
+        hg.add_edge_weighted(&[0, 1, 2], 2.0);
+        hg.add_edge_weighted(&[1, 2], 0.5);
+
+        let (_, triplets) = hg.laplacian(false);
+
+        assert_eq!(entry(&triplets, 0, 1), -2.0);
+        assert_eq!(entry(&triplets, 1, 2), -2.5);
+        assert_eq!(entry(&triplets, 1, 1), 4.5);
+    }
+
+    #[test]
+    fn test_laplacian_repeated_node() {
+        let repeated = Hypergraph::from(&[vec![0, 1, 1, 2], vec![2, 3]]);
+        let simple = Hypergraph::from(&[vec![0, 1, 2], vec![2, 3]]);
+
+        for normalized in [false, true] {
+            assert_eq!(repeated.laplacian(normalized), simple.laplacian(normalized));
+        }
+        let (_, triplets) = repeated.laplacian(false);
+        assert_eq!(entry(&triplets, 0, 1), -1.0);
+        assert_eq!(entry(&triplets, 1, 1), 2.0);
+    }
+
+    #[test]
+    fn test_laplacian_normalized() {
+        let (nodes, triplets) = build().laplacian(true);
+        let eps = 1e-12;
+
+        assert_eq!(nodes, vec![0, 1, 2, 3, 4]);
+        assert!((entry(&triplets, 0, 0) - 2.0 / 3.0).abs() < eps);
+        assert!((entry(&triplets, 0, 1) + 1.0 / 3.0).abs() < eps);
+        assert!((entry(&triplets, 2, 2) - 7.0 / 12.0).abs() < eps);
+        assert!((entry(&triplets, 2, 3) + 0.5 / 2_f64.sqrt()).abs() < eps);
+        assert!((entry(&triplets, 3, 2) - entry(&triplets, 2, 3)).abs() < eps);
+        assert!(triplets.iter().all(|(i, j, _)| *i != 4 && *j != 4));
+
+        // Triplets are sorted by row and column
+        let keys: Vec<(usize, usize)> = triplets.iter().map(|(i, j, _)| (*i, *j)).collect();
+        let mut sorted = keys.clone();
+        sorted.sort();
+        assert_eq!(keys, sorted);
+    }
+}
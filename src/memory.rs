@@ -0,0 +1,128 @@
+use std::mem::size_of;
+
+use ahash::AHashSet;
+
+use super::{EdgeID, Hyperedge, Hypergraph, Node};
+
+/// Estimated memory used by a hypergraph, in bytes, returned by `Hypergraph::memory_footprint`.
+///
+/// The estimate is based on the capacity of the collections, not on their length, so memory which is allocated but
+/// unused (eg after removals) is counted until `Hypergraph::shrink_to_fit` is called. The overhead of the allocator
+/// is not counted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct MemoryReport {
+    /// The hashmap from each node to its incidence list.
+    pub node_map: usize,
+
+    /// The incidence lists, ie the sets of the `EdgeID`s of each node.
+    pub incidence_sets: usize,
+
+    /// The hashmap from each `EdgeID` to its hyperedge.
+    pub edge_map: usize,
+
+    /// The lists of the nodes of the hyperedges.
+    pub edge_nodes: usize,
+}
+
+impl MemoryReport {
+    /// Returns the total estimated memory, in bytes.
+    ///
+    /// # Returns
+    /// - `usize` - The sum of all the fields.
+    pub fn total(&self) -> usize {
+        self.node_map + self.incidence_sets + self.edge_map + self.edge_nodes
+    }
+}
+
+impl Hypergraph {
+    /// Returns an estimate of the memory used by the hypergraph, see `MemoryReport`.
+    ///
+    /// Every slot of a hashmap is counted as its key, its value and one control byte.
+    ///
+    /// # Returns
+    /// - `MemoryReport` - The estimated bytes of each part of the hypergraph.
+    ///
+    /// # Performance
+    /// - `O(n + m)`, where `n` and `m` are the number of nodes and hyperedges.
+    pub fn memory_footprint(&self) -> MemoryReport {
+        let node_slot = size_of::<Node>() + size_of::<AHashSet<EdgeID>>() + 1;
+        let edge_id_slot = size_of::<EdgeID>() + 1;
+        let edge_slot = size_of::<EdgeID>() + size_of::<Hyperedge>() + 1;
+
+        MemoryReport {
+            node_map: self.incidence_list.capacity() * node_slot,
+            incidence_sets: self.incidence_list.values().map(|edge_ids| edge_ids.capacity() * edge_id_slot).sum(),
+            edge_map: self.edge_list.capacity() * edge_slot,
+            edge_nodes: self.edge_list.values().map(|hyperedge| hyperedge.nodes.capacity() * size_of::<Node>()).sum(),
+        }
+    }
+
+    /// Shrinks the capacity of all the inner collections as much as possible, eg to release the memory after
+    /// removing most of the hyperedges.
+    ///
+    /// # Returns
+    /// - `()`
+    ///
+    /// # Performance
+    /// - `O(n + m)`, where `n` and `m` are the number of nodes and hyperedges.
+    pub fn shrink_to_fit(&mut self) {
+        self.incidence_list.shrink_to_fit();
+        for edge_ids in self.incidence_list.values_mut() {
+            edge_ids.shrink_to_fit();
+        }
+
+        self.edge_list.shrink_to_fit();
+        for hyperedge in self.edge_list.values_mut() {
+            hyperedge.nodes.shrink_to_fit();
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shrink_to_fit() {
+        let edges: Vec<Vec<Node>> = (0..2000).map(|i| vec![i % 50, 50 + i]).collect();
+        let mut hg = Hypergraph::from(&edges);
+
+        let before = hg.memory_footprint();
+        assert!(before.node_map > 0 && before.incidence_sets > 0 && before.edge_map > 0 && before.edge_nodes > 0);
+
+        hg.remove_edges(&edges[..1800]);
+        hg.remove_isolated_nodes();
+        // Removals keep the memory allocated
+        let removed = hg.memory_footprint();
+        assert!(removed.total() > before.total() / 2);
+
+        hg.shrink_to_fit();
+        let after = hg.memory_footprint();
+        assert!(after.node_map < removed.node_map);
+        assert!(after.incidence_sets < removed.incidence_sets);
+        assert!(after.edge_map < removed.edge_map);
+        assert!(after.total() < before.total() / 2);
+        assert_eq!(hg.num_edges(), 200);
+        assert!(hg.check_edge(&edges[1999]));
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_memory_footprint_proportional() {
+        use crate::generators::{random_uniform, SplitMix64};
+
+        let mut rng = SplitMix64::new(3);
+        let mut small = random_uniform(1000, 3, 1000, &mut rng).unwrap();
+        let mut large = random_uniform(1000, 3, 8000, &mut rng).unwrap();
+        small.shrink_to_fit();
+        large.shrink_to_fit();
+
+        let (small, large) = (small.memory_footprint(), large.memory_footprint());
+        // The hashmaps grow by powers of 2, so the ratio is only roughly 8
+        let ratio = large.edge_map as f64 / small.edge_map as f64;
+        assert!((4.0..=16.0).contains(&ratio));
+        assert_eq!(large.edge_nodes, 8 * small.edge_nodes);
+        assert!(large.incidence_sets > small.incidence_sets);
+        assert!(large.node_map > 0 && large.node_map == small.node_map);
+    }
+}
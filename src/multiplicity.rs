@@ -0,0 +1,202 @@
+use super::{Hypergraph, Node};
+
+impl Hypergraph {
+    /// `type Node = i64`
+    ///
+    /// Adds an instance of a hyperedge, eg one more paper written by the same group of authors. If the hyperedge is
+    /// already in, its multiplicity is incremented and its weight is left unchanged; otherwise, it is added with
+    /// weight 0 and multiplicity 1.
+    ///
+    /// Every hyperedge has multiplicity 1 when added by the other methods, which do not change it, and `remove_edge`
+    /// removes all of its instances (see `remove_edge_multi`). When `remove_node`, `contract_nodes` or `relabel_nodes`
+    /// merge two hyperedges, their multiplicities are summed.
+    ///
+    /// # Parameters
+    /// - `edge` : `&[Node]` - Hyperedge to insert.
+    ///
+    /// # Returns
+    /// - `usize` - The multiplicity of the hyperedge after the insertion.
+    ///
+    /// # Performance
+    /// - `O(k)`, where `k` is the size of the hyperedge.
+    pub fn add_edge_multi(&mut self, edge: &[Node]) -> usize {
+        let edge_id = Self::compute_edge_id(edge);
+        match self.edge_list.get_mut(&edge_id) {
+            Some(hyperedge) => {
+                hyperedge.multiplicity += 1;
+                hyperedge.multiplicity
+            }
+            None => {
+                let policy = self.merge_policy;
+                Self::compute_add_edge_with_id(self, edge, edge_id, 0_f64, policy);
+                1
+            }
+        }
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Returns the number of instances of a hyperedge.
+    ///
+    /// # Parameters
+    /// - `edge` : `&[Node]` - The hyperedge.
+    ///
+    /// # Returns
+    /// - `Option<usize>` - The multiplicity of the hyperedge, `None` if it is not in the hypergraph.
+    ///
+    /// # Performance
+    /// - `O(k)`, where `k` is the size of the hyperedge.
+    pub fn multiplicity(&self, edge: &[Node]) -> Option<usize> {
+        self.edge_list.get(&Self::compute_edge_id(edge)).map(|hyperedge| hyperedge.multiplicity)
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Removes one instance of a hyperedge, decrementing its multiplicity. The hyperedge is removed, as in
+    /// `remove_edge`, only when its last instance is.
+    ///
+    /// # Parameters
+    /// - `edge` : `&[Node]` - The hyperedge.
+    ///
+    /// # Returns
+    /// - `Option<usize>` - The multiplicity of the hyperedge after the removal, `Some(0)` if it was removed. `None` if
+    ///   the hyperedge is not in the hypergraph.
+    ///
+    /// # Performance
+    /// - `O(k)`, where `k` is the size of the hyperedge.
+    pub fn remove_edge_multi(&mut self, edge: &[Node]) -> Option<usize> {
+        let edge_id = Self::compute_edge_id(edge);
+        let hyperedge = self.edge_list.get_mut(&edge_id)?;
+
+        hyperedge.multiplicity -= 1;
+        let res = hyperedge.multiplicity;
+        if res == 0 {
+            self.remove_edge_by_id(edge_id);
+        }
+        Some(res)
+    }
+
+    /// Returns the number of instances of all the hyperedges, ie `num_edges` counting the multiplicities.
+    ///
+    /// # Returns
+    /// - `usize` - The sum of the multiplicities of the hyperedges.
+    ///
+    /// # Performance
+    /// - `O(m)`, where `m` is the number of hyperedges.
+    pub fn num_edge_instances(&self) -> usize {
+        self.edge_list.values().map(|hyperedge| hyperedge.multiplicity).sum()
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Returns the degree of a node, ie the number of hyperedges incident to it.
+    ///
+    /// # Parameters
+    /// - `node` : `Node` - The node.
+    /// - `count_multiplicities` : `bool` - If `true`, every hyperedge counts as many times as its multiplicity.
+    ///
+    /// # Returns
+    /// - `Option<usize>` - The degree of the node, `None` if the node is not in the hypergraph.
+    ///
+    /// # Performance
+    /// - `O(1)` expected, `O(d)` if `count_multiplicities` is `true`, where `d` is the degree of the node.
+    pub fn node_degree(&self, node: Node, count_multiplicities: bool) -> Option<usize> {
+        let edge_ids = self.incidence_list.get(&node)?;
+        if count_multiplicities {
+            Some(edge_ids.iter().map(|edge_id| self.edge_list[edge_id].multiplicity).sum())
+        } else {
+            Some(edge_ids.len())
+        }
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Returns the weighted degree (or strength) of a node, ie the sum of the weights of the hyperedges incident to it.
+    ///
+    /// # Parameters
+    /// - `node` : `Node` - The node.
+    /// - `count_multiplicities` : `bool` - If `true`, the weight of every hyperedge is multiplied by its multiplicity.
+    ///
+    /// # Returns
+    /// - `Option<f64>` - The weighted degree of the node, 0 if the hypergraph is not weighted. `None` if the node is
+    ///   not in the hypergraph.
+    ///
+    /// # Performance
+    /// - `O(d)`, where `d` is the degree of the node.
+    pub fn weighted_degree(&self, node: Node, count_multiplicities: bool) -> Option<f64> {
+        let edge_ids = self.incidence_list.get(&node)?;
+        Some(
+            edge_ids
+                .iter()
+                .map(|edge_id| {
+                    let hyperedge = &self.edge_list[edge_id];
+                    let copies = if count_multiplicities { hyperedge.multiplicity } else { 1 };
+                    hyperedge.weight * copies as f64
+                })
+                .sum(),
+        )
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    #[test]
+    fn test_multiplicity() {
+        let mut hg = Hypergraph::new(true);
+        assert_eq!(hg.add_edge_multi(&[1, 2]), 1);
+        assert_eq!(hg.add_edge_multi(&[1, 2]), 2);
+        assert_eq!(hg.add_edge_multi(&[1, 2]), 3);
+        hg.add_edge_weighted(&[1, 3], 2.0);
+        hg.set_weight(&[1, 2], 0.5).unwrap();
+
+        assert_eq!(hg.multiplicity(&[1, 2]), Some(3));
+        assert_eq!(hg.multiplicity(&[2, 1]), None);
+        assert_eq!(hg.num_edges(), 2);
+        assert_eq!(hg.num_edge_instances(), 4);
+
+        assert_eq!(hg.remove_edge_multi(&[1, 2]), Some(2));
+        assert_eq!(hg.num_edge_instances(), 3);
+        assert_eq!(hg.node_degree(1, false), Some(2));
+        assert_eq!(hg.node_degree(1, true), Some(3));
+        assert_eq!(hg.node_degree(2, true), Some(2));
+        assert_eq!(hg.node_degree(4, true), None);
+        assert_eq!(hg.weighted_degree(1, false), Some(2.5));
+        assert_eq!(hg.weighted_degree(1, true), Some(3.0));
+
+        // Adding it again the usual way does not change the multiplicity
+        hg.add_edge(&[1, 2]);
+        assert_eq!(hg.multiplicity(&[1, 2]), Some(2));
+
+        assert_eq!(hg.remove_edge_multi(&[1, 2]), Some(1));
+        assert_eq!(hg.remove_edge_multi(&[1, 2]), Some(0));
+        assert!(!hg.check_edge(&[1, 2]));
+        assert_eq!(hg.remove_edge_multi(&[1, 2]), None);
+        assert_eq!(hg.node_degree(2, true), Some(0));
+    }
+
+    #[test]
+    fn test_multiplicity_remove_node() {
+        let mut hg = Hypergraph::from(&[vec![2, 3], vec![1, 4]]);
+        hg.add_edge_multi(&[1, 2, 3]);
+        hg.add_edge_multi(&[1, 2, 3]);
+        hg.add_edge_multi(&[1, 4]);
+
+        // [1, 2, 3] is merged into [2, 3], and [1, 4] is moved to [4]
+        hg.remove_node(1);
+        assert_eq!(hg.multiplicity(&[2, 3]), Some(3));
+        assert_eq!(hg.multiplicity(&[4]), Some(2));
+
+        hg.contract_nodes(&[2, 4], 4, crate::MergePolicy::Replace, true).unwrap();
+        assert_eq!(hg.multiplicity(&[4, 3]), Some(3));
+
+        let sub = hg.subhypergraph(&[3, 4]);
+        assert_eq!(sub.multiplicity(&[4, 3]), Some(3));
+        assert_eq!(hg.clone(), hg);
+
+        let mut single = hg.clone();
+        single.remove_edge_multi(&[4, 3]);
+        assert_ne!(single, hg);
+    }
+}
@@ -0,0 +1,189 @@
+// Parallel algorithms, available with the `rayon` feature.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use ahash::{AHashMap, AHashSet};
+use rayon::prelude::*;
+
+use super::{EdgeID, Hypergraph, Node};
+
+type Component = AHashSet<Node>;
+
+impl Hypergraph {
+    /// `type Node = i64`
+    ///
+    /// Creates an unweighted `Hypergraph` from a list of hyperedges, as `from`, hashing the hyperedges in parallel.
+    ///
+    /// # Parameters
+    /// - `_edge_list`: (`&[Vec<Node>]`) - List of hyperedges, each represented as a vector of nodes.
+    ///
+    /// # Returns
+    /// - `Self` - A new instance of `Hypergraph`, equal to the one built by `from`.
+    ///
+    /// # Performance
+    /// - `O(l*k/p + l*k)`, where `l` is the length of `_edge_list`, `k` the maximum size of a hyperedge and `p` the
+    ///   number of threads: the hashing is parallel, while the insertion is sequential.
+    pub fn from_par(_edge_list: &[Vec<Node>]) -> Self {
+        let mut result = Self::with_capacity(false, 0, _edge_list.len());
+        result.add_edges_par(_edge_list);
+        result
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Adds a list of hyperedges, with weight 0, to the hypergraph, as `add_edges`. The `EdgeID`s of the hyperedges
+    /// are computed in parallel, then the hyperedges are inserted in the order of the list, so duplicates and weights
+    /// are handled exactly as in `add_edges`.
+    ///
+    /// # Parameters
+    /// - `edges` : `&[Vec<Node>]` - Hyperedges to insert.
+    ///
+    /// # Returns
+    /// - `bool` - `true` if all hyperedges were not already in, `false` otherwise.
+    ///
+    /// # Performance
+    /// - `O(l*k/p + l*k)`, where `l` is the length of `edges`, `k` the maximum size of a hyperedge and `p` the number
+    ///   of threads.
+    pub fn add_edges_par(&mut self, edges: &[Vec<Node>]) -> bool {
+        let edge_ids: Vec<EdgeID> = edges.par_iter().map(|edge| Self::compute_edge_id(edge)).collect();
+        self.reserve_edges(edges.len());
+
+        let policy = self.merge_policy;
+        let mut res = true;
+        for (edge, edge_id) in edges.iter().zip(edge_ids) {
+            res &= Self::compute_add_edge_with_id(self, edge, edge_id, 0_f64, policy);
+        }
+        res
+    }
+
+    /// `type Node = i64`
+    /// `type Component = AHashSet<Node>`
+    ///
+    /// Returns the connected components of the hypergraph, as `ccs` with no order or size, computed in parallel with
+    /// a concurrent union-find: the hyperedges are processed in parallel, each one merging the sets of its nodes.
+    ///
+    /// The components are the same as the ones of `ccs`, but their order may differ.
+    ///
+    /// # Returns
+    /// - `Vec<Component>` - The list of connected components, empty if the hypergraph has no nodes.
+    ///
+    /// # Performance
+    /// - `O((n + I)*α(n)/p)` expected, where `n` is the number of nodes, `I` the sum of the sizes of the hyperedges,
+    ///   `p` the number of threads and `α` the inverse Ackermann function.
+    pub fn par_ccs(&self) -> Vec<Component> {
+        let nodes: Vec<Node> = self.incidence_list.keys().copied().collect();
+        let index: AHashMap<Node, usize> = nodes.iter().enumerate().map(|(i, node)| (*node, i)).collect();
+        let parent: Vec<AtomicUsize> = (0..nodes.len()).map(AtomicUsize::new).collect();
+
+        self.edge_list.par_iter().for_each(|(_, hyperedge)| {
+            if let Some((first, others)) = hyperedge.nodes.split_first() {
+                for node in others.iter() {
+                    compute_union(&parent, index[first], index[node]);
+                }
+            }
+        });
+
+        let mut components: AHashMap<usize, Component> = AHashMap::new();
+        for (i, node) in nodes.iter().enumerate() {
+            components.entry(compute_find(&parent, i)).or_default().insert(*node);
+        }
+        components.into_values().collect()
+    }
+}
+
+/// Effectively finds the root of the set of `x`, halving the path on the way.
+fn compute_find(parent: &[AtomicUsize], mut x: usize) -> usize {
+    loop {
+        let p = parent[x].load(Ordering::Acquire);
+        if p == x {
+            return x;
+        }
+        let grandparent = parent[p].load(Ordering::Acquire);
+        // Losing the race only means that the path is not shortened
+        let _ = parent[x].compare_exchange(p, grandparent, Ordering::AcqRel, Ordering::Acquire);
+        x = grandparent;
+    }
+}
+
+/// Effectively merges the sets of `a` and `b`. The root with the larger index is always linked to the other one, so
+/// no cycle can arise.
+fn compute_union(parent: &[AtomicUsize], a: usize, b: usize) {
+    loop {
+        let (root_a, root_b) = (compute_find(parent, a), compute_find(parent, b));
+        if root_a == root_b {
+            return;
+        }
+
+        let (high, low) = if root_a > root_b { (root_a, root_b) } else { (root_b, root_a) };
+        // Fails if `high` is not a root anymore, then the roots are found again
+        if parent[high].compare_exchange(high, low, Ordering::AcqRel, Ordering::Acquire).is_ok() {
+            return;
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    fn sorted(components: Vec<Component>) -> Vec<Vec<Node>> {
+        let mut res: Vec<Vec<Node>> = components
+            .into_iter()
+            .map(|component| {
+                let mut nodes: Vec<Node> = component.into_iter().collect();
+                nodes.sort();
+                nodes
+            })
+            .collect();
+        res.sort();
+        res
+    }
+
+    #[test]
+    fn test_par_ccs() {
+        let mut hg = Hypergraph::from(&[vec![1, 2, 3], vec![3, 4], vec![5], vec![9, 10], vec![10, 11, 9]]);
+        hg.add_node(7);
+
+        assert_eq!(sorted(hg.par_ccs()), vec![vec![1, 2, 3, 4], vec![5], vec![7], vec![9, 10, 11]]);
+        assert!(Hypergraph::new(false).par_ccs().is_empty());
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_par_ccs_random() {
+        use crate::generators::{random_uniform, SplitMix64};
+
+        let mut rng = SplitMix64::new(11);
+        for (n, k, m) in [(100, 2, 40), (200, 3, 60), (500, 2, 400), (50, 5, 30)] {
+            let hg = random_uniform(n, k, m, &mut rng).unwrap();
+            assert_eq!(sorted(hg.par_ccs()), sorted(hg.ccs(None, None).unwrap()));
+        }
+    }
+
+    #[test]
+    fn test_add_edges_par() {
+        let edges = vec![vec![1, 2, 3], vec![3, 4], vec![1, 2, 3], vec![5], vec![4, 3], vec![]];
+
+        assert_eq!(Hypergraph::from_par(&edges), Hypergraph::from(&edges));
+
+        let mut sequential = Hypergraph::from_weighted(&[vec![3, 4], vec![6, 7]], &[2.0, 3.0]);
+        let mut parallel = sequential.clone();
+        assert_eq!(parallel.add_edges_par(&edges), sequential.add_edges(&edges));
+        assert_eq!(parallel, sequential);
+        assert_eq!(parallel.get_weight(&[3, 4]), Some(0.0));
+        assert!(parallel.add_edges_par(&[vec![8]]));
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_from_par_random() {
+        use crate::generators::{Rng, SplitMix64};
+
+        let mut rng = SplitMix64::new(5);
+        let edges: Vec<Vec<Node>> = (0..2000)
+            .map(|_| (0..1 + rng.gen_index(4)).map(|_| rng.gen_index(100) as Node).collect())
+            .collect();
+
+        assert_eq!(Hypergraph::from_par(&edges), Hypergraph::from(&edges));
+    }
+}
@@ -0,0 +1,498 @@
+use std::cmp::Ordering;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, VecDeque};
+
+use ahash::{AHashMap, AHashSet};
+
+use super::visits::_distances_from;
+use super::{EdgeID, Hypergraph, HypergraphError, Node};
+
+/// `type Node = i64`
+///
+/// Entry of the priority queue of Dijkstra's algorithm, ordered so that the `BinaryHeap` pops the lowest cost first.
+#[derive(PartialEq)]
+struct State {
+    cost: f64,
+    node: Node,
+}
+
+impl Eq for State {}
+
+impl Ord for State {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.cost.total_cmp(&self.cost).then_with(|| other.node.cmp(&self.node))
+    }
+}
+
+impl PartialOrd for State {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Hypergraph {
+    /// `type Node = i64`
+    ///
+    /// Returns the s-distance between two hyperedges, ie the length of the shortest s-walk between them: a sequence of
+    /// hyperedges where every two consecutive ones share at least `s` nodes.
+    ///
+    /// The search is a BFS on the s-line graph of the hypergraph, whose neighbors are computed lazily from the
+    /// incidences of the hyperedge being expanded. Since consecutive hyperedges have to intersect, `s` equal to `0`
+    /// is treated as `1`.
+    ///
+    /// # Parameters
+    /// - `edge_a` : `&[Node]` - The first hyperedge.
+    /// - `edge_b` : `&[Node]` - The second hyperedge.
+    /// - `s` : `usize` - The minimum number of nodes shared by consecutive hyperedges.
+    ///
+    /// # Returns
+    /// - `Option<usize>` - `Some` s-distance between the hyperedges (`0` if they are the same), `None` if they are not
+    ///   connected by any s-walk or if one of them is not in the hypergraph.
+    ///
+    /// # Performance
+    /// - `O(m*k*d)`, where `m` is the number of hyperedges, `k` their maximum size and `d` the maximum degree of a node.
+    pub fn s_distance(&self, edge_a: &[Node], edge_b: &[Node], s: usize) -> Option<usize> {
+        let (start, target) = (Self::compute_edge_id(edge_a), Self::compute_edge_id(edge_b));
+        if !self.edge_list.contains_key(&start) || !self.edge_list.contains_key(&target) {
+            return None;
+        }
+
+        let s = s.max(1);
+        let mut visited: AHashSet<EdgeID> = AHashSet::new();
+        let mut queue = VecDeque::new();
+        visited.insert(start);
+        queue.push_back((start, 0));
+
+        while let Some((now, distance)) = queue.pop_front() {
+            if now == target {
+                return Some(distance);
+            }
+
+            // Number of nodes shared with every intersecting hyperedge
+            let members: AHashSet<Node> = self.edge_list[&now].nodes.iter().copied().collect();
+            let mut shared: AHashMap<EdgeID, usize> = AHashMap::new();
+            for node in members.iter() {
+                for id in self.incidence_list[node].iter() {
+                    *shared.entry(*id).or_insert(0) += 1;
+                }
+            }
+
+            for (id, count) in shared {
+                if count >= s && visited.insert(id) {
+                    queue.push_back((id, distance + 1));
+                }
+            }
+        }
+
+        None
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Returns a shortest path between two nodes, where moving between two nodes of the same hyperedge counts as one
+    /// step.
+    ///
+    /// The search is a BFS keeping track of the predecessor of every visited node, which stops as soon as `to` is
+    /// reached.
+    ///
+    /// # Parameters
+    /// - `from` : `Node` - The first node of the path.
+    /// - `to` : `Node` - The last node of the path.
+    /// - `order` : `Option<usize>` - `Some` order of the hyperedges to consider. If `None` all hyperedges are considered.
+    /// - `size` : `Option<usize>` - `Some` size of the hyperedges to consider. If `None` all hyperedges are considered.
+    ///
+    /// # Returns
+    /// - `Result<Option<Vec<Node>>, HypergraphError>` - `Ok` containing `Some` sequence of nodes of the path, from `from` to `to`,
+    ///   or `None` if `to` is not reachable from `from` or one of them is not in the hypergraph. Returns
+    ///   `Err(HypergraphError::BothOrderAndSize)` if both `order` and `size` are specified.
+    ///
+    /// # Performance
+    /// - `O(n + I*log(I))`, where `n` is the number of nodes and `I` the sum of the sizes of the hyperedges.
+    pub fn shortest_path(&self, from: Node, to: Node, order: Option<usize>, size: Option<usize>) -> Result<Option<Vec<Node>>, HypergraphError> {
+        if order.is_some() && size.is_some() {
+            return Err(HypergraphError::BothOrderAndSize);
+        }
+        if !self.check_node(from) || !self.check_node(to) {
+            return Ok(None);
+        }
+
+        let mut predecessors: AHashMap<Node, Node> = AHashMap::new();
+        let mut queue = VecDeque::new();
+        predecessors.insert(from, from);
+        queue.push_back(from);
+
+        while let Some(now) = queue.pop_front() {
+            if now == to {
+                let mut path = vec![to];
+                let mut node = to;
+                while node != from {
+                    node = predecessors[&node];
+                    path.push(node);
+                }
+                path.reverse();
+                return Ok(Some(path));
+            }
+
+            if let Ok(Some(neighbors)) = self.get_neighbors(now, order, size) {
+                for neighbor in neighbors {
+                    if let Entry::Vacant(entry) = predecessors.entry(neighbor) {
+                        entry.insert(now);
+                        queue.push_back(neighbor);
+                    }
+                }
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Computes the cost of the cheapest path from a given node to every node reachable from it, with Dijkstra's
+    /// algorithm.
+    ///
+    /// Moving between two nodes of the same hyperedge costs the weight of the hyperedge, or `1` if the hypergraph is not
+    /// weighted. Each hyperedge is relaxed once, the first time one of its members is extracted from the queue, which
+    /// updates all its other members at once.
+    ///
+    /// # Parameters
+    /// - `start` : `Node` - The node to start the search from.
+    /// - `order` : `Option<usize>` - `Some` order of the hyperedges to consider. If `None` all hyperedges are considered.
+    /// - `size` : `Option<usize>` - `Some` size of the hyperedges to consider. If `None` all hyperedges are considered.
+    ///
+    /// # Returns
+    /// - `Result<AHashMap<Node, f64>, HypergraphError>` - `Ok` containing the cost of every reachable node, `start` included with
+    ///   cost `0` (or an empty map if `start` is not in the hypergraph). Returns
+    ///   `Err(HypergraphError::BothOrderAndSize)` if both `order` and `size` are specified, or
+    ///   `Err(HypergraphError::InvalidWeight)` if a considered hyperedge has a negative or NaN weight.
+    ///
+    /// # Performance
+    /// - `O((n + s)*log(n))`, where `n` is the number of nodes and `s` the sum of the sizes of the hyperedges.
+    pub fn dijkstra(&self, start: Node, order: Option<usize>, size: Option<usize>) -> Result<AHashMap<Node, f64>, HypergraphError> {
+        if order.is_some() && size.is_some() {
+            return Err(HypergraphError::BothOrderAndSize);
+        }
+
+        let filter = order.map(|value| value + 1).or(size);
+        let accepted = |id: &EdgeID| filter.is_none_or(|value| self.edge_list[id].nodes.len() == value);
+        let invalid = |weight: f64| weight < 0_f64 || weight.is_nan();
+        if self
+            .edge_list
+            .iter()
+            .any(|(id, hyperedge)| accepted(id) && invalid(self.compute_expansion_weight(hyperedge.weight)))
+        {
+            return Err(HypergraphError::InvalidWeight);
+        }
+
+        let mut costs: AHashMap<Node, f64> = AHashMap::new();
+        if !self.check_node(start) {
+            return Ok(costs);
+        }
+
+        let mut done: AHashSet<Node> = AHashSet::new();
+        let mut relaxed: AHashSet<EdgeID> = AHashSet::new();
+        let mut heap = BinaryHeap::new();
+        costs.insert(start, 0_f64);
+        heap.push(State { cost: 0_f64, node: start });
+
+        while let Some(State { cost, node }) = heap.pop() {
+            if !done.insert(node) {
+                continue;
+            }
+
+            for id in self.incidence_list[&node].iter() {
+                if !accepted(id) || !relaxed.insert(*id) {
+                    continue;
+                }
+
+                let hyperedge = &self.edge_list[id];
+                let next = cost + self.compute_expansion_weight(hyperedge.weight);
+                for member in hyperedge.nodes.iter() {
+                    if costs.get(member).is_none_or(|current| next < *current) {
+                        costs.insert(*member, next);
+                        heap.push(State { cost: next, node: *member });
+                    }
+                }
+            }
+        }
+
+        Ok(costs)
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Computes the distance, in number of hops, between every pair of connected nodes, running a BFS from every node.
+    ///
+    /// Both `(u, v)` and `(v, u)` are in the result, as well as `(u, u)` with distance `0`; pairs of nodes in different
+    /// connected components are not.
+    ///
+    /// # Returns
+    /// - `AHashMap<(Node, Node), usize>` - The distance of every pair of connected nodes.
+    ///
+    /// # Performance
+    /// - `O(n*(n + I*log(I)))`, where `n` is the number of nodes and `I` the sum of the sizes of the hyperedges.
+    pub fn all_pairs_distances(&self) -> AHashMap<(Node, Node), usize> {
+        let mut res = AHashMap::new();
+
+        for from in self.incidence_list.keys() {
+            for (to, distance) in _distances_from(self, *from, None, None, None) {
+                res.insert((*from, to), distance);
+            }
+        }
+
+        res
+    }
+
+    /// Computes the average distance between two distinct nodes of the largest connected component.
+    ///
+    /// If `sample` is `Some(k)`, the distances are only computed from `k` sources, evenly spread among the nodes of the
+    /// component sorted in ascending order, which estimates the average at a fraction of the cost on large hypergraphs.
+    ///
+    /// # Parameters
+    /// - `sample` : `Option<usize>` - `Some` number of source nodes to estimate the average from. If `None`, or greater
+    ///   than the size of the component, the average is exact.
+    ///
+    /// # Returns
+    /// - `Option<f64>` - `Some` average path length, `None` if the largest connected component has fewer than two nodes
+    ///   or `sample` is `Some(0)`.
+    ///
+    /// # Performance
+    /// - `O(k*(n + I*log(I)))`, where `n` is the number of nodes, `I` the sum of the sizes of the hyperedges and `k` the
+    ///   number of sources.
+    pub fn average_path_length(&self, sample: Option<usize>) -> Option<f64> {
+        let mut component: Vec<Node> = self.largest_cc(None, None).ok()?.into_iter().collect();
+        component.sort_unstable();
+        if component.len() < 2 {
+            return None;
+        }
+
+        let sources: Vec<Node> = match sample {
+            Some(0) => return None,
+            Some(k) if k < component.len() => (0..k).map(|i| component[i * component.len() / k]).collect(),
+            _ => component.clone(),
+        };
+
+        let total: usize = sources
+            .iter()
+            .map(|source| _distances_from(self, *source, None, None, None).values().sum::<usize>())
+            .sum();
+
+        Some(total as f64 / (sources.len() * (component.len() - 1)) as f64)
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Returns the eccentricity of a node, ie the maximum distance between it and any node of its connected component.
+    ///
+    /// # Parameters
+    /// - `node` : `Node` - The node to consider.
+    ///
+    /// # Returns
+    /// - `Option<usize>` - `Some` eccentricity of the node (`0` if it is isolated), `None` if it is not in the hypergraph.
+    ///
+    /// # Performance
+    /// - `O(n + I*log(I))`, where `n` is the number of nodes and `I` the sum of the sizes of the hyperedges.
+    pub fn eccentricity(&self, node: Node) -> Option<usize> {
+        _distances_from(self, node, None, None, None).into_values().max()
+    }
+
+    /// Returns the diameter of the largest connected component, ie the maximum eccentricity of its nodes.
+    ///
+    /// The diameter is computed exactly, running a BFS from every node of the component; see `diameter_approx` for a
+    /// faster estimate.
+    ///
+    /// # Returns
+    /// - `Option<usize>` - `Some` diameter, `None` if the hypergraph has no nodes.
+    ///
+    /// # Performance
+    /// - `O(c*(n + I*log(I)))`, where `c` is the size of the largest component, `n` the number of nodes and `I` the
+    ///   sum of the sizes of the hyperedges.
+    pub fn diameter(&self) -> Option<usize> {
+        let component = self.largest_cc(None, None).ok()?;
+        component.iter().filter_map(|node| self.eccentricity(*node)).max()
+    }
+
+    /// Returns a lower bound of the diameter of the largest connected component, computed with the double-sweep
+    /// heuristic: a BFS from a node finds a farthest node `u`, whose eccentricity is the estimate.
+    ///
+    /// The bound is exact on many real-world hypergraphs and on trees, and needs only two BFS.
+    ///
+    /// # Returns
+    /// - `Option<usize>` - `Some` lower bound of the diameter, `None` if the hypergraph has no nodes.
+    ///
+    /// # Performance
+    /// - `O(n + I*log(I))`, where `n` is the number of nodes and `I` the sum of the sizes of the hyperedges.
+    pub fn diameter_approx(&self) -> Option<usize> {
+        let start = self.largest_cc(None, None).ok()?.into_iter().min()?;
+
+        // Farthest node, the smallest one among the ties
+        let (farthest, _) = _distances_from(self, start, None, None, None)
+            .into_iter()
+            .max_by(|(a, da), (b, db)| da.cmp(db).then_with(|| b.cmp(a)))?;
+
+        self.eccentricity(farthest)
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    #[test]
+    fn test_s_distance() {
+        let hg = Hypergraph::from(&[
+            vec![0, 1, 2],
+            vec![1, 2, 3],
+            vec![2, 3, 11, 12],
+            vec![10, 11, 12],
+            vec![12, 20],
+            vec![7, 8],
+        ]);
+
+        assert_eq!(hg.s_distance(&[0, 1, 2], &[0, 1, 2], 1), Some(0));
+        assert_eq!(hg.s_distance(&[0, 1, 2], &[12, 20], 1), Some(2));
+        assert_eq!(hg.s_distance(&[0, 1, 2], &[12, 20], 0), Some(2));
+        assert_eq!(hg.s_distance(&[0, 1, 2], &[12, 20], 2), None);
+
+        // With s = 2 the shortcut through node 2 is no longer available
+        assert_eq!(hg.s_distance(&[0, 1, 2], &[10, 11, 12], 1), Some(2));
+        assert_eq!(hg.s_distance(&[0, 1, 2], &[10, 11, 12], 2), Some(3));
+        assert_eq!(hg.s_distance(&[10, 11, 12], &[0, 1, 2], 2), Some(3));
+        assert_eq!(hg.s_distance(&[0, 1, 2], &[10, 11, 12], 3), None);
+    }
+
+    #[test]
+    fn test_s_distance_missing() {
+        let hg = Hypergraph::from(&[vec![0, 1, 2], vec![7, 8]]);
+
+        assert_eq!(hg.s_distance(&[0, 1, 2], &[7, 8], 1), None);
+        assert_eq!(hg.s_distance(&[0, 1, 2], &[0, 1], 1), None);
+        assert_eq!(hg.s_distance(&[9], &[9], 1), None);
+    }
+
+    #[test]
+    fn test_shortest_path() {
+        let mut hg = Hypergraph::from(&[vec![0, 1], vec![1, 2], vec![2, 3], vec![3, 4], vec![0, 10, 11, 12, 13, 4], vec![20, 21]]);
+        hg.add_node(30);
+
+        // Through the large hyperedge
+        assert_eq!(hg.shortest_path(0, 4, None, None), Ok(Some(vec![0, 4])));
+        assert_eq!(hg.shortest_path(1, 12, None, None), Ok(Some(vec![1, 0, 12])));
+
+        // Only through the pairwise hyperedges
+        assert_eq!(hg.shortest_path(0, 4, None, Some(2)), Ok(Some(vec![0, 1, 2, 3, 4])));
+        assert_eq!(hg.shortest_path(0, 4, Some(1), None), Ok(Some(vec![0, 1, 2, 3, 4])));
+        assert_eq!(hg.shortest_path(0, 4, Some(1), Some(2)).unwrap_err(), HypergraphError::BothOrderAndSize);
+
+        assert_eq!(hg.shortest_path(3, 3, None, None), Ok(Some(vec![3])));
+        assert_eq!(hg.shortest_path(0, 20, None, None), Ok(None));
+        assert_eq!(hg.shortest_path(0, 30, None, None), Ok(None));
+        assert_eq!(hg.shortest_path(0, 99, None, None), Ok(None));
+        assert_eq!(hg.shortest_path(99, 99, None, None), Ok(None));
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let mut hg = Hypergraph::new(true);
+        hg.add_edge_weighted(&[0, 3], 10.0);
+        hg.add_edge_weighted(&[0, 1, 5], 2.0);
+        hg.add_edge_weighted(&[1, 2], 1.5);
+        hg.add_edge_weighted(&[2, 3], 3.0);
+        hg.add_edge_weighted(&[7, 8], 1.0);
+        hg.add_node(9);
+
+        let costs = hg.dijkstra(0, None, None).unwrap();
+        assert_eq!(costs[&0], 0.0);
+        assert_eq!(costs[&5], 2.0);
+        assert_eq!(costs[&2], 3.5);
+        // The cheaper two-hop route wins over the heavy direct hyperedge
+        assert_eq!(costs[&3], 6.5);
+        assert!(!costs.contains_key(&7));
+        assert!(!costs.contains_key(&9));
+        assert_eq!(costs.len(), 5);
+
+        // Only the pairwise hyperedges
+        let costs = hg.dijkstra(0, None, Some(2)).unwrap();
+        assert_eq!(costs[&3], 10.0);
+        assert_eq!(costs[&2], 13.0);
+        assert_eq!(costs[&1], 14.5);
+        assert!(!costs.contains_key(&5));
+
+        assert!(hg.dijkstra(42, None, None).unwrap().is_empty());
+        assert_eq!(hg.dijkstra(0, Some(1), Some(2)).unwrap_err(), HypergraphError::BothOrderAndSize);
+    }
+
+    #[test]
+    fn test_dijkstra_weights() {
+        let hg = Hypergraph::from(&[vec![0, 1, 2], vec![2, 3]]);
+        let costs = hg.dijkstra(0, None, None).unwrap();
+        assert_eq!(costs[&3], 2.0);
+
+        let mut hg = Hypergraph::new(true);
+        hg.add_edge_weighted(&[0, 1], 1.0);
+        hg.add_edge_weighted(&[1, 2, 3], -1.0);
+        assert_eq!(hg.dijkstra(0, None, None), Err(HypergraphError::InvalidWeight));
+
+        // Negative weights of hyperedges which are not considered do not matter
+        assert!(hg.dijkstra(0, None, Some(2)).is_ok());
+    }
+
+    #[test]
+    fn test_all_pairs_distances() {
+        let mut hg = Hypergraph::from(&[vec![0, 1, 2], vec![2, 3], vec![7, 8]]);
+        hg.add_node(9);
+
+        let distances = hg.all_pairs_distances();
+        assert_eq!(distances.len(), 4 * 4 + 2 * 2 + 1);
+        assert_eq!(distances[&(0, 0)], 0);
+        assert_eq!(distances[&(0, 1)], 1);
+        assert_eq!(distances[&(0, 3)], 2);
+        assert_eq!(distances[&(3, 1)], 2);
+        assert_eq!(distances[&(8, 7)], 1);
+        assert!(!distances.contains_key(&(0, 7)));
+    }
+
+    #[test]
+    fn test_average_path_length() {
+        let hg = Hypergraph::from(&[vec![0, 1, 2], vec![2, 3], vec![7, 8]]);
+
+        // Distances from 0, 1, 2 and 3 to the other nodes: (1+1+2) + (1+1+2) + (1+1+1) + (2+2+1)
+        assert_eq!(hg.average_path_length(None), Some(16.0 / 12.0));
+        assert_eq!(hg.average_path_length(Some(10)), Some(16.0 / 12.0));
+        // Sources 0 and 2
+        assert_eq!(hg.average_path_length(Some(2)), Some(7.0 / 6.0));
+        assert_eq!(hg.average_path_length(Some(0)), None);
+
+        let mut hg = Hypergraph::new(false);
+        assert_eq!(hg.average_path_length(None), None);
+        hg.add_node(0);
+        assert_eq!(hg.average_path_length(None), None);
+    }
+
+    #[test]
+    fn test_eccentricity_diameter() {
+        let mut hg = Hypergraph::from(&[vec![0, 1], vec![1, 2, 5], vec![2, 3], vec![3, 4], vec![7, 8]]);
+        hg.add_node(9);
+
+        assert_eq!(hg.eccentricity(0), Some(4));
+        assert_eq!(hg.eccentricity(2), Some(2));
+        assert_eq!(hg.eccentricity(5), Some(3));
+        assert_eq!(hg.eccentricity(8), Some(1));
+        assert_eq!(hg.eccentricity(9), Some(0));
+        assert_eq!(hg.eccentricity(42), None);
+
+        assert_eq!(hg.diameter(), Some(4));
+        assert_eq!(hg.diameter_approx(), Some(4));
+
+        // A single big hyperedge
+        let hg = Hypergraph::from(&[(0..20).collect::<Vec<Node>>()]);
+        assert_eq!(hg.diameter(), Some(1));
+        assert_eq!(hg.diameter_approx(), Some(1));
+
+        let mut hg = Hypergraph::new(false);
+        assert_eq!(hg.diameter(), None);
+        assert_eq!(hg.diameter_approx(), None);
+        hg.add_node(0);
+        assert_eq!(hg.diameter(), Some(0));
+    }
+}
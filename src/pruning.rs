@@ -0,0 +1,146 @@
+use std::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
+
+use super::{EdgeID, Hypergraph, Node};
+
+/// `type Node = i64`
+///
+/// Hyperedge ranked by weight, ordered so that the heaviest hyperedge is the greatest, with ties broken in favour of
+/// the lexicographically smallest nodes.
+#[derive(PartialEq)]
+struct Ranked<'a> {
+    weight: f64,
+    nodes: &'a Vec<Node>,
+}
+
+impl Eq for Ranked<'_> {}
+
+impl Ord for Ranked<'_> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.weight.total_cmp(&other.weight).then_with(|| other.nodes.cmp(self.nodes))
+    }
+}
+
+impl PartialOrd for Ranked<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Hypergraph {
+    /// Removes every hyperedge whose weight is outside the range `[min, max]`, eg to extract the backbone of the
+    /// hypergraph. The nodes are kept, even if they become isolated.
+    ///
+    /// Unweighted hypergraphs are treated as if every hyperedge had weight 0, so either all the hyperedges or none of
+    /// them are removed.
+    ///
+    /// # Parameters
+    /// - `min` : `f64` - The minimum weight of the hyperedges to keep.
+    /// - `max` : `f64` - The maximum weight of the hyperedges to keep.
+    ///
+    /// # Returns
+    /// - `usize` - The number of removed hyperedges.
+    ///
+    /// # Performance
+    /// - `O(m + r*n)`, where `m` is the number of hyperedges, `r` the number of removed ones, and `n` the number of
+    ///   nodes.
+    pub fn prune_by_weight(&mut self, min: f64, max: f64) -> usize {
+        let removed: Vec<EdgeID> = self
+            .edge_list
+            .iter()
+            .filter(|(_, hyperedge)| hyperedge.weight < min || hyperedge.weight > max)
+            .map(|(edge_id, _)| *edge_id)
+            .collect();
+
+        for edge_id in removed.iter() {
+            self.remove_edge_by_id(*edge_id);
+        }
+        removed.len()
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Returns the `k` heaviest hyperedges, with their weights, from the heaviest one. Ties are broken in favour of the
+    /// lexicographically smallest hyperedge, so the result does not depend on the order of the underlying hashmaps.
+    ///
+    /// Only `k` hyperedges are kept at a time, in a bounded heap, so the list of all hyperedges is never sorted.
+    /// Unweighted hypergraphs are treated as if every hyperedge had weight 0, so the result is ordered by nodes.
+    ///
+    /// # Parameters
+    /// - `k` : `usize` - The number of hyperedges to return. If it exceeds the number of hyperedges, all of them are
+    ///   returned.
+    ///
+    /// # Returns
+    /// - `Vec<(&Vec<Node>, f64)>` - The `k` heaviest hyperedges with their weights, in descending order of weight.
+    ///
+    /// # Performance
+    /// - `O(m*log(k))`, where `m` is the number of hyperedges.
+    pub fn top_k_edges_by_weight(&self, k: usize) -> Vec<(&Vec<Node>, f64)> {
+        if k == 0 {
+            return Vec::new();
+        }
+
+        // Min-heap of the best k hyperedges seen so far
+        let mut heap: BinaryHeap<Reverse<Ranked>> = BinaryHeap::with_capacity(k + 1);
+        for hyperedge in self.edge_list.values() {
+            heap.push(Reverse(Ranked { weight: hyperedge.weight, nodes: &hyperedge.nodes }));
+            if heap.len() > k {
+                heap.pop();
+            }
+        }
+
+        // Ascending order of Reverse, ie descending order of Ranked
+        heap.into_sorted_vec().into_iter().map(|Reverse(ranked)| (ranked.nodes, ranked.weight)).collect()
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prune_by_weight() {
+        let weights = [0.5, 1.0, 1.5, 2.0, 2.5, 3.0];
+        let edges: Vec<Vec<Node>> = (0..6).map(|i| vec![i, i + 1]).collect();
+        let mut hg = Hypergraph::from_weighted(&edges, &weights);
+
+        assert_eq!(hg.prune_by_weight(1.0, 2.5), 2);
+        assert_eq!(hg.num_edges(), 4);
+        assert!(!hg.check_edge(&[0, 1]) && !hg.check_edge(&[5, 6]));
+        assert!(hg.check_edge(&[1, 2]) && hg.check_edge(&[4, 5]));
+        assert_eq!(hg.num_nodes(), 7);
+        assert_eq!(hg.get_incident_edges(0, None, None).unwrap(), Some(Vec::new()));
+
+        assert_eq!(hg.prune_by_weight(f64::MIN, f64::MAX), 0);
+        assert_eq!(hg.prune_by_weight(3.0, 1.0), 4);
+
+        // Unweighted hypergraphs have weight 0
+        let mut hg = Hypergraph::from(&edges);
+        assert_eq!(hg.prune_by_weight(0.0, 1.0), 0);
+        assert_eq!(hg.prune_by_weight(0.5, 1.0), 6);
+    }
+
+    #[test]
+    fn test_top_k_edges_by_weight() {
+        let hg = Hypergraph::from_weighted(
+            &[vec![3, 4], vec![1, 2], vec![5], vec![0, 9], vec![2, 3]],
+            &[2.0, 2.0, 7.0, 1.0, 2.0],
+        );
+
+        assert_eq!(hg.top_k_edges_by_weight(1), vec![(&vec![5], 7.0)]);
+        // Ties are broken by nodes
+        assert_eq!(
+            hg.top_k_edges_by_weight(3),
+            vec![(&vec![5], 7.0), (&vec![1, 2], 2.0), (&vec![2, 3], 2.0)]
+        );
+
+        let all = hg.top_k_edges_by_weight(10);
+        assert_eq!(all.len(), 5);
+        assert_eq!(all[3], (&vec![3, 4], 2.0));
+        assert_eq!(all[4], (&vec![0, 9], 1.0));
+        assert!(hg.top_k_edges_by_weight(0).is_empty());
+
+        let hg = Hypergraph::from(&[vec![2, 3], vec![1, 2]]);
+        assert_eq!(hg.top_k_edges_by_weight(5), vec![(&vec![1, 2], 0.0), (&vec![2, 3], 0.0)]);
+    }
+}
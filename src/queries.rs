@@ -0,0 +1,434 @@
+use ahash::{AHashMap, AHashSet};
+
+use super::{EdgeID, Hypergraph, HypergraphError, Node};
+
+impl Hypergraph {
+    /// `type Node = i64`
+    ///
+    /// Returns the hyperedges which contain all the given nodes, eg the papers written together by a group of authors.
+    ///
+    /// Every hyperedge contains the empty set of nodes, so an empty query returns all the hyperedges. If one of the
+    /// nodes is not in the hypergraph, the result is empty.
+    ///
+    /// # Parameters
+    /// - `nodes` : `&[Node]` - The nodes which must be in every returned hyperedge.
+    ///
+    /// # Returns
+    /// - `Vec<&Vec<Node>>` - The hyperedges containing all the nodes.
+    ///
+    /// # Performance
+    /// - `O(d*k)`, where `d` is the minimum degree of the given nodes and `k` the number of given nodes, since the
+    ///   incidence lists are intersected starting from the smallest one.
+    pub fn edges_containing(&self, nodes: &[Node]) -> Vec<&Vec<Node>> {
+        if nodes.is_empty() {
+            return self.edge_list.values().map(|hyperedge| &hyperedge.nodes).collect();
+        }
+
+        self.compute_edges_containing(nodes)
+            .map(|edge_ids| edge_ids.map(|edge_id| &self.edge_list[edge_id].nodes).collect())
+            .unwrap_or_default()
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Returns the number of hyperedges which contain all the given nodes, as `edges_containing` without collecting
+    /// them.
+    ///
+    /// # Parameters
+    /// - `nodes` : `&[Node]` - The nodes which must be in every counted hyperedge.
+    ///
+    /// # Returns
+    /// - `usize` - The number of hyperedges containing all the nodes.
+    ///
+    /// # Performance
+    /// - `O(d*k)`, where `d` is the minimum degree of the given nodes and `k` the number of given nodes.
+    pub fn num_edges_containing(&self, nodes: &[Node]) -> usize {
+        if nodes.is_empty() {
+            return self.edge_list.len();
+        }
+
+        self.compute_edges_containing(nodes).map_or(0, |edge_ids| edge_ids.count())
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Returns the hyperedges whose nodes are all in the given set, with their weights (0 if the hypergraph is not
+    /// weighted). These are the hyperedges of `subhypergraph`, without building a new hypergraph, eg to count the
+    /// internal hyperedges of a community.
+    ///
+    /// The nodes which are not in the hypergraph are ignored.
+    ///
+    /// # Parameters
+    /// - `nodes` : `&[Node]` - The set of nodes which must contain every returned hyperedge.
+    ///
+    /// # Returns
+    /// - `Vec<(&Vec<Node>, f64)>` - The hyperedges inside the set, with their weights.
+    ///
+    /// # Performance
+    /// - `O(d*k)`, where `d` is the sum of the degrees of the given nodes and `k` the maximum size of a hyperedge,
+    ///   since only the hyperedges incident to the given nodes are checked.
+    pub fn edges_within(&self, nodes: &[Node]) -> Vec<(&Vec<Node>, f64)> {
+        let nodes_as_set = Self::compute_vec_to_set(nodes);
+        let mut visited: AHashSet<EdgeID> = AHashSet::new();
+        let mut res = Vec::new();
+
+        for edge_ids in nodes_as_set.iter().filter_map(|node| self.incidence_list.get(node)) {
+            for edge_id in edge_ids.iter() {
+                if !visited.insert(*edge_id) {
+                    continue;
+                }
+
+                let hyperedge = &self.edge_list[edge_id];
+                if Self::compute_vec_to_set(&hyperedge.nodes).is_subset(&nodes_as_set) {
+                    res.push((&hyperedge.nodes, hyperedge.weight));
+                }
+            }
+        }
+
+        // The empty hyperedge is inside every set, but it is incident to no node
+        if let Some(hyperedge) = self.edge_list.get(&Self::compute_edge_id(&[])) {
+            res.push((&hyperedge.nodes, hyperedge.weight));
+        }
+
+        res
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Returns the number of hyperedges which contain both nodes. `co_occurrence(a, b) == co_occurrence(b, a)`, and
+    /// `co_occurrence(a, a)` is the degree of `a`.
+    ///
+    /// # Parameters
+    /// - `a` : `Node` - The first node.
+    /// - `b` : `Node` - The second node.
+    ///
+    /// # Returns
+    /// - `usize` - The number of hyperedges containing `a` and `b`, 0 if one of them is not in the hypergraph.
+    ///
+    /// # Performance
+    /// - `O(min(d(a), d(b)))`, where `d(v)` is the degree of `v`.
+    pub fn co_occurrence(&self, a: Node, b: Node) -> usize {
+        self.compute_edges_containing(&[a, b]).map_or(0, |edge_ids| edge_ids.count())
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Returns the sum of the weights of the hyperedges which contain both nodes. If the hypergraph is not weighted,
+    /// every hyperedge counts 1, as in `co_occurrence`.
+    ///
+    /// # Parameters
+    /// - `a` : `Node` - The first node.
+    /// - `b` : `Node` - The second node.
+    ///
+    /// # Returns
+    /// - `f64` - The total weight of the hyperedges containing `a` and `b`, 0 if one of them is not in the hypergraph.
+    ///
+    /// # Performance
+    /// - `O(min(d(a), d(b)))`, where `d(v)` is the degree of `v`.
+    pub fn co_occurrence_weighted(&self, a: Node, b: Node) -> f64 {
+        self.compute_edges_containing(&[a, b]).map_or(0_f64, |edge_ids| {
+            edge_ids.map(|edge_id| self.compute_expansion_weight(self.edge_list[edge_id].weight)).sum()
+        })
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Returns every pair of distinct nodes which co-occur in at least one hyperedge, with the sum of the weights of
+    /// the hyperedges they share (their number, if the hypergraph is not weighted). Pairs with a total weight of 0 are
+    /// skipped.
+    ///
+    /// Unlike `clique_expansion`, nodes are not replaced by indices, and a node repeated in a hyperedge is counted
+    /// once.
+    ///
+    /// # Returns
+    /// - `Vec<(Node, Node, f64)>` - The `(a, b, weight)` triplets with `a < b`, sorted.
+    ///
+    /// # Performance
+    /// - `O(m*k*k + p*log(p))`, where `m` is the number of hyperedges, `k` the maximum size of a hyperedge and `p` the
+    ///   number of pairs.
+    pub fn co_occurrence_matrix(&self) -> Vec<(Node, Node, f64)> {
+        let mut weights: AHashMap<(Node, Node), f64> = AHashMap::new();
+
+        // O(m*k*k)
+        for hyperedge in self.edge_list.values() {
+            let weight = self.compute_expansion_weight(hyperedge.weight);
+            let mut nodes = hyperedge.nodes.clone();
+            nodes.sort_unstable();
+            nodes.dedup();
+
+            for (i, a) in nodes.iter().enumerate() {
+                for b in nodes[i + 1..].iter() {
+                    *weights.entry((*a, *b)).or_insert(0_f64) += weight;
+                }
+            }
+        }
+
+        let mut triplets: Vec<(Node, Node, f64)> = weights
+            .into_iter()
+            .filter(|(_, weight)| *weight != 0_f64)
+            .map(|((a, b), weight)| (a, b, weight))
+            .collect();
+        triplets.sort_unstable_by_key(|(a, b, _)| (*a, *b));
+
+        triplets
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Returns the nodes with the given degree, ie incident to exactly `degree` hyperedges. With `degree == 0` these
+    /// are the nodes in no hyperedge at all (a node in its own singleton hyperedge has degree 1).
+    ///
+    /// # Parameters
+    /// - `degree` : `usize` - The degree of the returned nodes.
+    /// - `sorted` : `bool` - If `true`, the nodes are sorted, eg for reproducibility. Otherwise they are in no
+    ///   particular order.
+    ///
+    /// # Returns
+    /// - `Vec<Node>` - The nodes with the given degree.
+    ///
+    /// # Performance
+    /// - `O(n)`, plus `O(r*log(r))` if sorted, where `n` is the number of nodes and `r` the number of returned ones.
+    pub fn nodes_with_degree(&self, degree: usize, sorted: bool) -> Vec<Node> {
+        self.compute_nodes_with_degree_between(degree, degree, None, sorted)
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Returns the nodes with degree between `min` and `max`, both included, eg to find the hubs above a threshold
+    /// with `max == usize::MAX`. If `min > max` the result is empty.
+    ///
+    /// # Parameters
+    /// - `min` : `usize` - The minimum degree of the returned nodes.
+    /// - `max` : `usize` - The maximum degree of the returned nodes.
+    /// - `sorted` : `bool` - If `true`, the nodes are sorted. Otherwise they are in no particular order.
+    ///
+    /// # Returns
+    /// - `Vec<Node>` - The nodes with degree in `[min, max]`.
+    ///
+    /// # Performance
+    /// - `O(n)`, plus `O(r*log(r))` if sorted, where `n` is the number of nodes and `r` the number of returned ones.
+    pub fn nodes_with_degree_between(&self, min: usize, max: usize, sorted: bool) -> Vec<Node> {
+        self.compute_nodes_with_degree_between(min, max, None, sorted)
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Returns the nodes with the given degree, as `nodes_with_degree`, counting only the incident hyperedges with the
+    /// given order or size.
+    ///
+    /// # Parameters
+    /// - `degree` : `usize` - The degree of the returned nodes.
+    /// - `order` : `Option<usize>` - The order of the hyperedges to count. If None, all hyperedges are counted.
+    /// - `size` : `Option<usize>` - The size of the hyperedges to count. If None, all hyperedges are counted.
+    /// - `sorted` : `bool` - If `true`, the nodes are sorted. Otherwise they are in no particular order.
+    ///
+    /// # Returns
+    /// - `Result<Vec<Node>, HypergraphError>` - `Ok` containing the nodes with the given degree. Returns
+    ///   `Err(HypergraphError::BothOrderAndSize)` if both `order` and `size` are specified.
+    ///
+    /// # Performance
+    /// - `O(n + I)`, plus `O(r*log(r))` if sorted, where `n` is the number of nodes, `I` the sum of the degrees and
+    ///   `r` the number of returned nodes.
+    pub fn nodes_with_degree_with(
+        &self,
+        degree: usize,
+        order: Option<usize>,
+        size: Option<usize>,
+        sorted: bool,
+    ) -> Result<Vec<Node>, HypergraphError> {
+        self.nodes_with_degree_between_with(degree, degree, order, size, sorted)
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Returns the nodes with degree between `min` and `max`, both included, as `nodes_with_degree_between`, counting
+    /// only the incident hyperedges with the given order or size.
+    ///
+    /// # Parameters
+    /// - `min` : `usize` - The minimum degree of the returned nodes.
+    /// - `max` : `usize` - The maximum degree of the returned nodes.
+    /// - `order` : `Option<usize>` - The order of the hyperedges to count. If None, all hyperedges are counted.
+    /// - `size` : `Option<usize>` - The size of the hyperedges to count. If None, all hyperedges are counted.
+    /// - `sorted` : `bool` - If `true`, the nodes are sorted. Otherwise they are in no particular order.
+    ///
+    /// # Returns
+    /// - `Result<Vec<Node>, HypergraphError>` - `Ok` containing the nodes with degree in `[min, max]`. Returns
+    ///   `Err(HypergraphError::BothOrderAndSize)` if both `order` and `size` are specified.
+    ///
+    /// # Performance
+    /// - `O(n + I)`, plus `O(r*log(r))` if sorted, where `n` is the number of nodes, `I` the sum of the degrees and
+    ///   `r` the number of returned nodes.
+    pub fn nodes_with_degree_between_with(
+        &self,
+        min: usize,
+        max: usize,
+        order: Option<usize>,
+        size: Option<usize>,
+        sorted: bool,
+    ) -> Result<Vec<Node>, HypergraphError> {
+        if order.is_some() && size.is_some() {
+            return Err(HypergraphError::BothOrderAndSize);
+        }
+
+        Ok(self.compute_nodes_with_degree_between(min, max, order.map(|val| val + 1).or(size), sorted))
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Effectively computes the nodes with degree in `[min, max]`, counting only the hyperedges of size `filter` if
+    /// specified. Without a filter the degree is read from the incidence list, without touching the hyperedges.
+    fn compute_nodes_with_degree_between(&self, min: usize, max: usize, filter: Option<usize>, sorted: bool) -> Vec<Node> {
+        let mut res: Vec<Node> = self
+            .incidence_list
+            .iter()
+            .filter(|(_, edge_ids)| {
+                let degree = match filter {
+                    Some(len) => edge_ids.iter().filter(|edge_id| self.edge_list[*edge_id].nodes.len() == len).count(),
+                    None => edge_ids.len(),
+                };
+                min <= degree && degree <= max
+            })
+            .map(|(node, _)| *node)
+            .collect();
+
+        if sorted {
+            res.sort_unstable();
+        }
+        res
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Effectively computes the ids of the hyperedges containing all the given nodes, by filtering the smallest
+    /// incidence list with the other ones.
+    ///
+    /// # Returns
+    /// - `Option<impl Iterator<Item = &EdgeID>>` - The ids of the hyperedges, `None` if one of the nodes is not in the
+    ///   hypergraph.
+    fn compute_edges_containing(&self, nodes: &[Node]) -> Option<impl Iterator<Item = &EdgeID>> {
+        let mut incidences: Vec<&AHashSet<EdgeID>> =
+            nodes.iter().map(|node| self.incidence_list.get(node)).collect::<Option<_>>()?;
+        incidences.sort_unstable_by_key(|edge_ids| edge_ids.len());
+
+        let (smallest, others) = incidences.split_first()?;
+        let others = others.to_vec();
+        Some(smallest.iter().filter(move |edge_id| others.iter().all(|edge_ids| edge_ids.contains(*edge_id))))
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    #[test]
+    fn test_edges_containing() {
+        let edges = vec![vec![1, 2, 3], vec![2, 3], vec![3, 4, 2, 5], vec![1, 5], vec![6], vec![2, 3, 1, 6]];
+        let mut hg = Hypergraph::from(&edges);
+        hg.add_node(7);
+
+        let queries: Vec<Vec<Node>> =
+            vec![vec![2], vec![2, 3], vec![3, 2, 1], vec![1, 5], vec![6, 6], vec![7], vec![4, 6]];
+        for query in queries.iter() {
+            // Brute-force reference
+            let mut expected: Vec<&Vec<Node>> =
+                edges.iter().filter(|edge| query.iter().all(|node| edge.contains(node))).collect();
+            expected.sort();
+
+            let mut res = hg.edges_containing(query);
+            res.sort();
+            assert_eq!(res, expected);
+            assert_eq!(hg.num_edges_containing(query), expected.len());
+        }
+
+        assert!(hg.edges_containing(&[2, 8]).is_empty());
+        assert_eq!(hg.num_edges_containing(&[8]), 0);
+        assert_eq!(hg.edges_containing(&[]).len(), 6);
+        assert_eq!(hg.num_edges_containing(&[]), 6);
+    }
+
+    #[test]
+    fn test_edges_within() {
+        let hg = Hypergraph::from_weighted(
+            &[vec![1, 2, 3], vec![2, 3], vec![3, 4, 2, 5], vec![1, 5], vec![6], vec![2, 3, 1, 6]],
+            &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0],
+        );
+
+        let queries: Vec<Vec<Node>> =
+            vec![vec![1, 2, 3], vec![3, 2], vec![1, 5, 6, 8], vec![2, 3, 4, 5, 1], vec![], vec![8]];
+        for query in queries.iter() {
+            let sub = hg.subhypergraph(query);
+            let mut expected = sub.get_edges_weighted().unwrap_or_default();
+            expected.sort_by(|a, b| a.0.cmp(b.0));
+
+            let mut res = hg.edges_within(query);
+            res.sort_by(|a, b| a.0.cmp(b.0));
+            assert_eq!(res, expected);
+        }
+
+        let mut res = hg.edges_within(&[1, 2, 3, 1]);
+        res.sort_by(|a, b| a.0.cmp(b.0));
+        assert_eq!(res, vec![(&vec![1, 2, 3], 1.0), (&vec![2, 3], 2.0)]);
+    }
+
+    #[test]
+    fn test_co_occurrence() {
+        let mut hg =
+            Hypergraph::from_weighted(&[vec![1, 2, 3], vec![2, 3], vec![3, 1], vec![4, 5]], &[1.0, 2.0, 4.0, 8.0]);
+        hg.add_node(6);
+
+        assert_eq!(hg.co_occurrence(2, 3), 2);
+        assert_eq!(hg.co_occurrence(3, 2), 2);
+        assert_eq!(hg.co_occurrence(1, 2), 1);
+        assert_eq!(hg.co_occurrence(3, 3), 3);
+        assert_eq!(hg.co_occurrence(1, 4), 0);
+        assert_eq!(hg.co_occurrence(1, 6), 0);
+        assert_eq!(hg.co_occurrence(1, 7), 0);
+        assert_eq!(hg.co_occurrence_weighted(2, 3), 3.0);
+        assert_eq!(hg.co_occurrence_weighted(1, 3), hg.co_occurrence_weighted(3, 1));
+        assert_eq!(hg.co_occurrence_weighted(1, 3), 5.0);
+        assert_eq!(hg.co_occurrence_weighted(3, 5), 0.0);
+
+        let matrix = hg.co_occurrence_matrix();
+        assert_eq!(matrix, vec![(1, 2, 1.0), (1, 3, 5.0), (2, 3, 3.0), (4, 5, 8.0)]);
+        for (a, b, weight) in matrix {
+            assert_eq!(hg.co_occurrence_weighted(a, b), weight);
+        }
+
+        let hg = hg.to_unweighted();
+        assert_eq!(hg.co_occurrence_weighted(1, 3), 2.0);
+        assert_eq!(hg.co_occurrence_matrix(), vec![(1, 2, 1.0), (1, 3, 2.0), (2, 3, 2.0), (4, 5, 1.0)]);
+    }
+
+    #[test]
+    fn test_nodes_with_degree() {
+        let mut hg = Hypergraph::from(&[vec![1, 2, 3], vec![2, 3], vec![3, 4, 2], vec![5], vec![3, 5]]);
+        hg.add_nodes(&[6, 7]);
+
+        // Degrees: 1 -> 1, 2 -> 3, 3 -> 4, 4 -> 1, 5 -> 2, 6 -> 0, 7 -> 0
+        assert_eq!(hg.nodes_with_degree(0, true), vec![6, 7]);
+        assert_eq!(hg.nodes_with_degree(1, true), vec![1, 4]);
+        assert_eq!(hg.nodes_with_degree(4, true), vec![3]);
+        assert!(hg.nodes_with_degree(5, true).is_empty());
+
+        let mut unsorted = hg.nodes_with_degree_between(1, 3, false);
+        unsorted.sort();
+        assert_eq!(unsorted, vec![1, 2, 4, 5]);
+        assert_eq!(hg.nodes_with_degree_between(2, usize::MAX, true), vec![2, 3, 5]);
+        assert_eq!(hg.nodes_with_degree_between(0, usize::MAX, true), hg.get_nodes_sorted());
+        assert!(hg.nodes_with_degree_between(3, 2, true).is_empty());
+
+        // Counting only the hyperedges of size 3: 1 -> 1, 2 -> 2, 3 -> 2, 4 -> 1, the other ones 0
+        assert_eq!(hg.nodes_with_degree_with(2, None, Some(3), true), Ok(vec![2, 3]));
+        assert_eq!(hg.nodes_with_degree_with(2, Some(2), None, true), Ok(vec![2, 3]));
+        assert_eq!(hg.nodes_with_degree_with(0, None, Some(3), true), Ok(vec![5, 6, 7]));
+        assert_eq!(hg.nodes_with_degree_between_with(1, 2, None, Some(3), true), Ok(vec![1, 2, 3, 4]));
+        assert_eq!(hg.nodes_with_degree_between_with(1, 1, None, Some(1), true), Ok(vec![5]));
+        assert_eq!(
+            hg.nodes_with_degree_between_with(0, 2, None, None, true),
+            Ok(hg.nodes_with_degree_between(0, 2, true))
+        );
+        assert_eq!(hg.nodes_with_degree_with(1, Some(1), Some(2), true), Err(HypergraphError::BothOrderAndSize));
+
+        assert!(Hypergraph::new(false).nodes_with_degree(0, true).is_empty());
+    }
+}
@@ -0,0 +1,377 @@
+use super::{EdgeID, Hypergraph, MergePolicy, Node};
+
+/// Options for `Hypergraph::remove_node_with`, deciding what happens to the hyperedges shrunk by the removal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NodeRemovalOptions {
+    /// How the weights are merged when a shrunk hyperedge becomes equal to another one, eg `[1, 2, 3]` and `[2, 3]`
+    /// after removing `1`. With `MergePolicy::Sum` no interaction is lost.
+    pub on_duplicate: MergePolicy,
+
+    /// Whether the hyperedges which become empty are dropped. If `false`, they are kept (merged) as the empty
+    /// hyperedge.
+    pub drop_empty: bool,
+
+    /// Whether the hyperedges which shrink to a single node are dropped.
+    pub drop_singletons: bool,
+}
+
+impl Default for NodeRemovalOptions {
+    /// Replaced weights on duplicates, empty hyperedges dropped and singletons kept, as in `Hypergraph::remove_node`
+    /// with the default merge policy.
+    fn default() -> Self {
+        Self { on_duplicate: MergePolicy::Replace, drop_empty: true, drop_singletons: false }
+    }
+}
+
+/// Summary of a weak removal of nodes, returned by `Hypergraph::remove_node_with` and `Hypergraph::remove_nodes`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct NodeRemovalReport {
+    /// The number of nodes removed, ie which were in the hypergraph.
+    pub removed_nodes: usize,
+
+    /// The number of hyperedges which lost the node and are still in the hypergraph, under a new `EdgeID`.
+    pub shrunk_edges: usize,
+
+    /// The number of hyperedges which became equal to another one and were merged into it.
+    pub merged_edges: usize,
+
+    /// The number of hyperedges dropped because they became empty or singletons.
+    pub dropped_edges: usize,
+}
+
+/// Summary of `Hypergraph::prune_low_degree`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PruneReport {
+    /// The number of removed nodes.
+    pub removed_nodes: usize,
+
+    /// The number of removed hyperedges, either too small or merged into an equal one after losing nodes.
+    pub removed_edges: usize,
+
+    /// The number of rounds needed to reach the fixed point, the last one removing nothing.
+    pub rounds: usize,
+}
+
+// What happened to a hyperedge which lost a node
+enum Shrink {
+    Moved,
+    Merged,
+    Dropped,
+}
+
+impl Hypergraph {
+    /// `type Node = i64`
+    ///
+    /// Weakly removes a node from the hypergraph, as `remove_node`, with explicit options for the hyperedges which
+    /// become duplicates, empty or singletons.
+    ///
+    /// If the node provided is not in the hypergraph, nothing happens and the report is empty.
+    ///
+    /// # Parameters
+    /// - `node` : `Node` - Node to be removed.
+    /// - `options` : `&NodeRemovalOptions` - What happens to the shrunk hyperedges.
+    ///
+    /// # Returns
+    /// - `NodeRemovalReport` - How many nodes were removed (0 or 1), and how many hyperedges were shrunk, merged and
+    ///   dropped.
+    ///
+    /// # Performance
+    /// - `O(d*k)`, where `d` is the degree of the node and `k` the maximum size of its incident hyperedges.
+    pub fn remove_node_with(&mut self, node: Node, options: &NodeRemovalOptions) -> NodeRemovalReport {
+        let mut report = NodeRemovalReport::default();
+        self.compute_remove_node(node, options, &mut report);
+        report
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Weakly removes a list of nodes from the hypergraph, see `remove_node_with`.
+    ///
+    /// If the list provided contains nodes which are not in the hypergraph, nothing happens for them.
+    ///
+    /// # Parameters
+    /// - `nodes` : `&[Node]` - List of the nodes to be removed.
+    /// - `options` : `&NodeRemovalOptions` - What happens to the shrunk hyperedges.
+    ///
+    /// # Returns
+    /// - `NodeRemovalReport` - The total over all the nodes. A hyperedge shrunk more than once is counted every time.
+    ///
+    /// # Performance
+    /// - `O(l*d*k)`, where `l` is the length of the list of nodes, `d` is the maximum degree and `k` the maximum size
+    ///   of a hyperedge.
+    pub fn remove_nodes_with(&mut self, nodes: &[Node], options: &NodeRemovalOptions) -> NodeRemovalReport {
+        let mut report = NodeRemovalReport::default();
+        for node in nodes.iter() {
+            self.compute_remove_node(*node, options, &mut report);
+        }
+        report
+    }
+
+    /// Removes the isolated nodes, ie the nodes which are in no hyperedge, or only in their own singleton hyperedge
+    /// (as in `is_isolated`). Their singleton hyperedges are removed as well.
+    ///
+    /// # Returns
+    /// - `usize` - The number of removed nodes.
+    ///
+    /// # Performance
+    /// - `O(n + m)`, where `n` and `m` are the number of nodes and the number of hyperedges of the hypergraph.
+    pub fn remove_isolated_nodes(&mut self) -> usize {
+        let isolated: Vec<Node> = self.iter_isolated_nodes().collect();
+        self.strong_remove_nodes(&isolated);
+        isolated.len()
+    }
+
+    /// Iteratively removes the nodes with degree lower than `min_degree` (weakly, see `remove_node`) and the
+    /// hyperedges with size lower than `min_edge_size`, until no node and no hyperedge has to be removed.
+    ///
+    /// Removing a node shrinks its hyperedges, which may become too small, and removing a hyperedge lowers the degree
+    /// of its nodes, so a single round is not enough in general. The result is stable: pruning it again with the same
+    /// thresholds removes nothing. Hyperedges which become equal are merged with the merge policy of the hypergraph.
+    ///
+    /// # Parameters
+    /// - `min_degree` : `usize` - The minimum degree of the nodes to keep.
+    /// - `min_edge_size` : `usize` - The minimum size of the hyperedges to keep.
+    ///
+    /// # Returns
+    /// - `PruneReport` - How many nodes and hyperedges were removed, and in how many rounds.
+    ///
+    /// # Performance
+    /// - `O(r*(n + m*k))`, where `r` is the number of rounds, `n` and `m` the number of nodes and hyperedges, and `k`
+    ///   the maximum size of a hyperedge.
+    pub fn prune_low_degree(&mut self, min_degree: usize, min_edge_size: usize) -> PruneReport {
+        let options = NodeRemovalOptions { on_duplicate: self.merge_policy, ..Default::default() };
+        let mut report = PruneReport::default();
+
+        loop {
+            report.rounds += 1;
+
+            // O(m*k)
+            let small: Vec<EdgeID> = self
+                .edge_list
+                .iter()
+                .filter(|(_, hyperedge)| hyperedge.nodes.len() < min_edge_size)
+                .map(|(edge_id, _)| *edge_id)
+                .collect();
+            for edge_id in small.iter() {
+                self.remove_edge_by_id(*edge_id);
+            }
+
+            // O(n + m*k)
+            let low: Vec<Node> = self
+                .incidence_list
+                .iter()
+                .filter(|(_, edge_ids)| edge_ids.len() < min_degree)
+                .map(|(node, _)| *node)
+                .collect();
+            let removal = self.remove_nodes_with(&low, &options);
+
+            report.removed_nodes += removal.removed_nodes;
+            report.removed_edges += small.len() + removal.merged_edges + removal.dropped_edges;
+            if small.is_empty() && low.is_empty() {
+                return report;
+            }
+        }
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Effectively removes a node, shrinking its incident hyperedges, and adds the outcome to `report`.
+    fn compute_remove_node(&mut self, node: Node, options: &NodeRemovalOptions, report: &mut NodeRemovalReport) {
+        let Some(edge_ids) = self.incidence_list.remove(&node) else {
+            return;
+        };
+        self.node_attrs.remove(&node);
+        report.removed_nodes += 1;
+
+        // O(d)
+        for edge_id in edge_ids.into_iter() {
+            // O(k)
+            match self.compute_shrink_edge(edge_id, node, options) {
+                Some(Shrink::Moved) => report.shrunk_edges += 1,
+                Some(Shrink::Merged) => report.merged_edges += 1,
+                Some(Shrink::Dropped) => report.dropped_edges += 1,
+                None => {}
+            }
+        }
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Effectively removes a node from a hyperedge, moving it under its new `EdgeID` and updating only the incidence
+    /// lists of its remaining nodes, and moving its attributes and timestamps. If the shrunk hyperedge is already in
+    /// the hypergraph, the weights are merged with `options.on_duplicate` and the multiplicities are summed. The
+    /// incidence list of `node` is not updated.
+    ///
+    /// # Returns
+    /// - `Option<Shrink>` - What happened to the hyperedge, `None` if it is not in the hypergraph.
+    fn compute_shrink_edge(&mut self, edge_id: EdgeID, node: Node, options: &NodeRemovalOptions) -> Option<Shrink> {
+        let mut hyperedge = self.edge_list.remove(&edge_id)?;
+        hyperedge.nodes.retain(|x| *x != node);
+
+        let dropped = match hyperedge.nodes.len() {
+            0 => options.drop_empty,
+            1 => options.drop_singletons,
+            _ => false,
+        };
+        if dropped {
+            for member in hyperedge.nodes.iter() {
+                if let Some(set) = self.incidence_list.get_mut(member) {
+                    set.remove(&edge_id);
+                }
+            }
+            self.edge_attrs.remove(&edge_id);
+            self.edge_times.remove(&edge_id);
+            return Some(Shrink::Dropped);
+        }
+
+        let new_id = Self::compute_edge_id(&hyperedge.nodes);
+        for member in hyperedge.nodes.iter() {
+            if let Some(set) = self.incidence_list.get_mut(member) {
+                set.remove(&edge_id);
+                set.insert(new_id);
+            }
+        }
+        self.compute_move_edge_attrs(edge_id, new_id);
+        self.compute_move_edge_times(edge_id, new_id);
+
+        match self.edge_list.get_mut(&new_id) {
+            Some(existing) => {
+                let weight = options.on_duplicate.merge(existing.weight, hyperedge.weight);
+                existing.set_weight(Self::compute_finite_weight(weight));
+                existing.multiplicity += hyperedge.multiplicity;
+                Some(Shrink::Merged)
+            }
+            None => {
+                self.edge_list.insert(new_id, hyperedge);
+                Some(Shrink::Moved)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    fn example() -> Hypergraph {
+        Hypergraph::from_weighted(
+            &[vec![1, 2, 3], vec![2, 3], vec![1, 4], vec![1], vec![5, 1, 5], vec![4, 6]],
+            &[1.0, 2.0, 4.0, 8.0, 16.0, 32.0],
+        )
+    }
+
+    #[test]
+    fn test_remove_node_default() {
+        let mut hg = example();
+        let report = hg.remove_node_with(1, &NodeRemovalOptions::default());
+
+        assert_eq!(report, NodeRemovalReport { removed_nodes: 1, shrunk_edges: 2, merged_edges: 1, dropped_edges: 1 });
+        assert_eq!(hg.get_edges_sorted(), Some(vec![vec![2, 3], vec![4], vec![4, 6], vec![5, 5]]));
+        assert_eq!(hg.get_weight(&[2, 3]), Some(1.0));
+        assert_eq!(hg.remove_node_with(1, &NodeRemovalOptions::default()), NodeRemovalReport::default());
+    }
+
+    #[test]
+    fn test_remove_node_options() {
+        let options = NodeRemovalOptions { on_duplicate: MergePolicy::Sum, drop_empty: false, drop_singletons: false };
+        let mut hg = example();
+        let report = hg.remove_node_with(1, &options);
+        assert_eq!(report, NodeRemovalReport { removed_nodes: 1, shrunk_edges: 3, merged_edges: 1, dropped_edges: 0 });
+        assert_eq!(hg.get_weight(&[2, 3]), Some(3.0));
+        assert_eq!(hg.get_weight(&[]), Some(8.0));
+
+        let options =
+            NodeRemovalOptions { on_duplicate: MergePolicy::KeepExisting, drop_empty: true, drop_singletons: true };
+        let mut hg = example();
+        let report = hg.remove_node_with(1, &options);
+        assert_eq!(report, NodeRemovalReport { removed_nodes: 1, shrunk_edges: 1, merged_edges: 1, dropped_edges: 2 });
+        assert_eq!(hg.get_edges_sorted(), Some(vec![vec![2, 3], vec![4, 6], vec![5, 5]]));
+        assert_eq!(hg.get_weight(&[2, 3]), Some(2.0));
+        assert_eq!(hg.get_incident_edges(4, None, None).unwrap().unwrap().len(), 1);
+
+        // [5, 5] keeps a single distinct node, but two members
+        let options = NodeRemovalOptions { on_duplicate: MergePolicy::Max, drop_empty: false, drop_singletons: true };
+        let mut hg = example();
+        hg.add_edge_weighted(&[1, 2], 64.0);
+        let report = hg.remove_nodes_with(&[1, 3, 7], &options);
+        assert_eq!(report, NodeRemovalReport { removed_nodes: 2, shrunk_edges: 2, merged_edges: 1, dropped_edges: 3 });
+        assert_eq!(hg.get_edges_sorted(), Some(vec![vec![], vec![4, 6], vec![5, 5]]));
+    }
+
+    #[test]
+    fn test_clear_edges() {
+        let mut hg = example();
+        hg.add_node(7);
+        let nodes = hg.get_nodes_sorted();
+
+        hg.clear_edges();
+        assert_eq!(hg.get_nodes_sorted(), nodes);
+        assert_eq!(hg.num_edges(), 0);
+        assert!(hg.is_weighted());
+        for node in nodes.iter() {
+            assert_eq!(hg.get_incident_edges(*node, None, None).unwrap(), Some(Vec::new()));
+        }
+
+        hg.add_edge_weighted(&[1, 2], 3.0);
+        assert_eq!(hg.remove_isolated_nodes(), nodes.len() - 2);
+        assert_eq!(hg.get_nodes_sorted(), vec![1, 2]);
+        assert_eq!(hg.remove_isolated_nodes(), 0);
+    }
+
+    #[test]
+    fn test_remove_isolated_nodes() {
+        let mut hg = example();
+        hg.add_nodes(&[7, 8]);
+        hg.add_edge(&[8]);
+        hg.add_edge(&[9]);
+
+        assert_eq!(hg.remove_isolated_nodes(), 3);
+        assert_eq!(hg.get_nodes_sorted(), vec![1, 2, 3, 4, 5, 6]);
+        assert!(!hg.check_edge(&[8]) && !hg.check_edge(&[9]));
+        // The singleton of a node which is not isolated is kept
+        assert!(hg.check_edge(&[1]));
+        assert_eq!(hg.num_edges(), 6);
+    }
+
+    #[test]
+    fn test_prune_low_degree() {
+        // A triangle, with the chain 3 - 4 - 5 hanging from it
+        let mut hg = Hypergraph::from(&[vec![1, 2], vec![2, 3], vec![3, 1], vec![3, 4], vec![4, 5]]);
+        hg.add_node(6);
+
+        let report = hg.prune_low_degree(2, 2);
+        assert_eq!(report, PruneReport { removed_nodes: 3, removed_edges: 2, rounds: 4 });
+        assert_eq!(hg.get_nodes_sorted(), vec![1, 2, 3]);
+        assert_eq!(hg.get_edges_sorted(), Some(vec![vec![1, 2], vec![1, 3], vec![2, 3]]));
+
+        // Stable under a second invocation
+        let before = hg.clone();
+        assert_eq!(hg.prune_low_degree(2, 2), PruneReport { removed_nodes: 0, removed_edges: 0, rounds: 1 });
+        assert_eq!(hg, before);
+
+        // Everything goes
+        assert_eq!(hg.prune_low_degree(3, 2).removed_nodes, 3);
+        assert_eq!((hg.num_nodes(), hg.num_edges()), (0, 0));
+    }
+
+    #[test]
+    fn test_prune_low_degree_sizes() {
+        let mut hg = Hypergraph::from_weighted(
+            &[vec![1, 2, 3], vec![2, 3], vec![1], vec![2, 3, 4], vec![5], vec![3, 2]],
+            &[1.0; 6],
+        );
+        hg.set_merge_policy(MergePolicy::Sum);
+
+        // Only the singletons are too small, then 5 is left with no hyperedges
+        let report = hg.prune_low_degree(1, 2);
+        assert_eq!(report, PruneReport { removed_nodes: 1, removed_edges: 2, rounds: 2 });
+        assert_eq!(hg.get_edges_sorted(), Some(vec![vec![1, 2, 3], vec![2, 3], vec![2, 3], vec![2, 3, 4]]));
+
+        // 1 and 4 have degree 1, and their hyperedges are merged into [2, 3]
+        let report = hg.prune_low_degree(2, 2);
+        assert_eq!(report, PruneReport { removed_nodes: 2, removed_edges: 2, rounds: 2 });
+        assert_eq!(hg.get_weight(&[2, 3]), Some(3.0));
+        assert_eq!(hg.get_weight(&[3, 2]), Some(1.0));
+        assert_eq!(hg.num_edges(), 2);
+    }
+}
@@ -0,0 +1,200 @@
+use ahash::AHashMap;
+
+use super::{AttrValue, Hypergraph, Node};
+
+type AttrList = Vec<(String, AttrValue)>;
+
+/// `type Node = i64`
+/// `type AttrList = Vec<(String, AttrValue)>`
+///
+/// Logical content of a `Hypergraph`, meant to be used as its serialized form.
+///
+/// It only stores what the user provided (the `weighted` flag, the nodes, the `(nodes, weight)` pairs of the
+/// hyperedges, their multiplicities and timestamps, and the attributes of the nodes and of the hyperedges), not the internal `EdgeID` maps: converting it back into a `Hypergraph` rebuilds `incidence_list` and
+/// `edge_list` by re-inserting every hyperedge, so the format stays valid even if the hashing seeds change.
+///
+/// Nodes are sorted in ascending order, hyperedges lexicographically and attributes by owner and then by name, so that equal hypergraphs always produce the
+/// same `HypergraphData`, regardless of the iteration order of the underlying hashmaps.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HypergraphData {
+    /// States if the hypergraph is weighted.
+    pub weighted: bool,
+
+    /// All the nodes of the hypergraph, including the isolated ones.
+    pub nodes: Vec<Node>,
+
+    /// The hyperedges of the hypergraph, each one with its weight.
+    pub edges: Vec<(Vec<Node>, f64)>,
+
+    /// The attributes of the nodes which have at least one, as `(name, value)` pairs.
+    pub node_attrs: Vec<(Node, AttrList)>,
+
+    /// The attributes of the hyperedges which have at least one, as `(name, value)` pairs.
+    pub edge_attrs: Vec<(Vec<Node>, AttrList)>,
+
+    /// The sorted timestamps of the hyperedges which have at least one.
+    pub edge_times: Vec<(Vec<Node>, Vec<i64>)>,
+
+    /// The multiplicities of the hyperedges with more than one instance.
+    pub multiplicities: Vec<(Vec<Node>, usize)>,
+}
+
+impl From<&Hypergraph> for HypergraphData {
+    fn from(hg: &Hypergraph) -> Self {
+        let mut nodes = hg.get_nodes();
+        nodes.sort_unstable();
+
+        let mut edges: Vec<(Vec<Node>, f64)> = hg
+            .edge_list
+            .values()
+            .map(|hyperedge| (hyperedge.nodes.clone(), hyperedge.weight))
+            .collect();
+        edges.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let mut node_attrs: Vec<(Node, AttrList)> =
+            hg.node_attrs.iter().map(|(node, attrs)| (*node, compute_sorted_attrs(attrs))).collect();
+        node_attrs.sort_by_key(|(node, _)| *node);
+
+        let mut edge_attrs: Vec<(Vec<Node>, AttrList)> = hg
+            .edge_attrs
+            .iter()
+            .map(|(edge_id, attrs)| (hg.edge_list[edge_id].nodes.clone(), compute_sorted_attrs(attrs)))
+            .collect();
+        edge_attrs.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let mut edge_times: Vec<(Vec<Node>, Vec<i64>)> = hg
+            .edge_times
+            .iter()
+            .map(|(edge_id, times)| (hg.edge_list[edge_id].nodes.clone(), times.clone()))
+            .collect();
+        edge_times.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let mut multiplicities: Vec<(Vec<Node>, usize)> = hg
+            .edge_list
+            .values()
+            .filter(|hyperedge| hyperedge.multiplicity > 1)
+            .map(|hyperedge| (hyperedge.nodes.clone(), hyperedge.multiplicity))
+            .collect();
+        multiplicities.sort();
+
+        Self {
+            weighted: hg.weighted,
+            nodes,
+            edges,
+            node_attrs,
+            edge_attrs,
+            edge_times,
+            multiplicities,
+        }
+    }
+}
+
+impl From<HypergraphData> for Hypergraph {
+    fn from(data: HypergraphData) -> Self {
+        let mut res = Hypergraph::new(data.weighted);
+
+        res.add_nodes(&data.nodes);
+        for (edge, weight) in data.edges.iter() {
+            Hypergraph::compute_add_edge(&mut res, edge, *weight);
+        }
+        for (node, attrs) in data.node_attrs {
+            res.add_node(node);
+            res.node_attrs.insert(node, attrs.into_iter().collect());
+        }
+        for (edge, attrs) in data.edge_attrs {
+            res.edge_attrs.insert(Hypergraph::compute_edge_id(&edge), attrs.into_iter().collect());
+        }
+        for (edge, times) in data.edge_times {
+            res.edge_times.insert(Hypergraph::compute_edge_id(&edge), times);
+        }
+        for (edge, multiplicity) in data.multiplicities {
+            if let Some(hyperedge) = res.edge_list.get_mut(&Hypergraph::compute_edge_id(&edge)) {
+                hyperedge.multiplicity = multiplicity;
+            }
+        }
+
+        res
+    }
+}
+
+/// Effectively clones the attributes of a node or a hyperedge, sorted by name.
+fn compute_sorted_attrs(attrs: &AHashMap<String, AttrValue>) -> AttrList {
+    let mut res: AttrList = attrs.iter().map(|(key, value)| (key.clone(), value.clone())).collect();
+    res.sort_by(|a, b| a.0.cmp(&b.0));
+    res
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    fn build() -> Hypergraph {
+        let mut hg = Hypergraph::new(true);
+        hg.add_nodes(&[10, -3]);
+        hg.add_edge_weighted(&[3, 1, 2], 1.5);
+        hg.add_edge_weighted(&[2, 4], 27.7);
+        hg.add_edge(&[5]);
+        hg.set_node_attr(10, "label", AttrValue::Text("hub".to_string())).unwrap();
+        hg.set_node_attr(2, "weight", AttrValue::Float(0.25)).unwrap();
+        hg.set_node_attr(2, "core", AttrValue::Bool(true)).unwrap();
+        hg.set_edge_attr(&[2, 4], "year", AttrValue::Int(2021)).unwrap();
+        hg.add_edge_at(&[5], 0.0, 3);
+        hg.add_edge_multi(&[3, 1, 2]);
+        hg
+    }
+
+    #[test]
+    fn test_data_content() {
+        let data = HypergraphData::from(&build());
+
+        assert!(data.weighted);
+        assert_eq!(data.nodes, vec![-3, 1, 2, 3, 4, 5, 10]);
+        assert_eq!(data.edges, vec![(vec![2, 4], 27.7), (vec![3, 1, 2], 1.5), (vec![5], 0.0)]);
+        assert_eq!(
+            data.node_attrs,
+            vec![
+                (2, vec![("core".to_string(), AttrValue::Bool(true)), ("weight".to_string(), AttrValue::Float(0.25))]),
+                (10, vec![("label".to_string(), AttrValue::Text("hub".to_string()))]),
+            ]
+        );
+        assert_eq!(data.edge_attrs, vec![(vec![2, 4], vec![("year".to_string(), AttrValue::Int(2021))])]);
+        assert_eq!(data.edge_times, vec![(vec![5], vec![3])]);
+        assert_eq!(data.multiplicities, vec![(vec![3, 1, 2], 2)]);
+    }
+
+    #[test]
+    fn test_data_round_trip() {
+        let hg = build();
+        let data = HypergraphData::from(&hg);
+        let restored: Hypergraph = data.clone().into();
+
+        assert_eq!(restored.is_weighted(), hg.is_weighted());
+        assert_eq!(restored.num_nodes(), hg.num_nodes());
+        assert_eq!(restored.num_edges(), hg.num_edges());
+        assert_eq!(restored.get_weight(&[2, 4]), Some(27.7));
+        assert_eq!(restored.get_incident_edges(10, None, None), Ok(Some(vec![])));
+        assert_eq!(restored.get_node_attr(2, "weight"), Some(&AttrValue::Float(0.25)));
+        assert_eq!(restored.get_edge_attr(&[2, 4], "year"), Some(&AttrValue::Int(2021)));
+        assert_eq!(restored.edge_times(&[5]), Some(&[3][..]));
+        assert_eq!(restored, hg);
+
+        // The logical content does not depend on the layout of the internal maps
+        assert_eq!(HypergraphData::from(&restored), data);
+    }
+
+    #[test]
+    fn test_data_independent_constructions() {
+        let mut other = Hypergraph::new(true);
+        other.add_edge_at(&[5], 0.0, 3);
+        other.add_edge_weighted(&[2, 4], 27.7);
+        other.add_nodes(&[-3, 10]);
+        other.add_edge_weighted(&[3, 1, 2], 1.5);
+        other.add_edge_multi(&[3, 1, 2]);
+        other.set_node_attr(2, "core", AttrValue::Bool(true)).unwrap();
+        other.set_node_attr(2, "weight", AttrValue::Float(0.25)).unwrap();
+        other.set_node_attr(10, "label", AttrValue::Text("hub".to_string())).unwrap();
+        other.set_edge_attr(&[2, 4], "year", AttrValue::Int(2021)).unwrap();
+
+        assert_eq!(HypergraphData::from(&other), HypergraphData::from(&build()));
+    }
+}
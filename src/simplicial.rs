@@ -0,0 +1,339 @@
+use ahash::{AHashMap, AHashSet};
+
+use super::{EdgeID, Hypergraph, Node};
+
+/// Sub-edges of a hyperedge considered by `Hypergraph::edge_simpliciality` and `Hypergraph::simplicial_fraction`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SubEdges {
+    /// Every proper subset with at least 2 nodes: `2^k - k - 2` sub-edges for a hyperedge of size `k`.
+    All,
+
+    /// Only the faces, ie the subsets with `k - 1` nodes: `k` sub-edges for a hyperedge of size `k`.
+    Faces,
+}
+
+impl Hypergraph {
+    /// `type Node = i64`
+    ///
+    /// Returns the simpliciality of a hyperedge, ie the fraction of its sub-edges which are hyperedges of the hypergraph
+    /// too. A hyperedge with simpliciality `1` is closed under taking subsets, as a simplex of a simplicial complex.
+    ///
+    /// Sub-edges are compared as sets of nodes, regardless of the order of their nodes.
+    ///
+    /// # Parameters
+    /// - `edge` : `&[Node]` - The hyperedge.
+    /// - `sub_edges` : `SubEdges` - Which sub-edges to consider.
+    /// - `max_size` : `usize` - Hyperedges with more distinct nodes are skipped, since their number of sub-edges grows
+    ///   exponentially with `SubEdges::All` (which never considers hyperedges with 64 nodes or more).
+    ///
+    /// # Returns
+    /// - `Option<f64>` - `Some` simpliciality of the hyperedge, `None` if it is not in the hypergraph, if it is larger
+    ///   than `max_size` or if it has fewer than 3 distinct nodes, and so no sub-edges.
+    ///
+    /// # Performance
+    /// - `O(s + 2^k*k)`, where `s` is the sum of the sizes of the hyperedges and `k` the size of the hyperedge.
+    pub fn edge_simpliciality(&self, edge: &[Node], sub_edges: SubEdges, max_size: usize) -> Option<f64> {
+        let hyperedge = self.edge_list.get(&Self::compute_edge_id(edge))?;
+        compute_simpliciality(&hyperedge.nodes, sub_edges, max_size, &self.compute_canonical_edges())
+    }
+
+    /// Returns the fraction of hyperedges which are closed under taking subsets, ie with simpliciality `1` (see
+    /// `edge_simpliciality`), among the ones with at least 3 and at most `max_size` distinct nodes.
+    ///
+    /// # Parameters
+    /// - `sub_edges` : `SubEdges` - Which sub-edges to consider.
+    /// - `max_size` : `usize` - Hyperedges with more distinct nodes are skipped.
+    ///
+    /// # Returns
+    /// - `f64` - The fraction of closed hyperedges, `0` if no hyperedge has a size in the range.
+    ///
+    /// # Performance
+    /// - `O(s + m*2^k*k)`, where `s` is the sum of the sizes of the hyperedges, `m` their number and `k` the maximum size
+    ///   of a considered hyperedge.
+    pub fn simplicial_fraction(&self, sub_edges: SubEdges, max_size: usize) -> f64 {
+        let canonical = self.compute_canonical_edges();
+        let (mut closed, mut total) = (0, 0);
+
+        for hyperedge in self.edge_list.values() {
+            if let Some(value) = compute_simpliciality(&hyperedge.nodes, sub_edges, max_size, &canonical) {
+                total += 1;
+                if value == 1_f64 {
+                    closed += 1;
+                }
+            }
+        }
+
+        if total > 0 {
+            closed as f64 / total as f64
+        } else {
+            0_f64
+        }
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Returns the toplexes of the hypergraph, ie the hyperedges which are maximal: not strictly contained, as sets of
+    /// nodes, in any other hyperedge.
+    ///
+    /// Only the hyperedges incident to the member with the lowest degree are candidate supersets of a hyperedge.
+    ///
+    /// # Returns
+    /// - `Vec<&Vec<Node>>` - The list of the toplexes.
+    ///
+    /// # Performance
+    /// - `O(m*d*k)`, where `m` is the number of hyperedges, `d` the maximum degree of a node and `k` the maximum size of
+    ///   a hyperedge.
+    pub fn toplexes(&self) -> Vec<&Vec<Node>> {
+        self.edge_list
+            .values()
+            .filter(|hyperedge| self.compute_is_maximal(&hyperedge.nodes))
+            .map(|hyperedge| &hyperedge.nodes)
+            .collect()
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Checks whether a hyperedge is maximal, ie not strictly contained, as a set of nodes, in any other hyperedge.
+    ///
+    /// # Parameters
+    /// - `edge` : `&[Node]` - The hyperedge.
+    ///
+    /// # Returns
+    /// - `Option<bool>` - `Some(true)` if the hyperedge is a toplex, `Some(false)` if it is not, `None` if it is not in
+    ///   the hypergraph.
+    ///
+    /// # Performance
+    /// - `O(d*k)`, where `d` is the maximum degree of a node and `k` the maximum size of a hyperedge.
+    pub fn is_maximal(&self, edge: &[Node]) -> Option<bool> {
+        let hyperedge = self.edge_list.get(&Self::compute_edge_id(edge))?;
+        Some(self.compute_is_maximal(&hyperedge.nodes))
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Returns the downward closure of the hypergraph: every subset of a hyperedge with at least `min_size` nodes is
+    /// added as a hyperedge too, so that the result is a simplicial complex.
+    ///
+    /// Subsets are added with their nodes sorted, and only if no hyperedge with the same set of nodes is already in the
+    /// hypergraph; the original hyperedges are kept as they are.
+    ///
+    /// # Parameters
+    /// - `min_size` : `usize` - The minimum size of the added subsets, at least `1`.
+    /// - `max_edge_size` : `usize` - Hyperedges with more distinct nodes are not closed, since their number of subsets
+    ///   grows exponentially (hyperedges with 64 nodes or more are never closed).
+    /// - `inherit_weights` : `bool` - If `true`, a subset gets the maximum weight of the hyperedges containing it, if
+    ///   `false` it gets weight `0`. Ignored if the hypergraph is not weighted.
+    ///
+    /// # Returns
+    /// - `Hypergraph` - The closed hypergraph.
+    ///
+    /// # Performance
+    /// - `O(s + m*2^k*k)`, where `s` is the sum of the sizes of the hyperedges, `m` their number and `k` the maximum size
+    ///   of a closed hyperedge.
+    pub fn downward_closure(&self, min_size: usize, max_edge_size: usize, inherit_weights: bool) -> Hypergraph {
+        let canonical = self.compute_canonical_edges();
+
+        // Every missing subset with its weight, deduplicated by EdgeID since the nodes are sorted
+        let mut subsets: AHashMap<EdgeID, (Vec<Node>, f64)> = AHashMap::new();
+        for hyperedge in self.edge_list.values() {
+            let nodes = compute_canonical(&hyperedge.nodes);
+            if nodes.len() > max_edge_size || nodes.len() >= u64::BITS as usize {
+                continue;
+            }
+
+            let weight = if inherit_weights { hyperedge.weight } else { 0_f64 };
+            for subset in compute_subsets(&nodes, min_size.max(1)) {
+                if canonical.contains(&subset) {
+                    continue;
+                }
+                subsets
+                    .entry(Self::compute_edge_id(&subset))
+                    .and_modify(|(_, current)| *current = current.max(weight))
+                    .or_insert((subset, weight));
+            }
+        }
+
+        let mut res = self.clone();
+        for (subset, weight) in subsets.into_values() {
+            res.add_edge_weighted(&subset, weight);
+        }
+
+        res
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Effectively computes the set of the hyperedges as sorted lists of distinct nodes, so that they can be looked up
+    /// regardless of the order of their nodes.
+    fn compute_canonical_edges(&self) -> AHashSet<Vec<Node>> {
+        self.edge_list.values().map(|hyperedge| compute_canonical(&hyperedge.nodes)).collect()
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Effectively checks whether no hyperedge strictly contains the given one, looking only at the hyperedges incident
+    /// to its member with the lowest degree.
+    fn compute_is_maximal(&self, nodes: &[Node]) -> bool {
+        let members: AHashSet<&Node> = nodes.iter().collect();
+
+        match members.iter().min_by_key(|node| self.incidence_list[**node].len()) {
+            Some(pivot) => !self.incidence_list[*pivot].iter().any(|id| {
+                let other: AHashSet<&Node> = self.edge_list[id].nodes.iter().collect();
+                other.len() > members.len() && members.iter().all(|node| other.contains(node))
+            }),
+            // The empty hyperedge is contained in any other one
+            None => self.edge_list.values().all(|hyperedge| hyperedge.nodes.is_empty()),
+        }
+    }
+}
+
+/// `type Node = i64`
+///
+/// Effectively computes the simpliciality of a hyperedge given the canonical forms of all the hyperedges.
+fn compute_simpliciality(
+    nodes: &[Node],
+    sub_edges: SubEdges,
+    max_size: usize,
+    canonical: &AHashSet<Vec<Node>>,
+) -> Option<f64> {
+    let nodes = compute_canonical(nodes);
+    let k = nodes.len();
+    if k < 3 || k > max_size || (sub_edges == SubEdges::All && k >= u64::BITS as usize) {
+        return None;
+    }
+
+    let (mut present, mut total) = (0, 0);
+    let mut check = |subset: Vec<Node>| {
+        total += 1;
+        if canonical.contains(&subset) {
+            present += 1;
+        }
+    };
+
+    match sub_edges {
+        SubEdges::Faces => {
+            for skip in 0..k {
+                check(nodes.iter().enumerate().filter(|(i, _)| *i != skip).map(|(_, node)| *node).collect());
+            }
+        }
+        SubEdges::All => {
+            for subset in compute_subsets(&nodes, 2) {
+                check(subset);
+            }
+        }
+    }
+
+    Some(present as f64 / total as f64)
+}
+
+/// `type Node = i64`
+///
+/// Effectively computes the sorted list of the distinct nodes of a hyperedge.
+fn compute_canonical(nodes: &[Node]) -> Vec<Node> {
+    let mut res = nodes.to_vec();
+    res.sort_unstable();
+    res.dedup();
+    res
+}
+
+/// `type Node = i64`
+///
+/// Effectively computes the proper subsets of a hyperedge with fewer than 64 nodes, with at least `min_size` nodes.
+fn compute_subsets(nodes: &[Node], min_size: usize) -> Vec<Vec<Node>> {
+    let k = nodes.len();
+
+    // Every bitmask except the hyperedge itself
+    (0_u64..(1 << k) - 1)
+        .filter(|mask| mask.count_ones() as usize >= min_size)
+        .map(|mask| (0..k).filter(|i| mask & (1 << i) != 0).map(|i| nodes[i]).collect())
+        .collect()
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    #[test]
+    fn test_simpliciality_closed() {
+        // A triangle with all its sides, the sides listed in a different order
+        let hg = Hypergraph::from(&[vec![0, 1, 2], vec![1, 0], vec![2, 1], vec![0, 2], vec![5, 6]]);
+
+        assert_eq!(hg.edge_simpliciality(&[0, 1, 2], SubEdges::All, 10), Some(1.0));
+        assert_eq!(hg.edge_simpliciality(&[0, 1, 2], SubEdges::Faces, 10), Some(1.0));
+        assert_eq!(hg.simplicial_fraction(SubEdges::All, 10), 1.0);
+    }
+
+    #[test]
+    fn test_simpliciality_open() {
+        let hg = Hypergraph::from(&[vec![0, 1, 2, 3], vec![0, 1, 2], vec![1, 2], vec![3, 4, 5], vec![7, 8]]);
+
+        // Faces: only [0, 1, 2] out of 4; all: [0, 1, 2] and [1, 2] out of 4 + 6
+        assert_eq!(hg.edge_simpliciality(&[0, 1, 2, 3], SubEdges::Faces, 10), Some(0.25));
+        assert_eq!(hg.edge_simpliciality(&[0, 1, 2, 3], SubEdges::All, 10), Some(0.2));
+        assert_eq!(hg.edge_simpliciality(&[0, 1, 2], SubEdges::All, 10), Some(1.0 / 3.0));
+        assert_eq!(hg.edge_simpliciality(&[3, 4, 5], SubEdges::All, 10), Some(0.0));
+
+        assert_eq!(hg.edge_simpliciality(&[0, 1, 2, 3], SubEdges::All, 3), None);
+        assert_eq!(hg.edge_simpliciality(&[7, 8], SubEdges::All, 10), None);
+        assert_eq!(hg.edge_simpliciality(&[9, 8], SubEdges::All, 10), None);
+
+        assert_eq!(hg.simplicial_fraction(SubEdges::All, 10), 0.0);
+        assert_eq!(Hypergraph::from(&[vec![0, 1]]).simplicial_fraction(SubEdges::All, 10), 0.0);
+    }
+
+    #[test]
+    fn test_toplexes() {
+        let hg = Hypergraph::from(&[vec![1, 2], vec![1, 2, 3], vec![3, 2, 1, 4], vec![4, 5], vec![6, 7], vec![7, 6]]);
+
+        let mut toplexes = hg.toplexes();
+        toplexes.sort();
+        assert_eq!(toplexes, vec![&vec![3, 2, 1, 4], &vec![4, 5], &vec![6, 7], &vec![7, 6]]);
+
+        assert_eq!(hg.is_maximal(&[1, 2]), Some(false));
+        assert_eq!(hg.is_maximal(&[1, 2, 3]), Some(false));
+        assert_eq!(hg.is_maximal(&[3, 2, 1, 4]), Some(true));
+        assert_eq!(hg.is_maximal(&[6, 7]), Some(true));
+        assert_eq!(hg.is_maximal(&[2, 1]), None);
+    }
+
+    #[test]
+    fn test_toplexes_independent() {
+        let hg = Hypergraph::from(&[vec![0, 1, 2], vec![2, 3], vec![3, 4, 5]]);
+
+        assert_eq!(hg.toplexes().len(), 3);
+        assert!(Hypergraph::new(false).toplexes().is_empty());
+    }
+
+    #[test]
+    fn test_downward_closure() {
+        let hg = Hypergraph::from(&[vec![3, 1, 2, 0]]);
+
+        // 6 pairs, 4 triples and the hyperedge itself
+        let closure = hg.downward_closure(2, 10, false);
+        assert_eq!(closure.num_edges(), 11);
+        assert_eq!(closure.num_nodes(), 4);
+        assert!(closure.check_edge(&[3, 1, 2, 0]));
+        assert!(closure.check_edge(&[0, 3]));
+        assert_eq!(closure.simplicial_fraction(SubEdges::All, 10), 1.0);
+
+        assert_eq!(hg.downward_closure(3, 10, false).num_edges(), 5);
+        assert_eq!(hg.downward_closure(0, 10, false).num_edges(), 15);
+        assert_eq!(hg.downward_closure(2, 3, false).num_edges(), 1);
+    }
+
+    #[test]
+    fn test_downward_closure_weights() {
+        let hg = Hypergraph::from_weighted(&[vec![0, 1, 2], vec![1, 2, 3], vec![2, 1], vec![9]], &[1.0, 3.0, 5.0, 7.0]);
+
+        // [2, 1] is already in, so [1, 2] is not added
+        let closure = hg.downward_closure(2, 10, true);
+        assert_eq!(closure.num_edges(), 8);
+        assert!(!closure.check_edge(&[1, 2]));
+        assert_eq!(closure.get_weight(&[2, 1]), Some(5.0));
+        assert_eq!(closure.get_weight(&[0, 1]), Some(1.0));
+        assert_eq!(closure.get_weight(&[1, 3]), Some(3.0));
+
+        let closure = hg.downward_closure(2, 10, false);
+        assert_eq!(closure.get_weight(&[1, 3]), Some(0.0));
+    }
+}
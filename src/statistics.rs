@@ -0,0 +1,166 @@
+use ahash::{AHashMap, AHashSet};
+
+use super::{EdgeID, Hypergraph, Node};
+
+impl Hypergraph {
+    /// `type Node = i64`
+    ///
+    /// Returns the number of distinct nodes shared by two hyperedges of the hypergraph.
+    ///
+    /// # Parameters
+    /// - `a` : `&[Node]` - The first hyperedge.
+    /// - `b` : `&[Node]` - The second hyperedge.
+    ///
+    /// # Returns
+    /// - `Option<usize>` - `Some` size of the intersection of the hyperedges, `None` if one of them is not in the
+    ///   hypergraph.
+    ///
+    /// # Performance
+    /// - `O(k)`, where `k` is the maximum size of the two hyperedges.
+    pub fn edge_overlap(&self, a: &[Node], b: &[Node]) -> Option<usize> {
+        let first = &self.edge_list.get(&Self::compute_edge_id(a))?.nodes;
+        let second = &self.edge_list.get(&Self::compute_edge_id(b))?.nodes;
+
+        let first: AHashSet<&Node> = first.iter().collect();
+        let second: AHashSet<&Node> = second.iter().collect();
+        Some(first.intersection(&second).count())
+    }
+
+    /// Returns the distribution of the sizes of the intersections of the pairs of hyperedges sharing at least one node.
+    ///
+    /// Each unordered pair of distinct hyperedges is counted once; disjoint pairs are not counted, so the key `0` is
+    /// never present. The intersecting pairs are found through the incidences of the nodes, without looping over all
+    /// the pairs of hyperedges.
+    ///
+    /// # Returns
+    /// - `AHashMap<usize, usize>` - The number of pairs of hyperedges for every size of their intersection.
+    ///
+    /// # Performance
+    /// - `O(s*d)`, where `s` is the sum of the sizes of the hyperedges and `d` the maximum degree of a node.
+    pub fn intersection_profile(&self) -> AHashMap<usize, usize> {
+        let mut res = AHashMap::new();
+
+        for (id, hyperedge) in self.edge_list.iter() {
+            let members: AHashSet<&Node> = hyperedge.nodes.iter().collect();
+
+            // Shared nodes with every intersecting hyperedge, counting each pair from its smallest ID
+            let mut shared: AHashMap<EdgeID, usize> = AHashMap::new();
+            for node in members {
+                for other in self.incidence_list[node].iter().filter(|other| *other > id) {
+                    *shared.entry(*other).or_insert(0) += 1;
+                }
+            }
+
+            for count in shared.into_values() {
+                *res.entry(count).or_insert(0) += 1;
+            }
+        }
+
+        res
+    }
+
+    /// Returns the density of the hypergraph, ie the number of hyperedges divided by the number of possible hyperedges
+    /// with size from 1 up to `max_size`, which is the sum of the binomials `C(n, k)`.
+    ///
+    /// The binomials are accumulated as `f64`, so that they do not overflow for large hypergraphs.
+    ///
+    /// # Returns
+    /// - `f64` - The density of the hypergraph, `0` if it has no hyperedges.
+    ///
+    /// # Performance
+    /// - `O(m + k^2)`, where `m` is the number of hyperedges and `k` their maximum size.
+    pub fn density(&self) -> f64 {
+        let n = self.num_nodes();
+        let possible: f64 = (1..=self.max_size()).map(|k| compute_binomial(n, k)).sum();
+
+        if possible > 0_f64 {
+            self.num_edges() as f64 / possible
+        } else {
+            0_f64
+        }
+    }
+
+    /// Returns the density of the hyperedges of every size `k` present in the hypergraph, ie their number `m_k` divided
+    /// by the number of possible hyperedges of size `k`, the binomial `C(n, k)`.
+    ///
+    /// The binomials are accumulated as `f64`, so that they do not overflow for large hypergraphs.
+    ///
+    /// # Returns
+    /// - `AHashMap<usize, f64>` - The density for every size of the hyperedges.
+    ///
+    /// # Performance
+    /// - `O(m + k^2)`, where `m` is the number of hyperedges and `k` their maximum size.
+    pub fn density_by_size(&self) -> AHashMap<usize, f64> {
+        let n = self.num_nodes();
+
+        self.distrbution_orders()
+            .into_iter()
+            .map(|(k, count)| (k, count as f64 / compute_binomial(n, k)))
+            .collect()
+    }
+}
+
+/// Effectively computes the binomial `C(n, k)` as `f64`, `0` if `k > n`.
+fn compute_binomial(n: usize, k: usize) -> f64 {
+    if k > n {
+        return 0_f64;
+    }
+
+    // The product stays an integer at every step, up to the rounding of the floats
+    let k = k.min(n - k);
+    (0..k).fold(1_f64, |res, i| res * (n - i) as f64 / (i + 1) as f64)
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    #[test]
+    fn test_edge_overlap() {
+        let hg = Hypergraph::from(&[vec![0, 1, 2, 3], vec![1, 2], vec![3, 4], vec![7, 8]]);
+
+        assert_eq!(hg.edge_overlap(&[0, 1, 2, 3], &[1, 2]), Some(2));
+        assert_eq!(hg.edge_overlap(&[1, 2], &[3, 4]), Some(0));
+        assert_eq!(hg.edge_overlap(&[3, 4], &[3, 4]), Some(2));
+        assert_eq!(hg.edge_overlap(&[3, 4], &[4, 3]), None);
+    }
+
+    #[test]
+    fn test_intersection_profile() {
+        // Nested, partially overlapping and disjoint hyperedges
+        let hg = Hypergraph::from(&[vec![0, 1, 2, 3], vec![1, 2], vec![0, 1, 2], vec![3, 4], vec![7, 8]]);
+        let profile = hg.intersection_profile();
+
+        let expected: AHashMap<usize, usize> = [(1, 1), (2, 2), (3, 1)].into_iter().collect();
+        assert_eq!(profile, expected);
+        assert!(!profile.contains_key(&0));
+
+        // Pairwise disjoint hyperedges
+        let hg = Hypergraph::from(&[vec![0, 1], vec![2, 3], vec![4]]);
+        assert!(hg.intersection_profile().is_empty());
+    }
+
+    #[test]
+    fn test_density() {
+        // 4 nodes: C(4, 2) = 6 and C(4, 3) = 4
+        let hg = Hypergraph::from(&[vec![0, 1], vec![1, 2], vec![2, 3], vec![0, 1, 2]]);
+
+        assert!((hg.density() - 4.0 / 14.0).abs() < 1e-12);
+        let expected: AHashMap<usize, f64> = [(2, 0.5), (3, 0.25)].into_iter().collect();
+        assert_eq!(hg.density_by_size(), expected);
+
+        assert_eq!(Hypergraph::new(false).density(), 0.0);
+        assert!(Hypergraph::new(false).density_by_size().is_empty());
+    }
+
+    #[test]
+    fn test_density_large() {
+        // C(200, 100) does not fit in any integer type
+        let mut hg = Hypergraph::from(&[(0..100).collect::<Vec<Node>>()]);
+        hg.add_nodes(&(100..200).collect::<Vec<Node>>());
+
+        let density = hg.density_by_size()[&100];
+        assert!(density > 0.0 && density < 1e-50);
+        assert!(hg.density() > 0.0 && hg.density().is_finite());
+    }
+}
@@ -0,0 +1,207 @@
+use ahash::AHashMap;
+
+use super::{EdgeID, Hypergraph, Node};
+
+impl Hypergraph {
+    /// `type Node = i64`
+    ///
+    /// Adds a hyperedge observed at time `t`, eg an interaction recorded on a given day. If the hyperedge is already
+    /// in, `t` is added to its timestamps, so the same hyperedge may be active at many times, and its weight is merged
+    /// as in `add_edge_weighted` (by default, replaced).
+    ///
+    /// Timestamps follow the hyperedge as its attributes do (see `set_edge_attr`): when `remove_node` merges two
+    /// hyperedges, their timestamps are joined.
+    ///
+    /// # Parameters
+    /// - `edge` : `&[Node]` - Hyperedge to insert.
+    /// - `weight` : `f64` - Weight of the hyperedge.
+    /// - `t` : `i64` - The time at which the hyperedge is active.
+    ///
+    /// # Returns
+    /// - `bool` - `true` if the hyperedge was not already active at `t`, `false` otherwise.
+    ///
+    /// # Performance
+    /// - `O(k + s)`, where `k` is the size of the hyperedge and `s` its number of timestamps.
+    pub fn add_edge_at(&mut self, edge: &[Node], weight: f64, t: i64) -> bool {
+        self.add_edge_weighted(edge, weight);
+        let times = self.edge_times.entry(Self::compute_edge_id(edge)).or_default();
+        match times.binary_search(&t) {
+            Ok(_) => false,
+            Err(position) => {
+                times.insert(position, t);
+                true
+            }
+        }
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Returns the times at which a hyperedge is active.
+    ///
+    /// # Parameters
+    /// - `edge` : `&[Node]` - The hyperedge.
+    ///
+    /// # Returns
+    /// - `Option<&[i64]>` - The sorted timestamps of the hyperedge, empty if it was added without a time. `None` if
+    ///   the hyperedge is not in the hypergraph.
+    ///
+    /// # Performance
+    /// - `O(k)`, where `k` is the size of the hyperedge.
+    pub fn edge_times(&self, edge: &[Node]) -> Option<&[i64]> {
+        let edge_id = Self::compute_edge_id(edge);
+        if !self.edge_list.contains_key(&edge_id) {
+            return None;
+        }
+        Some(self.edge_times.get(&edge_id).map_or(&[], |times| times.as_slice()))
+    }
+
+    /// Returns the subhypergraph of the hyperedges active in the time window `[t0, t1)`, with their weights, their
+    /// attributes and their timestamps within the window. The hyperedges without timestamps are never active.
+    ///
+    /// Only the nodes of the selected hyperedges are kept, so that eg the degrees in the window count the interactions
+    /// in the window only.
+    ///
+    /// # Parameters
+    /// - `t0` : `i64` - The start of the window, included.
+    /// - `t1` : `i64` - The end of the window, excluded.
+    ///
+    /// # Returns
+    /// - `Hypergraph` - The hyperedges active in the window, empty if `t1 <= t0`.
+    ///
+    /// # Performance
+    /// - `O(e*(k + log(s)))`, where `e` is the number of hyperedges with timestamps, `k` their maximum size and `s`
+    ///   their maximum number of timestamps.
+    pub fn window(&self, t0: i64, t1: i64) -> Hypergraph {
+        let mut res = Hypergraph::with_merge_policy(self.weighted, self.merge_policy);
+
+        let mut active: Vec<(EdgeID, Vec<i64>)> = Vec::new();
+        for (edge_id, times) in self.edge_times.iter() {
+            // The timestamps are sorted, so the ones in the window are contiguous
+            let (start, end) = (times.partition_point(|t| *t < t0), times.partition_point(|t| *t < t1));
+            if start < end {
+                let hyperedge = &self.edge_list[edge_id];
+                Self::compute_add_edge(&mut res, &hyperedge.nodes, hyperedge.weight);
+                active.push((*edge_id, times[start..end].to_vec()));
+            }
+        }
+        // After the copy, which would bring all the timestamps
+        self.compute_copy_metadata(&mut res);
+        res.edge_times.extend(active);
+
+        debug_assert!(res.is_subhypergraph_of(self, true));
+        res
+    }
+
+    /// Returns the number of active hyperedges at every time, eg to spot the busy days of an interaction dataset.
+    ///
+    /// # Returns
+    /// - `Vec<(i64, usize)>` - The `(time, count)` pairs, sorted by time, for every time at which at least one
+    ///   hyperedge is active.
+    ///
+    /// # Performance
+    /// - `O(T + u*log(u))`, where `T` is the total number of timestamps and `u` the number of distinct ones.
+    pub fn timeline(&self) -> Vec<(i64, usize)> {
+        let mut counts: AHashMap<i64, usize> = AHashMap::new();
+        for t in self.edge_times.values().flatten() {
+            *counts.entry(*t).or_insert(0) += 1;
+        }
+
+        let mut res: Vec<(i64, usize)> = counts.into_iter().collect();
+        res.sort_unstable();
+        res
+    }
+
+    /// Effectively moves the timestamps of the hyperedge `from` to the hyperedge `to`, joining them with its own,
+    /// eg after `from` is shrunk into `to`.
+    pub(crate) fn compute_move_edge_times(&mut self, from: EdgeID, to: EdgeID) {
+        if let Some(times) = self.edge_times.remove(&from) {
+            let target = self.edge_times.entry(to).or_default();
+            target.extend(times);
+            target.sort_unstable();
+            target.dedup();
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    fn build() -> Hypergraph {
+        let mut hg = Hypergraph::new(true);
+        hg.add_edge_at(&[1, 2, 3], 1.0, 10);
+        hg.add_edge_at(&[1, 2, 3], 1.0, 12);
+        hg.add_edge_at(&[3, 4], 2.0, 11);
+        hg.add_edge_at(&[1, 4], 3.0, 12);
+        hg.add_edge_at(&[4, 5], 4.0, 20);
+        hg.add_edge(&[5, 6]);
+        hg
+    }
+
+    #[test]
+    fn test_add_edge_at() {
+        let mut hg = build();
+
+        assert_eq!(hg.num_edges(), 5);
+        assert_eq!(hg.edge_times(&[1, 2, 3]), Some(&[10, 12][..]));
+        assert_eq!(hg.edge_times(&[5, 6]), Some(&[][..]));
+        assert_eq!(hg.edge_times(&[6, 7]), None);
+
+        // A new time is accumulated, a repeated one is not
+        assert!(hg.add_edge_at(&[1, 2, 3], 5.0, 7));
+        assert!(!hg.add_edge_at(&[1, 2, 3], 6.0, 12));
+        assert_eq!(hg.edge_times(&[1, 2, 3]), Some(&[7, 10, 12][..]));
+        assert_eq!(hg.get_weight(&[1, 2, 3]), Some(6.0));
+        assert_eq!(hg.num_edges(), 5);
+
+        assert_eq!(hg.timeline(), vec![(7, 1), (10, 1), (11, 1), (12, 2), (20, 1)]);
+
+        assert!(hg.remove_edge(&[1, 2, 3]));
+        hg.add_edge(&[1, 2, 3]);
+        assert_eq!(hg.edge_times(&[1, 2, 3]), Some(&[][..]));
+        assert_eq!(hg.timeline(), vec![(11, 1), (12, 1), (20, 1)]);
+    }
+
+    #[test]
+    fn test_window() {
+        let mut hg = build();
+
+        let early = hg.window(10, 12);
+        assert_eq!(early.get_edges_sorted(), Some(vec![vec![1, 2, 3], vec![3, 4]]));
+        assert_eq!(early.edge_times(&[1, 2, 3]), Some(&[10][..]));
+        assert_eq!(early.get_weight(&[3, 4]), Some(2.0));
+
+        let late = hg.window(12, 21);
+        assert_eq!(late.get_edges_sorted(), Some(vec![vec![1, 2, 3], vec![1, 4], vec![4, 5]]));
+        assert!(!late.check_node(6));
+
+        // The degrees change across the windows
+        let degree = |hg: &Hypergraph, node| hg.get_incident_edges(node, None, None).unwrap().map(|edges| edges.len());
+        assert_eq!(degree(&early, 4), Some(1));
+        assert_eq!(degree(&late, 4), Some(2));
+        assert_eq!(degree(&early, 5), None);
+        assert_eq!(degree(&hg, 4), Some(3));
+
+        assert_eq!(hg.window(13, 20).num_edges(), 0);
+        assert_eq!(hg.window(12, 10).num_nodes(), 0);
+        assert_eq!(hg.window(i64::MIN, i64::MAX).num_edges(), 4);
+
+        hg.set_merge_policy(crate::MergePolicy::Sum);
+        assert_eq!(hg.window(10, 12).merge_policy(), crate::MergePolicy::Sum);
+    }
+
+    #[test]
+    fn test_edge_times_remove_node() {
+        let mut hg = build();
+        hg.add_edge_at(&[2, 3], 1.0, 11);
+
+        // [1, 2, 3] is merged into [2, 3], and [1, 4] is moved to [4]
+        assert!(hg.remove_node(1));
+        assert_eq!(hg.edge_times(&[2, 3]), Some(&[10, 11, 12][..]));
+        assert_eq!(hg.edge_times(&[4]), Some(&[12][..]));
+        assert_eq!(hg.timeline(), vec![(10, 1), (11, 2), (12, 2), (20, 1)]);
+
+        hg.clear_edges();
+        assert!(hg.timeline().is_empty());
+    }
+}
@@ -1,4 +1,4 @@
-use hgraph::Hypergraph;
+use hgraph::{EdgeID, Hypergraph, HypergraphError, MergePolicy};
 
 #[test]
 pub fn test1() {
@@ -7,14 +7,15 @@ pub fn test1() {
         mat.add_node(i);
     }
 
-    mat.add_edge(&vec![0, 2, 5, 6]);
-    mat.add_edge(&vec![3, 4, 5, 9]);
-    mat.add_edge(&vec![10, 11, 5]);
+    mat.add_edge(&[0, 2, 5, 6]);
+    mat.add_edge(&[3, 4, 5, 9]);
+    mat.add_edge(&[10, 11, 5]);
 
     println!("mat = {:?}", mat);
 }
 
 #[test]
+#[allow(clippy::vec_init_then_push)]
 pub fn test2() {
     let mut edges = Vec::new();
     edges.push(vec![1, 3, 5]);
@@ -27,7 +28,7 @@ pub fn test2() {
 
     println!("max order = {}", mat.max_order());
 
-    mat.add_edge_weighted(&vec![1, 6, 3, 5], 100.7);
+    mat.add_edge_weighted(&[1, 6, 3, 5], 100.7);
     mat.add_node(-3);
     println!("distribution orders: {:?}", mat.distrbution_orders());
 
@@ -37,3 +38,390 @@ pub fn test2() {
 
     println!("test2 a: {:?}", mat);
 }
+
+#[test]
+pub fn test_from_bipartite() {
+    // (author, paper) pairs
+    let authorships = vec![(1, 100), (2, 100), (3, 100), (2, 101), (4, 101), (5, 102), (1, 103), (3, 103), (1, 100)];
+
+    let hg = Hypergraph::from_bipartite(&authorships);
+
+    assert!(!hg.is_weighted());
+    assert_eq!(hg.num_nodes(), 5);
+    assert_eq!(hg.num_edges(), 4);
+    assert!(hg.check_edge(&[1, 2, 3]));
+    assert!(hg.check_edge(&[2, 4]));
+    assert!(hg.check_edge(&[5]));
+    assert!(hg.check_edge(&[1, 3]));
+
+    let degree = |node| hg.get_incident_edges(node, None, None).unwrap().unwrap().len();
+    assert_eq!(degree(1), 2);
+    assert_eq!(degree(2), 2);
+    assert_eq!(degree(3), 2);
+    assert_eq!(degree(4), 1);
+    assert_eq!(degree(5), 1);
+}
+
+#[test]
+pub fn test_from_bipartite_weighted() {
+    let authorships = vec![(1, 100), (2, 100), (2, 101), (4, 101), (5, 102)];
+    let weights = [(100, 2.5), (102, 7.0)].into_iter().collect();
+
+    let hg = Hypergraph::from_bipartite_weighted(&authorships, &weights);
+
+    assert!(hg.is_weighted());
+    assert_eq!(hg.get_weight(&[1, 2]), Some(2.5));
+    assert_eq!(hg.get_weight(&[2, 4]), Some(0.0));
+    assert_eq!(hg.get_weight(&[5]), Some(7.0));
+}
+
+#[test]
+pub fn test_edge_containers() {
+    let mut hg = Hypergraph::new(true);
+
+    // Arrays, slices and Vecs are all accepted, and denote the same hyperedge
+    let array = [1, 2, 3];
+    let vector = vec![4, 5];
+    let nodes = vec![1, 2, 3, 4, 5, 6];
+    assert!(hg.add_edge_weighted(&array, 1.0));
+    assert!(hg.add_edge(&vector));
+    assert!(hg.add_edge(&nodes[4..]));
+
+    let copy: Vec<i64> = array.into();
+    assert!(hg.check_edge(&copy));
+    assert!(hg.check_edge(&nodes[..3]));
+    assert!(!hg.add_edge(&[4, 5]));
+    assert!(hg.check_edge(&[5, 6]));
+    assert_eq!(hg.get_weight(&nodes[..3]), Some(1.0));
+    assert_eq!(hg.set_weight(&[1, 2, 3], 2.0), Ok(1.0));
+    assert_eq!(hg.get_weight(&array), Some(2.0));
+
+    assert_eq!(hg.subhypergraph(&[1, 2, 3, 4]).num_edges(), 1);
+    assert_eq!(hg.subhypergraph(&nodes).num_edges(), 3);
+
+    assert!(hg.remove_edge(&nodes[4..]));
+    assert!(hg.remove_edge(&vector));
+    assert!(!hg.check_edge(&[4, 5]));
+    assert_eq!(hg.num_edges(), 1);
+}
+
+#[test]
+pub fn test_edge_id_handles() {
+    let mut hg = Hypergraph::new(true);
+
+    let a = hg.insert_edge_weighted(&[1, 2, 3], 1.5);
+    let b = hg.insert_edge(&[3, 4]);
+    assert_ne!(a, b);
+    assert_eq!(hg.insert_edge_weighted(&[1, 2, 3], 2.5), a);
+
+    // Handles round-trip through the nodes
+    assert_eq!(hg.edge_id(&[1, 2, 3]), Some(a));
+    assert_eq!(hg.edge_id(&[3, 2, 1]), None);
+    assert_eq!(hg.get_edge_by_id(a), Some((&vec![1, 2, 3], 2.5)));
+    assert_eq!(hg.get_edge_by_id(b), Some((&vec![3, 4], 0.0)));
+    assert!(hg.contains_edge_id(a));
+
+    let raw: u64 = a.into();
+    assert_eq!(EdgeID::from(raw), a);
+
+    // Removing a hyperedge invalidates its handle, until it is added again
+    assert!(hg.remove_edge_by_id(a));
+    assert!(!hg.remove_edge_by_id(a));
+    assert!(!hg.contains_edge_id(a));
+    assert_eq!(hg.get_edge_by_id(a), None);
+    assert!(!hg.check_edge(&[1, 2, 3]));
+    assert_eq!(hg.get_incident_edges(1, None, None).unwrap(), Some(Vec::new()));
+    assert!(hg.contains_edge_id(b));
+
+    assert_eq!(hg.insert_edge(&[1, 2, 3]), a);
+    assert!(hg.contains_edge_id(a));
+}
+
+#[test]
+fn test_unweighted_getters() {
+    let mut hg = Hypergraph::new(false);
+    let id = hg.insert_edge(&[1, 2]);
+
+    // Only get_weight tells the missing weight apart
+    assert_eq!(hg.get_weight(&[1, 2]), None);
+    assert_eq!(hg.get_edge_by_id(id), Some((&vec![1, 2], 0.0)));
+    assert_eq!(hg.get_edges_weighted(), Some(vec![(&vec![1, 2], 0.0)]));
+    assert_eq!(hg.get_incident_edges_weighted(1, None, None).unwrap(), Some(vec![(&vec![1, 2], 0.0)]));
+}
+
+#[test]
+pub fn test_std_traits() {
+    let edges = vec![vec![1, 2, 3], vec![3, 4], vec![1, 2, 3], vec![5]];
+
+    let hg: Hypergraph = edges.clone().into_iter().collect();
+    let expected = Hypergraph::from(&edges);
+    assert!(!hg.is_weighted());
+    assert_eq!(hg, expected);
+    assert_eq!(Hypergraph::default().num_nodes(), 0);
+    assert!(!Hypergraph::default().is_weighted());
+
+    // The weighted pairs produce a weighted hypergraph, and extend merges repeated hyperedges
+    let mut hg: Hypergraph = vec![(vec![1, 2], 1.0), (vec![2, 3], 2.0)].into_iter().collect();
+    assert!(hg.is_weighted());
+    hg.extend(vec![(vec![1, 2], 5.0), (vec![4], 3.0)]);
+    hg.extend(vec![vec![5, 6]].into_iter().chain(std::iter::once(vec![2, 3])));
+    assert_eq!(hg.num_edges(), 4);
+    assert_eq!(hg.get_weight(&[1, 2]), Some(5.0));
+    assert_eq!(hg.get_weight(&[2, 3]), Some(0.0));
+    assert_eq!(hg.get_weight(&[5, 6]), Some(0.0));
+
+    // Draining into another container
+    let mut pairs: Vec<(Vec<i64>, f64)> = hg.into_iter().collect();
+    pairs.sort_by(|a, b| a.0.cmp(&b.0));
+    assert_eq!(pairs, vec![(vec![1, 2], 5.0), (vec![2, 3], 0.0), (vec![4], 3.0), (vec![5, 6], 0.0)]);
+}
+
+#[test]
+pub fn test_is_subhypergraph_of() {
+    let mut hg = Hypergraph::from_weighted(&[vec![1, 2, 3], vec![3, 4], vec![4, 5, 6]], &[1.0, 2.0, 3.0]);
+    hg.add_node(7);
+
+    // Filtering operations always give subhypergraphs, even with nodes which are not in the hypergraph
+    let sub = hg.subhypergraph(&[1, 2, 3, 4, 7, 100]);
+    assert!(sub.is_subhypergraph_of(&hg, true));
+    assert!(!sub.check_node(100));
+    assert!(!hg.is_subhypergraph_of(&sub, false));
+    assert!(hg.subhypergraph_by_orders(Some(&vec![1]), None, true).unwrap().is_subhypergraph_of(&hg, true));
+    assert!(hg.is_subhypergraph_of(&hg, true));
+
+    // Weights only matter with strict_weights
+    let mut lighter = sub.clone();
+    lighter.set_weight(&[3, 4], 0.5).unwrap();
+    assert!(lighter.is_subhypergraph_of(&hg, false));
+    assert!(!lighter.is_subhypergraph_of(&hg, true));
+
+    // Isolated nodes count, and the order of the nodes in the hyperedges matters
+    let mut other = Hypergraph::new(true);
+    other.add_node(8);
+    assert!(!other.is_subhypergraph_of(&hg, false));
+    assert!(!Hypergraph::from(&[vec![2, 1, 3]]).is_subhypergraph_of(&hg, false));
+    assert!(Hypergraph::from(&[vec![1, 2, 3]]).is_subhypergraph_of(&hg, false));
+
+    assert!(Hypergraph::new(false).is_subhypergraph_of(&hg, true));
+    assert!(Hypergraph::new(false).is_subhypergraph_of(&Hypergraph::new(true), true));
+}
+
+#[test]
+fn test_subhypergraph_by_edges() {
+    let mut hg = Hypergraph::from_weighted(&[vec![1, 2, 3], vec![3, 4], vec![4, 5], vec![6]], &[1.0, 2.0, 3.0, 4.0]);
+    hg.add_node(7);
+    let selected = vec![vec![3, 4], vec![5, 4], vec![6], vec![1, 2, 3], vec![8, 9]];
+
+    let sub = hg.subhypergraph_by_edges(&selected, true, false).unwrap();
+    assert_eq!(sub.get_nodes_sorted(), vec![1, 2, 3, 4, 6]);
+    assert_eq!(sub.get_edges_sorted(), Some(vec![vec![1, 2, 3], vec![3, 4], vec![6]]));
+    assert_eq!(sub.get_weight(&[3, 4]), Some(2.0));
+    assert!(sub.is_subhypergraph_of(&hg, true));
+
+    let sub = hg.subhypergraph_by_edges(&selected, true, true).unwrap();
+    assert_eq!(sub.get_nodes_sorted(), hg.get_nodes_sorted());
+    assert_eq!(sub.num_edges(), 3);
+
+    assert_eq!(hg.subhypergraph_by_edges(&selected, false, false), Err(HypergraphError::EdgeNotFound));
+    assert_eq!(hg.subhypergraph_by_edges(&selected, false, true), Err(HypergraphError::EdgeNotFound));
+    assert_eq!(hg.subhypergraph_by_edges(&selected[2..4], false, false).unwrap().num_edges(), 2);
+    assert_eq!(hg.subhypergraph_by_edges(&[], false, false).unwrap().num_nodes(), 0);
+
+    hg.set_merge_policy(MergePolicy::Sum);
+    assert_eq!(hg.subhypergraph_by_edges(&selected, true, false).unwrap().merge_policy(), MergePolicy::Sum);
+    assert_eq!(hg.subhypergraph(&[3, 4]).merge_policy(), MergePolicy::Sum);
+    assert_eq!(hg.subhypergraph_by_orders(Some(&vec![1]), None, false).unwrap().merge_policy(), MergePolicy::Sum);
+}
+
+#[test]
+fn test_subhypergraph_filter() {
+    let mut hg = Hypergraph::from_weighted(
+        &[vec![1, 2, 3], vec![3, 4], vec![4, 5], vec![6], vec![4, 6, 7, 8]],
+        &[1.0, 2.0, 0.5, 4.0, 1.5],
+    );
+    hg.add_node(9);
+
+    let heavy = hg.subhypergraph_filter(|_, weight| weight > 1.0, false);
+    assert_eq!(heavy.get_edges_sorted(), Some(vec![vec![3, 4], vec![4, 6, 7, 8], vec![6]]));
+    assert_eq!(heavy.get_weight(&[6]), Some(4.0));
+    assert_eq!(heavy.get_nodes_sorted(), vec![3, 4, 6, 7, 8]);
+
+    let sub = hg.subhypergraph_filter(|nodes, _| nodes.len() >= 2 && nodes.contains(&4), true);
+    assert_eq!(sub.get_edges_sorted(), Some(vec![vec![3, 4], vec![4, 5], vec![4, 6, 7, 8]]));
+    assert_eq!(sub.get_nodes_sorted(), hg.get_nodes_sorted());
+
+    for keep_nodes in [false, true] {
+        let by_size = hg.subhypergraph_filter(|nodes, _| nodes.len() == 2 || nodes.len() == 4, keep_nodes);
+        assert_eq!(by_size, hg.subhypergraph_by_orders(None, Some(&vec![2, 4]), keep_nodes).unwrap());
+    }
+
+    hg.set_merge_policy(MergePolicy::Max);
+    assert_eq!(hg.subhypergraph_filter(|_, weight| weight > 1.0, false).merge_policy(), MergePolicy::Max);
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn test_subhypergraph_randomized() {
+    use hgraph::generators::{random_uniform, Rng, SplitMix64};
+    use std::collections::HashSet;
+
+    let mut rng = SplitMix64::new(42);
+    let mut hg = random_uniform(60, 2, 150, &mut rng).unwrap().to_weighted(1.0);
+    for size in [1, 3, 5] {
+        for edge in random_uniform(60, size, 60, &mut rng).unwrap() {
+            hg.add_edge_weighted(&edge.0, rng.gen_f64());
+        }
+    }
+    hg.add_edge(&[]);
+
+    for len in [0, 1, 5, 20, 40, 60] {
+        let mut query: Vec<i64> = (0..len).map(|_| rng.gen_index(70) as i64).collect();
+        query.push(-1);
+
+        // Reference: the previous implementation, scanning all the hyperedges
+        let query_set: HashSet<i64> = query.iter().copied().collect();
+        let mut expected = hg.subhypergraph_filter(|nodes, _| nodes.iter().all(|node| query_set.contains(node)), false);
+        expected.add_nodes(&query.iter().copied().filter(|node| hg.check_node(*node)).collect::<Vec<i64>>());
+
+        assert_eq!(hg.subhypergraph(&query), expected);
+    }
+}
+
+#[test]
+fn test_remove_edge_incidence() {
+    use hgraph::HypergraphOps;
+
+    let mut hg = Hypergraph::from(&[vec![1, 2, 3], vec![3, 4], vec![4, 5, 5], vec![6], vec![1, 6]]);
+    hg.add_node(7);
+
+    assert!(hg.remove_edge(&[3, 4]));
+    assert!(!hg.remove_edge(&[3, 4]));
+    assert!(hg.remove_edge(&[4, 5, 5]));
+    let id = hg.edge_id(&[1, 6]).unwrap();
+    assert!(hg.remove_edge_by_id(id));
+    assert!(!hg.remove_edge_by_id(id));
+
+    // No stale ids in any incidence list
+    for node in hg.get_nodes() {
+        for edge_id in hg.incident_edge_ids(node).unwrap() {
+            assert!(hg.contains_edge_id(edge_id));
+            assert!(hg.edge_nodes(edge_id).unwrap().contains(&node));
+        }
+    }
+    assert_eq!(hg.num_nodes(), 7);
+    assert_eq!(hg.degree(3), Some(1));
+    assert_eq!(hg.degree(4), Some(0));
+    assert_eq!(hg.degree(5), Some(0));
+    assert_eq!(hg.degree(6), Some(1));
+    assert_eq!(hg.get_edges_sorted(), Some(vec![vec![1, 2, 3], vec![6]]));
+}
+
+// Checks that the incidence lists and the hyperedges agree, with no dangling ids
+fn check_incidence(hg: &Hypergraph) {
+    use hgraph::HypergraphOps;
+
+    let mut incidences = 0;
+    for node in hg.get_nodes() {
+        for edge_id in hg.incident_edge_ids(node).unwrap() {
+            assert!(hg.edge_nodes(edge_id).unwrap().contains(&node));
+            incidences += 1;
+        }
+    }
+    for (edge, _) in hg.get_edges_weighted().unwrap_or_default() {
+        let mut members = edge.clone();
+        members.dedup();
+        for node in members.iter() {
+            assert!(hg.incident_edge_ids(*node).unwrap().any(|edge_id| Some(edge_id) == hg.edge_id(edge)));
+        }
+    }
+    let expected: usize = hg
+        .get_edges()
+        .unwrap_or_default()
+        .iter()
+        .map(|edge| edge.iter().collect::<std::collections::HashSet<_>>().len())
+        .sum();
+    assert_eq!(incidences, expected);
+}
+
+#[test]
+fn test_remove_node_in_place() {
+    let mut hg = Hypergraph::from_weighted(
+        &[vec![1, 2, 3], vec![2, 3], vec![1], vec![1, 4], vec![4, 5], vec![3, 1, 2]],
+        &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0],
+    );
+    hg.set_merge_policy(hgraph::MergePolicy::Sum);
+
+    assert!(hg.remove_node(1));
+    assert!(!hg.remove_node(1));
+    check_incidence(&hg);
+
+    // [1, 2, 3] merged into [2, 3], [1] dropped, [1, 4] shrunk
+    assert_eq!(hg.get_edges_sorted(), Some(vec![vec![2, 3], vec![2, 3], vec![4], vec![4, 5]]));
+    assert_eq!(hg.get_weight(&[2, 3]), Some(3.0));
+    assert_eq!(hg.get_weight(&[3, 2]), Some(6.0));
+    assert_eq!(hg.get_weight(&[4]), Some(4.0));
+    assert_eq!(hg.get_nodes_sorted(), vec![2, 3, 4, 5]);
+    assert_eq!(hg.get_incident_edges(2, None, None).unwrap().unwrap().len(), 2);
+
+    hg.remove_nodes(&[4, 2]);
+    check_incidence(&hg);
+    assert_eq!(hg.get_edges_sorted(), Some(vec![vec![3], vec![5]]));
+    assert_eq!(hg.get_weight(&[3]), Some(9.0));
+}
+
+#[test]
+fn test_remove_high_degree_node() {
+    let edges: Vec<Vec<i64>> = (1..50_000).map(|i| vec![0, i, i + 1]).collect();
+    let mut hg = Hypergraph::from(&edges);
+
+    assert!(hg.remove_node(0));
+    check_incidence(&hg);
+    assert_eq!(hg.num_edges(), 49_999);
+    assert_eq!(hg.num_nodes(), 50_000);
+    assert!(hg.check_edge(&[7, 8]));
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn test_strong_remove_nodes_batched() {
+    use hgraph::generators::{random_uniform, Rng, SplitMix64};
+
+    let mut rng = SplitMix64::new(7);
+    for size in [2, 3, 5] {
+        let hg = random_uniform(80, size, 120, &mut rng).unwrap();
+        let nodes: Vec<i64> = (0..20).map(|_| rng.gen_index(90) as i64).collect();
+
+        let mut one_by_one = hg.clone();
+        for node in nodes.iter() {
+            one_by_one.strong_remove_node(*node);
+        }
+
+        // Reference: the hyperedges with none of the nodes
+        let mut expected = hg.subhypergraph_filter(|edge, _| edge.iter().all(|node| !nodes.contains(node)), false);
+        expected.add_nodes(&hg.get_nodes().into_iter().filter(|node| !nodes.contains(node)).collect::<Vec<i64>>());
+
+        let mut batched = hg.clone();
+        assert_eq!(batched.strong_remove_nodes(&nodes), hg.num_edges() - expected.num_edges());
+        assert_eq!(batched, expected);
+        assert_eq!(one_by_one, expected);
+        check_incidence(&batched);
+    }
+}
+
+#[test]
+fn test_with_capacity() {
+    let edges = vec![vec![1, 2, 3], vec![3, 4], vec![1, 2, 3], vec![5]];
+
+    let mut hg = Hypergraph::with_capacity(false, 100, 1000);
+    assert_eq!(hg, Hypergraph::new(false));
+    hg.add_edges(&edges);
+    assert_eq!(hg, Hypergraph::from(&edges));
+
+    let mut hg = Hypergraph::with_capacity(true, 0, 0);
+    hg.reserve_nodes(10);
+    hg.reserve_edges(10);
+    hg.add_edges_weighted(&edges, &[1.0, 2.0, 3.0, 4.0]);
+    assert!(hg.is_weighted());
+    assert_eq!(hg, Hypergraph::from_weighted(&edges, &[1.0, 2.0, 3.0, 4.0]));
+    assert_eq!(hg.get_weight(&[1, 2, 3]), Some(3.0));
+}
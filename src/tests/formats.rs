@@ -0,0 +1,474 @@
+use hgraph::{Hypergraph, HypergraphError};
+
+const HYPERGRAPHX_WEIGHTED: &str = include_str!("fixtures/hypergraphx_weighted.json");
+const HYPERGRAPHX_UNWEIGHTED: &str = include_str!("fixtures/hypergraphx_unweighted.json");
+
+fn parse_message(err: HypergraphError) -> String {
+    match err {
+        HypergraphError::Parse(message) => message,
+        other => panic!("Unexpected error {:?}", other),
+    }
+}
+
+fn is_parse_error<T>(result: Result<T, HypergraphError>) -> bool {
+    matches!(result, Err(HypergraphError::Parse(_)))
+}
+
+#[test]
+pub fn test_from_json_weighted() {
+    let hg = Hypergraph::from_json(HYPERGRAPHX_WEIGHTED).unwrap();
+
+    assert!(hg.is_weighted());
+    assert_eq!(hg.num_nodes(), 6);
+    assert_eq!(hg.num_edges(), 3);
+    assert_eq!(hg.get_weight(&[0, 1, 2]), Some(1.5));
+    assert_eq!(hg.get_weight(&[2, 3]), Some(27.7));
+    // Missing weights default to 0
+    assert_eq!(hg.get_weight(&[3, 4]), Some(0.0));
+    // Isolated nodes are kept
+    assert!(hg.check_node(5));
+}
+
+#[test]
+pub fn test_from_json_unweighted() {
+    let hg = Hypergraph::from_json(HYPERGRAPHX_UNWEIGHTED).unwrap();
+
+    assert!(!hg.is_weighted());
+    assert_eq!(hg.num_nodes(), 4);
+    assert_eq!(hg.num_edges(), 2);
+    assert!(hg.check_edge(&[2, 3, 4]));
+}
+
+#[test]
+pub fn test_to_json_shape() {
+    let hg = Hypergraph::from_json(HYPERGRAPHX_WEIGHTED).unwrap();
+
+    assert_eq!(
+        hg.to_json(),
+        "{\"type\": \"Hypergraph\", \"weighted\": true, \"nodes\": [0, 1, 2, 3, 4, 5], \
+         \"edges\": [[0, 1, 2], [2, 3], [3, 4]], \"weights\": {\"0\": 1.5, \"1\": 27.7, \"2\": 0}}"
+    );
+
+    let hg = Hypergraph::from_json(HYPERGRAPHX_UNWEIGHTED).unwrap();
+    assert_eq!(
+        hg.to_json(),
+        "{\"type\": \"Hypergraph\", \"weighted\": false, \"nodes\": [1, 2, 3, 4], \"edges\": [[1, 2], [2, 3, 4]]}"
+    );
+}
+
+#[test]
+pub fn test_json_round_trip() {
+    let mut hg = Hypergraph::new(true);
+    hg.add_nodes(&[-7, 100]);
+    hg.add_edge_weighted(&[3, 1, 2], 0.125);
+    hg.add_edge_weighted(&[-7, 4], 1e-3);
+    hg.add_edge(&[9]);
+
+    let json = hg.to_json();
+    let restored = Hypergraph::from_json(&json).unwrap();
+
+    assert_eq!(restored.to_json(), json);
+    assert_eq!(restored.num_nodes(), hg.num_nodes());
+    assert_eq!(restored.get_weight(&[3, 1, 2]), Some(0.125));
+    assert_eq!(restored.get_weight(&[-7, 4]), Some(1e-3));
+}
+
+#[test]
+pub fn test_from_json_errors() {
+    assert!(is_parse_error(Hypergraph::from_json("[]")));
+    assert!(is_parse_error(Hypergraph::from_json("{\"nodes\": [1]}")));
+    assert!(is_parse_error(Hypergraph::from_json("{\"edges\": [[1, \"a\"]]}")));
+    assert!(is_parse_error(Hypergraph::from_json("{\"edges\": [[1, 2]], \"weights\": {\"0\": \"x\"}}")));
+    assert!(is_parse_error(Hypergraph::from_json("{\"edges\": [[1, 2]]")));
+}
+
+#[test]
+pub fn test_from_json_deep_nesting() {
+    // Deep enough to overflow the stack of the parser without a limit on the nesting
+    let json = format!("{{\"edges\": {}{}}}", "[".repeat(200_000), "]".repeat(200_000));
+    assert!(parse_message(Hypergraph::from_json(&json).unwrap_err()).contains("nesting too deep"));
+}
+
+const HIF_WEIGHTED: &str = include_str!("fixtures/hif_weighted.json");
+const HIF_ISOLATED: &str = include_str!("fixtures/hif_isolated.json");
+
+#[test]
+pub fn test_from_hif_weighted() {
+    let hg = Hypergraph::from_hif(HIF_WEIGHTED.as_bytes()).unwrap();
+
+    assert!(hg.is_weighted());
+    assert_eq!(hg.num_nodes(), 4);
+    assert_eq!(hg.num_edges(), 2);
+    assert_eq!(hg.get_weight(&[1, 2, 3]), Some(2.5));
+    assert_eq!(hg.get_weight(&[3, 4]), Some(0.5));
+}
+
+#[test]
+pub fn test_from_hif_isolated() {
+    let hg = Hypergraph::from_hif(HIF_ISOLATED.as_bytes()).unwrap();
+
+    assert!(!hg.is_weighted());
+    assert_eq!(hg.num_nodes(), 5);
+    // The edge without incidences is skipped
+    assert_eq!(hg.num_edges(), 2);
+    assert!(hg.check_edge(&[10, 11]));
+    assert!(hg.check_edge(&[12]));
+    assert_eq!(hg.get_incident_edges(13, None, None), Ok(Some(vec![])));
+    assert!(hg.check_node(-5));
+}
+
+#[test]
+pub fn test_hif_round_trip() {
+    for fixture in [HIF_WEIGHTED, HIF_ISOLATED] {
+        let hg = Hypergraph::from_hif(fixture.as_bytes()).unwrap();
+
+        let mut out = Vec::new();
+        hg.to_hif(&mut out).unwrap();
+        let restored = Hypergraph::from_hif(out.as_slice()).unwrap();
+
+        assert_eq!(restored.to_json(), hg.to_json());
+    }
+}
+
+#[test]
+pub fn test_to_hif_layout() {
+    let mut hg = Hypergraph::new(true);
+    hg.add_edge_weighted(&[2, 1], 1.5);
+    hg.add_node(7);
+
+    let mut out = Vec::new();
+    hg.to_hif(&mut out).unwrap();
+
+    assert_eq!(
+        String::from_utf8(out).unwrap(),
+        "{\"network-type\": \"undirected\", \"incidences\": [{\"edge\": 0, \"node\": 2}, {\"edge\": 0, \"node\": 1}], \
+         \"nodes\": [{\"node\": 1}, {\"node\": 2}, {\"node\": 7}], \"edges\": [{\"edge\": 0, \"weight\": 1.5}]}"
+    );
+}
+
+#[test]
+pub fn test_from_hif_errors() {
+    assert!(is_parse_error(Hypergraph::from_hif("[]".as_bytes())));
+    assert!(is_parse_error(Hypergraph::from_hif("{\"incidences\": [{\"edge\": 0, \"node\": \"alice\"}]}".as_bytes())));
+    assert!(is_parse_error(Hypergraph::from_hif("{\"incidences\": [{\"node\": 1}]}".as_bytes())));
+    assert!(is_parse_error(Hypergraph::from_hif("{\"edges\": [{\"edge\": 0, \"weight\": \"x\"}]}".as_bytes())));
+}
+
+#[test]
+pub fn test_from_hif_deep_nesting() {
+    let hif = format!("{{\"incidences\": {}{}}}", "[".repeat(200_000), "]".repeat(200_000));
+    assert!(parse_message(Hypergraph::from_hif(hif.as_bytes()).unwrap_err()).contains("nesting too deep"));
+}
+
+const EDGE_LIST: &str = include_str!("fixtures/edge_list.txt");
+const EDGE_LIST_WEIGHTED: &str = include_str!("fixtures/edge_list_weighted.csv");
+
+#[test]
+pub fn test_read_edge_list() {
+    let hg = Hypergraph::read_edge_list(EDGE_LIST.as_bytes(), ' ', false).unwrap();
+
+    assert!(!hg.is_weighted());
+    assert_eq!(hg.num_nodes(), 5);
+    // The duplicated hyperedge is inserted once
+    assert_eq!(hg.num_edges(), 3);
+    assert!(hg.check_edge(&[1, 2, 3]));
+    assert!(hg.check_edge(&[2, 4]));
+    assert!(hg.check_edge(&[5]));
+}
+
+#[test]
+pub fn test_read_edge_list_weighted() {
+    let hg = Hypergraph::read_edge_list(EDGE_LIST_WEIGHTED.as_bytes(), ',', true).unwrap();
+
+    assert!(hg.is_weighted());
+    assert_eq!(hg.num_edges(), 2);
+    // The last occurrence of a duplicated hyperedge gives its weight
+    assert_eq!(hg.get_weight(&[1, 2, 3]), Some(1.5));
+    assert_eq!(hg.get_weight(&[2, 4]), Some(27.7));
+}
+
+#[test]
+pub fn test_read_edge_list_errors() {
+    let err = parse_message(Hypergraph::read_edge_list("1 2\n# ok\n3 x 4\n".as_bytes(), ' ', false).unwrap_err());
+    assert!(err.starts_with("Line 3"), "{}", err);
+    assert!(err.contains("'x'"), "{}", err);
+
+    let err = parse_message(Hypergraph::read_edge_list("1,2,0.5\n1,2,heavy\n".as_bytes(), ',', true).unwrap_err());
+    assert!(err.starts_with("Line 2"), "{}", err);
+
+    let err = parse_message(Hypergraph::read_edge_list("\n\n3.5\n".as_bytes(), ' ', true).unwrap_err());
+    assert!(err.starts_with("Line 3"), "{}", err);
+}
+
+#[test]
+pub fn test_edge_list_round_trip() {
+    for (input, separator, weighted) in [(EDGE_LIST, ' ', false), (EDGE_LIST_WEIGHTED, ',', true)] {
+        let hg = Hypergraph::read_edge_list(input.as_bytes(), separator, weighted).unwrap();
+
+        let mut out = Vec::new();
+        hg.write_edge_list(&mut out, separator).unwrap();
+        let restored = Hypergraph::read_edge_list(out.as_slice(), separator, weighted).unwrap();
+
+        assert_eq!(restored.to_json(), hg.to_json());
+    }
+
+    let hg = Hypergraph::read_edge_list(EDGE_LIST_WEIGHTED.as_bytes(), ',', true).unwrap();
+    let mut out = Vec::new();
+    hg.write_edge_list(&mut out, '\t').unwrap();
+    assert_eq!(String::from_utf8(out).unwrap(), "1\t2\t3\t1.5\n2\t4\t27.7\n");
+}
+
+#[cfg(feature = "csv")]
+const PAPERS: &str = include_str!("fixtures/papers.csv");
+
+#[cfg(feature = "csv")]
+fn papers_options(lossy: bool) -> hgraph::CsvOptions {
+    hgraph::CsvOptions {
+        nodes_column: 1,
+        node_delimiter: ';',
+        weight_column: Some(2),
+        lossy,
+        ..Default::default()
+    }
+}
+
+#[cfg(feature = "csv")]
+#[test]
+pub fn test_from_csv_lossy() {
+    let (hg, skipped) = Hypergraph::from_csv(PAPERS.as_bytes(), &papers_options(true)).unwrap();
+
+    // The header is row 1, the row with node "x" is row 5
+    assert_eq!(skipped, vec![5]);
+    assert!(hg.is_weighted());
+    assert_eq!(hg.num_edges(), 4);
+    assert_eq!(hg.get_weight(&[1, 2, 3]), Some(12.5));
+    // Missing weights default to 0
+    assert_eq!(hg.get_weight(&[4]), Some(0.0));
+    assert_eq!(hg.get_weight(&[2, 5]), Some(3.0));
+    assert_eq!(hg.get_weight(&[7, 8]), Some(2.0));
+}
+
+#[cfg(feature = "csv")]
+#[test]
+pub fn test_from_csv_fail_fast() {
+    let err = parse_message(Hypergraph::from_csv(PAPERS.as_bytes(), &papers_options(false)).unwrap_err());
+    assert!(err.starts_with("Row 5"), "{}", err);
+    assert!(err.contains("'x'"), "{}", err);
+}
+
+#[cfg(feature = "csv")]
+#[test]
+pub fn test_from_csv_defaults() {
+    let input = "nodes\n1 2 3\n\n\"4  5\"\n";
+    let (hg, skipped) = Hypergraph::from_csv(input.as_bytes(), &hgraph::CsvOptions::default()).unwrap();
+
+    assert!(skipped.is_empty());
+    assert!(!hg.is_weighted());
+    assert!(hg.check_edge(&[1, 2, 3]));
+    assert!(hg.check_edge(&[4, 5]));
+
+    let options = hgraph::CsvOptions { has_header: false, ..Default::default() };
+    assert!(is_parse_error(Hypergraph::from_csv("nodes\n1 2\n".as_bytes(), &options)));
+    assert!(is_parse_error(Hypergraph::from_csv("\"1 2\n".as_bytes(), &options)));
+}
+
+/// Builds a pseudo-random hypergraph with a fixed linear congruential generator.
+fn pseudo_random_hypergraph(num_edges: usize, weighted: bool) -> Hypergraph {
+    let mut state = 0x2545F4914F6CDD1D_u64;
+    let mut next = move || {
+        state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        state >> 33
+    };
+
+    let mut hg = Hypergraph::new(weighted);
+    hg.add_nodes(&[-1, -2, -3]);
+    while hg.num_edges() < num_edges {
+        let len = 1 + (next() % 6) as usize;
+        let edge: Vec<i64> = (0..len).map(|_| (next() % 5000) as i64).collect();
+        hg.add_edge_weighted(&edge, (next() % 1000) as f64 / 8.0);
+    }
+    hg
+}
+
+#[test]
+pub fn test_binary_round_trip() {
+    for weighted in [true, false] {
+        let hg = pseudo_random_hypergraph(10_000, weighted);
+
+        let mut out = Vec::new();
+        hg.save_binary(&mut out).unwrap();
+        let restored = Hypergraph::load_binary(out.as_slice()).unwrap();
+
+        assert_eq!(restored.is_weighted(), weighted);
+        assert_eq!(restored.num_nodes(), hg.num_nodes());
+        assert_eq!(restored.num_edges(), 10_000);
+        assert_eq!(restored.to_json(), hg.to_json());
+    }
+}
+
+#[test]
+pub fn test_binary_corrupted() {
+    let hg = pseudo_random_hypergraph(100, true);
+    let mut out = Vec::new();
+    hg.save_binary(&mut out).unwrap();
+
+    // Truncated snapshots
+    for len in [0, 3, 6, 20, out.len() / 2, out.len() - 1] {
+        let err = Hypergraph::load_binary(&out[..len]).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::UnexpectedEof);
+    }
+
+    // Wrong magic bytes, version and weighted flag
+    for (position, value) in [(0, b'X'), (4, 99), (5, 7)] {
+        let mut corrupted = out.clone();
+        corrupted[position] = value;
+        let err = Hypergraph::load_binary(corrupted.as_slice()).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+    }
+
+    // A huge number of hyperedges must not be trusted
+    let mut corrupted = out.clone();
+    corrupted[14..22].copy_from_slice(&u64::MAX.to_le_bytes());
+    assert!(Hypergraph::load_binary(corrupted.as_slice()).is_err());
+}
+
+fn dot_hypergraph(weighted: bool) -> Hypergraph {
+    let mut hg = Hypergraph::new(weighted);
+    hg.add_edge_weighted(&[3, 1, 2], 1.5);
+    hg.add_edge_weighted(&[2, 3], 2.0);
+    hg.add_node(-4);
+    hg
+}
+
+#[test]
+pub fn test_to_dot_bipartite() {
+    let expected = "graph hypergraph {
+    node [shape=circle];
+    \"-4\";
+    \"1\";
+    \"2\";
+    \"3\";
+    \"e0\" [shape=square, label=\"2\"];
+    \"e1\" [shape=square, label=\"1.5\"];
+    \"e0\" -- \"2\";
+    \"e0\" -- \"3\";
+    \"e1\" -- \"3\";
+    \"e1\" -- \"1\";
+    \"e1\" -- \"2\";
+}
+";
+    assert_eq!(dot_hypergraph(true).to_dot(hgraph::DotStyle::Bipartite), expected);
+    assert!(dot_hypergraph(false).to_dot(hgraph::DotStyle::Bipartite).contains("\"e1\" [shape=square, label=\"\"];"));
+}
+
+#[test]
+pub fn test_to_dot_clique() {
+    let expected = "graph hypergraph {
+    node [shape=circle];
+    \"-4\";
+    \"1\";
+    \"2\";
+    \"3\";
+    \"1\" -- \"2\" [label=\"1.5\"];
+    \"1\" -- \"3\" [label=\"1.5\"];
+    \"2\" -- \"3\" [label=\"3.5\"];
+}
+";
+    assert_eq!(dot_hypergraph(true).to_dot(hgraph::DotStyle::Clique), expected);
+
+    let unweighted = dot_hypergraph(false).to_dot(hgraph::DotStyle::Clique);
+    assert!(unweighted.contains("    \"2\" -- \"3\";\n"));
+    assert!(!unweighted.contains("label"));
+}
+
+/// Lightweight XML reader: checks that the tags are well nested and counts the opening tags by name.
+fn count_xml_elements(xml: &str) -> std::collections::HashMap<String, usize> {
+    let mut counts = std::collections::HashMap::new();
+    let mut stack: Vec<String> = Vec::new();
+
+    let mut rest = xml;
+    while let Some(start) = rest.find('<') {
+        let end = rest[start..].find('>').expect("unterminated tag") + start;
+        let tag = &rest[start + 1..end];
+        rest = &rest[end + 1..];
+
+        if tag.starts_with('?') {
+            continue;
+        }
+        if let Some(name) = tag.strip_prefix('/') {
+            assert_eq!(stack.pop().as_deref(), Some(name), "mismatched closing tag");
+            continue;
+        }
+
+        assert_eq!(tag.matches('"').count() % 2, 0, "unbalanced quotes in <{}>", tag);
+        let name = tag.split_whitespace().next().unwrap().trim_end_matches('/').to_string();
+        *counts.entry(name.clone()).or_insert(0) += 1;
+        if !tag.ends_with('/') {
+            stack.push(name);
+        }
+    }
+
+    assert!(stack.is_empty(), "unclosed tags: {:?}", stack);
+    counts
+}
+
+#[test]
+pub fn test_to_graphml() {
+    let hg = dot_hypergraph(true);
+
+    let mut out = Vec::new();
+    hg.to_graphml(&mut out).unwrap();
+    let xml = String::from_utf8(out).unwrap();
+
+    let counts = count_xml_elements(&xml);
+    assert_eq!(counts["graphml"], 1);
+    assert_eq!(counts["graph"], 1);
+    assert_eq!(counts["key"], 2);
+    // 4 nodes and 2 hyperedges
+    assert_eq!(counts["node"], 6);
+    // 3 + 2 incidences
+    assert_eq!(counts["edge"], 5);
+    assert_eq!(xml.matches("<data key=\"kind\">edge</data>").count(), 2);
+    assert!(xml.contains("<node id=\"n-4\"><data key=\"kind\">node</data></node>"));
+    assert!(xml.contains("<node id=\"e1\"><data key=\"kind\">edge</data><data key=\"weight\">1.5</data></node>"));
+    assert!(xml.contains("<edge id=\"i4\" source=\"e1\" target=\"n2\"/>"));
+
+    // Ids are deterministic
+    let mut again = Vec::new();
+    dot_hypergraph(true).to_graphml(&mut again).unwrap();
+    assert_eq!(String::from_utf8(again).unwrap(), xml);
+}
+
+#[test]
+pub fn test_sorted_output() {
+    // The same hypergraph, built in a different order and with a different history
+    let mut a = Hypergraph::new(true);
+    a.add_nodes(&[9, 8]);
+    a.add_edges_weighted(&[vec![3, 1, 2], vec![5, 4], vec![2, 7], vec![1, 6]], &[1.0, 2.0, 3.0, 4.0]);
+
+    let mut b = Hypergraph::new(true);
+    b.add_edge_weighted(&[1, 6], 4.0);
+    b.add_edge_weighted(&[100, 101], 5.0);
+    b.add_edge_weighted(&[2, 7], 3.0);
+    b.add_edge_weighted(&[5, 4], 2.0);
+    b.add_edge_weighted(&[3, 1, 2], 0.5);
+    b.set_weight(&[3, 1, 2], 1.0).unwrap();
+    b.remove_edge(&[100, 101]);
+    b.remove_node(100);
+    b.remove_node(101);
+    b.add_nodes(&[8, 9]);
+
+    assert_eq!(a.get_nodes_sorted(), vec![1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    assert_eq!(a.get_nodes_sorted(), b.get_nodes_sorted());
+    assert_eq!(a.get_edges_sorted(), Some(vec![vec![1, 2, 3], vec![1, 6], vec![2, 7], vec![4, 5]]));
+    assert_eq!(a.get_edges_sorted(), b.get_edges_sorted());
+    assert_eq!(format!("{:?}", a.debug_sorted()), format!("{:?}", b.debug_sorted()));
+
+    let (mut bytes_a, mut bytes_b) = (Vec::new(), Vec::new());
+    a.save_binary(&mut bytes_a).unwrap();
+    b.save_binary(&mut bytes_b).unwrap();
+    assert_eq!(bytes_a, bytes_b);
+    assert_eq!(a.to_json(), b.to_json());
+
+    assert_eq!(Hypergraph::new(false).get_edges_sorted(), None);
+}
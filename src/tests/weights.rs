@@ -0,0 +1,234 @@
+use hgraph::{Hypergraph, HypergraphError, MergePolicy};
+
+#[test]
+pub fn test_weighted_hypergraph_weighted_calls() {
+    let mut hg = Hypergraph::new(true);
+
+    assert!(hg.add_edge_weighted(&[1, 2, 3], 2.5));
+    assert_eq!(hg.try_add_edge_weighted(&[3, 4], 1.5), Ok(true));
+    assert_eq!(hg.try_add_edge_weighted(&[3, 4], 4.0), Ok(false));
+
+    assert_eq!(hg.get_weight(&[1, 2, 3]), Some(2.5));
+    assert_eq!(hg.get_weight(&[3, 4]), Some(4.0));
+    assert_eq!(hg.set_weight(&[1, 2, 3], 7.0), Ok(2.5));
+    assert_eq!(hg.get_weight(&[1, 2, 3]), Some(7.0));
+
+    assert_eq!(hg.get_weight(&[3, 2, 1]), None);
+    assert_eq!(hg.set_weight(&[3, 2, 1], 1.0), Err(HypergraphError::EdgeNotFound));
+}
+
+#[test]
+pub fn test_weighted_hypergraph_unweighted_calls() {
+    let mut hg = Hypergraph::new(true);
+
+    // A hyperedge added without a weight has a real weight 0
+    assert!(hg.add_edge(&[1, 2, 3]));
+    assert_eq!(hg.get_weight(&[1, 2, 3]), Some(0.0));
+}
+
+#[test]
+pub fn test_unweighted_hypergraph_weighted_calls() {
+    let mut hg = Hypergraph::new(false);
+
+    // The lenient call adds the hyperedge and drops the weight, the strict one refuses it
+    assert!(hg.add_edge_weighted(&[1, 2, 3], 2.5));
+    assert_eq!(hg.try_add_edge_weighted(&[3, 4], 1.5), Err(HypergraphError::NotWeighted));
+    assert!(hg.check_edge(&[1, 2, 3]));
+    assert!(!hg.check_edge(&[3, 4]));
+
+    assert_eq!(hg.get_weight(&[1, 2, 3]), None);
+    assert_eq!(hg.set_weight(&[1, 2, 3], 7.0), Err(HypergraphError::NotWeighted));
+    assert_eq!(hg.set_weight(&[3, 4], 7.0), Err(HypergraphError::NotWeighted));
+}
+
+#[test]
+pub fn test_unweighted_hypergraph_unweighted_calls() {
+    let mut hg = Hypergraph::new(false);
+
+    assert!(hg.add_edge(&[1, 2, 3]));
+    assert!(!hg.add_edge(&[1, 2, 3]));
+    assert_eq!(hg.get_weight(&[1, 2, 3]), None);
+}
+
+#[test]
+pub fn test_weighted_round_trip() {
+    let mut hg = Hypergraph::from(&[vec![1, 2, 3], vec![3, 4], vec![5]]);
+    hg.add_node(9);
+    assert_eq!(hg.get_weights(), None);
+    assert_eq!(hg.get_weights_with(None, Some(2), false), Ok(None));
+
+    let mut hg = hg.to_weighted(1.5);
+    assert!(hg.is_weighted());
+    assert_eq!(hg.get_weight(&[3, 4]), Some(1.5));
+    assert_eq!(hg.get_weights(), Some(vec![1.5; 3]));
+    assert_eq!(hg.get_weights_with(None, Some(2), false), Ok(Some(vec![1.5])));
+    assert_eq!(hg.set_weight(&[5], 4.0), Ok(1.5));
+
+    // Already weighted: the weights are kept
+    let hg = hg.to_weighted(0.0);
+    assert_eq!(hg.get_weight(&[5]), Some(4.0));
+
+    let hg = hg.to_unweighted();
+    assert!(!hg.is_weighted());
+    assert_eq!(hg.num_nodes(), 6);
+    assert_eq!(hg.num_edges(), 3);
+    assert!(hg.check_edge(&[1, 2, 3]));
+    assert_eq!(hg.get_weight(&[5]), None);
+    assert_eq!(hg.get_weights(), None);
+
+    // The zeroed weights do not come back
+    let hg = hg.to_weighted(0.0);
+    assert_eq!(hg.get_weight(&[5]), Some(0.0));
+}
+
+#[test]
+pub fn test_merge_policies() {
+    let merged = |policy| {
+        let mut hg = Hypergraph::new(true);
+        hg.add_edge_with_policy(&[1, 2, 3], 1.0, policy);
+        assert!(!hg.add_edge_with_policy(&[1, 2, 3], 2.0, policy));
+        hg.get_weight(&[1, 2, 3]).unwrap()
+    };
+    assert_eq!(merged(MergePolicy::Sum), 3.0);
+    assert_eq!(merged(MergePolicy::Replace), 2.0);
+    assert_eq!(merged(MergePolicy::KeepExisting), 1.0);
+    assert_eq!(merged(MergePolicy::Max), 2.0);
+    assert_eq!(merged(MergePolicy::Min), 1.0);
+
+    // The default policy of the hypergraph is used by the plain insertions
+    let mut hg = Hypergraph::with_merge_policy(true, MergePolicy::Sum);
+    assert_eq!(hg.merge_policy(), MergePolicy::Sum);
+    hg.add_edges_weighted(&[vec![1, 2], vec![1, 2], vec![2, 3]], &[1.0, 2.0, 4.0]);
+    hg.add_edge_weighted(&[1, 2], 0.5);
+    assert_eq!(hg.get_weight(&[1, 2]), Some(3.5));
+    assert_eq!(hg.clone().merge_policy(), MergePolicy::Sum);
+
+    hg.set_merge_policy(MergePolicy::Min);
+    hg.add_edge_weighted(&[2, 3], 2.0);
+    assert_eq!(hg.get_weight(&[2, 3]), Some(2.0));
+    assert_eq!(Hypergraph::new(true).merge_policy(), MergePolicy::Replace);
+
+    let hg = Hypergraph::from_weighted_with_policy(&[vec![1, 2], vec![1, 2], vec![3]], &[1.5, 2.5], MergePolicy::Max);
+    assert_eq!(hg.get_weight(&[1, 2]), Some(2.5));
+    assert_eq!(hg.get_weight(&[3]), Some(0.0));
+
+    // Unweighted hypergraphs ignore the weights
+    let mut hg = Hypergraph::new(false);
+    hg.add_edges_with_policy(&[vec![1, 2], vec![1, 2]], &[1.0, 2.0], MergePolicy::Sum);
+    assert_eq!(hg.get_weight(&[1, 2]), None);
+}
+
+#[test]
+pub fn test_from_weighted_assignment() {
+    let edges = [vec![1, 2], vec![2, 3], vec![3, 4]];
+
+    let hg = Hypergraph::from_weighted(&edges, &[1.0, 2.0, 3.0]);
+    assert_eq!(hg.get_weight(&[1, 2]), Some(1.0));
+    assert_eq!(hg.get_weight(&[2, 3]), Some(2.0));
+    assert_eq!(hg.get_weight(&[3, 4]), Some(3.0));
+
+    // Lenient mode pads only the last hyperedge
+    let hg = Hypergraph::from_weighted(&edges, &[1.0, 2.0]);
+    assert_eq!(hg.get_weight(&[1, 2]), Some(1.0));
+    assert_eq!(hg.get_weight(&[2, 3]), Some(2.0));
+    assert_eq!(hg.get_weight(&[3, 4]), Some(0.0));
+
+    // Duplicates keep the last weight
+    let hg = Hypergraph::from_weighted(&[vec![1, 2], vec![2, 3], vec![1, 2]], &[1.0, 2.0, 5.0]);
+    assert_eq!(hg.num_edges(), 2);
+    assert_eq!(hg.get_weight(&[1, 2]), Some(5.0));
+
+    let mut hg = Hypergraph::new(true);
+    assert!(hg.add_edges_weighted(&edges, &[4.0, 5.0]));
+    assert_eq!(hg.get_weight(&[2, 3]), Some(5.0));
+    assert_eq!(hg.get_weight(&[3, 4]), Some(0.0));
+}
+
+#[test]
+pub fn test_try_from_weighted() {
+    let edges = [vec![1, 2], vec![2, 3], vec![3, 4]];
+
+    let hg = Hypergraph::try_from_weighted(&edges, &[1.0, 2.0, 3.0]).unwrap();
+    assert_eq!(hg.get_weight(&[3, 4]), Some(3.0));
+
+    assert_eq!(
+        Hypergraph::try_from_weighted(&edges, &[1.0, 2.0]).unwrap_err(),
+        HypergraphError::WeightCountMismatch(3, 2)
+    );
+    assert_eq!(
+        Hypergraph::try_from_weighted(&edges[..1], &[1.0, 2.0]).unwrap_err(),
+        HypergraphError::WeightCountMismatch(1, 2)
+    );
+}
+
+#[test]
+pub fn test_non_finite_weights_rejected() {
+    let mut hg = Hypergraph::new(true);
+    hg.add_edge_weighted(&[1, 2], 1.0);
+
+    assert_eq!(hg.set_weight(&[1, 2], f64::NAN), Err(HypergraphError::NonFiniteWeight));
+    assert_eq!(hg.set_weight(&[1, 2], f64::INFINITY), Err(HypergraphError::NonFiniteWeight));
+    assert_eq!(hg.get_weight(&[1, 2]), Some(1.0));
+
+    assert_eq!(hg.try_add_edge_weighted(&[2, 3], f64::NAN), Err(HypergraphError::NonFiniteWeight));
+    assert_eq!(hg.try_add_edge_weighted(&[1, 2], f64::NEG_INFINITY), Err(HypergraphError::NonFiniteWeight));
+    assert!(!hg.check_edge(&[2, 3]));
+
+    assert_eq!(
+        Hypergraph::try_from_weighted(&[vec![1, 2], vec![2, 3]], &[1.0, f64::NAN]).unwrap_err(),
+        HypergraphError::NonFiniteWeight
+    );
+    assert_eq!(hg.validate_weights(), Ok(()));
+}
+
+#[test]
+pub fn test_non_finite_weights_clamped() {
+    let mut hg = Hypergraph::new(true);
+    hg.add_edge_weighted(&[1, 2], f64::NAN);
+    hg.add_edges_weighted(&[vec![2, 3], vec![3, 4]], &[f64::INFINITY, f64::NEG_INFINITY]);
+    assert_eq!(hg.get_weight(&[1, 2]), Some(0.0));
+    assert_eq!(hg.get_weight(&[2, 3]), Some(f64::MAX));
+    assert_eq!(hg.get_weight(&[3, 4]), Some(f64::MIN));
+
+    // NaN replacing an existing weight, and a sum which overflows
+    hg.add_edge_weighted(&[2, 3], f64::NAN);
+    assert_eq!(hg.get_weight(&[2, 3]), Some(0.0));
+    hg.add_edge_weighted(&[2, 3], f64::MAX);
+    hg.add_edge_with_policy(&[2, 3], f64::MAX, MergePolicy::Sum);
+    assert_eq!(hg.get_weight(&[2, 3]), Some(f64::MAX));
+
+    let hg = Hypergraph::from_weighted(&[vec![1, 2], vec![2, 3]], &[f64::NAN, 2.0]);
+    assert_eq!(hg.get_weight(&[1, 2]), Some(0.0));
+    assert_eq!(hg.validate_weights(), Ok(()));
+
+    let hg = Hypergraph::from(&[vec![1, 2]]).to_weighted(f64::NAN);
+    assert_eq!(hg.get_weight(&[1, 2]), Some(0.0));
+}
+
+#[test]
+pub fn test_weighted_getters() {
+    let hg = Hypergraph::from_weighted(&[vec![1, 2, 3], vec![3, 4], vec![4, 5], vec![3]], &[1.5, 2.5, 3.5, 4.5]);
+
+    let pairs = hg.get_edges_weighted().unwrap();
+    assert_eq!(pairs.len(), 4);
+    for (edge, weight) in pairs {
+        assert_eq!(hg.get_weight(edge), Some(weight));
+    }
+
+    let mut incident = hg.get_incident_edges_weighted(3, None, None).unwrap().unwrap();
+    incident.sort_by(|a, b| a.0.cmp(b.0));
+    assert_eq!(incident, vec![(&vec![1, 2, 3], 1.5), (&vec![3], 4.5), (&vec![3, 4], 2.5)]);
+    assert_eq!(hg.get_incident_edges_weighted(3, Some(1), None).unwrap().unwrap(), vec![(&vec![3, 4], 2.5)]);
+    assert_eq!(hg.get_incident_edges_weighted(3, None, Some(1)).unwrap().unwrap(), vec![(&vec![3], 4.5)]);
+    assert_eq!(hg.get_incident_edges_weighted(9, None, None), Ok(None));
+    assert_eq!(hg.get_incident_edges_weighted(3, Some(1), Some(2)), Err(HypergraphError::BothOrderAndSize));
+
+    // Unweighted hypergraphs store 0
+    let hg = Hypergraph::from(&[vec![1, 2, 3], vec![3, 4]]);
+    for (edge, weight) in hg.get_edges_weighted().unwrap() {
+        assert_eq!(weight, 0.0);
+        assert_eq!(hg.get_weight(edge), None);
+    }
+    assert!(hg.get_incident_edges_weighted(3, None, None).unwrap().unwrap().iter().all(|(_, weight)| *weight == 0.0));
+    assert_eq!(Hypergraph::new(true).get_edges_weighted(), None);
+}
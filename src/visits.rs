@@ -1,15 +1,139 @@
 use std::collections::VecDeque;
 
-use ahash::AHashSet;
+use ahash::{AHashMap, AHashSet};
 
-use super::{Hypergraph, Node};
+use super::{DirectedHypergraph, EdgeID, Hypergraph, HypergraphError, HypergraphOps, Node};
+
+/// What a traversal does after a callback of a `Visitor`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Control {
+    /// Go on with the traversal.
+    Continue,
+
+    /// Go on, but do not expand the current node (from `Visitor::discover_node`) or do not reach the nodes of the
+    /// current hyperedge (from `Visitor::traverse_edge`). The same as `Continue` from `Visitor::finish_node`.
+    SkipNeighbors,
+
+    /// End the traversal immediately.
+    Stop,
+}
+
+/// `type Node = i64`
+///
+/// Callbacks of the traversals `bfs_visit` and `dfs_visit`. All of them return `Control::Continue` by default, so a
+/// visitor only implements the ones it needs.
+pub trait Visitor {
+    /// Called once per node, when it is reached for the first time, `start` included at depth 0.
+    fn discover_node(&mut self, _node: Node, _depth: usize) -> Control {
+        Control::Continue
+    }
+
+    /// Called for every considered hyperedge incident to a node being expanded, before its nodes are reached.
+    fn traverse_edge(&mut self, _from: Node, _edge_id: EdgeID, _edge_nodes: &[Node], _weight: f64) -> Control {
+        Control::Continue
+    }
+
+    /// Called once per node, when it is done: in a BFS when it leaves the queue, in a DFS after all the nodes
+    /// discovered from it.
+    fn finish_node(&mut self, _node: Node) -> Control {
+        Control::Continue
+    }
+}
+
+/// Limits of `bfs_visit` and `dfs_visit`. The default visits all the reachable nodes through all the hyperedges.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct VisitOptions {
+    /// `Some` maximum depth for the search, the nodes at this depth are not expanded. If `None` the search is not
+    /// limited.
+    pub max_depth: Option<usize>,
+
+    /// `Some` order of the hyperedges to consider. If `None` all hyperedges are considered.
+    pub order: Option<usize>,
+
+    /// `Some` size of the hyperedges to consider. If `None` all hyperedges are considered.
+    pub size: Option<usize>,
+
+    /// `Some` minimum weight of the hyperedges to consider, eg to ignore the weak links of a co-occurrence
+    /// hypergraph. If `None` all hyperedges are considered.
+    pub min_weight: Option<f64>,
+}
+
+/// `type Node = i64`
+///
+/// Breadth-First-Search of the hypergraph starting from a given node, calling the visitor on every event. This is the
+/// core of `_bfs`, `bfs_order`, `bfs_tree` and `_distances_from`.
+///
+/// The incident hyperedges of a node are traversed in increasing `EdgeID`, and the nodes discovered from it are
+/// enqueued in increasing order, so the events are reproducible. A node is discovered when it is first reached, and
+/// finished when it leaves the queue, ie in the order of `bfs_order`.
+///
+/// # Parameters
+/// - `hg` : `&impl HypergraphOps` - The hypergraph to search.
+/// - `start` : `Node` - The node to start the search from.
+/// - `options` : `VisitOptions` - The maximum depth, and the order or size and the minimum weight of the hyperedges to
+///   consider.
+/// - `visitor` : `&mut impl Visitor` - The callbacks.
+///
+/// # Returns
+/// - `Result<(), HypergraphError>` - `Ok` once the visit is over or stopped by the visitor, without any event if
+///   `start` is not in the hypergraph. Returns `Err(HypergraphError::BothOrderAndSize)` if both `order` and `size` are
+///   specified.
+///
+/// # Performance
+/// - `O(n + I*log(I))`, where `n` is the number of nodes and `I` the sum of the sizes of the hyperedges, plus the cost
+///   of the callbacks.
+pub fn bfs_visit(
+    hg: &impl HypergraphOps,
+    start: Node,
+    options: VisitOptions,
+    visitor: &mut impl Visitor,
+) -> Result<(), HypergraphError> {
+    let filter = compute_options_filter(options)?;
+    compute_bfs(hg, start, options.max_depth, &filter, visitor);
+    Ok(())
+}
 
 /// `type Node = i64`
 ///
-/// Breadth-First-Search of the hypergraph starting from a given node.   
+/// Depth-First-Search of the hypergraph starting from a given node, calling the visitor on every event. This is the
+/// core of `_dfs` and `dfs_order`.
+///
+/// The search uses an explicit stack, so it does not overflow the call stack on deep hypergraphs. The incident
+/// hyperedges of a node are traversed in increasing `EdgeID`, and its neighbors are visited in increasing order. A
+/// node is discovered in pre-order and finished in post-order, after all the nodes discovered from it.
+///
+/// # Parameters
+/// - `hg` : `&impl HypergraphOps` - The hypergraph to search.
+/// - `start` : `Node` - The node to start the search from.
+/// - `options` : `VisitOptions` - The maximum depth, and the order or size and the minimum weight of the hyperedges to
+///   consider.
+/// - `visitor` : `&mut impl Visitor` - The callbacks.
+///
+/// # Returns
+/// - `Result<(), HypergraphError>` - `Ok` once the visit is over or stopped by the visitor, without any event if
+///   `start` is not in the hypergraph. Returns `Err(HypergraphError::BothOrderAndSize)` if both `order` and `size` are
+///   specified.
+///
+/// # Performance
+/// - `O(n + I*log(I))`, where `n` is the number of nodes and `I` the sum of the sizes of the hyperedges, plus the cost
+///   of the callbacks.
+pub fn dfs_visit(
+    hg: &impl HypergraphOps,
+    start: Node,
+    options: VisitOptions,
+    visitor: &mut impl Visitor,
+) -> Result<(), HypergraphError> {
+    let filter = compute_options_filter(options)?;
+    compute_dfs(hg, start, options.max_depth, &filter, visitor);
+    Ok(())
+}
+
+/// `type Node = i64`
+///
+/// Breadth-First-Search of the hypergraph starting from a given node. The visited nodes are the ones of `bfs_order`.
 ///
 /// # Parameters  
-/// - `hg` : `&Hypergraph` - The hypergraph to search.
+/// - `hg` : `&impl HypergraphOps` - The hypergraph to search.
 /// - `start` : `Node` - The node to start the search from.
 /// - `max_depth` : `Option<usize>` - `Some` maximum depth for the search. If `None` the search is not limited.
 /// - `order` : `Option<usize>` - `Some` order of the hyperedges to consider. If `None` all hyperedges are considered.
@@ -17,51 +141,447 @@ use super::{Hypergraph, Node};
 ///
 /// # Returns
 /// - `AHashSet<Node>` - The nodes visited during the search. If the length of the returned hashset is `0`, then it means 
-/// that the node provided was not in the hypergraph.
+///   that the node provided was not in the hypergraph.
 ///
 /// # Performance
-/// - `O(n*n*m)`, where `n` and `m` are the number of nodes and hyperedges of the hypergraph, respectively.
+/// - `O(n + I*log(I))`, where `n` is the number of nodes and `I` the sum of the sizes of the hyperedges.
 pub fn _bfs(
-    hg: &Hypergraph,
+    hg: &impl HypergraphOps,
     start: Node,
     max_depth: Option<usize>,
     order: Option<usize>,
     size: Option<usize>,
 ) -> AHashSet<Node> {
-    let mut visited = AHashSet::new();
+    let (max_depth, filter) = compute_visit_limits(max_depth, order, size);
+    _bfs_filtered(hg, start, max_depth, filter)
+}
 
-    if hg.check_node(start) { // Added this check
-        let mut queue = VecDeque::new();
-        queue.push_back((start, 0));
-        visited.insert(start);
+/// `type Node = i64`
+///
+/// Breadth-First-Search of the hypergraph starting from a given node, as `_bfs`, through the hyperedges accepted by
+/// `filter` only, eg `|nodes, weight| nodes.len() == 3 && weight > 1.0` for the heavy triangles.
+///
+/// # Parameters
+/// - `hg` : `&impl HypergraphOps` - The hypergraph to search.
+/// - `start` : `Node` - The node to start the search from.
+/// - `max_depth` : `Option<usize>` - `Some` maximum depth for the search. If `None` the search is not limited.
+/// - `filter` : `impl Fn(&[Node], f64) -> bool` - Given the nodes and the weight of a hyperedge, whether to consider it.
+///
+/// # Returns
+/// - `AHashSet<Node>` - The nodes visited during the search, empty if `start` is not in the hypergraph.
+///
+/// # Performance
+/// - `O(n + I*log(I))`, where `n` is the number of nodes and `I` the sum of the sizes of the hyperedges, plus a call
+///   of `filter` for every incident hyperedge of every visited node.
+pub fn _bfs_filtered(
+    hg: &impl HypergraphOps,
+    start: Node,
+    max_depth: Option<usize>,
+    filter: impl Fn(&[Node], f64) -> bool,
+) -> AHashSet<Node> {
+    let mut visitor = Discovered(Vec::new());
+    compute_bfs(hg, start, max_depth, &filter, &mut visitor);
+    visitor.0.into_iter().collect()
+}
+
+/// `type Node = i64`
+///
+/// Breadth-First-Search of the hypergraph starting from a given node, returning the nodes in the order in which they
+/// are dequeued, ie level by level.
+///
+/// Within a level, the nodes discovered from the same node are enqueued in increasing order, so the result does not
+/// depend on the iteration order of the hashmaps and is reproducible.
+///
+/// # Parameters
+/// - `hg` : `&impl HypergraphOps` - The hypergraph to search.
+/// - `start` : `Node` - The node to start the search from.
+/// - `max_depth` : `Option<usize>` - `Some` maximum depth for the search. If `None` the search is not limited.
+/// - `order` : `Option<usize>` - `Some` order of the hyperedges to consider. If `None` all hyperedges are considered.
+/// - `size` : `Option<usize>` - `Some` size of the hyperedges to consider. If `None` all hyperedges are considered.
+///
+/// # Returns
+/// - `Vec<Node>` - The nodes visited during the search, `start` first, in visitation order. If the returned vector is
+///   empty, then the node provided was not in the hypergraph.
+///
+/// # Performance
+/// - `O(n + I*log(I))`, where `n` is the number of nodes and `I` the sum of the sizes of the hyperedges.
+pub fn bfs_order(
+    hg: &impl HypergraphOps,
+    start: Node,
+    max_depth: Option<usize>,
+    order: Option<usize>,
+    size: Option<usize>,
+) -> Vec<Node> {
+    let mut visitor = Finished(Vec::new());
+    let (max_depth, filter) = compute_visit_limits(max_depth, order, size);
+    compute_bfs(hg, start, max_depth, &filter, &mut visitor);
+    visitor.0
+}
+
+/// `type Node = i64`
+///
+/// Breadth-First-Search of the hypergraph starting from a given node, returning the BFS tree: for every reached node,
+/// its predecessor and the hyperedge through which it was first discovered. Walking the predecessors from any node
+/// back to `start` gives a shortest path, and the hyperedges explain why each step is possible.
+///
+/// `start` is the root of the tree, so it is not in the returned map. The nodes are discovered in the same order as
+/// in `bfs_order`, and a node reachable through several hyperedges of its predecessor is attributed to the one with
+/// the smallest `EdgeID`, so the tree is reproducible.
+///
+/// # Examples
+/// ```
+/// use hgraph::Hypergraph;
+/// use hgraph::visits::bfs_tree;
+///
+/// let hg = Hypergraph::from(&[vec![1, 2], vec![2, 3, 4], vec![4, 5]]);
+/// let tree = bfs_tree(&hg, 1, None, None);
+///
+/// let mut path = vec![5];
+/// while let Some((predecessor, _)) = tree.get(path.last().unwrap()) {
+///     path.push(*predecessor);
+/// }
+/// path.reverse();
+/// assert_eq!(path, vec![1, 2, 4, 5]);
+///
+/// let (_, edge_id) = tree[&4];
+/// assert_eq!(hg.get_edge_by_id(edge_id), Some((&vec![2, 3, 4], 0.0)));
+/// ```
+///
+/// # Parameters
+/// - `hg` : `&impl HypergraphOps` - The hypergraph to search.
+/// - `start` : `Node` - The node to start the search from.
+/// - `order` : `Option<usize>` - `Some` order of the hyperedges to consider. If `None` all hyperedges are considered.
+/// - `size` : `Option<usize>` - `Some` size of the hyperedges to consider. If `None` all hyperedges are considered.
+///
+/// # Returns
+/// - `AHashMap<Node, (Node, EdgeID)>` - The predecessor and the discovering hyperedge of every node reached from
+///   `start`, `start` excluded. The map is empty if `start` is not in the hypergraph, has no neighbors, or both
+///   `order` and `size` are specified.
+///
+/// # Performance
+/// - `O(n + I*log(d))`, where `n` is the number of nodes, `I` the sum of the sizes of the hyperedges and `d` the
+///   maximum degree.
+pub fn bfs_tree(
+    hg: &impl HypergraphOps,
+    start: Node,
+    order: Option<usize>,
+    size: Option<usize>,
+) -> AHashMap<Node, (Node, EdgeID)> {
+    let mut visitor = Tree { current: None, tree: AHashMap::new() };
+    if let Ok(filter) = compute_order_filter(order, size, false) {
+        compute_bfs(hg, start, None, &filter, &mut visitor);
+    }
+    visitor.tree
+}
+
+/// `type Node = i64`
+///
+/// Breadth-First-Search on the hyperedges, starting from a given hyperedge, where two hyperedges are adjacent when they
+/// share at least `s` nodes (ie on the s-line graph, as in `Hypergraph::s_distance`). This tells which hyperedges
+/// chain together, where `_bfs` tells which nodes are reachable.
+///
+/// The hyperedges adjacent to the current one are found through the incidence lists of its nodes, counting the shared
+/// nodes along the way, and they are enqueued in increasing `EdgeID`, so the result is reproducible. Since adjacent
+/// hyperedges have to intersect, `s` equal to `0` is treated as `1`.
+///
+/// # Parameters
+/// - `hg` : `&impl HypergraphOps` - The hypergraph to search.
+/// - `start_edge` : `&[Node]` - The hyperedge to start the search from.
+/// - `s` : `usize` - The minimum number of nodes shared by adjacent hyperedges.
+/// - `max_depth` : `Option<usize>` - `Some` maximum depth for the search. If `None` the search is not limited.
+///
+/// # Returns
+/// - `Vec<EdgeID>` - The ids of the hyperedges reached, in visitation order, the one of `start_edge` first. If the
+///   returned vector is empty, then the hyperedge provided was not in the hypergraph.
+///
+/// # Performance
+/// - `O(m*k*d)`, where `m` is the number of hyperedges, `k` their maximum size and `d` the maximum degree of a node.
+pub fn edge_bfs(hg: &impl HypergraphOps, start_edge: &[Node], s: usize, max_depth: Option<usize>) -> Vec<EdgeID> {
+    let mut res = Vec::new();
+    if let Some(start) = hg.edge_id(start_edge) {
+        let s = s.max(1);
+        let mut visited = AHashSet::from([start]);
+        let mut queue = VecDeque::from([(start, 0)]);
 
-        // O(n)
         while let Some((now, depth)) = queue.pop_front() {
-            if max_depth.map_or(true, |max| depth < max) {
-                // O(n*m)
-                if let Ok(Some(neighbors)) = hg.get_neighbors(now,order,size) {
+            res.push(now);
+            if max_depth.is_some_and(|max| depth >= max) {
+                continue;
+            }
 
+            // Number of nodes shared with every intersecting hyperedge
+            let members: AHashSet<Node> = hg.edge_nodes(now).unwrap_or_default().iter().copied().collect();
+            let mut shared: AHashMap<EdgeID, usize> = AHashMap::new();
+            for node in members.iter() {
+                for edge_id in hg.incident_edge_ids(*node).into_iter().flatten() {
+                    *shared.entry(edge_id).or_insert(0) += 1;
+                }
+            }
 
-                    for neighbor in neighbors.iter() {
-                        if !visited.contains(neighbor) {
-                            queue.push_back((*neighbor, depth + 1));
-                            visited.insert(*neighbor);
-                        }
-                    }
+            let mut adjacent: Vec<EdgeID> = shared
+                .into_iter()
+                .filter(|(edge_id, count)| *count >= s && visited.insert(*edge_id))
+                .map(|(edge_id, _)| edge_id)
+                .collect();
+            adjacent.sort_unstable();
+            queue.extend(adjacent.into_iter().map(|edge_id| (edge_id, depth + 1)));
+        }
+    }
+
+    res
+}
+
+/// `type Node = i64`
+///
+/// Breadth-First-Search of a directed hypergraph starting from a given node, following the hyperedges only from their
+/// tail to their head: a hyperedge is crossed as soon as one of the nodes of its tail is reached, and all the nodes of
+/// its head are reached. In a reaction network, this gives the species which may be produced from `start`, assuming
+/// the other reactants are available.
+///
+/// As in `bfs_order`, the nodes are returned in the order in which they are dequeued, and the nodes discovered from
+/// the same node are enqueued in increasing order.
+///
+/// # Parameters
+/// - `hg` : `&DirectedHypergraph` - The hypergraph to search.
+/// - `start` : `Node` - The node to start the search from.
+/// - `max_depth` : `Option<usize>` - `Some` maximum depth for the search. If `None` the search is not limited.
+///
+/// # Returns
+/// - `Vec<Node>` - The nodes visited during the search, `start` first, in visitation order. If the returned vector is
+///   empty, then the node provided was not in the hypergraph.
+///
+/// # Performance
+/// - `O(n + I*log(I))`, where `n` is the number of nodes and `I` the sum of the sizes of the hyperedges.
+pub fn forward_bfs(hg: &DirectedHypergraph, start: Node, max_depth: Option<usize>) -> Vec<Node> {
+    let mut res = Vec::new();
+
+    if hg.successors(start).is_some() {
+        let mut visited = AHashSet::from([start]);
+        let mut queue = VecDeque::from([(start, 0)]);
+
+        while let Some((now, depth)) = queue.pop_front() {
+            res.push(now);
+            if max_depth.is_some_and(|max| depth >= max) {
+                continue;
+            }
+
+            // Already sorted, so the order of the queue is reproducible
+            for next in hg.successors(now).unwrap_or_default() {
+                if visited.insert(next) {
+                    queue.push_back((next, depth + 1));
                 }
             }
         }
     }
 
-    visited
+    res
+}
+
+/// `type Node = i64`
+///
+/// Computes the distance, in number of hops, of every node reachable from a given node.
+///
+/// # Parameters
+/// - `hg` : `&impl HypergraphOps` - The hypergraph to search.
+/// - `start` : `Node` - The node to start the search from.
+/// - `max_depth` : `Option<usize>` - `Some` maximum distance for the search. If `None` the search is not limited.
+/// - `order` : `Option<usize>` - `Some` order of the hyperedges to consider. If `None` all hyperedges are considered.
+/// - `size` : `Option<usize>` - `Some` size of the hyperedges to consider. If `None` all hyperedges are considered.
+///
+/// # Returns
+/// - `AHashMap<Node, usize>` - The distance from `start` of every node visited during the search, `start` included.
+///   If the returned hashmap is empty, then the node provided was not in the hypergraph.
+///
+/// # Performance
+/// - `O(n + I*log(I))`, where `n` is the number of nodes and `I` the sum of the sizes of the hyperedges.
+pub fn _distances_from(
+    hg: &impl HypergraphOps,
+    start: Node,
+    max_depth: Option<usize>,
+    order: Option<usize>,
+    size: Option<usize>,
+) -> AHashMap<Node, usize> {
+    let mut visitor = Distances(AHashMap::new());
+    let (max_depth, filter) = compute_visit_limits(max_depth, order, size);
+    compute_bfs(hg, start, max_depth, &filter, &mut visitor);
+    visitor.0
+}
+
+impl Hypergraph {
+    /// `type Node = i64`
+    ///
+    /// Returns the distance, in number of hops, of every node reachable from a given node.
+    /// See `_distances_from` for more details.
+    ///
+    /// # Parameters
+    /// - `start` : `Node` - The node to start the search from.
+    /// - `max_depth` : `Option<usize>` - `Some` maximum distance for the search. If `None` the search is not limited.
+    /// - `order` : `Option<usize>` - `Some` order of the hyperedges to consider. If `None` all hyperedges are considered.
+    /// - `size` : `Option<usize>` - `Some` size of the hyperedges to consider. If `None` all hyperedges are considered.
+    ///
+    /// # Returns
+    /// - `Result<AHashMap<Node, usize>, HypergraphError>` - `Ok` containing the distance of every reachable node (or an empty map if
+    ///   `start` is not in the hypergraph). Returns `Err(HypergraphError::BothOrderAndSize)` if both `order` and `size` are specified.
+    ///
+    /// # Performance
+    /// - `O(n + I*log(I))`, where `n` is the number of nodes and `I` the sum of the sizes of the hyperedges.
+    pub fn distances_from(
+        &self,
+        start: Node,
+        max_depth: Option<usize>,
+        order: Option<usize>,
+        size: Option<usize>,
+    ) -> Result<AHashMap<Node, usize>, HypergraphError> {
+        if order.is_some() && size.is_some() {
+            Err(HypergraphError::BothOrderAndSize)
+        } else {
+            Ok(_distances_from(self, start, max_depth, order, size))
+        }
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Returns the nodes at distance exactly `d` from a given node, in number of hops, computed with a single BFS
+    /// bounded at depth `d`. `neighbors_at_distance(node, 1, ..)` gives the neighbors of `node`, and
+    /// `neighbors_at_distance(node, 0, ..)` the node itself.
+    ///
+    /// # Parameters
+    /// - `node` : `Node` - The node to start the search from.
+    /// - `d` : `usize` - The distance of the returned nodes.
+    /// - `order` : `Option<usize>` - `Some` order of the hyperedges to consider. If `None` all hyperedges are considered.
+    /// - `size` : `Option<usize>` - `Some` size of the hyperedges to consider. If `None` all hyperedges are considered.
+    ///
+    /// # Returns
+    /// - `Result<Option<Vec<Node>>, HypergraphError>` - `Ok` containing `Some` sorted list of the nodes at distance `d`,
+    ///   possibly empty, or `None` if `node` is not in the hypergraph. Returns `Err(HypergraphError::BothOrderAndSize)`
+    ///   if both `order` and `size` are specified.
+    ///
+    /// # Performance
+    /// - `O(n + I*log(I))`, where `n` is the number of nodes and `I` the sum of the sizes of the hyperedges, in the
+    ///   worst case, as only the nodes at distance less than `d` are expanded.
+    pub fn neighbors_at_distance(
+        &self,
+        node: Node,
+        d: usize,
+        order: Option<usize>,
+        size: Option<usize>,
+    ) -> Result<Option<Vec<Node>>, HypergraphError> {
+        let rings = self.compute_rings(node, d, order, size)?;
+        Ok(rings.map(|rings| rings.into_iter().nth(d).unwrap_or_default()))
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Returns the nodes within distance `d` from a given node, grouped by ring: the `k`-th list contains the nodes at
+    /// distance exactly `k`, so the first one is `[node]`. The rings after the last reachable one are not returned.
+    ///
+    /// # Parameters
+    /// - `node` : `Node` - The node at the center of the ball.
+    /// - `d` : `usize` - The maximum distance from `node`.
+    ///
+    /// # Returns
+    /// - `Option<Vec<Vec<Node>>>` - `Some` list of the rings, each one sorted, or `None` if `node` is not in the
+    ///   hypergraph.
+    ///
+    /// # Performance
+    /// - `O(n + I*log(I))`, where `n` is the number of nodes and `I` the sum of the sizes of the hyperedges, in the
+    ///   worst case.
+    pub fn ball(&self, node: Node, d: usize) -> Option<Vec<Vec<Node>>> {
+        self.compute_rings(node, d, None, None).unwrap_or_default()
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Effectively computes the rings of `ball`, with a BFS bounded at depth `d`.
+    ///
+    /// # Returns
+    /// - `Result<Option<Vec<Vec<Node>>>, HypergraphError>` - `Ok` containing `Some` sorted rings, or `None` if `node` is
+    ///   not in the hypergraph. Returns `Err(HypergraphError::BothOrderAndSize)` if both `order` and `size` are
+    ///   specified.
+    fn compute_rings(
+        &self,
+        node: Node,
+        d: usize,
+        order: Option<usize>,
+        size: Option<usize>,
+    ) -> Result<Option<Vec<Vec<Node>>>, HypergraphError> {
+        let mut visitor = Rings(Vec::new());
+        bfs_visit(self, node, VisitOptions { max_depth: Some(d), order, size, min_weight: None }, &mut visitor)?;
+        if visitor.0.is_empty() {
+            return Ok(None);
+        }
+
+        for ring in visitor.0.iter_mut() {
+            ring.sort_unstable();
+        }
+        Ok(Some(visitor.0))
+    }
+
+    /// `type Node = i64`
+    ///
+    /// Returns the ego hypergraph of a node, ie the hypergraph around the nodes at distance at most `radius` from
+    /// `center` (the ball), found with a bounded BFS (see `_bfs`). Only the hyperedges with the given order or size, if
+    /// any, are considered, both for the visit and for the result.
+    ///
+    /// If `truncate` is `false`, the result is the subhypergraph induced by the ball: only the hyperedges fully inside
+    /// it are kept. If `truncate` is `true`, every hyperedge touching the ball is kept, restricted to its nodes in the
+    /// ball, as long as at least two of them remain (or it was already fully inside). Truncated hyperedges which
+    /// become equal are merged according to the merge policy of the hypergraph.
+    ///
+    /// As in `node_cc`, if `center` is not in the hypergraph the result is empty.
+    ///
+    /// # Parameters
+    /// - `center` : `Node` - The node at the center of the ball.
+    /// - `radius` : `usize` - The maximum distance from `center`.
+    /// - `order` : `Option<usize>` - `Some` order of the hyperedges to consider. If `None` all hyperedges are considered.
+    /// - `size` : `Option<usize>` - `Some` size of the hyperedges to consider. If `None` all hyperedges are considered.
+    /// - `truncate` : `bool` - Whether to keep the hyperedges crossing the border of the ball, truncated.
+    ///
+    /// # Returns
+    /// - `Result<Hypergraph, HypergraphError>` - `Ok` containing the ego hypergraph, with the same weighted flag and
+    ///   merge policy. Returns `Err(HypergraphError::BothOrderAndSize)` if both `order` and `size` are specified.
+    ///
+    /// # Performance
+    /// - `O(n + I*log(I))`, where `n` is the number of nodes and `I` the sum of the sizes of the hyperedges.
+    pub fn ego_hypergraph(
+        &self,
+        center: Node,
+        radius: usize,
+        order: Option<usize>,
+        size: Option<usize>,
+        truncate: bool,
+    ) -> Result<Hypergraph, HypergraphError> {
+        let filter = compute_order_filter(order, size, false)?;
+        let ball = _bfs_filtered(self, center, Some(radius), filter);
+
+        let mut res = Hypergraph::with_merge_policy(self.weighted, self.merge_policy);
+        let mut visited = AHashSet::new();
+        for node in ball.iter() {
+            res.add_node(*node);
+
+            for edge_id in self.incidence_list[node].iter() {
+                let hyperedge = &self.edge_list[edge_id];
+                if !visited.insert(*edge_id) || !filter(&hyperedge.nodes, hyperedge.weight) {
+                    continue;
+                }
+
+                let inside: Vec<Node> = hyperedge.nodes.iter().copied().filter(|node| ball.contains(node)).collect();
+                if inside.len() == hyperedge.nodes.len() || (truncate && inside.len() > 1) {
+                    res.add_edge_weighted(&inside, hyperedge.weight);
+                }
+            }
+        }
+
+        Ok(res)
+    }
 }
 
 /// `type Node = i64`
 ///
-/// Depth-First-Search of the hypergraph starting from a given node.   
+/// Depth-First-Search of the hypergraph starting from a given node. The visited nodes are the ones of `dfs_order`.
 ///
 /// # Parameters  
-/// - `hg` : `&Hypergraph` - The hypergraph to search.
+/// - `hg` : `&impl HypergraphOps` - The hypergraph to search.
 /// - `start` : `Node` - The node to start the search from.
 /// - `max_depth` : `Option<usize>` - `Some` maximum depth for the search. If `None` the search is not limited.
 /// - `order` : `Option<usize>` - `Some` order of the hyperedges to consider. If `None` all hyperedges are considered.
@@ -69,62 +589,359 @@ pub fn _bfs(
 ///
 /// # Returns
 /// - `AHashSet<Node>` - The nodes visited during the search. If the length of the returned hashset is `0`, then it means 
-/// that the node provided was not in the hypergraph.
+///   that the node provided was not in the hypergraph.
 ///
 /// # Performance
-/// - `O(n*n*m)`, where `n` and `m` are the number of nodes and the number of hyperedges of the hypergraph, respectively.
+/// - `O(n + I*log(I))`, where `n` is the number of nodes and `I` the sum of the sizes of the hyperedges.
 pub fn _dfs(
-    hg: &Hypergraph,
+    hg: &impl HypergraphOps,
     start: Node,
     max_depth: Option<usize>,
     order: Option<usize>,
     size: Option<usize>,
 ) -> AHashSet<Node> {
-    let mut visited = AHashSet::new();
-    
-    if hg.check_node(start) {
-        compute_dfs(hg, start, max_depth, 0, order, size, &mut visited);
-    }
-    visited
+    let (max_depth, filter) = compute_visit_limits(max_depth, order, size);
+    _dfs_filtered(hg, start, max_depth, filter)
 }
 
-/// Effectively computes the dfs of the hypergraph.
-fn compute_dfs(
-    hg: &Hypergraph,
-    node: Node,
+/// `type Node = i64`
+///
+/// Depth-First-Search of the hypergraph starting from a given node, as `_dfs`, through the hyperedges accepted by
+/// `filter` only.
+///
+/// # Parameters
+/// - `hg` : `&impl HypergraphOps` - The hypergraph to search.
+/// - `start` : `Node` - The node to start the search from.
+/// - `max_depth` : `Option<usize>` - `Some` maximum depth for the search. If `None` the search is not limited.
+/// - `filter` : `impl Fn(&[Node], f64) -> bool` - Given the nodes and the weight of a hyperedge, whether to consider it.
+///
+/// # Returns
+/// - `AHashSet<Node>` - The nodes visited during the search, empty if `start` is not in the hypergraph.
+///
+/// # Performance
+/// - `O(n + I*log(I))`, where `n` is the number of nodes and `I` the sum of the sizes of the hyperedges, plus a call
+///   of `filter` for every incident hyperedge of every visited node.
+pub fn _dfs_filtered(
+    hg: &impl HypergraphOps,
+    start: Node,
+    max_depth: Option<usize>,
+    filter: impl Fn(&[Node], f64) -> bool,
+) -> AHashSet<Node> {
+    let mut visitor = Discovered(Vec::new());
+    compute_dfs(hg, start, max_depth, &filter, &mut visitor);
+    visitor.0.into_iter().collect()
+}
+
+/// `type Node = i64`
+///
+/// Depth-First-Search of the hypergraph starting from a given node, returning the nodes in pre-order, ie each node
+/// before the ones discovered from it.
+///
+/// The neighbors of a node are visited in increasing order, so the result is reproducible. The search uses an
+/// explicit stack instead of recursion, so it does not overflow the call stack on deep hypergraphs (eg long chains).
+/// As in a recursive search, with `max_depth` a node is expanded only from the depth at which it is first reached.
+///
+/// # Parameters
+/// - `hg` : `&impl HypergraphOps` - The hypergraph to search.
+/// - `start` : `Node` - The node to start the search from.
+/// - `max_depth` : `Option<usize>` - `Some` maximum depth for the search. If `None` the search is not limited.
+/// - `order` : `Option<usize>` - `Some` order of the hyperedges to consider. If `None` all hyperedges are considered.
+/// - `size` : `Option<usize>` - `Some` size of the hyperedges to consider. If `None` all hyperedges are considered.
+///
+/// # Returns
+/// - `Vec<Node>` - The nodes visited during the search, in pre-order, `start` first. If the returned vector is empty,
+///   then the node provided was not in the hypergraph.
+///
+/// # Performance
+/// - `O(n + I*log(I))`, where `n` is the number of nodes and `I` the sum of the sizes of the hyperedges.
+pub fn dfs_order(
+    hg: &impl HypergraphOps,
+    start: Node,
     max_depth: Option<usize>,
-    depth: usize,
     order: Option<usize>,
     size: Option<usize>,
-    visited: &mut AHashSet<Node>,
-) {
-    if ! visited.contains(&node) {
-        visited.insert(node);
-
-        if max_depth.map_or(true, |max| depth < max) {
-            if let Ok(Some(neighbors)) = hg.get_neighbors(node, order, size) {
-                for neighbor in neighbors.iter() {
-                    compute_dfs(hg, *neighbor, max_depth, depth + 1, order, size, visited);
+) -> Vec<Node> {
+    let mut visitor = Discovered(Vec::new());
+    let (max_depth, filter) = compute_visit_limits(max_depth, order, size);
+    compute_dfs(hg, start, max_depth, &filter, &mut visitor);
+    visitor.0
+}
+
+/// `type Node = i64`
+///
+/// Effectively computes the BFS of `bfs_visit`, with the hyperedges to consider already checked.
+///
+/// # Returns
+/// - `bool` - `false` if the visitor stopped the search, `true` otherwise.
+fn compute_bfs(
+    hg: &impl HypergraphOps,
+    start: Node,
+    max_depth: Option<usize>,
+    filter: &impl Fn(&[Node], f64) -> bool,
+    visitor: &mut impl Visitor,
+) -> bool {
+    if !hg.contains_node(start) {
+        return true;
+    }
+
+    let mut visited = AHashSet::from([start]);
+    let mut queue = VecDeque::new();
+    match visitor.discover_node(start, 0) {
+        Control::Stop => return false,
+        control => queue.push_back((start, 0, control == Control::Continue)),
+    }
+
+    // O(n)
+    while let Some((now, depth, expand)) = queue.pop_front() {
+        if expand && max_depth.is_none_or(|max| depth < max) {
+            let mut edge_ids: Vec<EdgeID> = hg.incident_edge_ids(now).into_iter().flatten().collect();
+            edge_ids.sort_unstable();
+
+            // The nodes reached from `now`, with whether to expand them
+            let mut discovered = Vec::new();
+            for edge_id in edge_ids {
+                let nodes = hg.edge_nodes(edge_id).unwrap_or_default();
+                let weight = hg.edge_weight(edge_id).unwrap_or_default();
+                if !filter(nodes, weight) {
+                    continue;
                 }
+                match visitor.traverse_edge(now, edge_id, nodes, weight) {
+                    Control::Stop => return false,
+                    Control::SkipNeighbors => continue,
+                    Control::Continue => {}
+                }
+
+                for node in nodes.iter() {
+                    if visited.insert(*node) {
+                        match visitor.discover_node(*node, depth + 1) {
+                            Control::Stop => return false,
+                            control => discovered.push((*node, control == Control::Continue)),
+                        }
+                    }
+                }
+            }
+
+            discovered.sort_unstable();
+            queue.extend(discovered.into_iter().map(|(node, expand)| (node, depth + 1, expand)));
+        }
+
+        if visitor.finish_node(now) == Control::Stop {
+            return false;
+        }
+    }
+
+    true
+}
+
+// Step of the DFS, the node is left on the stack until all the nodes discovered from it are done
+enum Frame {
+    Enter(Node, usize),
+    Exit(Node),
+}
+
+/// `type Node = i64`
+///
+/// Effectively computes the DFS of `dfs_visit`, with the hyperedges to consider already checked.
+///
+/// # Returns
+/// - `bool` - `false` if the visitor stopped the search, `true` otherwise.
+fn compute_dfs(
+    hg: &impl HypergraphOps,
+    start: Node,
+    max_depth: Option<usize>,
+    filter: &impl Fn(&[Node], f64) -> bool,
+    visitor: &mut impl Visitor,
+) -> bool {
+    if !hg.contains_node(start) {
+        return true;
+    }
+
+    let mut visited = AHashSet::new();
+    // A node may be pushed more than once, only its last push (the deepest in the search) is expanded, as in a
+    // recursive search
+    let mut stack = vec![Frame::Enter(start, 0)];
+
+    while let Some(frame) = stack.pop() {
+        let (now, depth) = match frame {
+            Frame::Enter(node, depth) => (node, depth),
+            Frame::Exit(node) => {
+                if visitor.finish_node(node) == Control::Stop {
+                    return false;
+                }
+                continue;
+            }
+        };
+        if !visited.insert(now) {
+            continue;
+        }
+
+        let control = visitor.discover_node(now, depth);
+        if control == Control::Stop {
+            return false;
+        }
+        stack.push(Frame::Exit(now));
+
+        if control == Control::Continue && max_depth.is_none_or(|max| depth < max) {
+            let mut edge_ids: Vec<EdgeID> = hg.incident_edge_ids(now).into_iter().flatten().collect();
+            edge_ids.sort_unstable();
+
+            let mut neighbors = Vec::new();
+            for edge_id in edge_ids {
+                let nodes = hg.edge_nodes(edge_id).unwrap_or_default();
+                let weight = hg.edge_weight(edge_id).unwrap_or_default();
+                if !filter(nodes, weight) {
+                    continue;
+                }
+                match visitor.traverse_edge(now, edge_id, nodes, weight) {
+                    Control::Stop => return false,
+                    Control::SkipNeighbors => continue,
+                    Control::Continue => {}
+                }
+                neighbors.extend(nodes.iter().copied().filter(|node| !visited.contains(node)));
             }
+
+            // The smallest neighbor is on top of the stack
+            neighbors.sort_unstable_by(|a, b| b.cmp(a));
+            neighbors.dedup();
+            stack.extend(neighbors.into_iter().map(|neighbor| Frame::Enter(neighbor, depth + 1)));
         }
     }
+
+    true
 }
 
+// Nodes in the order of `Visitor::discover_node`
+struct Discovered(Vec<Node>);
+
+impl Visitor for Discovered {
+    fn discover_node(&mut self, node: Node, _depth: usize) -> Control {
+        self.0.push(node);
+        Control::Continue
+    }
+}
+
+// Nodes in the order of `Visitor::finish_node`
+struct Finished(Vec<Node>);
+
+impl Visitor for Finished {
+    fn finish_node(&mut self, node: Node) -> Control {
+        self.0.push(node);
+        Control::Continue
+    }
+}
+
+// Depth at which every node is discovered
+struct Distances(AHashMap<Node, usize>);
+
+impl Visitor for Distances {
+    fn discover_node(&mut self, node: Node, depth: usize) -> Control {
+        self.0.insert(node, depth);
+        Control::Continue
+    }
+}
+
+// Predecessor and hyperedge through which every node is discovered
+struct Tree {
+    current: Option<(Node, EdgeID)>,
+    tree: AHashMap<Node, (Node, EdgeID)>,
+}
+
+impl Visitor for Tree {
+    fn traverse_edge(&mut self, from: Node, edge_id: EdgeID, _edge_nodes: &[Node], _weight: f64) -> Control {
+        self.current = Some((from, edge_id));
+        Control::Continue
+    }
+
+    fn discover_node(&mut self, node: Node, _depth: usize) -> Control {
+        // `start` is discovered before any hyperedge
+        if let Some(current) = self.current {
+            self.tree.insert(node, current);
+        }
+        Control::Continue
+    }
+}
+
+// Nodes grouped by the depth at which they are discovered
+struct Rings(Vec<Vec<Node>>);
+
+impl Visitor for Rings {
+    fn discover_node(&mut self, node: Node, depth: usize) -> Control {
+        // The depths are discovered in increasing order, one level at a time
+        if self.0.len() == depth {
+            self.0.push(Vec::new());
+        }
+        self.0[depth].push(node);
+        Control::Continue
+    }
+}
+
+/// Effectively computes the maximum depth and the hyperedges to consider of the traversals which do not return an
+/// error: if both `order` and `size` are specified, only `start` is visited.
+fn compute_visit_limits(
+    max_depth: Option<usize>,
+    order: Option<usize>,
+    size: Option<usize>,
+) -> (Option<usize>, impl Fn(&[Node], f64) -> bool + Copy) {
+    let (max_depth, size) = match compute_size_filter(order, size) {
+        Ok(size) => (max_depth, size),
+        Err(_) => (Some(0), None),
+    };
+    (max_depth, move |nodes: &[Node], _weight: f64| size.is_none_or(|len| nodes.len() == len))
+}
+
+/// Effectively computes the hyperedges to consider of `bfs_visit` and `dfs_visit`.
+fn compute_options_filter(options: VisitOptions) -> Result<impl Fn(&[Node], f64) -> bool + Copy, HypergraphError> {
+    let size = compute_size_filter(options.order, options.size)?;
+    let min_weight = options.min_weight;
+    Ok(move |nodes: &[Node], weight: f64| {
+        size.is_none_or(|len| nodes.len() == len) && min_weight.is_none_or(|min| weight >= min)
+    })
+}
+
+/// Effectively computes the filter of the `*_filtered` methods equivalent to the given order or size, ie accepting
+/// the hyperedges with that size (at most that size if `up_to`), or all of them if neither is specified.
+///
+/// # Returns
+/// - `Result<impl Fn(&[Node], f64) -> bool + Copy, HypergraphError>` - `Ok` containing the filter. Returns
+///   `Err(HypergraphError::BothOrderAndSize)` if both `order` and `size` are specified.
+pub(crate) fn compute_order_filter(
+    order: Option<usize>,
+    size: Option<usize>,
+    up_to: bool,
+) -> Result<impl Fn(&[Node], f64) -> bool + Copy, HypergraphError> {
+    let size = compute_size_filter(order, size)?;
+    Ok(move |nodes: &[Node], _weight: f64| {
+        size.is_none_or(|len| if up_to { nodes.len() <= len } else { nodes.len() == len })
+    })
+}
+
+/// Effectively computes the size of the hyperedges to consider, following the convention `order == size - 1`.
+///
+/// # Returns
+/// - `Result<Option<usize>, HypergraphError>` - `Ok` containing `Some` size, or `None` if all hyperedges are considered.
+///   Returns `Err(HypergraphError::BothOrderAndSize)` if both `order` and `size` are specified.
+fn compute_size_filter(order: Option<usize>, size: Option<usize>) -> Result<Option<usize>, HypergraphError> {
+    if order.is_some() && size.is_some() {
+        Err(HypergraphError::BothOrderAndSize)
+    } else {
+        Ok(order.map(|val| val + 1).or(size))
+    }
+}
 
 #[cfg(test)]
 pub mod tests {
     use super::*;
+    use crate::Hypergraph;
 
     #[test]
     fn test_bfs_0() {
         let mut hg = Hypergraph::new(true);
-        hg.add_nodes(&vec![1,2,3,4,5,6]);
+        hg.add_nodes(&[1,2,3,4,5,6]);
         
-        hg.add_edge(&vec![1, 2]);
-        hg.add_edge_weighted(&vec![2, 3, 4], 27.7);
-        hg.add_edge(&vec![2, 3, 5]);
-        hg.add_edge(&vec![4, 6, 5]);
+        hg.add_edge(&[1, 2]);
+        hg.add_edge_weighted(&[2, 3, 4], 27.7);
+        hg.add_edge(&[2, 3, 5]);
+        hg.add_edge(&[4, 6, 5]);
 
         let result = _bfs(&hg, 1, None, None, None);
         let expected: AHashSet<Node> = [1, 2, 3, 4, 5, 6].iter().cloned().collect();
@@ -132,6 +949,194 @@ pub mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn test_bfs_order() {
+        let edges = vec![vec![1, 3, 7], vec![2, 4, 3], vec![5, 6, 4], vec![7, 6, 9], vec![3, 9]];
+        let hg = Hypergraph::from(&edges);
+
+        for start in hg.get_nodes() {
+            for (max_depth, size) in [(None, None), (Some(1), None), (None, Some(2)), (Some(2), Some(3))] {
+                let res = bfs_order(&hg, start, max_depth, None, size);
+                let as_set: AHashSet<Node> = res.iter().copied().collect();
+                assert_eq!(res.len(), as_set.len());
+                assert_eq!(as_set, _bfs(&hg, start, max_depth, None, size));
+                assert_eq!(res[0], start);
+            }
+        }
+
+        // Neighbors of 1 are {3, 7}, then 3 discovers {2, 4, 9} and 7 discovers {6}
+        assert_eq!(bfs_order(&hg, 1, None, None, None), vec![1, 3, 7, 2, 4, 9, 6, 5]);
+        assert_eq!(bfs_order(&hg, 1, None, None, None), bfs_order(&hg.clone(), 1, None, None, None));
+        assert!(bfs_order(&hg, 42, None, None, None).is_empty());
+        assert_eq!(bfs_order(&hg, 1, None, Some(1), Some(2)), vec![1]);
+    }
+
+    #[test]
+    fn test_bfs_order_chain() {
+        let edges: Vec<Vec<Node>> = (0..10).map(|i| vec![i, i + 1]).collect();
+        let hg = Hypergraph::from(&edges);
+
+        assert_eq!(bfs_order(&hg, 0, None, None, None), (0..=10).collect::<Vec<Node>>());
+        assert_eq!(bfs_order(&hg, 5, Some(2), None, None), vec![5, 4, 6, 3, 7]);
+
+        // The levels are visited in order
+        let distances = _distances_from(&hg, 5, None, None, None);
+        let levels: Vec<usize> = bfs_order(&hg, 5, None, None, None).iter().map(|node| distances[node]).collect();
+        assert_eq!(levels, vec![0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5]);
+    }
+
+    #[test]
+    fn test_bfs_tree() {
+        let edges = vec![vec![1, 3, 7], vec![2, 4, 3], vec![5, 6, 4], vec![7, 6, 9], vec![3, 9], vec![10, 11]];
+        let hg = Hypergraph::from(&edges);
+
+        for start in hg.get_nodes() {
+            for size in [None, Some(2), Some(3)] {
+                let tree = bfs_tree(&hg, start, None, size);
+                let reached: AHashSet<Node> = tree.keys().copied().chain([start]).collect();
+                assert_eq!(reached, _bfs(&hg, start, None, None, size));
+                assert!(!tree.contains_key(&start));
+
+                for (node, (predecessor, edge_id)) in tree.iter() {
+                    let (nodes, _) = hg.get_edge_by_id(*edge_id).unwrap();
+                    assert!(nodes.contains(node) && nodes.contains(predecessor));
+                    assert!(size.is_none_or(|len| nodes.len() == len));
+
+                    // Walking the predecessors gives a shortest path
+                    let mut path = vec![*node];
+                    while let Some((predecessor, _)) = tree.get(path.last().unwrap()) {
+                        path.push(*predecessor);
+                    }
+                    path.reverse();
+                    let expected = hg.shortest_path(start, *node, None, size).unwrap().unwrap();
+                    assert_eq!(path.len(), expected.len());
+                    assert_eq!(path[0], start);
+                }
+            }
+        }
+
+        let tree = bfs_tree(&hg, 1, None, None);
+        assert_eq!(tree[&6].0, 7);
+        assert_eq!(hg.get_edge_by_id(tree[&6].1), Some((&vec![7, 6, 9], 0.0)));
+        assert_eq!(tree[&5].0, 4);
+        assert!(bfs_tree(&hg, 42, None, None).is_empty());
+        assert!(bfs_tree(&hg, 1, Some(1), Some(2)).is_empty());
+    }
+
+    #[test]
+    fn test_neighbors_at_distance() {
+        let edges: Vec<Vec<Node>> = (0..10).map(|i| vec![i, i + 1]).collect();
+        let mut hg = Hypergraph::from(&edges);
+        hg.add_edge(&[20, 21, 22, 23, 24, 25]);
+
+        // Along the chain every ring has exactly one node
+        for d in 0..=10 {
+            assert_eq!(hg.neighbors_at_distance(0, d, None, None), Ok(Some(vec![d as Node])));
+        }
+        assert_eq!(hg.neighbors_at_distance(0, 11, None, None), Ok(Some(vec![])));
+        assert_eq!(hg.neighbors_at_distance(5, 2, None, None), Ok(Some(vec![3, 7])));
+        assert_eq!(hg.neighbors_at_distance(5, 2, Some(1), None), Ok(Some(vec![3, 7])));
+        assert_eq!(hg.neighbors_at_distance(5, 2, None, Some(3)), Ok(Some(vec![])));
+        assert_eq!(hg.neighbors_at_distance(42, 1, None, None), Ok(None));
+        assert_eq!(hg.neighbors_at_distance(5, 1, Some(1), Some(2)), Err(HypergraphError::BothOrderAndSize));
+
+        // A star through a big hyperedge
+        assert_eq!(hg.neighbors_at_distance(20, 1, None, None), Ok(Some(vec![21, 22, 23, 24, 25])));
+        assert_eq!(hg.neighbors_at_distance(20, 2, None, None), Ok(Some(vec![])));
+
+        assert_eq!(hg.ball(5, 2), Some(vec![vec![5], vec![4, 6], vec![3, 7]]));
+        assert_eq!(hg.ball(9, 3), Some(vec![vec![9], vec![8, 10], vec![7], vec![6]]));
+        assert_eq!(hg.ball(22, 5), Some(vec![vec![22], vec![20, 21, 23, 24, 25]]));
+        assert_eq!(hg.ball(22, 0), Some(vec![vec![22]]));
+        assert_eq!(hg.ball(42, 2), None);
+
+        // The rings partition the result of the bounded BFS
+        let ball: AHashSet<Node> = hg.ball(3, 4).unwrap().into_iter().flatten().collect();
+        assert_eq!(ball, _bfs(&hg, 3, Some(4), None, None));
+    }
+
+    #[test]
+    fn test_edge_bfs() {
+        // Triangles sharing an edge, then a node
+        let hg = Hypergraph::from(&[vec![1, 2, 3], vec![2, 3, 4], vec![4, 5, 6], vec![5, 6, 7], vec![8, 9]]);
+        let id = |edge: &[Node]| Hypergraph::compute_edge_id(edge);
+        let as_set = |ids: Vec<EdgeID>| ids.into_iter().collect::<AHashSet<EdgeID>>();
+
+        let res = edge_bfs(&hg, &[1, 2, 3], 1, None);
+        assert_eq!(res.len(), 4);
+        assert_eq!(res[..2], [id(&[1, 2, 3]), id(&[2, 3, 4])]);
+        assert_eq!(res[3], id(&[5, 6, 7]));
+
+        assert_eq!(as_set(edge_bfs(&hg, &[1, 2, 3], 2, None)), as_set(vec![id(&[1, 2, 3]), id(&[2, 3, 4])]));
+        assert_eq!(as_set(edge_bfs(&hg, &[4, 5, 6], 2, None)), as_set(vec![id(&[4, 5, 6]), id(&[5, 6, 7])]));
+        assert_eq!(edge_bfs(&hg, &[1, 2, 3], 1, Some(2)).len(), 3);
+        assert_eq!(edge_bfs(&hg, &[1, 2, 3], 0, None), edge_bfs(&hg, &[1, 2, 3], 1, None));
+        assert_eq!(edge_bfs(&hg, &[1, 2, 3], 3, None), vec![id(&[1, 2, 3])]);
+        assert_eq!(edge_bfs(&hg, &[8, 9], 1, None), vec![id(&[8, 9])]);
+        assert!(edge_bfs(&hg, &[1, 2], 1, None).is_empty());
+
+        // The same reachability as `s_distance`
+        for s in 1..=3 {
+            for edge in hg.get_edges().unwrap() {
+                let reached = as_set(edge_bfs(&hg, &[1, 2, 3], s, None));
+                assert_eq!(reached.contains(&id(edge)), hg.s_distance(&[1, 2, 3], edge, s).is_some());
+            }
+        }
+    }
+
+    #[test]
+    fn test_forward_bfs() {
+        // 1 + 2 -> 3, 3 -> 4 + 5, 5 + 6 -> 1, 7 -> 8
+        let mut hg = DirectedHypergraph::new(false);
+        hg.add_directed_edge(&[1, 2], &[3]);
+        hg.add_directed_edge(&[3], &[4, 5]);
+        hg.add_directed_edge(&[5, 6], &[1]);
+        hg.add_directed_edge(&[7], &[8]);
+
+        assert_eq!(forward_bfs(&hg, 1, None), vec![1, 3, 4, 5]);
+        assert_eq!(forward_bfs(&hg, 6, None), vec![6, 1, 3, 4, 5]);
+        assert_eq!(forward_bfs(&hg, 3, Some(1)), vec![3, 4, 5]);
+        assert_eq!(forward_bfs(&hg, 5, Some(0)), vec![5]);
+        // The products do not reach the reactants
+        assert_eq!(forward_bfs(&hg, 4, None), vec![4]);
+        assert_eq!(forward_bfs(&hg, 8, None), vec![8]);
+        assert!(forward_bfs(&hg, 9, None).is_empty());
+
+        // While the undirected view reaches the whole component
+        let undirected = Hypergraph::from(&[vec![1, 2, 3], vec![3, 4, 5], vec![5, 6, 1], vec![7, 8]]);
+        assert_eq!(bfs_order(&undirected, 4, None, None, None).len(), 6);
+    }
+
+    #[test]
+    fn test_ego_hypergraph() {
+        let hg =
+            Hypergraph::from(&[vec![1, 2], vec![2, 3], vec![3, 4], vec![4, 5], vec![3, 4, 5, 6], vec![5, 6, 7, 8]]);
+
+        // Along the chain, a larger radius includes more hyperedges
+        let ego = hg.ego_hypergraph(1, 1, None, None, false).unwrap();
+        assert_eq!((ego.num_nodes(), ego.num_edges()), (2, 1));
+        let ego = hg.ego_hypergraph(1, 2, None, None, false).unwrap();
+        assert_eq!((ego.num_nodes(), ego.num_edges()), (3, 2));
+        assert!(ego.is_subhypergraph_of(&hg, true));
+
+        // Ball {3, 4, 5, 6}
+        let ego = hg.ego_hypergraph(4, 1, None, None, false).unwrap();
+        assert_eq!(ego.get_nodes_sorted(), vec![3, 4, 5, 6]);
+        assert_eq!(ego.num_edges(), 3);
+        let ego = hg.ego_hypergraph(4, 1, None, None, true).unwrap();
+        assert_eq!(ego.num_edges(), 4);
+        assert!(ego.check_edge(&[5, 6]) && !ego.check_edge(&[3]));
+
+        let ego = hg.ego_hypergraph(4, 1, None, Some(2), false).unwrap();
+        assert_eq!(ego.get_nodes_sorted(), vec![3, 4, 5]);
+        assert_eq!(ego.get_edges_sorted(), Some(vec![vec![3, 4], vec![4, 5]]));
+
+        let ego = hg.ego_hypergraph(4, 0, None, None, true).unwrap();
+        assert_eq!((ego.num_nodes(), ego.num_edges()), (1, 0));
+        assert_eq!(hg.ego_hypergraph(9, 2, None, None, false).unwrap().num_nodes(), 0);
+        assert_eq!(hg.ego_hypergraph(1, 2, Some(1), Some(2), false), Err(HypergraphError::BothOrderAndSize));
+    }
+
     #[test]
     fn test_bfs_cycle() {
         let edges = vec![vec![1,3,7], vec![2, 4,3], vec![5,6,4], vec![7,6,9], vec![3,9]];
@@ -148,9 +1153,9 @@ pub mod tests {
     fn test_bfs_depth_limit() {
         let mut hg = Hypergraph::new(false);
 
-        hg.add_edge(&vec![1, 2]);
-        hg.add_edge(&vec![2, 3]);
-        hg.add_edge(&vec![3, 4]);
+        hg.add_edge(&[1, 2]);
+        hg.add_edge(&[2, 3]);
+        hg.add_edge(&[3, 4]);
 
         let result = _bfs(&hg, 1, Some(2), None, None);
         let expected: AHashSet<Node> = [1, 2, 3].iter().cloned().collect(); 
@@ -162,10 +1167,10 @@ pub mod tests {
     fn test_bfs_with_size() {
         let mut hg = Hypergraph::new(true);
 
-        hg.add_edge(&vec![1, 2, 3]); 
-        hg.add_edge(&vec![2, 5]);
-        hg.add_edge_weighted(&vec![5, 1], 45.9);
-        hg.add_edge_weighted(&vec![3, 4], 100.1);    
+        hg.add_edge(&[1, 2, 3]); 
+        hg.add_edge(&[2, 5]);
+        hg.add_edge_weighted(&[5, 1], 45.9);
+        hg.add_edge_weighted(&[3, 4], 100.1);    
 
         let result = _bfs(&hg, 1, None, None, Some(2));
         let expected: AHashSet<Node> = [1, 5, 2].iter().cloned().collect();
@@ -176,12 +1181,12 @@ pub mod tests {
     #[test]
     fn test_dfs_0() {
         let mut hg = Hypergraph::new(true);
-        hg.add_nodes(&vec![1,2,3,4,5,6]);
+        hg.add_nodes(&[1,2,3,4,5,6]);
         
-        hg.add_edge(&vec![1, 2]);
-        hg.add_edge_weighted(&vec![2, 3, 4], 27.7);
-        hg.add_edge(&vec![2, 3, 5]);
-        hg.add_edge(&vec![4, 6, 5]);
+        hg.add_edge(&[1, 2]);
+        hg.add_edge_weighted(&[2, 3, 4], 27.7);
+        hg.add_edge(&[2, 3, 5]);
+        hg.add_edge(&[4, 6, 5]);
 
         let result = _dfs(&hg, 1, None, None, None);
         let expected: AHashSet<Node> = [1, 2, 3, 4, 5, 6].iter().cloned().collect();
@@ -205,9 +1210,9 @@ pub mod tests {
     fn test_dfs_depth_limit() {
         let mut hg = Hypergraph::new(false);
 
-        hg.add_edge(&vec![1, 2]);
-        hg.add_edge(&vec![2, 3]);
-        hg.add_edge(&vec![3, 4]);
+        hg.add_edge(&[1, 2]);
+        hg.add_edge(&[2, 3]);
+        hg.add_edge(&[3, 4]);
 
         let result = _dfs(&hg, 1, Some(2), None, None);
         let expected: AHashSet<Node> = [1, 2, 3].iter().cloned().collect(); 
@@ -219,14 +1224,274 @@ pub mod tests {
     fn test_dfs_with_size() {
         let mut hg = Hypergraph::new(true);
 
-        hg.add_edge(&vec![1, 2, 3]); 
-        hg.add_edge(&vec![2,5]);
-        hg.add_edge_weighted(&vec![5, 1], 45.9);
-        hg.add_edge_weighted(&vec![3, 4], 100.1);    
+        hg.add_edge(&[1, 2, 3]); 
+        hg.add_edge(&[2,5]);
+        hg.add_edge_weighted(&[5, 1], 45.9);
+        hg.add_edge_weighted(&[3, 4], 100.1);    
 
         let result = _dfs(&hg, 1, None, None, Some(2)); 
         let expected: AHashSet<Node> = [1, 5, 2].iter().cloned().collect();
 
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn test_dfs_order() {
+        let edges = vec![vec![1, 3, 7], vec![2, 4, 3], vec![5, 6, 4], vec![7, 6, 9], vec![3, 9]];
+        let hg = Hypergraph::from(&edges);
+
+        // 1 -> 3 -> 2 -> 4 -> 5 -> 6 -> 7 -> 9
+        assert_eq!(dfs_order(&hg, 1, None, None, None), vec![1, 3, 2, 4, 5, 6, 7, 9]);
+        assert_eq!(dfs_order(&hg, 1, None, None, Some(2)), vec![1]);
+        assert_eq!(dfs_order(&hg, 3, None, None, Some(2)), vec![3, 9]);
+        // 7 is first reached at depth 2 from 3, so it is not expanded and 6 is not reached, even if 7 is a neighbor of 1
+        assert_eq!(dfs_order(&hg, 1, Some(2), None, None), vec![1, 3, 2, 4, 7, 9]);
+        assert!(dfs_order(&hg, 42, None, None, None).is_empty());
+        assert_eq!(dfs_order(&hg, 1, None, Some(1), Some(2)), vec![1]);
+
+        for start in hg.get_nodes() {
+            for max_depth in [None, Some(1), Some(3)] {
+                let res = dfs_order(&hg, start, max_depth, None, None);
+                let as_set: AHashSet<Node> = res.iter().copied().collect();
+                assert_eq!(res.len(), as_set.len());
+                assert_eq!(as_set, _dfs(&hg, start, max_depth, None, None));
+            }
+        }
+    }
+
+    #[test]
+    fn test_dfs_deep_chain() {
+        let edges: Vec<Vec<Node>> = (0..200_000).map(|i| vec![i, i + 1]).collect();
+        let mut hg = Hypergraph::new(false);
+        hg.add_edges(&edges);
+
+        assert_eq!(_dfs(&hg, 0, None, None, None).len(), 200_001);
+        assert_eq!(dfs_order(&hg, 100_000, None, None, None)[..3], [100_000, 99_999, 99_998]);
+        assert_eq!(_dfs(&hg, 0, Some(10), None, None).len(), 11);
+    }
+
+    #[derive(Default)]
+    struct Counter {
+        nodes: usize,
+        edges: usize,
+        finished: Vec<Node>,
+        max_depth: usize,
+    }
+
+    impl Visitor for Counter {
+        fn discover_node(&mut self, _node: Node, depth: usize) -> Control {
+            self.nodes += 1;
+            self.max_depth = self.max_depth.max(depth);
+            Control::Continue
+        }
+
+        fn traverse_edge(&mut self, _from: Node, _edge_id: EdgeID, _edge_nodes: &[Node], _weight: f64) -> Control {
+            self.edges += 1;
+            Control::Continue
+        }
+
+        fn finish_node(&mut self, node: Node) -> Control {
+            self.finished.push(node);
+            Control::Continue
+        }
+    }
+
+    // Stops as soon as the target is discovered, without expanding the nodes in `skip`
+    struct Search {
+        target: Node,
+        skip: Vec<Node>,
+        discovered: Vec<Node>,
+    }
+
+    impl Visitor for Search {
+        fn discover_node(&mut self, node: Node, _depth: usize) -> Control {
+            self.discovered.push(node);
+            if node == self.target {
+                Control::Stop
+            } else if self.skip.contains(&node) {
+                Control::SkipNeighbors
+            } else {
+                Control::Continue
+            }
+        }
+    }
+
+    #[test]
+    fn test_visitor_counting() {
+        let edges = vec![vec![1, 3, 7], vec![2, 4, 3], vec![5, 6, 4], vec![7, 6, 9], vec![3, 9], vec![10, 11]];
+        let hg = Hypergraph::from(&edges);
+
+        let mut counter = Counter::default();
+        bfs_visit(&hg, 1, VisitOptions::default(), &mut counter).unwrap();
+        assert_eq!(counter.nodes, 8);
+        // Every hyperedge is traversed from each of its nodes
+        assert_eq!(counter.edges, 3 + 3 + 3 + 3 + 2);
+        assert_eq!(counter.finished, bfs_order(&hg, 1, None, None, None));
+        assert_eq!(counter.max_depth, 3);
+
+        let mut counter = Counter::default();
+        dfs_visit(&hg, 1, VisitOptions::default(), &mut counter).unwrap();
+        assert_eq!(counter.nodes, 8);
+        assert_eq!(counter.edges, 14);
+        // Post-order: the first discovered node is the last finished
+        assert_eq!(counter.finished.last(), Some(&1));
+        assert_eq!(counter.finished.len(), 8);
+
+        let options = VisitOptions { max_depth: Some(1), size: Some(3), ..Default::default() };
+        let mut counter = Counter::default();
+        bfs_visit(&hg, 1, options, &mut counter).unwrap();
+        assert_eq!((counter.nodes, counter.edges, counter.max_depth), (3, 1, 1));
+
+        let mut counter = Counter::default();
+        bfs_visit(&hg, 42, VisitOptions::default(), &mut counter).unwrap();
+        assert_eq!((counter.nodes, counter.edges), (0, 0));
+
+        let options = VisitOptions { order: Some(1), size: Some(2), ..Default::default() };
+        assert_eq!(bfs_visit(&hg, 1, options, &mut counter), Err(HypergraphError::BothOrderAndSize));
+        assert_eq!(dfs_visit(&hg, 1, options, &mut counter), Err(HypergraphError::BothOrderAndSize));
+    }
+
+    #[test]
+    fn test_visitor_early_stop() {
+        let edges: Vec<Vec<Node>> = (0..10).map(|i| vec![i, i + 1]).collect();
+        let hg = Hypergraph::from(&edges);
+
+        let mut search = Search { target: 7, skip: vec![], discovered: vec![] };
+        bfs_visit(&hg, 5, VisitOptions::default(), &mut search).unwrap();
+        // 4 and 6 are discovered in the order of their hyperedges, but 4 is expanded first
+        search.discovered[1..3].sort();
+        assert_eq!(search.discovered, vec![5, 4, 6, 3, 7]);
+
+        let mut search = Search { target: 7, skip: vec![], discovered: vec![] };
+        dfs_visit(&hg, 5, VisitOptions::default(), &mut search).unwrap();
+        assert_eq!(search.discovered, vec![5, 4, 3, 2, 1, 0, 6, 7]);
+
+        // 6 is not expanded, so 7 is never reached
+        let mut search = Search { target: 7, skip: vec![6], discovered: vec![] };
+        bfs_visit(&hg, 5, VisitOptions::default(), &mut search).unwrap();
+        search.discovered[1..3].sort();
+        assert_eq!(search.discovered, vec![5, 4, 6, 3, 2, 1, 0]);
+
+        let mut search = Search { target: 7, skip: vec![4, 6], discovered: vec![] };
+        dfs_visit(&hg, 5, VisitOptions::default(), &mut search).unwrap();
+        assert_eq!(search.discovered, vec![5, 4, 6]);
+    }
+
+    #[test]
+    fn test_distances_from() {
+        let mut hg = Hypergraph::from(&[vec![0, 1], vec![1, 2], vec![2, 3], vec![3, 0, 4, 5]]);
+        hg.add_node(9);
+
+        let result = hg.distances_from(0, None, None, Some(2)).unwrap();
+        let expected: AHashMap<Node, usize> = [(0, 0), (1, 1), (2, 2), (3, 3)].into_iter().collect();
+        assert_eq!(result, expected);
+
+        let result = hg.distances_from(0, Some(2), None, Some(2)).unwrap();
+        let expected: AHashMap<Node, usize> = [(0, 0), (1, 1), (2, 2)].into_iter().collect();
+        assert_eq!(result, expected);
+
+        let result = hg.distances_from(0, None, None, None).unwrap();
+        assert_eq!(result[&3], 1);
+        assert_eq!(result[&2], 2);
+        assert!(!result.contains_key(&9));
+
+        assert!(hg.distances_from(42, None, None, None).unwrap().is_empty());
+        assert_eq!(hg.distances_from(0, None, Some(1), Some(2)).unwrap_err(), HypergraphError::BothOrderAndSize);
+    }
+
+    /// Visit through the allocated lists of `get_neighbors`, as the searches were computed before the lazy iterator.
+    fn reference_visit(hg: &Hypergraph, start: Node, order: Option<usize>, size: Option<usize>) -> AHashSet<Node> {
+        let mut visited = AHashSet::new();
+        let mut stack = vec![start];
+        while let Some(now) = stack.pop() {
+            if visited.insert(now) {
+                stack.extend(hg.get_neighbors(now, order, size).unwrap().unwrap());
+            }
+        }
+        visited
+    }
+
+    #[test]
+    fn test_lazy_neighbors_same_visits() {
+        let mut hg = Hypergraph::from(&[
+            vec![0, 1, 2],
+            vec![2, 3],
+            vec![3, 4, 5, 3],
+            vec![5, 6],
+            vec![6, 7, 8],
+            vec![9, 10],
+            vec![10, 11, 12],
+            vec![12],
+        ]);
+        hg.add_node(13);
+
+        for node in hg.get_nodes() {
+            let mut expected = hg.get_neighbors(node, None, None).unwrap().unwrap();
+            expected.sort();
+            let mut lazy: Vec<Node> = hg.iter_neighbors(node).collect();
+            lazy.sort();
+            assert_eq!(lazy, expected);
+            assert_eq!(hg.num_neighbors(node), Some(expected.len()));
+
+            for (order, size) in [(None, None), (Some(1), None), (None, Some(3))] {
+                let expected = reference_visit(&hg, node, order, size);
+                assert_eq!(_bfs(&hg, node, None, order, size), expected);
+                assert_eq!(_dfs(&hg, node, None, order, size), expected);
+            }
+        }
+
+        assert_eq!(hg.iter_neighbors(100).count(), 0);
+        assert_eq!(hg.num_neighbors(100), None);
+        assert_eq!(hg.num_neighbors(13), Some(0));
+        assert_eq!(hg.num_neighbors(3), Some(3));
+    }
+
+    #[test]
+    fn test_visit_min_weight() {
+        // Two clusters joined by a single light hyperedge
+        let mut hg = Hypergraph::new(true);
+        hg.add_edge_weighted(&[1, 2, 3], 5.0);
+        hg.add_edge_weighted(&[4, 5, 6], 4.0);
+        hg.add_edge_weighted(&[3, 4], 0.5);
+
+        let mut counter = Counter::default();
+        bfs_visit(&hg, 1, VisitOptions::default(), &mut counter).unwrap();
+        assert_eq!(counter.nodes, 6);
+
+        let options = VisitOptions { min_weight: Some(1.0), ..Default::default() };
+        let mut counter = Counter::default();
+        bfs_visit(&hg, 1, options, &mut counter).unwrap();
+        assert_eq!((counter.nodes, counter.edges), (3, 3));
+        let mut counter = Counter::default();
+        dfs_visit(&hg, 6, options, &mut counter).unwrap();
+        assert_eq!(counter.nodes, 3);
+
+        // The threshold is inclusive, and combined with the size
+        let options = VisitOptions { min_weight: Some(0.5), size: Some(2), ..Default::default() };
+        let mut counter = Counter::default();
+        bfs_visit(&hg, 3, options, &mut counter).unwrap();
+        assert_eq!(counter.nodes, 2);
+    }
+
+    #[test]
+    fn test_visits_filtered() {
+        let mut hg = Hypergraph::new(true);
+        hg.add_edge_weighted(&[1, 2, 3], 2.0);
+        hg.add_edge_weighted(&[3, 4, 5], 0.5);
+        hg.add_edge_weighted(&[3, 6], 3.0);
+        hg.add_edge_weighted(&[6, 7, 8], 1.5);
+
+        let heavy = |nodes: &[Node], weight: f64| nodes.len() == 3 && weight > 1.0;
+        assert_eq!(_bfs_filtered(&hg, 1, None, heavy), AHashSet::from([1, 2, 3]));
+        assert_eq!(_dfs_filtered(&hg, 8, None, heavy), AHashSet::from([6, 7, 8]));
+        assert_eq!(_bfs_filtered(&hg, 1, None, |_, weight| weight > 1.0).len(), 6);
+        assert_eq!(_dfs_filtered(&hg, 1, Some(1), |_, _| true), _dfs(&hg, 1, Some(1), None, None));
+        assert_eq!(_bfs_filtered(&hg, 1, None, |_, _| false), AHashSet::from([1]));
+        assert!(_bfs_filtered(&hg, 42, None, heavy).is_empty());
+
+        // The order/size versions are the same as their filters
+        let size_3 = |nodes: &[Node], _: f64| nodes.len() == 3;
+        assert_eq!(_bfs(&hg, 1, None, Some(2), None), _bfs_filtered(&hg, 1, None, size_3));
+        assert_eq!(_dfs(&hg, 1, None, None, Some(3)), _dfs_filtered(&hg, 1, None, size_3));
+    }
 }